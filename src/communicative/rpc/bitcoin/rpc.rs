@@ -1,7 +1,7 @@
 use crate::communicative::rpc::bitcoin::error::ValidateRPCError;
 use crate::communicative::rpc::bitcoin::rpcholder::RPCHolder;
 use crate::operative::Chain;
-use bitcoin::{Block, BlockHash};
+use bitcoin::{block::Header, Block, BlockHash};
 use bitcoincore_rpc::{json::GetBlockchainInfoResult, Auth, Client, RpcApi};
 
 /// Validates the Bitcoin RPC.
@@ -87,3 +87,19 @@ pub fn get_block(
 
     Ok(block)
 }
+
+/// Returns the block header at the given height.
+pub fn get_block_header(
+    rpc_holder: &RPCHolder,
+    height: u64,
+) -> Result<Header, bitcoincore_rpc::Error> {
+    let rpc_url = rpc_holder.url();
+    let rpc_user = rpc_holder.user();
+    let rpc_password = rpc_holder.password();
+
+    let rpc_client = Client::new(&rpc_url, Auth::UserPass(rpc_user, rpc_password))?;
+
+    let block_hash: BlockHash = rpc_client.get_block_hash(height)?;
+
+    rpc_client.get_block_header(&block_hash)
+}
@@ -9,7 +9,9 @@ use crate::operative::session::opcovack::OSessionOpCovAck;
 use crate::operative::session::uphold::NSessionUphold;
 use crate::operative::session::upholdack::CSessionUpholdAck;
 use crate::operative::session::upholdnack::CSessionUpholdNack;
+use crate::transmutative::codec::canonical::canonical::Canonical;
 use crate::transmutative::musig::session::MusigSessionCtx;
+use crate::transmutative::noist::beacon::RandomnessBeacon;
 use crate::transmutative::noist::dkg::package::DKGPackage;
 use crate::transmutative::noist::dkg::session::DKGSession;
 use crate::transmutative::noist::setup::keymap::VSEKeyMap;
@@ -18,7 +20,6 @@ use crate::transmutative::secp::authenticable::Authenticable;
 use async_trait::async_trait;
 use chrono::Utc;
 use secp::Scalar;
-use std::collections::HashMap;
 use std::time::Duration;
 
 #[async_trait]
@@ -70,6 +71,12 @@ pub trait TCPClient {
         &self,
         auth_uphold: Authenticable<NSessionUphold>,
     ) -> Result<Result<CSessionUpholdAck, CSessionUpholdNack>, RequestError>;
+
+    /// msg.sender asking the coordinator the next session height and its base ops price.
+    async fn request_base_ops_price(&self) -> Result<(u64, u32), RequestError>;
+
+    /// msg.sender asking the coordinator the randomness beacon of a finalized session.
+    async fn request_beacon(&self, session_height: u64) -> Result<RandomnessBeacon, RequestError>;
 }
 
 #[derive(Copy, Clone)]
@@ -128,8 +135,7 @@ impl TCPClient for PEER {
     ) -> Result<VSEKeyMap, RequestError> {
         let signatory_keys = signatory_keys.clone();
 
-        let payload =
-            serde_json::to_vec(&signatory_keys).map_err(|_| RequestError::InvalidRequest)?;
+        let payload = signatory_keys.encode_canonical();
 
         // Build request package.
         let request_package = {
@@ -207,8 +213,7 @@ impl TCPClient for PEER {
         setup_no: u64,
         package_count: u64,
     ) -> Result<Vec<Authenticable<DKGPackage>>, RequestError> {
-        let payload = serde_json::to_vec(&(setup_no, package_count))
-            .map_err(|_| RequestError::InvalidRequest)?;
+        let payload = (setup_no, package_count).encode_canonical();
 
         // Build request package.
         let request_package = {
@@ -236,7 +241,8 @@ impl TCPClient for PEER {
         };
 
         let auth_packages: Vec<Authenticable<DKGPackage>> =
-            serde_json::from_slice(&response_payload).map_err(|_| RequestError::InvalidResponse)?;
+            Canonical::decode_canonical(&response_payload)
+                .map_err(|_| RequestError::InvalidResponse)?;
 
         Ok(auth_packages)
     }
@@ -250,8 +256,7 @@ impl TCPClient for PEER {
     ) -> Result<(), RequestError> {
         let dkg_sessions_len = dkg_sessions.len() as u64;
 
-        let payload = serde_json::to_vec(&(dir_height, dkg_sessions))
-            .map_err(|_| RequestError::InvalidRequest)?;
+        let payload = (dir_height, dkg_sessions).encode_canonical();
 
         // Build request package.
         let request_package = {
@@ -294,8 +299,7 @@ impl TCPClient for PEER {
     ) -> Result<Vec<Scalar>, RequestError> {
         let requests_len = requests.len() as u64;
 
-        let payload = serde_json::to_vec(&(dir_height, requests.to_owned()))
-            .map_err(|_| RequestError::InvalidRequest)?;
+        let payload = (dir_height, requests.to_owned()).encode_canonical();
 
         // Build request package.
         let request_package = {
@@ -322,8 +326,8 @@ impl TCPClient for PEER {
             _ => response_package.payload(),
         };
 
-        let partial_sigs: Vec<Scalar> =
-            serde_json::from_slice(&response_payload).map_err(|_| RequestError::InvalidResponse)?;
+        let partial_sigs: Vec<Scalar> = Canonical::decode_canonical(&response_payload)
+            .map_err(|_| RequestError::InvalidResponse)?;
 
         Ok(partial_sigs)
     }
@@ -334,7 +338,7 @@ impl TCPClient for PEER {
         &self,
         dir_height: u64,
     ) -> Result<(VSESetup, Vec<DKGSession>), RequestError> {
-        let payload = serde_json::to_vec(&dir_height).map_err(|_| RequestError::InvalidRequest)?;
+        let payload = dir_height.encode_canonical();
 
         // Build request package.
         let request_package = {
@@ -361,25 +365,18 @@ impl TCPClient for PEER {
             _ => response_package.payload(),
         };
 
-        let (setup, sessions): (VSESetup, HashMap<u64, DKGSession>) =
-            match serde_json::from_slice(&response_payload) {
+        // Sessions are received in the order of their indexes.
+        let (setup, sessions): (VSESetup, Vec<DKGSession>) =
+            match Canonical::decode_canonical(&response_payload) {
                 Ok(tuple) => tuple,
                 Err(_) => return Err(RequestError::EmptyResponse),
             };
 
-        let mut sorted_vec: Vec<(u64, DKGSession)> =
-            sessions.into_iter().collect::<Vec<(u64, DKGSession)>>();
-
-        sorted_vec.sort_by_key(|k| k.0);
-
-        let sorted_sessions: Vec<DKGSession> =
-            sorted_vec.into_iter().map(|(_, session)| session).collect();
-
-        Ok((setup, sorted_sessions))
+        Ok((setup, sessions))
     }
 
     async fn request_opcov(&self, opcov: CSessionOpCov) -> Result<OSessionOpCovAck, RequestError> {
-        let payload = serde_json::to_vec(&opcov).map_err(|_| RequestError::InvalidRequest)?;
+        let payload = opcov.encode_canonical();
 
         let request_package = {
             let kind = PackageKind::RequestOpCov;
@@ -403,8 +400,8 @@ impl TCPClient for PEER {
             _ => response_package.payload(),
         };
 
-        let opcovack =
-            serde_json::from_slice(&response_payload).map_err(|_| RequestError::InvalidResponse)?;
+        let opcovack = Canonical::decode_canonical(&response_payload)
+            .map_err(|_| RequestError::InvalidResponse)?;
 
         Ok(opcovack)
     }
@@ -413,7 +410,7 @@ impl TCPClient for PEER {
         &self,
         auth_commit: Authenticable<NSessionCommit>,
     ) -> Result<Result<CSessionCommitAck, CSessionCommitNack>, RequestError> {
-        let payload = serde_json::to_vec(&auth_commit).map_err(|_| RequestError::InvalidRequest)?;
+        let payload = auth_commit.encode_canonical();
 
         let request_package = {
             let kind = PackageKind::CommitSession;
@@ -438,7 +435,8 @@ impl TCPClient for PEER {
         };
 
         let commit_result: Result<CSessionCommitAck, CSessionCommitNack> =
-            serde_json::from_slice(&response_payload).map_err(|_| RequestError::InvalidResponse)?;
+            Canonical::decode_canonical(&response_payload)
+                .map_err(|_| RequestError::InvalidResponse)?;
 
         Ok(commit_result)
     }
@@ -447,7 +445,7 @@ impl TCPClient for PEER {
         &self,
         auth_uphold: Authenticable<NSessionUphold>,
     ) -> Result<Result<CSessionUpholdAck, CSessionUpholdNack>, RequestError> {
-        let payload = serde_json::to_vec(&auth_uphold).map_err(|_| RequestError::InvalidRequest)?;
+        let payload = auth_uphold.encode_canonical();
 
         let request_package = {
             let kind = PackageKind::UpholdSession;
@@ -472,8 +470,72 @@ impl TCPClient for PEER {
         };
 
         let uphold_result: Result<CSessionUpholdAck, CSessionUpholdNack> =
-            serde_json::from_slice(&response_payload).map_err(|_| RequestError::InvalidResponse)?;
+            Canonical::decode_canonical(&response_payload)
+                .map_err(|_| RequestError::InvalidResponse)?;
 
         Ok(uphold_result)
     }
+
+    async fn request_base_ops_price(&self) -> Result<(u64, u32), RequestError> {
+        let payload = [0x00u8];
+
+        let request_package = {
+            let kind = PackageKind::RequestBaseOpsPrice;
+            let timestamp = Utc::now().timestamp();
+            TCPPackage::new(kind, timestamp, &payload)
+        };
+
+        let socket: SOCKET = self
+            .socket()
+            .await
+            .ok_or(RequestError::TCPErr(TCPError::ConnErr))?;
+
+        let timeout = Duration::from_millis(1_000);
+
+        let (response_package, _) = tcp::request(&socket, request_package, Some(timeout))
+            .await
+            .map_err(|err| RequestError::TCPErr(err))?;
+
+        let response_payload = match response_package.payload_len() {
+            0 => return Err(RequestError::EmptyResponse),
+            _ => response_package.payload(),
+        };
+
+        let (session_height, base_ops_price): (u64, u32) =
+            Canonical::decode_canonical(&response_payload)
+                .map_err(|_| RequestError::InvalidResponse)?;
+
+        Ok((session_height, base_ops_price))
+    }
+
+    async fn request_beacon(&self, session_height: u64) -> Result<RandomnessBeacon, RequestError> {
+        let payload = session_height.to_be_bytes();
+
+        let request_package = {
+            let kind = PackageKind::RequestBeacon;
+            let timestamp = Utc::now().timestamp();
+            TCPPackage::new(kind, timestamp, &payload)
+        };
+
+        let socket: SOCKET = self
+            .socket()
+            .await
+            .ok_or(RequestError::TCPErr(TCPError::ConnErr))?;
+
+        let timeout = Duration::from_millis(1_000);
+
+        let (response_package, _) = tcp::request(&socket, request_package, Some(timeout))
+            .await
+            .map_err(RequestError::TCPErr)?;
+
+        let response_payload = match response_package.payload_len() {
+            0 => return Err(RequestError::EmptyResponse),
+            _ => response_package.payload(),
+        };
+
+        let beacon =
+            RandomnessBeacon::from_slice(&response_payload).ok_or(RequestError::InvalidResponse)?;
+
+        Ok(beacon)
+    }
 }
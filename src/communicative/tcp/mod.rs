@@ -1,4 +1,4 @@
 pub mod client;
 pub mod package;
 pub mod server;
-pub mod tcp;
\ No newline at end of file
+pub mod tcp;
@@ -14,6 +14,8 @@ pub enum PackageKind {
     RequestOpCov,
     CommitSession,
     UpholdSession,
+    RequestBaseOpsPrice,
+    RequestBeacon,
 }
 
 impl PackageKind {
@@ -29,6 +31,8 @@ impl PackageKind {
             PackageKind::RequestOpCov => 0x07,
             PackageKind::CommitSession => 0x08,
             PackageKind::UpholdSession => 0x09,
+            PackageKind::RequestBaseOpsPrice => 0x0a,
+            PackageKind::RequestBeacon => 0x0b,
         }
     }
     pub fn from_bytecode(bytecode: u8) -> Option<Self> {
@@ -43,6 +47,8 @@ impl PackageKind {
             0x07 => Some(PackageKind::RequestOpCov),
             0x08 => Some(PackageKind::CommitSession),
             0x09 => Some(PackageKind::UpholdSession),
+            0x0a => Some(PackageKind::RequestBaseOpsPrice),
+            0x0b => Some(PackageKind::RequestBeacon),
             _ => None,
         }
     }
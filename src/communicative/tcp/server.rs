@@ -10,6 +10,7 @@ use crate::operative::session::uphold::NSessionUphold;
 use crate::operative::session::upholdack::CSessionUpholdAck;
 use crate::operative::session::upholdnack::CSessionUpholdNack;
 use crate::operative::{Chain, OperatingMode};
+use crate::transmutative::codec::canonical::canonical::Canonical;
 use crate::transmutative::key::{KeyHolder, ToNostrKeyStr};
 use crate::transmutative::musig::session::MusigSessionCtx;
 use crate::transmutative::noist::dkg::directory::DKG_DIRECTORY;
@@ -277,6 +278,20 @@ async fn handle_package(
                         .await
                 }
 
+                PackageKind::RequestBaseOpsPrice => {
+                    handle_request_base_ops_price(
+                        package.timestamp(),
+                        &package.payload(),
+                        csession_ctx,
+                    )
+                    .await
+                }
+
+                PackageKind::RequestBeacon => {
+                    handle_request_beacon(package.timestamp(), &package.payload(), csession_ctx)
+                        .await
+                }
+
                 _ => return,
             },
             OperatingMode::Operator => match package.kind() {
@@ -351,7 +366,7 @@ async fn handle_request_vse_keymap(
     keys: &KeyHolder,
     _dkg_manager: &mut DKG_MANAGER,
 ) -> Option<TCPPackage> {
-    let signatory_keys: Vec<[u8; 32]> = match serde_json::from_slice(payload) {
+    let signatory_keys: Vec<[u8; 32]> = match Canonical::decode_canonical(payload) {
         Ok(no) => no,
         Err(_) => return None,
     };
@@ -429,7 +444,7 @@ async fn handle_sync_dkg_dir(
     payload: &[u8],
     dkg_manager: &DKG_MANAGER,
 ) -> Option<TCPPackage> {
-    let dir_height: u64 = match serde_json::from_slice(payload) {
+    let dir_height: u64 = match Canonical::decode_canonical(payload) {
         Ok(no) => no,
         Err(_) => return None,
     };
@@ -445,10 +460,11 @@ async fn handle_sync_dkg_dir(
         }
     };
 
-    let payload = match serde_json::to_vec(&(setup, sessions)) {
-        Ok(bytes) => bytes,
-        Err(_) => return None,
-    };
+    // Sessions are sent in the order of their indexes.
+    let mut sessions: Vec<DKGSession> = sessions.into_values().collect();
+    sessions.sort_by_key(|session| session.index());
+
+    let payload = (setup, sessions).encode_canonical();
 
     let response_package = {
         let kind = PackageKind::SyncDKGDir;
@@ -464,7 +480,7 @@ async fn handle_request_dkg_packages(
     dkg_manager: &DKG_MANAGER,
     keys: &KeyHolder,
 ) -> Option<TCPPackage> {
-    let (setup_no, package_count): (u64, u64) = match serde_json::from_slice(payload) {
+    let (setup_no, package_count): (u64, u64) = match Canonical::decode_canonical(payload) {
         Ok(tuple) => tuple,
         Err(_) => return None,
     };
@@ -495,10 +511,7 @@ async fn handle_request_dkg_packages(
         auth_packages.push(auth_package);
     }
 
-    let response_payload = match serde_json::to_vec(&auth_packages) {
-        Ok(bytes) => bytes,
-        Err(_) => return None,
-    };
+    let response_payload = auth_packages.encode_canonical();
 
     let response_package = {
         let kind = PackageKind::RequestDKGPackages;
@@ -513,10 +526,11 @@ async fn handle_deliver_dkg_sessions(
     payload: &[u8],
     dkg_manager: &mut DKG_MANAGER,
 ) -> Option<TCPPackage> {
-    let (dir_height, dkg_sessions): (u64, Vec<DKGSession>) = match serde_json::from_slice(payload) {
-        Ok(tuple) => tuple,
-        Err(_) => return None,
-    };
+    let (dir_height, dkg_sessions): (u64, Vec<DKGSession>) =
+        match Canonical::decode_canonical(payload) {
+            Ok(tuple) => tuple,
+            Err(_) => return None,
+        };
 
     let dkg_dir = {
         let _dkg_manager = dkg_manager.lock().await;
@@ -551,7 +565,7 @@ async fn handle_request_partial_sigs(
     keys: &KeyHolder,
 ) -> Option<TCPPackage> {
     let (dir_height, requests): (u64, Vec<(u64, [u8; 32], Option<MusigSessionCtx>)>) =
-        match serde_json::from_slice(payload) {
+        match Canonical::decode_canonical(payload) {
             Ok(triple) => triple,
             Err(_) => return None,
         };
@@ -581,10 +595,7 @@ async fn handle_request_partial_sigs(
         };
     }
 
-    let response_payload = match serde_json::to_vec(&partial_sigs) {
-        Ok(bytes) => bytes,
-        Err(_) => return None,
-    };
+    let response_payload = partial_sigs.encode_canonical();
 
     let response_package = {
         let kind = PackageKind::RequestPartialSigs;
@@ -601,17 +612,14 @@ async fn _handle_request_opcov(
     dkg_manager: &mut DKG_MANAGER,
     keys: &KeyHolder,
 ) -> Option<TCPPackage> {
-    let opcov: CSessionOpCov = match serde_json::from_slice(payload) {
+    let opcov: CSessionOpCov = match Canonical::decode_canonical(payload) {
         Ok(opcov) => opcov,
         Err(_) => return None,
     };
 
     let opcovack = opcov.opcovack(dkg_manager, keys).await?;
 
-    let response_payload = match serde_json::to_vec(&opcovack) {
-        Ok(bytes) => bytes,
-        Err(_) => return None,
-    };
+    let response_payload = opcovack.encode_canonical();
 
     let response_package = {
         let kind = PackageKind::RequestOpCov;
@@ -628,7 +636,7 @@ async fn handle_commit_session(
     csession_ctx: &Option<CSESSION_CTX>,
 ) -> Option<TCPPackage> {
     let csession_ctx: CSESSION_CTX = Arc::clone(&csession_ctx.to_owned()?);
-    let auth_commit: Authenticable<NSessionCommit> = serde_json::from_slice(payload).ok()?;
+    let auth_commit: Authenticable<NSessionCommit> = Canonical::decode_canonical(payload).ok()?;
     let account = auth_commit.object().account();
 
     // Wait until the session is on.
@@ -672,10 +680,7 @@ async fn handle_commit_session(
         }
     };
 
-    let response_payload = match serde_json::to_vec(&commit_result) {
-        Ok(bytes) => bytes,
-        Err(_) => return None,
-    };
+    let response_payload = commit_result.encode_canonical();
 
     let response_package = {
         let kind = PackageKind::CommitSession;
@@ -692,7 +697,7 @@ async fn handle_uphold_session(
     csession_ctx: &Option<CSESSION_CTX>,
 ) -> Option<TCPPackage> {
     let csession_ctx: CSESSION_CTX = Arc::clone(&csession_ctx.to_owned()?);
-    let auth_uphold: Authenticable<NSessionUphold> = serde_json::from_slice(payload).ok()?;
+    let auth_uphold: Authenticable<NSessionUphold> = Canonical::decode_canonical(payload).ok()?;
     let msg_sender = auth_uphold.object().msg_sender();
 
     // Check if the session is locked.
@@ -743,10 +748,7 @@ async fn handle_uphold_session(
         }
     };
 
-    let response_payload = match serde_json::to_vec(&uphold_result) {
-        Ok(bytes) => bytes,
-        Err(_) => return None,
-    };
+    let response_payload = uphold_result.encode_canonical();
 
     let response_package = {
         let kind = PackageKind::UpholdSession;
@@ -755,3 +757,62 @@ async fn handle_uphold_session(
 
     Some(response_package)
 }
+
+/// Coordinator handling msg.sender's next base ops price request.
+async fn handle_request_base_ops_price(
+    timestamp: i64,
+    payload: &[u8],
+    csession_ctx: &Option<CSESSION_CTX>,
+) -> Option<TCPPackage> {
+    // Expected payload: 0x00.
+    if payload != [0x00] {
+        return None;
+    }
+
+    let csession_ctx: CSESSION_CTX = Arc::clone(&csession_ctx.to_owned()?);
+
+    let price_dir = { csession_ctx.lock().await.price_dir() };
+
+    let (session_height, base_ops_price) = {
+        let _price_dir = price_dir.lock().await;
+        (
+            _price_dir.next_session_height(),
+            _price_dir.next_base_ops_price(),
+        )
+    };
+
+    let response_payload = (session_height, base_ops_price).encode_canonical();
+
+    let response_package = {
+        let kind = PackageKind::RequestBaseOpsPrice;
+        TCPPackage::new(kind, timestamp, &response_payload)
+    };
+
+    Some(response_package)
+}
+
+/// Coordinator handling msg.sender's randomness beacon request.
+async fn handle_request_beacon(
+    timestamp: i64,
+    payload: &[u8],
+    csession_ctx: &Option<CSESSION_CTX>,
+) -> Option<TCPPackage> {
+    // Expected payload: the session height.
+    let session_height = u64::from_be_bytes(payload.try_into().ok()?);
+
+    let csession_ctx: CSESSION_CTX = Arc::clone(&csession_ctx.to_owned()?);
+
+    let beacon_dir = { csession_ctx.lock().await.beacon_dir() };
+
+    let beacon = {
+        let _beacon_dir = beacon_dir.lock().await;
+        _beacon_dir.beacon(session_height)?
+    };
+
+    let response_package = {
+        let kind = PackageKind::RequestBeacon;
+        TCPPackage::new(kind, timestamp, &beacon.serialize())
+    };
+
+    Some(response_package)
+}
@@ -0,0 +1,137 @@
+use crate::{
+    constructive::{
+        calldata::{element::element::CallElement, element_type::CallElementType},
+        entity::{account::account::Account, contract::contract::Contract},
+        valtype::val::{long_val::long_val::LongVal, short_val::short_val::ShortVal},
+    },
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+        writer::CanonicalWriter,
+    },
+};
+
+/// The canonical encoding for `CallElement`.
+///
+/// An element is its type tag, the first byte of its type bytecode, followed by its value.
+/// Composite items carry their own tag, and arrays are preceded by the item type bytecode.
+impl Canonical for CallElement {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        match self {
+            CallElement::U8(value) => {
+                bytes.push(0x00);
+                bytes.push(*value);
+            }
+            CallElement::U16(value) => {
+                bytes.push(0x01);
+                bytes.extend(value.to_le_bytes());
+            }
+            CallElement::U32(value) => {
+                bytes.push(0x02);
+                bytes.extend(value.value().to_le_bytes());
+            }
+            CallElement::U64(value) => {
+                bytes.push(0x03);
+                bytes.extend(value.value().to_le_bytes());
+            }
+            CallElement::Bool(value) => {
+                bytes.push(0x04);
+                bytes.write_bool(*value);
+            }
+            CallElement::Account(account) => {
+                bytes.push(0x05);
+                account.encode_fields(bytes);
+            }
+            CallElement::Contract(contract) => {
+                bytes.push(0x06);
+                contract.encode_fields(bytes);
+            }
+            CallElement::Bytes(value) => {
+                bytes.push(0x07);
+                bytes.write_bytes(value);
+            }
+            CallElement::Varbytes(value) => {
+                bytes.push(0x08);
+                bytes.write_bytes(value);
+            }
+            CallElement::Payable(value) => {
+                bytes.push(0x09);
+                bytes.extend(value.value().to_le_bytes());
+            }
+            CallElement::U128(value) => {
+                bytes.push(0x0a);
+                bytes.extend(value.to_le_bytes());
+            }
+            CallElement::U256(value) => {
+                bytes.push(0x0b);
+                bytes.extend(value);
+            }
+            CallElement::String(value) => {
+                bytes.push(0x0c);
+                bytes.write_bytes(value.as_bytes());
+            }
+            CallElement::Array(item_type, items) => {
+                bytes.push(0x0d);
+                bytes.extend(item_type.bytecode());
+                bytes.write_list(items, |bytes, item| item.encode_fields(bytes));
+            }
+            CallElement::Vararray(item_type, items) => {
+                bytes.push(0x0e);
+                bytes.extend(item_type.bytecode());
+                bytes.write_list(items, |bytes, item| item.encode_fields(bytes));
+            }
+            CallElement::Tuple(items) => {
+                bytes.push(0x0f);
+                bytes.write_list(items, |bytes, item| item.encode_fields(bytes));
+            }
+        }
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<CallElement, CanonicalDecodeError> {
+        match reader.read_u8()? {
+            0x00 => Ok(CallElement::U8(reader.read_u8()?)),
+            0x01 => Ok(CallElement::U16(reader.read_u16()?)),
+            0x02 => Ok(CallElement::U32(ShortVal::new(reader.read_u32()?))),
+            0x03 => Ok(CallElement::U64(LongVal::new(reader.read_u64()?))),
+            0x04 => Ok(CallElement::Bool(reader.read_bool()?)),
+            0x05 => Ok(CallElement::Account(Account::decode_fields(reader)?)),
+            0x06 => Ok(CallElement::Contract(Contract::decode_fields(reader)?)),
+            0x07 => Ok(CallElement::Bytes(reader.read_bytes()?)),
+            0x08 => Ok(CallElement::Varbytes(reader.read_bytes()?)),
+            0x09 => Ok(CallElement::Payable(ShortVal::new(reader.read_u32()?))),
+            0x0a => Ok(CallElement::U128(u128::from_le_bytes(
+                reader.read_array::<16>()?,
+            ))),
+            0x0b => Ok(CallElement::U256(reader.read_array::<32>()?)),
+            0x0c => {
+                let string = String::from_utf8(reader.read_bytes()?)
+                    .map_err(|_| CanonicalDecodeError::InvalidString)?;
+                Ok(CallElement::String(string))
+            }
+            0x0d => {
+                let item_type = read_element_type(reader)?;
+                let items = reader.read_list(CallElement::decode_fields)?;
+                Ok(CallElement::Array(item_type, items))
+            }
+            0x0e => {
+                let item_type = read_element_type(reader)?;
+                let items = reader.read_list(CallElement::decode_fields)?;
+                Ok(CallElement::Vararray(item_type, items))
+            }
+            0x0f => Ok(CallElement::Tuple(
+                reader.read_list(CallElement::decode_fields)?,
+            )),
+            tag => Err(CanonicalDecodeError::InvalidTag(tag)),
+        }
+    }
+}
+
+/// Reads an element type from its bytecode.
+fn read_element_type(
+    reader: &mut CanonicalReader<'_>,
+) -> Result<CallElementType, CanonicalDecodeError> {
+    let mut bytecode_stream = std::iter::from_fn(|| reader.read_u8().ok());
+    CallElementType::from_bytecode(&mut bytecode_stream)
+        .ok_or(CanonicalDecodeError::InvalidElementType)
+}
@@ -1,11 +1,14 @@
 use crate::constructive::calldata::element::cpe::decode::decode_error::{
-    AccountArgCPEDecodingError, BoolArgCPEDecodingError, BytesArgCPEDecodingError,
-    CallArgCPEDecodingError, ContractArgCPEDecodingError, PayableArgCPEDecodingError,
-    U16ArgCPEDecodingError, U32ArgCPEDecodingError, U64ArgCPEDecodingError, U8ArgCPEDecodingError,
-    VarbytesArgCPEDecodingError,
+    AccountArgCPEDecodingError, ArrayArgCPEDecodingError, BoolArgCPEDecodingError,
+    BytesArgCPEDecodingError, CallArgCPEDecodingError, ContractArgCPEDecodingError,
+    PayableArgCPEDecodingError, StringArgCPEDecodingError, TupleArgCPEDecodingError,
+    U128ArgCPEDecodingError, U16ArgCPEDecodingError, U256ArgCPEDecodingError,
+    U32ArgCPEDecodingError, U64ArgCPEDecodingError, U8ArgCPEDecodingError,
+    VararrayArgCPEDecodingError, VarbytesArgCPEDecodingError,
 };
 use crate::constructive::calldata::element::element::CallElement;
 use crate::constructive::calldata::element_type::CallElementType;
+use crate::constructive::calldata::limits::{MAX_TUPLE_LENGTH, MIN_TUPLE_LENGTH};
 use crate::constructive::entity::account::account::Account;
 use crate::constructive::entity::contract::contract::Contract;
 use crate::constructive::valtype::maybe_common::maybe_common::maybe_common::MaybeCommon;
@@ -22,6 +25,130 @@ impl CallElement {
         bit_stream: &mut bit_vec::Iter<'_>,
         element_type: CallElementType,
         registery: &REGISTERY,
+    ) -> Result<Self, CallArgCPEDecodingError> {
+        // Match on the calldata element type.
+        match element_type {
+            // Decode the `Array`.
+            CallElementType::Array(item_type, index) => {
+                // Check the item type.
+                if !item_type.is_item_type() {
+                    return Err(CallArgCPEDecodingError::Array(
+                        ArrayArgCPEDecodingError::InvalidItemType,
+                    ));
+                }
+
+                // Array length is the index + 1.
+                let length = index as usize + 1;
+
+                // Decode the items.
+                let mut items = Vec::<CallElement>::with_capacity(length);
+                for item_index in 0..length {
+                    let item = Self::decode_item_cpe(bit_stream, *item_type.clone(), registery)
+                        .await
+                        .map_err(|e| {
+                            CallArgCPEDecodingError::Array(ArrayArgCPEDecodingError::Item(
+                                item_index,
+                                Box::new(e),
+                            ))
+                        })?;
+                    items.push(item);
+                }
+
+                // Construct the `CallElement`.
+                let element = CallElement::Array(*item_type, items);
+
+                // Return the element.
+                Ok(element)
+            }
+
+            // Decode the `Vararray`.
+            CallElementType::Vararray(item_type) => {
+                // Check the item type.
+                if !item_type.is_item_type() {
+                    return Err(CallArgCPEDecodingError::Vararray(
+                        VararrayArgCPEDecodingError::InvalidItemType,
+                    ));
+                }
+
+                // Collect 8 bits representing the length.
+                let mut length_bits = BitVec::new();
+                for _ in 0..8 {
+                    length_bits.push(bit_stream.next().ok_or(
+                        CallArgCPEDecodingError::Vararray(
+                            VararrayArgCPEDecodingError::CollectVararrayLengthBitsError,
+                        ),
+                    )?);
+                }
+                let length = length_bits.to_bytes()[0] as usize;
+
+                // Decode the items.
+                let mut items = Vec::<CallElement>::with_capacity(length);
+                for item_index in 0..length {
+                    let item = Self::decode_item_cpe(bit_stream, *item_type.clone(), registery)
+                        .await
+                        .map_err(|e| {
+                            CallArgCPEDecodingError::Vararray(VararrayArgCPEDecodingError::Item(
+                                item_index,
+                                Box::new(e),
+                            ))
+                        })?;
+                    items.push(item);
+                }
+
+                // Construct the `CallElement`.
+                let element = CallElement::Vararray(*item_type, items);
+
+                // Return the element.
+                Ok(element)
+            }
+
+            // Decode the `Tuple`.
+            CallElementType::Tuple(item_types) => {
+                // Check the tuple length.
+                if !(MIN_TUPLE_LENGTH..=MAX_TUPLE_LENGTH).contains(&item_types.len()) {
+                    return Err(CallArgCPEDecodingError::Tuple(
+                        TupleArgCPEDecodingError::InvalidTupleLength(item_types.len()),
+                    ));
+                }
+
+                // Decode the items.
+                let mut items = Vec::<CallElement>::with_capacity(item_types.len());
+                for (item_index, item_type) in item_types.into_iter().enumerate() {
+                    // Check the item type.
+                    if !item_type.is_item_type() {
+                        return Err(CallArgCPEDecodingError::Tuple(
+                            TupleArgCPEDecodingError::InvalidItemType(item_index),
+                        ));
+                    }
+
+                    let item = Self::decode_item_cpe(bit_stream, item_type, registery)
+                        .await
+                        .map_err(|e| {
+                            CallArgCPEDecodingError::Tuple(TupleArgCPEDecodingError::Item(
+                                item_index,
+                                Box::new(e),
+                            ))
+                        })?;
+                    items.push(item);
+                }
+
+                // Construct the `CallElement`.
+                let element = CallElement::Tuple(items);
+
+                // Return the element.
+                Ok(element)
+            }
+
+            // Decode a non-composite element.
+            element_type => Self::decode_item_cpe(bit_stream, element_type, registery).await,
+        }
+    }
+
+    /// Decodes a non-composite `CallElement` from a bit stream.
+    async fn decode_item_cpe(
+        bit_stream: &mut bit_vec::Iter<'_>,
+        element_type: CallElementType,
+        registery: &REGISTERY,
     ) -> Result<Self, CallArgCPEDecodingError> {
         // Match on the calldata element type.
         match element_type {
@@ -279,10 +406,157 @@ impl CallElement {
                 // Return the element.
                 Ok(element)
             }
+
+            // Decode the u128.
+            CallElementType::U128 => {
+                // Decode the minimal little-endian bytes with a 5-bit byte length.
+                let bytes = decode_minimal_uint::<16>(bit_stream, 5).map_err(|e| {
+                    CallArgCPEDecodingError::U128(match e {
+                        MinimalUintError::CollectByteLengthBits => {
+                            U128ArgCPEDecodingError::CollectByteLengthBitsError
+                        }
+                        MinimalUintError::ByteLengthTooLarge(byte_length) => {
+                            U128ArgCPEDecodingError::ByteLengthGreaterThan16Error(byte_length)
+                        }
+                        MinimalUintError::CollectDataBits => {
+                            U128ArgCPEDecodingError::CollectDataBitsError
+                        }
+                        MinimalUintError::NonMinimal => U128ArgCPEDecodingError::NonMinimalError,
+                    })
+                })?;
+
+                // Construct the `CallElement`.
+                let element = CallElement::U128(u128::from_le_bytes(bytes));
+
+                // Return the element.
+                Ok(element)
+            }
+
+            // Decode the u256.
+            CallElementType::U256 => {
+                // Decode the minimal little-endian bytes with a 6-bit byte length.
+                let bytes = decode_minimal_uint::<32>(bit_stream, 6).map_err(|e| {
+                    CallArgCPEDecodingError::U256(match e {
+                        MinimalUintError::CollectByteLengthBits => {
+                            U256ArgCPEDecodingError::CollectByteLengthBitsError
+                        }
+                        MinimalUintError::ByteLengthTooLarge(byte_length) => {
+                            U256ArgCPEDecodingError::ByteLengthGreaterThan32Error(byte_length)
+                        }
+                        MinimalUintError::CollectDataBits => {
+                            U256ArgCPEDecodingError::CollectDataBitsError
+                        }
+                        MinimalUintError::NonMinimal => U256ArgCPEDecodingError::NonMinimalError,
+                    })
+                })?;
+
+                // Construct the `CallElement`.
+                let element = CallElement::U256(bytes);
+
+                // Return the element.
+                Ok(element)
+            }
+
+            // Decode the `String`.
+            CallElementType::String => {
+                // Collect 12 bits representing the byte length.
+                // Supported byte-length range: 0 to 4095.
+                let mut byte_length_bits = BitVec::new();
+                for _ in 0..12 {
+                    byte_length_bits.push(bit_stream.next().ok_or(
+                        CallArgCPEDecodingError::String(
+                            StringArgCPEDecodingError::CollectStringLengthBitsError,
+                        ),
+                    )?);
+                }
+
+                // Convert the byte length bits to a u16.
+                let byte_length = convert_12_bits_to_u16(&byte_length_bits);
+
+                // Collect the data bit by bit.
+                let mut data_bits = BitVec::new();
+                for _ in 0..byte_length as usize * 8 {
+                    data_bits.push(bit_stream.next().ok_or(CallArgCPEDecodingError::String(
+                        StringArgCPEDecodingError::CollectStringDataBitsError,
+                    ))?);
+                }
+
+                // The bytes must be valid UTF-8.
+                let string = String::from_utf8(data_bits.to_bytes()).map_err(|_| {
+                    CallArgCPEDecodingError::String(StringArgCPEDecodingError::InvalidUTF8Error)
+                })?;
+
+                // Construct the `CallElement`.
+                let element = CallElement::String(string);
+
+                // Return the element.
+                Ok(element)
+            }
+
+            // Composites are not nested.
+            CallElementType::Array(_, _) => Err(CallArgCPEDecodingError::Array(
+                ArrayArgCPEDecodingError::InvalidItemType,
+            )),
+            CallElementType::Vararray(_) => Err(CallArgCPEDecodingError::Vararray(
+                VararrayArgCPEDecodingError::InvalidItemType,
+            )),
+            CallElementType::Tuple(_) => Err(CallArgCPEDecodingError::Tuple(
+                TupleArgCPEDecodingError::InvalidItemType(0),
+            )),
         }
     }
 }
 
+/// Errors that can occur when decoding a minimally encoded integer.
+enum MinimalUintError {
+    CollectByteLengthBits,
+    ByteLengthTooLarge(u8),
+    CollectDataBits,
+    NonMinimal,
+}
+
+/// Decodes little-endian integer bytes with the trailing zero bytes left out,
+/// prefixed with the byte length in the given number of bits.
+fn decode_minimal_uint<const N: usize>(
+    bit_stream: &mut bit_vec::Iter<'_>,
+    length_bit_count: usize,
+) -> Result<[u8; N], MinimalUintError> {
+    // Collect the byte length.
+    let mut byte_length = 0u8;
+    for i in 0..length_bit_count {
+        if bit_stream
+            .next()
+            .ok_or(MinimalUintError::CollectByteLengthBits)?
+        {
+            byte_length |= 1 << i;
+        }
+    }
+
+    // Check the byte length.
+    if byte_length as usize > N {
+        return Err(MinimalUintError::ByteLengthTooLarge(byte_length));
+    }
+
+    // Collect the data bits.
+    let mut data_bits = BitVec::new();
+    for _ in 0..byte_length as usize * 8 {
+        data_bits.push(bit_stream.next().ok_or(MinimalUintError::CollectDataBits)?);
+    }
+    let data_bytes = data_bits.to_bytes();
+
+    // The most significant byte cannot be zero.
+    if data_bytes.last() == Some(&0) {
+        return Err(MinimalUintError::NonMinimal);
+    }
+
+    // Pad the bytes to the full width.
+    let mut bytes = [0u8; N];
+    bytes[..data_bytes.len()].copy_from_slice(&data_bytes);
+
+    // Return the bytes.
+    Ok(bytes)
+}
+
 /// Converts 12 bits to a u16.
 fn convert_12_bits_to_u16(bits: &BitVec) -> u16 {
     // Initialize a u16 value.
@@ -12,6 +12,15 @@ type BytesLength = usize;
 /// Type alias for the varbytes byte length.
 type VarbytesByteLength = u16;
 
+/// Type alias for the byte length of a minimally encoded integer.
+type UintByteLength = u8;
+
+/// Type alias for the item index of a composite element.
+type ItemIndex = usize;
+
+/// Type alias for the tuple length.
+type TupleLength = usize;
+
 /// Enum to represent errors that can occur when decoding a `CallElement` from a bit stream.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum U8ArgCPEDecodingError {
@@ -77,6 +86,55 @@ pub enum PayableArgCPEDecodingError {
     MaybeCommonShortValCPEDecodingError(MaybeCommonCPEDecodingError),
 }
 
+/// Enum to represent errors that can occur when decoding a `CallElement` from a bit stream.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum U128ArgCPEDecodingError {
+    CollectByteLengthBitsError,
+    ByteLengthGreaterThan16Error(UintByteLength),
+    CollectDataBitsError,
+    NonMinimalError,
+}
+
+/// Enum to represent errors that can occur when decoding a `CallElement` from a bit stream.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum U256ArgCPEDecodingError {
+    CollectByteLengthBitsError,
+    ByteLengthGreaterThan32Error(UintByteLength),
+    CollectDataBitsError,
+    NonMinimalError,
+}
+
+/// Enum to represent errors that can occur when decoding a `CallElement` from a bit stream.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StringArgCPEDecodingError {
+    CollectStringLengthBitsError,
+    CollectStringDataBitsError,
+    InvalidUTF8Error,
+}
+
+/// Enum to represent errors that can occur when decoding a `CallElement` from a bit stream.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ArrayArgCPEDecodingError {
+    InvalidItemType,
+    Item(ItemIndex, Box<CallArgCPEDecodingError>),
+}
+
+/// Enum to represent errors that can occur when decoding a `CallElement` from a bit stream.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VararrayArgCPEDecodingError {
+    CollectVararrayLengthBitsError,
+    InvalidItemType,
+    Item(ItemIndex, Box<CallArgCPEDecodingError>),
+}
+
+/// Enum to represent errors that can occur when decoding a `CallElement` from a bit stream.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TupleArgCPEDecodingError {
+    InvalidTupleLength(TupleLength),
+    InvalidItemType(ItemIndex),
+    Item(ItemIndex, Box<CallArgCPEDecodingError>),
+}
+
 /// Enum to represent errors that can occur when decoding a `CallElement` from a bit stream.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CallArgCPEDecodingError {
@@ -90,4 +148,10 @@ pub enum CallArgCPEDecodingError {
     Bytes(BytesArgCPEDecodingError),
     Varbytes(VarbytesArgCPEDecodingError),
     Payable(PayableArgCPEDecodingError),
+    U128(U128ArgCPEDecodingError),
+    U256(U256ArgCPEDecodingError),
+    String(StringArgCPEDecodingError),
+    Array(ArrayArgCPEDecodingError),
+    Vararray(VararrayArgCPEDecodingError),
+    Tuple(TupleArgCPEDecodingError),
 }
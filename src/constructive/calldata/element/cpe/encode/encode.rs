@@ -1,9 +1,16 @@
+use crate::constructive::calldata::element::cpe::encode::encode_error::CallArgCPEEncodeError;
 use crate::constructive::calldata::element::element::CallElement;
+use crate::constructive::calldata::element_type::CallElementType;
+use crate::constructive::calldata::limits::{
+    MAX_ARRAY_LENGTH, MAX_STRING_BYTE_LENGTH, MAX_TUPLE_LENGTH, MAX_VARARRAY_LENGTH,
+    MIN_TUPLE_LENGTH,
+};
+use crate::constructive::valtype::maybe_common::maybe_common::maybe_common::MaybeCommon;
 use bit_vec::BitVec;
 
 impl CallElement {
     /// Encodes the `CallElement` as a bit vector.
-    pub fn encode_cpe(&self) -> BitVec {
+    pub fn encode_cpe(&self) -> Result<BitVec, CallArgCPEEncodeError> {
         // Match on the element type.
         match self {
             CallElement::U8(u8_value) => {
@@ -17,7 +24,7 @@ impl CallElement {
                 let bits = BitVec::from_bytes(&byte);
 
                 // Return the bits.
-                bits
+                Ok(bits)
             }
             CallElement::U16(u16_value) => {
                 // Get the u16 value.
@@ -30,21 +37,25 @@ impl CallElement {
                 let bits = BitVec::from_bytes(&bytes);
 
                 // Return the bits.
-                bits
+                Ok(bits)
             }
             CallElement::U32(short_val) => {
-                // Encode the `ShortVal`.
-                let bits = short_val.encode_cpe();
+                // Encode the `ShortVal` as `MaybeCommon<ShortVal>`.
+                let bits = MaybeCommon::new(*short_val)
+                    .encode_cpe()
+                    .map_err(CallArgCPEEncodeError::U32)?;
 
                 // Return the bits.
-                bits
+                Ok(bits)
             }
             CallElement::U64(long_val) => {
-                // Encode the `LongVal`.
-                let bits = long_val.encode_cpe();
+                // Encode the `LongVal` as `MaybeCommon<LongVal>`.
+                let bits = MaybeCommon::new(*long_val)
+                    .encode_cpe()
+                    .map_err(CallArgCPEEncodeError::U64)?;
 
                 // Return the bits.
-                bits
+                Ok(bits)
             }
             CallElement::Bool(value) => {
                 // Get the bool value.
@@ -57,28 +68,28 @@ impl CallElement {
                 bits.push(bool);
 
                 // Return the bits.
-                bits
+                Ok(bits)
             }
             CallElement::Account(account) => {
                 // Encode the `Account`.
                 let bits = account.encode_cpe();
 
                 // Return the bits.
-                bits
+                Ok(bits)
             }
             CallElement::Contract(contract) => {
                 // Encode the `Contract`.
                 let bits = contract.encode_cpe();
 
                 // Return the bits.
-                bits
+                Ok(bits)
             }
             CallElement::Bytes(bytes) => {
                 // Encode the bytes.
                 let bits = BitVec::from_bytes(bytes);
 
                 // Return the bits.
-                bits
+                Ok(bits)
             }
             CallElement::Varbytes(bytes) => {
                 // Initialize bit vector to fill with length plus data.
@@ -96,7 +107,7 @@ impl CallElement {
                 // If data length is 0, return the bit vector with length-bits-only.
                 // This is to avoid encoding empty data, as data can be empty.
                 if byte_length == 0 {
-                    return bits;
+                    return Ok(bits);
                 }
 
                 // Get the data bits.
@@ -106,17 +117,147 @@ impl CallElement {
                 bits.extend(data_bits);
 
                 // Return the bits.
-                bits
+                Ok(bits)
             }
             CallElement::Payable(short_val) => {
-                // Encode the `ShortVal`.
-                let bits = short_val.encode_cpe();
+                // Encode the `ShortVal` as `MaybeCommon<ShortVal>`.
+                let bits = MaybeCommon::new(*short_val)
+                    .encode_cpe()
+                    .map_err(CallArgCPEEncodeError::Payable)?;
 
                 // Return the bits.
-                bits
+                Ok(bits)
+            }
+            CallElement::U128(value) => {
+                // Encode the minimal little-endian bytes with a 5-bit byte length (0-16).
+                let bits = encode_minimal_uint(&value.to_le_bytes(), 5);
+
+                // Return the bits.
+                Ok(bits)
+            }
+            CallElement::U256(bytes) => {
+                // Encode the minimal little-endian bytes with a 6-bit byte length (0-32).
+                let bits = encode_minimal_uint(bytes, 6);
+
+                // Return the bits.
+                Ok(bits)
+            }
+            CallElement::String(string) => {
+                // Get the string bytes.
+                let bytes = string.as_bytes();
+
+                // Check the byte length.
+                if bytes.len() > MAX_STRING_BYTE_LENGTH {
+                    return Err(CallArgCPEEncodeError::StringByteLengthGreaterThan4095Error(
+                        bytes.len(),
+                    ));
+                }
+
+                // Initialize bit vector to fill with length plus data.
+                let mut bits = BitVec::new();
+
+                // Extend the bit vector with the 12-bit byte length.
+                bits.extend(convert_u16_to_12_bits(bytes.len() as u16));
+
+                // Extend the bit vector with the data bits.
+                bits.extend(BitVec::from_bytes(bytes));
+
+                // Return the bits.
+                Ok(bits)
+            }
+            CallElement::Array(item_type, items) => {
+                // Check the array length.
+                if items.is_empty() || items.len() > MAX_ARRAY_LENGTH {
+                    return Err(CallArgCPEEncodeError::InvalidArrayLength(items.len()));
+                }
+
+                // The array length is implied by the element type.
+                encode_items(items, Some(item_type))
+            }
+            CallElement::Vararray(item_type, items) => {
+                // Check the vararray length.
+                if items.len() > MAX_VARARRAY_LENGTH {
+                    return Err(CallArgCPEEncodeError::VararrayLengthGreaterThan255Error(
+                        items.len(),
+                    ));
+                }
+
+                // Initialize bit vector to fill with length plus items.
+                let mut bits = BitVec::from_bytes(&[items.len() as u8]);
+
+                // Extend the bit vector with the items.
+                bits.extend(encode_items(items, Some(item_type))?);
+
+                // Return the bits.
+                Ok(bits)
+            }
+            CallElement::Tuple(items) => {
+                // Check the tuple length.
+                if !(MIN_TUPLE_LENGTH..=MAX_TUPLE_LENGTH).contains(&items.len()) {
+                    return Err(CallArgCPEEncodeError::InvalidTupleLength(items.len()));
+                }
+
+                // The tuple length and item types are implied by the element type.
+                encode_items(items, None)
+            }
+        }
+    }
+}
+
+/// Encodes the items of a composite element one after another.
+///
+/// Each item must be of a valid item type, and of the expected item type if there is one.
+fn encode_items(
+    items: &[CallElement],
+    expected_item_type: Option<&CallElementType>,
+) -> Result<BitVec, CallArgCPEEncodeError> {
+    // Initialize bit vector.
+    let mut bits = BitVec::new();
+
+    for (index, item) in items.iter().enumerate() {
+        // Check the item type.
+        let item_type = item.element_type();
+        if !item_type.is_item_type() {
+            return Err(CallArgCPEEncodeError::InvalidItemType(index));
+        }
+        if let Some(expected_item_type) = expected_item_type {
+            if item_type != *expected_item_type {
+                return Err(CallArgCPEEncodeError::InvalidItemType(index));
             }
         }
+
+        // Encode the item.
+        let item_bits = item
+            .encode_cpe()
+            .map_err(|e| CallArgCPEEncodeError::Item(index, Box::new(e)))?;
+
+        // Extend the bit vector with the item.
+        bits.extend(item_bits);
+    }
+
+    // Return the bits.
+    Ok(bits)
+}
+
+/// Encodes little-endian integer bytes with the trailing zero bytes left out,
+/// prefixed with the remaining byte length in the given number of bits.
+fn encode_minimal_uint(bytes: &[u8], length_bit_count: usize) -> BitVec {
+    // Get the minimal byte length.
+    let byte_length = bytes.len() - bytes.iter().rev().take_while(|byte| **byte == 0).count();
+
+    // Initialize bit vector to fill with length plus data.
+    let mut bits = BitVec::new();
+
+    // Extend the bit vector with the byte length.
+    for i in 0..length_bit_count {
+        bits.push((byte_length >> i) & 1 == 1);
     }
+
+    // Extend the bit vector with the data bits.
+    bits.extend(BitVec::from_bytes(&bytes[..byte_length]));
+
+    // Return the bits.
+    bits
 }
 
 /// Converts a u16 to 12 bits.
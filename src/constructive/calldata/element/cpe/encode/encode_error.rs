@@ -0,0 +1,18 @@
+use crate::constructive::valtype::maybe_common::maybe_common::cpe::encode::encode_error::MaybeCommonCPEEncodeError;
+
+/// Type alias for the item index of a composite element.
+type ItemIndex = usize;
+
+/// Enum to represent errors that can occur when encoding a `CallElement` as a bit vector.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CallArgCPEEncodeError {
+    U32(MaybeCommonCPEEncodeError),
+    U64(MaybeCommonCPEEncodeError),
+    Payable(MaybeCommonCPEEncodeError),
+    StringByteLengthGreaterThan4095Error(usize),
+    InvalidArrayLength(usize),
+    VararrayLengthGreaterThan255Error(usize),
+    InvalidTupleLength(usize),
+    InvalidItemType(ItemIndex),
+    Item(ItemIndex, Box<CallArgCPEEncodeError>),
+}
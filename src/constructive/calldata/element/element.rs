@@ -20,6 +20,15 @@ pub enum CallElement {
     Bytes(Vec<u8>),
     Varbytes(Vec<u8>),
     Payable(ShortVal),
+    U128(u128),
+    // Little-endian bytes of the 256-bit integer.
+    U256([u8; 32]),
+    String(String),
+    // Item type and the items.
+    Array(CallElementType, Vec<CallElement>),
+    // Item type and the items.
+    Vararray(CallElementType, Vec<CallElement>),
+    Tuple(Vec<CallElement>),
 }
 
 impl CallElement {
@@ -42,6 +51,21 @@ impl CallElement {
             }
             CallElement::Varbytes(_) => CallElementType::Varbytes,
             CallElement::Payable(_) => CallElementType::Payable,
+            CallElement::U128(_) => CallElementType::U128,
+            CallElement::U256(_) => CallElementType::U256,
+            CallElement::String(_) => CallElementType::String,
+            CallElement::Array(item_type, items) => {
+                // Array length is the inner value + 1. So we need to subtract 1 from the length.
+                let index = items.len().saturating_sub(1) as u8;
+                // Return the element type.
+                CallElementType::Array(Box::new(item_type.clone()), index)
+            }
+            CallElement::Vararray(item_type, _) => {
+                CallElementType::Vararray(Box::new(item_type.clone()))
+            }
+            CallElement::Tuple(items) => {
+                CallElementType::Tuple(items.iter().map(|item| item.element_type()).collect())
+            }
         }
     }
 
@@ -124,6 +148,49 @@ impl CallElement {
                 // Return the stack item.
                 value_as_stack_item
             }
+            // 0-16 bytes in stack.
+            CallElement::U128(value) => {
+                // Convert the value to a `StackUint`.
+                let value_as_stack_uint = StackUint::from(*value);
+
+                // Convert the value to a `StackItem`.
+                let value_as_stack_item = StackItem::from_stack_uint(value_as_stack_uint);
+
+                // Return the stack item.
+                value_as_stack_item
+            }
+            // 0-32 bytes in stack.
+            CallElement::U256(bytes) => {
+                // Convert the value to a `StackUint`.
+                let value_as_stack_uint = StackUint::from_little_endian(bytes);
+
+                // Convert the value to a `StackItem`.
+                let value_as_stack_item = StackItem::from_stack_uint(value_as_stack_uint);
+
+                // Return the stack item.
+                value_as_stack_item
+            }
+            // 0-4095 bytes in stack.
+            CallElement::String(string) => StackItem::new(string.as_bytes().to_vec()),
+            // Items are laid out one after another, each prefixed with its 2-byte length.
+            CallElement::Array(_, items) => composite_stack_item(items),
+            CallElement::Vararray(_, items) => composite_stack_item(items),
+            CallElement::Tuple(items) => composite_stack_item(items),
         }
     }
 }
+
+/// Lays out the items of a composite element in a single stack item.
+///
+/// Each item is prefixed with its byte length as a little-endian u16.
+fn composite_stack_item(items: &[CallElement]) -> StackItem {
+    let mut bytes = Vec::<u8>::new();
+
+    for item in items.iter() {
+        let item = item.into_stack_item();
+        bytes.extend((item.len() as u16).to_le_bytes());
+        bytes.extend(item.bytes());
+    }
+
+    StackItem::new(bytes)
+}
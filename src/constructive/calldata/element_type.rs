@@ -1,7 +1,12 @@
+use crate::constructive::calldata::limits::{
+    MAX_STRING_BYTE_LENGTH, MAX_TUPLE_LENGTH, MAX_VARARRAY_LENGTH, MIN_TUPLE_LENGTH,
+};
+use crate::executive::stack::limits::MAX_STACK_ITEM_SIZE;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Represents the type of a single element of calldata.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CallElementType {
     // Represents an unsigned 8-bit integer.
     U8,
@@ -26,6 +31,23 @@ pub enum CallElementType {
     Varbytes,
     // Represents a payable value.
     Payable,
+    // Represents an unsigned 128-bit integer.
+    U128,
+    // Represents an unsigned 256-bit integer.
+    U256,
+    // Represents a UTF-8 string.
+    // Supported byte-length range: 0-4095 bytes
+    String,
+    // Represents an array of items of the same type with a known length.
+    // Array length is the inner value + 1.
+    // Supported length range: 1-256 items
+    Array(Box<CallElementType>, u8),
+    // Represents an array of items of the same type with an unknown length.
+    // Supported length range: 0-255 items
+    Vararray(Box<CallElementType>),
+    // Represents a sequence of items of possibly different types.
+    // Supported length range: 2-16 items
+    Tuple(Vec<CallElementType>),
 }
 
 impl CallElementType {
@@ -45,9 +67,95 @@ impl CallElementType {
             }
             CallElementType::Varbytes => vec![0x08],
             CallElementType::Payable => vec![0x09],
+            CallElementType::U128 => vec![0x0a],
+            CallElementType::U256 => vec![0x0b],
+            CallElementType::String => vec![0x0c],
+            CallElementType::Array(item_type, index) => {
+                // Return the length index followed by the item type.
+                let mut bytecode = vec![0x0d, index.to_owned()];
+                bytecode.extend(item_type.bytecode());
+                bytecode
+            }
+            CallElementType::Vararray(item_type) => {
+                // Return the item type.
+                let mut bytecode = vec![0x0e];
+                bytecode.extend(item_type.bytecode());
+                bytecode
+            }
+            CallElementType::Tuple(item_types) => {
+                // Return the number of items followed by the item types.
+                let mut bytecode = vec![0x0f, item_types.len() as u8];
+                for item_type in item_types.iter() {
+                    bytecode.extend(item_type.bytecode());
+                }
+                bytecode
+            }
         }
     }
 
+    /// Returns whether the element type is a composite of other element types.
+    pub fn is_composite(&self) -> bool {
+        matches!(
+            self,
+            CallElementType::Array(_, _) | CallElementType::Vararray(_) | CallElementType::Tuple(_)
+        )
+    }
+
+    /// Returns whether the element type can be an item of a composite element type.
+    ///
+    /// Composites are not nested, and a payable cannot be an item.
+    pub fn is_item_type(&self) -> bool {
+        !self.is_composite() && *self != CallElementType::Payable
+    }
+
+    /// Returns the maximum byte length of an element of this type, as a stack item.
+    ///
+    /// Composite items are each prefixed with their 2-byte length.
+    pub fn max_byte_length(&self) -> u32 {
+        match self {
+            CallElementType::U8 => 1,
+            CallElementType::U16 => 2,
+            CallElementType::U32 => 4,
+            CallElementType::U64 => 8,
+            CallElementType::Bool => 1,
+            CallElementType::Account => 32,
+            CallElementType::Contract => 32,
+            CallElementType::Bytes(index) => *index as u32 + 1,
+            CallElementType::Varbytes => 4096,
+            CallElementType::Payable => 4,
+            CallElementType::U128 => 16,
+            CallElementType::U256 => 32,
+            CallElementType::String => MAX_STRING_BYTE_LENGTH as u32,
+            CallElementType::Array(item_type, index) => {
+                (*index as u32 + 1) * (2 + item_type.max_byte_length())
+            }
+            CallElementType::Vararray(item_type) => {
+                MAX_VARARRAY_LENGTH as u32 * (2 + item_type.max_byte_length())
+            }
+            CallElementType::Tuple(item_types) => item_types
+                .iter()
+                .map(|item_type| 2 + item_type.max_byte_length())
+                .sum(),
+        }
+    }
+
+    /// Returns whether the element type is well-formed.
+    ///
+    /// Composites must also fit in a single stack item at their maximum length.
+    pub fn is_valid(&self) -> bool {
+        let is_well_formed = match self {
+            CallElementType::Array(item_type, _) => item_type.is_item_type(),
+            CallElementType::Vararray(item_type) => item_type.is_item_type(),
+            CallElementType::Tuple(item_types) => {
+                (MIN_TUPLE_LENGTH..=MAX_TUPLE_LENGTH).contains(&item_types.len())
+                    && item_types.iter().all(|item_type| item_type.is_item_type())
+            }
+            _ => true,
+        };
+
+        is_well_formed && self.max_byte_length() <= MAX_STACK_ITEM_SIZE
+    }
+
     /// Returns the element type from the bytecode.
     pub fn from_bytecode<I>(bytecode_stream: &mut I) -> Option<Self>
     where
@@ -66,9 +174,46 @@ impl CallElementType {
                 .map(|index| CallElementType::Bytes(index)),
             Some(0x08) => Some(CallElementType::Varbytes),
             Some(0x09) => Some(CallElementType::Payable),
+            Some(0x0a) => Some(CallElementType::U128),
+            Some(0x0b) => Some(CallElementType::U256),
+            Some(0x0c) => Some(CallElementType::String),
+            Some(0x0d) => {
+                let index = bytecode_stream.next()?;
+                let item_type = Self::item_type_from_bytecode(bytecode_stream)?;
+                Some(CallElementType::Array(Box::new(item_type), index))
+            }
+            Some(0x0e) => {
+                let item_type = Self::item_type_from_bytecode(bytecode_stream)?;
+                Some(CallElementType::Vararray(Box::new(item_type)))
+            }
+            Some(0x0f) => {
+                let length = bytecode_stream.next()? as usize;
+                if !(MIN_TUPLE_LENGTH..=MAX_TUPLE_LENGTH).contains(&length) {
+                    return None;
+                }
+
+                let mut item_types = Vec::<CallElementType>::with_capacity(length);
+                for _ in 0..length {
+                    item_types.push(Self::item_type_from_bytecode(bytecode_stream)?);
+                }
+                Some(CallElementType::Tuple(item_types))
+            }
             _ => None,
         }
     }
+
+    /// Returns the item type of a composite element type from the bytecode.
+    fn item_type_from_bytecode<I>(bytecode_stream: &mut I) -> Option<Self>
+    where
+        I: Iterator<Item = u8>,
+    {
+        // The item type is a single, non-composite element type.
+        let item_type = Self::from_bytecode(bytecode_stream)?;
+        match item_type.is_item_type() {
+            true => Some(item_type),
+            false => None,
+        }
+    }
 }
 
 impl fmt::Display for CallElementType {
@@ -84,6 +229,20 @@ impl fmt::Display for CallElementType {
             CallElementType::Bytes(index) => write!(f, "Bytes{}", (index + 1)),
             CallElementType::Varbytes => write!(f, "Varbytes"),
             CallElementType::Payable => write!(f, "Payable"),
+            CallElementType::U128 => write!(f, "U128"),
+            CallElementType::U256 => write!(f, "U256"),
+            CallElementType::String => write!(f, "String"),
+            CallElementType::Array(item_type, index) => {
+                write!(f, "Array[{}; {}]", item_type, (*index as usize + 1))
+            }
+            CallElementType::Vararray(item_type) => write!(f, "Vararray[{}]", item_type),
+            CallElementType::Tuple(item_types) => {
+                let item_types: Vec<String> = item_types
+                    .iter()
+                    .map(|item_type| item_type.to_string())
+                    .collect();
+                write!(f, "Tuple({})", item_types.join(", "))
+            }
         }
     }
 }
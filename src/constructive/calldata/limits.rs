@@ -0,0 +1,14 @@
+/// The maximum byte length of a `String`.
+pub const MAX_STRING_BYTE_LENGTH: usize = 4095;
+
+/// The maximum number of items in a fixed-length `Array`.
+pub const MAX_ARRAY_LENGTH: usize = 256;
+
+/// The maximum number of items in a variable-length `Vararray`.
+pub const MAX_VARARRAY_LENGTH: usize = 255;
+
+/// The minimum number of items in a `Tuple`.
+pub const MIN_TUPLE_LENGTH: usize = 2;
+
+/// The maximum number of items in a `Tuple`.
+pub const MAX_TUPLE_LENGTH: usize = 16;
@@ -1,2 +1,3 @@
 pub mod element;
 pub mod element_type;
+pub mod limits;
@@ -1,4 +1,8 @@
 use crate::constructive::valtype::val::short_val::short_val::ShortVal;
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    writer::CanonicalWriter,
+};
 use secp::Point;
 use serde::{Deserialize, Serialize};
 
@@ -70,12 +74,27 @@ impl Account {
         self.key.parity().into()
     }
 
-    /// Serializes the account.
+    /// Serializes the account, in the canonical encoding.
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
+    }
+}
+
+impl Canonical for Account {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.write_account_key(&self.key);
+        bytes.write_option(&self.registery_index(), |bytes, index| {
+            bytes.extend(index.to_le_bytes())
+        });
+        bytes.write_option(&self.rank(), |bytes, rank| bytes.extend(rank.to_le_bytes()));
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Account, CanonicalDecodeError> {
+        let key = reader.read_account_key()?;
+        let registery_index = reader.read_option(CanonicalReader::read_u32)?;
+        let rank = reader.read_option(CanonicalReader::read_u32)?;
+
+        Account::new(key, registery_index, rank).ok_or(CanonicalDecodeError::InvalidAccountKey)
     }
 }
 
@@ -1,4 +1,8 @@
 use crate::constructive::valtype::val::short_val::short_val::ShortVal;
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    writer::CanonicalWriter,
+};
 use serde::{Deserialize, Serialize};
 
 /// Represents a contract; a program that can be executed on Cube.
@@ -48,12 +52,25 @@ impl Contract {
         self.rank = rank.map(|rank| ShortVal::new(rank));
     }
 
-    /// Serializes the contract.
+    /// Serializes the contract, in the canonical encoding.
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
+    }
+}
+
+impl Canonical for Contract {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.contract_id);
+        bytes.extend(self.registery_index().to_le_bytes());
+        bytes.write_option(&self.rank(), |bytes, rank| bytes.extend(rank.to_le_bytes()));
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Contract, CanonicalDecodeError> {
+        let contract_id = reader.read_array::<32>()?;
+        let registery_index = reader.read_u32()?;
+        let rank = reader.read_option(CanonicalReader::read_u32)?;
+
+        Ok(Contract::new(contract_id, registery_index, rank))
     }
 }
 
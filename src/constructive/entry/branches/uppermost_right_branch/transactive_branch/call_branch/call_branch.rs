@@ -0,0 +1,10 @@
+use crate::constructive::entry::combinator::combinators::bundle::bundle::Bundle;
+use crate::constructive::entry::combinator::combinators::call::call::Call;
+use serde::{Deserialize, Serialize};
+
+/// The call branch of an entry. Descend from the transactive branch.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CallBranch {
+    Call(Call),
+    Bundle(Bundle),
+}
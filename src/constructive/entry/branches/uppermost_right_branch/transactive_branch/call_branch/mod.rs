@@ -0,0 +1 @@
+pub mod call_branch;
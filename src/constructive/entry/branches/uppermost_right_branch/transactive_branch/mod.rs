@@ -1 +1,3 @@
+pub mod call_branch;
+pub mod move_branch;
 pub mod transactive_branch;
@@ -0,0 +1,10 @@
+use crate::constructive::entry::combinator::combinators::batch_move::batch_move::BatchMove;
+use crate::constructive::entry::combinator::combinators::r#move::r#move::Move;
+use serde::{Deserialize, Serialize};
+
+/// The move branch of an entry. Descend from the transactive branch.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MoveBranch {
+    Move(Move),
+    BatchMove(BatchMove),
+}
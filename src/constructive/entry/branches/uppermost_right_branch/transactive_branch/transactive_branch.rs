@@ -1,10 +1,10 @@
-use crate::constructive::entry::combinator::combinators::call::call::Call;
-use crate::constructive::entry::combinator::combinators::r#move::Move;
+use crate::constructive::entry::branches::uppermost_right_branch::transactive_branch::call_branch::call_branch::CallBranch;
+use crate::constructive::entry::branches::uppermost_right_branch::transactive_branch::move_branch::move_branch::MoveBranch;
 use serde::{Deserialize, Serialize};
 
 /// The transactive branch of an entry. Descend from the uppermost right branch.
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum TransactiveBranch {
-    Move(Move),
-    Call(Call),
+    MoveBranch(MoveBranch),
+    CallBranch(CallBranch),
 }
@@ -1,5 +1,5 @@
-use crate::constructive::entry::combinator::combinators::add::Add;
-use crate::constructive::entry::combinator::combinators::sub::Sub;
+use crate::constructive::entry::combinator::combinators::add::add::Add;
+use crate::constructive::entry::combinator::combinators::sub::sub::Sub;
 use serde::{Deserialize, Serialize};
 
 /// The liquidity branch of an entry. Descend from the upper right branch.
@@ -1,5 +1,5 @@
 use crate::constructive::entry::combinator::combinators::deploy::Deploy;
-use crate::constructive::entry::combinator::combinators::swapout::Swapout;
+use crate::constructive::entry::combinator::combinators::swapout::swapout::Swapout;
 use serde::{Deserialize, Serialize};
 
 /// The lower left branch of an entry. Descend from the right branch.
@@ -1,5 +1,5 @@
-use crate::constructive::entry::combinator::combinators::claim::Claim;
-use crate::constructive::entry::combinator::combinators::revive::Revive;
+use crate::constructive::entry::combinator::combinators::claim::claim::Claim;
+use crate::constructive::entry::combinator::combinators::revive::revive::Revive;
 use serde::{Deserialize, Serialize};
 
 /// The recovery branch of an entry. Descend from the lower right branch.
@@ -1,10 +1,14 @@
 use crate::constructive::entry::combinator::combinators::{
-    add::Add, call::call::Call, deploy::Deploy, r#move::Move, reserved::Reserved, revive::Revive,
-    sub::Sub, swapout::Swapout,
+    add::add::Add, batch_move::batch_move::BatchMove, bundle::bundle::Bundle, call::call::Call,
+    deploy::Deploy, r#move::r#move::Move, reserved::Reserved, revive::revive::Revive,
+    sub::sub::Sub, swapout::swapout::Swapout,
 };
 use crate::constructive::entry::{
     branches::uppermost_right_branch::{
-        transactive_branch::transactive_branch::TransactiveBranch,
+        transactive_branch::{
+            call_branch::call_branch::CallBranch, move_branch::move_branch::MoveBranch,
+            transactive_branch::TransactiveBranch,
+        },
         upper_right_branch::{
             liquidity_branch::liquidity_branch::LiquidityBranch,
             right_branch::{
@@ -18,7 +22,7 @@ use crate::constructive::entry::{
             upper_right_branch::UpperRightBranch,
         },
     },
-    combinator::{combinator::Combinator, combinators::claim::Claim},
+    combinator::{combinator::Combinator, combinators::claim::claim::Claim},
 };
 use serde::{Deserialize, Serialize};
 
@@ -32,12 +36,24 @@ pub enum UppermostRightBranch {
 impl UppermostRightBranch {
     /// Create a branch containing a `Move` combinator.
     pub fn new_move(r#move: Move) -> Self {
-        Self::TransactiveBranch(TransactiveBranch::Move(r#move))
+        Self::TransactiveBranch(TransactiveBranch::MoveBranch(MoveBranch::Move(r#move)))
+    }
+
+    /// Create a branch containing a `BatchMove` combinator.
+    pub fn new_batch_move(batch_move: BatchMove) -> Self {
+        Self::TransactiveBranch(TransactiveBranch::MoveBranch(MoveBranch::BatchMove(
+            batch_move,
+        )))
     }
 
     /// Create a branch containing a `Call` combinator.
     pub fn new_call(call: Call) -> Self {
-        Self::TransactiveBranch(TransactiveBranch::Call(call))
+        Self::TransactiveBranch(TransactiveBranch::CallBranch(CallBranch::Call(call)))
+    }
+
+    /// Create a branch containing a `Bundle` combinator.
+    pub fn new_bundle(bundle: Bundle) -> Self {
+        Self::TransactiveBranch(TransactiveBranch::CallBranch(CallBranch::Bundle(bundle)))
     }
 
     /// Create a branch containing a `Add` combinator.
@@ -89,12 +105,40 @@ impl UppermostRightBranch {
         ))
     }
 
+    /// Create a branch containing the given main combinator.
+    ///
+    /// Returns `None` for `Liftup` and `Recharge`, which belong to the uppermost left branch.
+    pub fn from_combinator(combinator: Combinator) -> Option<Self> {
+        match combinator {
+            Combinator::Move(r#move) => Some(Self::new_move(r#move)),
+            Combinator::BatchMove(batch_move) => Some(Self::new_batch_move(batch_move)),
+            Combinator::Call(call) => Some(Self::new_call(call)),
+            Combinator::Bundle(bundle) => Some(Self::new_bundle(bundle)),
+            Combinator::Add(add) => Some(Self::new_add(add)),
+            Combinator::Sub(sub) => Some(Self::new_sub(sub)),
+            Combinator::Deploy(deploy) => Some(Self::new_deploy(deploy)),
+            Combinator::Swapout(swapout) => Some(Self::new_swapout(swapout)),
+            Combinator::Revive(revive) => Some(Self::new_revive(revive)),
+            Combinator::Claim(claim) => Some(Self::new_claim(claim)),
+            Combinator::Reserved(reserved) => Some(Self::new_reserved(reserved)),
+            Combinator::Liftup(_) | Combinator::Recharge(_) => None,
+        }
+    }
+
     /// Returns the main combinator of the branch.
     pub fn main_combinator(&self) -> Combinator {
         match self {
             Self::TransactiveBranch(transactive_branch) => match transactive_branch {
-                TransactiveBranch::Move(r#move) => Combinator::Move(r#move.clone()),
-                TransactiveBranch::Call(call) => Combinator::Call(call.clone()),
+                // Move branch.
+                TransactiveBranch::MoveBranch(move_branch) => match move_branch {
+                    MoveBranch::Move(r#move) => Combinator::Move(r#move.clone()),
+                    MoveBranch::BatchMove(batch_move) => Combinator::BatchMove(batch_move.clone()),
+                },
+                // Call branch.
+                TransactiveBranch::CallBranch(call_branch) => match call_branch {
+                    CallBranch::Call(call) => Combinator::Call(call.clone()),
+                    CallBranch::Bundle(bundle) => Combinator::Bundle(bundle.clone()),
+                },
             },
             Self::UpperRightBranch(upper_right_branch) => match upper_right_branch {
                 // Liquidity branch.
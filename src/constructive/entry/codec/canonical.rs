@@ -0,0 +1,62 @@
+use crate::{
+    constructive::{
+        entity::account::account::Account,
+        entry::{
+            branches::{
+                uppermost_left_branch::uppermost_left_branch::UppermostLeftBranch,
+                uppermost_right_branch::uppermost_right_branch::UppermostRightBranch,
+            },
+            combinator::{
+                combinator::Combinator,
+                combinators::{liftup::liftup::Liftup, recharge::recharge::Recharge},
+            },
+            entry::Entry,
+        },
+    },
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+        writer::CanonicalWriter,
+    },
+};
+
+/// The canonical encoding for `Entry`.
+///
+/// An entry is its account and nonce, followed by the optional liftup and recharge of its
+/// uppermost left branch, and the optional main combinator of its uppermost right branch.
+impl Canonical for Entry {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.account().encode_fields(bytes);
+        bytes.extend(self.nonce().to_le_bytes());
+        bytes.write_option(&self.uppermost_left_branch(), |bytes, branch| {
+            bytes.write_option(&branch.liftup, |bytes, liftup| liftup.encode_fields(bytes));
+            bytes.write_option(&branch.recharge, |bytes, recharge| {
+                recharge.encode_fields(bytes)
+            });
+        });
+        bytes.write_option(&self.uppermost_right_branch(), |bytes, branch| {
+            branch.main_combinator().encode_fields(bytes)
+        });
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Entry, CanonicalDecodeError> {
+        let account = Account::decode_fields(reader)?;
+        let nonce = reader.read_u64()?;
+
+        let uppermost_left_branch = reader.read_option(|reader| {
+            let liftup = reader.read_option(Liftup::decode_fields)?;
+            let recharge = reader.read_option(Recharge::decode_fields)?;
+            Ok(UppermostLeftBranch::new(liftup, recharge))
+        })?;
+
+        // Liftup and recharge cannot be the main combinator.
+        let uppermost_right_branch = reader.read_option(|reader| {
+            UppermostRightBranch::from_combinator(Combinator::decode_fields(reader)?)
+                .ok_or(CanonicalDecodeError::InvalidValue)
+        })?;
+
+        let mut entry = Entry::new(account, uppermost_left_branch, uppermost_right_branch);
+        entry.set_nonce(nonce);
+
+        Ok(entry)
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    constructive::{
+        entity::account::account::Account,
+        entry::{
+            branches::{
+                uppermost_left_branch::uppermost_left_branch::UppermostLeftBranch,
+                uppermost_right_branch::uppermost_right_branch::UppermostRightBranch,
+            },
+            codec::cpe::decode::decode_error::EntryCPEDecodingError,
+            combinator::combinators::{
+                add::add::Add, batch_move::batch_move::BatchMove, bundle::bundle::Bundle,
+                call::call::Call, claim::claim::Claim, deploy::Deploy, liftup::liftup::Liftup,
+                r#move::r#move::Move, recharge::recharge::Recharge, revive::revive::Revive,
+                sub::sub::Sub, swapout::swapout::Swapout,
+            },
+            entry::Entry,
+        },
+        txn::txholder::TxHolder,
+        valtype::val::long_val::long_val::LongVal,
+    },
+    inscriptive::{
+        epoch::dir::EPOCH_DIRECTORY, registery::registery::REGISTERY, repo::repo::PROGRAMS_REPO,
+        set::vtxo_set::VTXO_SET,
+    },
+};
+
+impl Entry {
+    /// Compact payload decoding for `Entry`.
+    /// Decodes an `Entry` from a bit stream and `TxHolder`.
+    pub async fn decode_cpe<'a>(
+        bit_stream: &mut bit_vec::Iter<'a>,
+        txholder: &mut TxHolder,
+        epoch_dir: &EPOCH_DIRECTORY,
+        registery: &REGISTERY,
+        repo: &PROGRAMS_REPO,
+        vtxo_set: &VTXO_SET,
+        current_bitcoin_height: u32,
+        ops_price_base: u32,
+    ) -> Result<Entry, EntryCPEDecodingError> {
+        // Get the account registery.
+        let account_registery = {
+            let _registery = registery.lock().await;
+            _registery.account_registery()
+        };
+
+        // Decode the account.
+        let account = Account::decode_cpe(bit_stream, &account_registery)
+            .await
+            .map_err(EntryCPEDecodingError::AccountCPEDecodeError)?;
+
+        // Decode the nonce.
+        let nonce = LongVal::decode_cpe(bit_stream)
+            .map_err(EntryCPEDecodingError::NonceCPEDecodeError)?
+            .value();
+
+        // Uppermost left branch.
+        let uppermost_left_branch = match next_bit(bit_stream)? {
+            false => None,
+            true => {
+                // Decode the liftup.
+                let liftup = match next_bit(bit_stream)? {
+                    false => None,
+                    true => Some(
+                        Liftup::decode_cpe(bit_stream, txholder, epoch_dir, account.key())
+                            .await
+                            .map_err(EntryCPEDecodingError::LiftupCPEDecodeError)?,
+                    ),
+                };
+
+                // Recharge is implied on if the liftup is off.
+                let recharge_on = match liftup {
+                    Some(_) => next_bit(bit_stream)?,
+                    None => true,
+                };
+
+                // Decode the recharge.
+                let recharge = match recharge_on {
+                    false => None,
+                    true => Some(
+                        Recharge::decode_cpe(
+                            bit_stream,
+                            account.key(),
+                            vtxo_set,
+                            current_bitcoin_height,
+                        )
+                        .await
+                        .map_err(EntryCPEDecodingError::RechargeCPEDecodeError)?,
+                    ),
+                };
+
+                Some(UppermostLeftBranch::new(liftup, recharge))
+            }
+        };
+
+        // Uppermost right branch.
+        // It is implied on if the uppermost left branch is off.
+        let uppermost_right_branch_on = match uppermost_left_branch {
+            Some(_) => next_bit(bit_stream)?,
+            None => true,
+        };
+
+        if !uppermost_right_branch_on {
+            let mut entry = Entry::new(account, uppermost_left_branch, None);
+            entry.set_nonce(nonce);
+            return Ok(entry);
+        }
+
+        let account_key = account.key().serialize_xonly();
+
+        let uppermost_right_branch = match next_bit(bit_stream)? {
+            // Transactive branch.
+            false => match next_bit(bit_stream)? {
+                // Move branch.
+                false => match next_bit(bit_stream)? {
+                    false => UppermostRightBranch::new_move(
+                        Move::decode_cpe(bit_stream, account, &account_registery)
+                            .await
+                            .map_err(EntryCPEDecodingError::MoveCPEDecodeError)?,
+                    ),
+                    true => UppermostRightBranch::new_batch_move(
+                        BatchMove::decode_cpe(bit_stream, account, &account_registery)
+                            .await
+                            .map_err(EntryCPEDecodingError::BatchMoveCPEDecodeError)?,
+                    ),
+                },
+                // Call branch.
+                true => match next_bit(bit_stream)? {
+                    false => UppermostRightBranch::new_call(
+                        Call::decode_cpe(bit_stream, account_key, registery, repo, ops_price_base)
+                            .await
+                            .map_err(EntryCPEDecodingError::CallCPEDecodeError)?,
+                    ),
+                    true => UppermostRightBranch::new_bundle(
+                        Bundle::decode_cpe(bit_stream, account, registery, repo, ops_price_base)
+                            .await
+                            .map_err(EntryCPEDecodingError::BundleCPEDecodeError)?,
+                    ),
+                },
+            },
+            // Upper right branch.
+            true => match next_bit(bit_stream)? {
+                // Liquidity branch.
+                false => match next_bit(bit_stream)? {
+                    false => UppermostRightBranch::new_add(
+                        Add::decode_cpe(bit_stream, account)
+                            .map_err(EntryCPEDecodingError::AddCPEDecodeError)?,
+                    ),
+                    true => UppermostRightBranch::new_sub(
+                        Sub::decode_cpe(bit_stream, account)
+                            .map_err(EntryCPEDecodingError::SubCPEDecodeError)?,
+                    ),
+                },
+                // Right branch.
+                true => match next_bit(bit_stream)? {
+                    // Lower left branch.
+                    false => match next_bit(bit_stream)? {
+                        false => UppermostRightBranch::new_deploy(Deploy::new()),
+                        true => UppermostRightBranch::new_swapout(
+                            Swapout::decode_cpe(bit_stream, account)
+                                .map_err(EntryCPEDecodingError::SwapoutCPEDecodeError)?,
+                        ),
+                    },
+                    // Lower right branch.
+                    true => match next_bit(bit_stream)? {
+                        // Recovery branch.
+                        false => match next_bit(bit_stream)? {
+                            false => UppermostRightBranch::new_revive(
+                                Revive::decode_cpe(
+                                    bit_stream,
+                                    account,
+                                    vtxo_set,
+                                    current_bitcoin_height,
+                                )
+                                .await
+                                .map_err(EntryCPEDecodingError::ReviveCPEDecodeError)?,
+                            ),
+                            true => UppermostRightBranch::new_claim(
+                                Claim::decode_cpe(bit_stream, account, vtxo_set)
+                                    .await
+                                    .map_err(EntryCPEDecodingError::ClaimCPEDecodeError)?,
+                            ),
+                        },
+                        // The reserved branch fails the entry.
+                        true => return Err(EntryCPEDecodingError::ReservedBranch),
+                    },
+                },
+            },
+        };
+
+        // Construct the entry.
+        let mut entry = Entry::new(account, uppermost_left_branch, Some(uppermost_right_branch));
+        entry.set_nonce(nonce);
+
+        // Return the entry.
+        Ok(entry)
+    }
+}
+
+/// Collects the next selector bit.
+fn next_bit(bit_stream: &mut bit_vec::Iter<'_>) -> Result<bool, EntryCPEDecodingError> {
+    bit_stream
+        .next()
+        .ok_or(EntryCPEDecodingError::SelectorBitCollectError)
+}
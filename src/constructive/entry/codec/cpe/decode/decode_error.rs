@@ -0,0 +1,37 @@
+use crate::constructive::{
+    entity::account::cpe::decode::decode_error::AccountCPEDecodingError,
+    entry::combinator::combinators::{
+        add::codec::cpe::decode::decode_error::AddCPEDecodingError,
+        batch_move::codec::cpe::decode::decode_error::BatchMoveCPEDecodingError,
+        bundle::codec::cpe::decode::decode_error::BundleCPEDecodingError,
+        call::codec::cpe::decode::decode_error::CallCPEDecodingError,
+        claim::codec::cpe::decode::decode_error::ClaimCPEDecodingError,
+        liftup::codec::cpe::decode::decode_error::LiftupCPEDecodingError,
+        r#move::codec::cpe::decode::decode_error::MoveCPEDecodingError,
+        recharge::codec::cpe::decode::decode_error::RechargeCPEDecodingError,
+        revive::codec::cpe::decode::decode_error::ReviveCPEDecodingError,
+        sub::codec::cpe::decode::decode_error::SubCPEDecodingError,
+        swapout::codec::cpe::decode::decode_error::SwapoutCPEDecodingError,
+    },
+    valtype::val::long_val::cpe::decode::decode_error::LongValCPEDecodingError,
+};
+
+/// The error type for decoding an `Entry` from a bit stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EntryCPEDecodingError {
+    AccountCPEDecodeError(AccountCPEDecodingError),
+    NonceCPEDecodeError(LongValCPEDecodingError),
+    SelectorBitCollectError,
+    LiftupCPEDecodeError(LiftupCPEDecodingError),
+    RechargeCPEDecodeError(RechargeCPEDecodingError),
+    MoveCPEDecodeError(MoveCPEDecodingError),
+    BatchMoveCPEDecodeError(BatchMoveCPEDecodingError),
+    CallCPEDecodeError(CallCPEDecodingError),
+    BundleCPEDecodeError(BundleCPEDecodingError),
+    AddCPEDecodeError(AddCPEDecodingError),
+    SubCPEDecodeError(SubCPEDecodingError),
+    SwapoutCPEDecodeError(SwapoutCPEDecodingError),
+    ReviveCPEDecodeError(ReviveCPEDecodingError),
+    ClaimCPEDecodeError(ClaimCPEDecodingError),
+    ReservedBranch,
+}
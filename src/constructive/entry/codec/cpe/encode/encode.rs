@@ -0,0 +1,268 @@
+use crate::{
+    constructive::{
+        entry::{
+            branches::uppermost_right_branch::{
+                transactive_branch::{
+                    call_branch::call_branch::CallBranch, move_branch::move_branch::MoveBranch,
+                    transactive_branch::TransactiveBranch,
+                },
+                upper_right_branch::{
+                    liquidity_branch::liquidity_branch::LiquidityBranch,
+                    right_branch::{
+                        lower_left_branch::lower_left_branch::LowerLeftBranch,
+                        lower_right_branch::{
+                            lower_right_branch::LowerRightBranch,
+                            recovery_branch::recovery_branch::RecoveryBranch,
+                        },
+                        right_branch::RightBranch,
+                    },
+                    upper_right_branch::UpperRightBranch,
+                },
+                uppermost_right_branch::UppermostRightBranch,
+            },
+            codec::cpe::encode::encode_error::EntryCPEEncodeError,
+            entry::Entry,
+        },
+        valtype::val::long_val::long_val::LongVal,
+    },
+    inscriptive::{
+        registery::registery::REGISTERY, repo::repo::PROGRAMS_REPO, set::vtxo_set::VTXO_SET,
+    },
+};
+use bit_vec::BitVec;
+
+impl Entry {
+    /// Encodes the entry as a bit vector.
+    ///
+    /// The entry tree is walked with a selector bit per branch. Bits implied by the others are
+    /// left out; an entry without an uppermost left branch has an uppermost right branch, and an
+    /// uppermost left branch without a liftup has a recharge.
+    pub async fn encode_cpe(
+        &self,
+        registery: &REGISTERY,
+        repo: &PROGRAMS_REPO,
+        vtxo_set: &VTXO_SET,
+        ops_price_base: u32,
+    ) -> Result<BitVec, EntryCPEEncodeError> {
+        // Initialize empty bit vector.
+        let mut bits = BitVec::new();
+
+        // Get the account and the uppermost branches.
+        let account = self.account();
+        let uppermost_left_branch = self.uppermost_left_branch();
+        let uppermost_right_branch = self.uppermost_right_branch();
+
+        // Extend the account.
+        bits.extend(account.encode_cpe());
+
+        // Extend the nonce.
+        bits.extend(LongVal::new(self.nonce()).encode_cpe());
+
+        // An entry must have at least one of its uppermost branches on.
+        if uppermost_left_branch.is_none() && uppermost_right_branch.is_none() {
+            return Err(EntryCPEEncodeError::EmptyEntry);
+        }
+
+        // Uppermost left branch.
+        match &uppermost_left_branch {
+            None => bits.push(false),
+            Some(uppermost_left_branch) => {
+                bits.push(true);
+
+                match (
+                    &uppermost_left_branch.liftup,
+                    &uppermost_left_branch.recharge,
+                ) {
+                    (None, None) => return Err(EntryCPEEncodeError::EmptyUppermostLeftBranch),
+                    (Some(liftup), recharge) => {
+                        // Liftup is on.
+                        bits.push(true);
+                        bits.extend(liftup.encode_cpe());
+
+                        // Recharge is on or off.
+                        match recharge {
+                            Some(recharge) => {
+                                bits.push(true);
+                                bits.extend(recharge.encode_cpe());
+                            }
+                            None => bits.push(false),
+                        }
+                    }
+                    (None, Some(recharge)) => {
+                        // Liftup is off, so recharge is implied on.
+                        bits.push(false);
+                        bits.extend(recharge.encode_cpe());
+                    }
+                }
+            }
+        }
+
+        // Uppermost right branch.
+        // It is implied on if the uppermost left branch is off.
+        let uppermost_right_branch = match &uppermost_right_branch {
+            Some(uppermost_right_branch) => {
+                if uppermost_left_branch.is_some() {
+                    bits.push(true);
+                }
+                uppermost_right_branch
+            }
+            None => {
+                bits.push(false);
+                return Ok(bits);
+            }
+        };
+
+        // Get the account key and the contract registery.
+        let account_key = account.key().serialize_xonly();
+        let contract_registery = {
+            let _registery = registery.lock().await;
+            _registery.contract_registery()
+        };
+
+        match uppermost_right_branch {
+            UppermostRightBranch::TransactiveBranch(transactive_branch) => {
+                bits.push(false);
+
+                match transactive_branch {
+                    TransactiveBranch::MoveBranch(move_branch) => {
+                        bits.push(false);
+
+                        match move_branch {
+                            MoveBranch::Move(r#move) => {
+                                bits.push(false);
+                                bits.extend(
+                                    r#move
+                                        .encode_cpe(account_key)
+                                        .map_err(EntryCPEEncodeError::MoveCPEEncodeError)?,
+                                );
+                            }
+                            MoveBranch::BatchMove(batch_move) => {
+                                bits.push(true);
+                                bits.extend(
+                                    batch_move
+                                        .encode_cpe(account_key)
+                                        .map_err(EntryCPEEncodeError::BatchMoveCPEEncodeError)?,
+                                );
+                            }
+                        }
+                    }
+                    TransactiveBranch::CallBranch(call_branch) => {
+                        bits.push(true);
+
+                        match call_branch {
+                            CallBranch::Call(call) => {
+                                bits.push(false);
+                                bits.extend(
+                                    call.encode_cpe(
+                                        account_key,
+                                        &contract_registery,
+                                        repo,
+                                        ops_price_base,
+                                    )
+                                    .await
+                                    .map_err(EntryCPEEncodeError::CallCPEEncodeError)?,
+                                );
+                            }
+                            CallBranch::Bundle(bundle) => {
+                                bits.push(true);
+                                bits.extend(
+                                    bundle
+                                        .encode_cpe(
+                                            account_key,
+                                            &contract_registery,
+                                            repo,
+                                            ops_price_base,
+                                        )
+                                        .await
+                                        .map_err(EntryCPEEncodeError::BundleCPEEncodeError)?,
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+            UppermostRightBranch::UpperRightBranch(upper_right_branch) => {
+                bits.push(true);
+
+                match upper_right_branch {
+                    UpperRightBranch::LiquidityBranch(liquidity_branch) => {
+                        bits.push(false);
+
+                        match liquidity_branch {
+                            LiquidityBranch::Add(add) => {
+                                bits.push(false);
+                                bits.extend(
+                                    add.encode_cpe(account_key)
+                                        .map_err(EntryCPEEncodeError::AddCPEEncodeError)?,
+                                );
+                            }
+                            LiquidityBranch::Sub(sub) => {
+                                bits.push(true);
+                                bits.extend(
+                                    sub.encode_cpe(account_key)
+                                        .map_err(EntryCPEEncodeError::SubCPEEncodeError)?,
+                                );
+                            }
+                        }
+                    }
+                    UpperRightBranch::RightBranch(right_branch) => {
+                        bits.push(true);
+
+                        match right_branch {
+                            RightBranch::LowerLeftBranch(lower_left_branch) => {
+                                bits.push(false);
+
+                                match lower_left_branch {
+                                    // Deploy carries no payload.
+                                    LowerLeftBranch::Deploy(_) => bits.push(false),
+                                    LowerLeftBranch::Swapout(swapout) => {
+                                        bits.push(true);
+                                        bits.extend(
+                                            swapout.encode_cpe(account_key).map_err(
+                                                EntryCPEEncodeError::SwapoutCPEEncodeError,
+                                            )?,
+                                        );
+                                    }
+                                }
+                            }
+                            RightBranch::LowerRightBranch(lower_right_branch) => {
+                                bits.push(true);
+
+                                match lower_right_branch {
+                                    LowerRightBranch::RecoveryBranch(recovery_branch) => {
+                                        bits.push(false);
+
+                                        match recovery_branch {
+                                            RecoveryBranch::Revive(revive) => {
+                                                bits.push(false);
+                                                bits.extend(revive.encode_cpe());
+                                            }
+                                            RecoveryBranch::Claim(claim) => {
+                                                bits.push(true);
+                                                bits.extend(
+                                                    claim
+                                                        .encode_cpe(account_key, vtxo_set)
+                                                        .await
+                                                        .map_err(
+                                                        EntryCPEEncodeError::ClaimCPEEncodeError,
+                                                    )?,
+                                                );
+                                            }
+                                        }
+                                    }
+                                    // The reserved branch fails the entry.
+                                    LowerRightBranch::Reserved(_) => {
+                                        return Err(EntryCPEEncodeError::ReservedBranch)
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Return the bits.
+        Ok(bits)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::constructive::entry::combinator::combinators::{
+    add::codec::cpe::encode::encode_error::AddCPEEncodeError,
+    batch_move::codec::cpe::encode::encode_error::BatchMoveCPEEncodeError,
+    bundle::codec::cpe::encode::encode_error::BundleCPEEncodeError,
+    call::codec::cpe::encode::encode_error::CallCPEEncodeError,
+    claim::codec::cpe::encode::encode_error::ClaimCPEEncodeError,
+    r#move::codec::cpe::encode::encode_error::MoveCPEEncodeError,
+    sub::codec::cpe::encode::encode_error::SubCPEEncodeError,
+    swapout::codec::cpe::encode::encode_error::SwapoutCPEEncodeError,
+};
+
+/// The error type for encoding an entry as a CPE.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EntryCPEEncodeError {
+    EmptyEntry,
+    EmptyUppermostLeftBranch,
+    MoveCPEEncodeError(MoveCPEEncodeError),
+    BatchMoveCPEEncodeError(BatchMoveCPEEncodeError),
+    CallCPEEncodeError(CallCPEEncodeError),
+    BundleCPEEncodeError(BundleCPEEncodeError),
+    AddCPEEncodeError(AddCPEEncodeError),
+    SubCPEEncodeError(SubCPEEncodeError),
+    SwapoutCPEEncodeError(SwapoutCPEEncodeError),
+    ClaimCPEEncodeError(ClaimCPEEncodeError),
+    ReservedBranch,
+}
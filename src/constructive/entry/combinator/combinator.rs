@@ -1,13 +1,18 @@
-use crate::constructive::entry::combinator::combinators::call::call::Call;
+use crate::constructive::entry::combinator::combinators::{
+    batch_move::batch_move::BatchMove, bundle::bundle::Bundle, call::call::Call,
+};
 
 use super::{
     combinator_type::CombinatorType,
     combinators::{
-        add::Add, claim::Claim, deploy::Deploy, liftup::liftup::Liftup, r#move::Move,
-        recharge::recharge::Recharge, reserved::Reserved, revive::Revive, sub::Sub,
-        swapout::Swapout,
+        add::add::Add, claim::claim::Claim, deploy::Deploy, liftup::liftup::Liftup,
+        r#move::r#move::Move, recharge::recharge::Recharge, reserved::Reserved,
+        revive::revive::Revive, sub::sub::Sub, swapout::swapout::Swapout,
     },
 };
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
 use serde::{Deserialize, Serialize};
 
 /// The combinator.
@@ -16,7 +21,9 @@ pub enum Combinator {
     Liftup(Liftup),
     Recharge(Recharge),
     Move(Move),
+    BatchMove(BatchMove),
     Call(Call),
+    Bundle(Bundle),
     Add(Add),
     Sub(Sub),
     Deploy(Deploy),
@@ -42,11 +49,21 @@ impl Combinator {
         Combinator::Move(r#move)
     }
 
+    /// Create a new `BatchMove` combinator.
+    pub fn new_batch_move(batch_move: BatchMove) -> Combinator {
+        Combinator::BatchMove(batch_move)
+    }
+
     /// Create a new `Call` combinator.
     pub fn new_call(call: Call) -> Combinator {
         Combinator::Call(call)
     }
 
+    /// Create a new `Bundle` combinator.
+    pub fn new_bundle(bundle: Bundle) -> Combinator {
+        Combinator::Bundle(bundle)
+    }
+
     /// Create a new `Add` combinator.
     pub fn new_add(add: Add) -> Combinator {
         Combinator::Add(add)
@@ -88,7 +105,9 @@ impl Combinator {
             Combinator::Liftup(_) => CombinatorType::Liftup,
             Combinator::Recharge(_) => CombinatorType::Recharge,
             Combinator::Move(_) => CombinatorType::Move,
+            Combinator::BatchMove(_) => CombinatorType::BatchMove,
             Combinator::Call(_) => CombinatorType::Call,
+            Combinator::Bundle(_) => CombinatorType::Bundle,
             Combinator::Add(_) => CombinatorType::Add,
             Combinator::Sub(_) => CombinatorType::Sub,
             Combinator::Deploy(_) => CombinatorType::Deploy,
@@ -101,9 +120,88 @@ impl Combinator {
 
     /// Serializes the combinator.
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
+        self.encode_canonical()
+    }
+}
+
+/// The canonical encoding for `Combinator`.
+///
+/// A combinator is tagged with its position in the enum, from 0x00 for `Liftup` to 0x0c for
+/// `Reserved`, followed by its fields.
+impl Canonical for Combinator {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Combinator::Liftup(liftup) => {
+                bytes.push(0x00);
+                liftup.encode_fields(bytes);
+            }
+            Combinator::Recharge(recharge) => {
+                bytes.push(0x01);
+                recharge.encode_fields(bytes);
+            }
+            Combinator::Move(r#move) => {
+                bytes.push(0x02);
+                r#move.encode_fields(bytes);
+            }
+            Combinator::BatchMove(batch_move) => {
+                bytes.push(0x03);
+                batch_move.encode_fields(bytes);
+            }
+            Combinator::Call(call) => {
+                bytes.push(0x04);
+                call.encode_fields(bytes);
+            }
+            Combinator::Bundle(bundle) => {
+                bytes.push(0x05);
+                bundle.encode_fields(bytes);
+            }
+            Combinator::Add(add) => {
+                bytes.push(0x06);
+                add.encode_fields(bytes);
+            }
+            Combinator::Sub(sub) => {
+                bytes.push(0x07);
+                sub.encode_fields(bytes);
+            }
+            Combinator::Deploy(deploy) => {
+                bytes.push(0x08);
+                deploy.encode_fields(bytes);
+            }
+            Combinator::Swapout(swapout) => {
+                bytes.push(0x09);
+                swapout.encode_fields(bytes);
+            }
+            Combinator::Revive(revive) => {
+                bytes.push(0x0a);
+                revive.encode_fields(bytes);
+            }
+            Combinator::Claim(claim) => {
+                bytes.push(0x0b);
+                claim.encode_fields(bytes);
+            }
+            Combinator::Reserved(reserved) => {
+                bytes.push(0x0c);
+                reserved.encode_fields(bytes);
+            }
+        }
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Combinator, CanonicalDecodeError> {
+        match reader.read_u8()? {
+            0x00 => Ok(Combinator::Liftup(Liftup::decode_fields(reader)?)),
+            0x01 => Ok(Combinator::Recharge(Recharge::decode_fields(reader)?)),
+            0x02 => Ok(Combinator::Move(Move::decode_fields(reader)?)),
+            0x03 => Ok(Combinator::BatchMove(BatchMove::decode_fields(reader)?)),
+            0x04 => Ok(Combinator::Call(Call::decode_fields(reader)?)),
+            0x05 => Ok(Combinator::Bundle(Bundle::decode_fields(reader)?)),
+            0x06 => Ok(Combinator::Add(Add::decode_fields(reader)?)),
+            0x07 => Ok(Combinator::Sub(Sub::decode_fields(reader)?)),
+            0x08 => Ok(Combinator::Deploy(Deploy::decode_fields(reader)?)),
+            0x09 => Ok(Combinator::Swapout(Swapout::decode_fields(reader)?)),
+            0x0a => Ok(Combinator::Revive(Revive::decode_fields(reader)?)),
+            0x0b => Ok(Combinator::Claim(Claim::decode_fields(reader)?)),
+            0x0c => Ok(Combinator::Reserved(Reserved::decode_fields(reader)?)),
+            tag => Err(CanonicalDecodeError::InvalidTag(tag)),
         }
     }
 }
@@ -6,7 +6,9 @@ pub enum CombinatorType {
     Liftup,
     Recharge,
     Move,
+    BatchMove,
     Call,
+    Bundle,
     Add,
     Sub,
     Deploy,
@@ -23,7 +25,9 @@ impl CombinatorType {
             CombinatorType::Liftup => "liftup",
             CombinatorType::Recharge => "recharge",
             CombinatorType::Move => "move",
+            CombinatorType::BatchMove => "batch_move",
             CombinatorType::Call => "call",
+            CombinatorType::Bundle => "bundle",
             CombinatorType::Add => "add",
             CombinatorType::Sub => "sub",
             CombinatorType::Deploy => "deploy",
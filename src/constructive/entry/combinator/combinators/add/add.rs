@@ -2,9 +2,10 @@ use crate::{
     constructive::{
         entity::account::account::Account, entry::combinator::combinator_type::CombinatorType,
     },
+    operative::Chain,
     transmutative::{
+        codec::canonical::canonical::Canonical,
         hash::{Hash, HashTag},
-        secp::authenticable::AuthSighash,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -29,24 +30,19 @@ impl Add {
     }
 
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
     }
 
     pub fn validate_account(&self, account: Account) -> bool {
         self.account.key() == account.key()
     }
-}
 
-impl AuthSighash for Add {
-    fn auth_sighash(&self) -> [u8; 32] {
+    pub fn auth_sighash(&self, chain: Chain) -> [u8; 32] {
         let mut preimage: Vec<u8> = Vec::<u8>::new();
 
         preimage.extend(self.account.key().serialize_xonly());
         preimage.extend(self.amount.to_le_bytes());
 
-        preimage.hash(Some(HashTag::SighashCombinator(CombinatorType::Add)))
+        preimage.hash(Some(HashTag::SighashCombinator(chain, CombinatorType::Add)))
     }
 }
@@ -0,0 +1,23 @@
+use crate::{
+    constructive::{
+        entity::account::account::Account, entry::combinator::combinators::add::add::Add,
+    },
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    },
+};
+
+/// The canonical encoding for `Add`.
+impl Canonical for Add {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.account().encode_fields(bytes);
+        bytes.extend(self.amount().to_le_bytes());
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Add, CanonicalDecodeError> {
+        let account = Account::decode_fields(reader)?;
+        let amount = reader.read_u32()?;
+
+        Ok(Add::new(account, amount))
+    }
+}
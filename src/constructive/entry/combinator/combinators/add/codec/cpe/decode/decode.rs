@@ -0,0 +1,27 @@
+use crate::constructive::{
+    entity::account::account::Account,
+    entry::combinator::combinators::add::{
+        add::Add, codec::cpe::decode::decode_error::AddCPEDecodingError,
+    },
+    valtype::val::short_val::short_val::ShortVal,
+};
+
+impl Add {
+    /// Compact payload decoding for `Add`.
+    /// Decodes an `Add` from a bit stream, with the account implied by the entry.
+    pub fn decode_cpe(
+        bit_stream: &mut bit_vec::Iter<'_>,
+        account: Account,
+    ) -> Result<Add, AddCPEDecodingError> {
+        // Decode the amount.
+        let amount = ShortVal::decode_cpe(bit_stream)
+            .map_err(AddCPEDecodingError::AmountCPEDecodeError)?
+            .value();
+
+        // Construct the `Add`.
+        let add = Add::new(account, amount);
+
+        // Return the `Add`.
+        Ok(add)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::constructive::{
+    entry::combinator::combinators::add::{
+        add::Add, codec::cpe::encode::encode_error::AddCPEEncodeError,
+    },
+    valtype::val::short_val::short_val::ShortVal,
+};
+use bit_vec::BitVec;
+
+impl Add {
+    /// Encodes the `Add` as a bit vector.
+    pub fn encode_cpe(&self, account_key: [u8; 32]) -> Result<BitVec, AddCPEEncodeError> {
+        // Initialize empty bit vector.
+        let mut bits = BitVec::new();
+
+        // Match the account key, as the account is implied by the entry.
+        let found_key = self.account().key().serialize_xonly();
+        if account_key != found_key {
+            return Err(AddCPEEncodeError::AccountKeyMismatch(
+                account_key,
+                found_key,
+            ));
+        }
+
+        // Extend the amount as shortval.
+        bits.extend(ShortVal::new(self.amount()).encode_cpe());
+
+        // Return the bits.
+        Ok(bits)
+    }
+}
@@ -0,0 +1,9 @@
+/// Types for account key.
+type ExpectedAccountKey = [u8; 32];
+type FoundAccountKey = [u8; 32];
+
+/// The error type for encoding an `Add` as a CPE.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddCPEEncodeError {
+    AccountKeyMismatch(ExpectedAccountKey, FoundAccountKey),
+}
@@ -0,0 +1,62 @@
+use super::limits::{MAX_BATCH_MOVE_RECIPIENTS, MIN_BATCH_MOVE_RECIPIENTS};
+use crate::constructive::entity::account::account::Account;
+use crate::transmutative::codec::canonical::canonical::Canonical;
+use serde::{Deserialize, Serialize};
+
+/// A move from one account to many, such as a payroll or an exchange payout.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BatchMove {
+    from: Account,
+    recipients: Vec<(Account, u32)>,
+}
+
+impl BatchMove {
+    /// Creates a new batch move.
+    ///
+    /// A batch move pays between `MIN_BATCH_MOVE_RECIPIENTS` and `MAX_BATCH_MOVE_RECIPIENTS`
+    /// recipients, and its total amount must fit in a `u32`.
+    pub fn new(from: Account, recipients: Vec<(Account, u32)>) -> Option<BatchMove> {
+        // Check the number of recipients.
+        if recipients.len() < MIN_BATCH_MOVE_RECIPIENTS
+            || recipients.len() > MAX_BATCH_MOVE_RECIPIENTS
+        {
+            return None;
+        }
+
+        let batch_move = BatchMove { from, recipients };
+
+        // Check that the total amount does not overflow.
+        batch_move.total_amount()?;
+
+        Some(batch_move)
+    }
+
+    pub fn from(&self) -> Account {
+        self.from
+    }
+
+    /// Returns the recipients and their amounts, in order.
+    pub fn recipients(&self) -> Vec<(Account, u32)> {
+        self.recipients.clone()
+    }
+
+    /// Returns the number of recipients.
+    pub fn recipients_len(&self) -> usize {
+        self.recipients.len()
+    }
+
+    /// Returns the total amount moved, or `None` if it overflows a `u32`.
+    pub fn total_amount(&self) -> Option<u32> {
+        self.recipients
+            .iter()
+            .try_fold(0u32, |total, (_, amount)| total.checked_add(*amount))
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        self.encode_canonical()
+    }
+
+    pub fn validate_account(&self, account: Account) -> bool {
+        self.from.key() == account.key()
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    constructive::{
+        entity::account::account::Account,
+        entry::combinator::combinators::batch_move::batch_move::BatchMove,
+    },
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+        writer::CanonicalWriter,
+    },
+};
+
+/// The canonical encoding for `BatchMove`.
+impl Canonical for BatchMove {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.from().encode_fields(bytes);
+        bytes.write_list(&self.recipients(), |bytes, (to, amount)| {
+            to.encode_fields(bytes);
+            bytes.extend(amount.to_le_bytes());
+        });
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<BatchMove, CanonicalDecodeError> {
+        let from = Account::decode_fields(reader)?;
+        let recipients = reader.read_list(|reader| {
+            let to = Account::decode_fields(reader)?;
+            let amount = reader.read_u32()?;
+            Ok((to, amount))
+        })?;
+
+        // The recipient count and the total amount must be in range.
+        BatchMove::new(from, recipients).ok_or(CanonicalDecodeError::InvalidValue)
+    }
+}
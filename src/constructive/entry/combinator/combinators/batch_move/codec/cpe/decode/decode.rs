@@ -0,0 +1,54 @@
+use crate::{
+    constructive::{
+        entity::account::account::Account,
+        entry::combinator::combinators::batch_move::{
+            batch_move::BatchMove,
+            codec::cpe::decode::decode_error::BatchMoveCPEDecodingError,
+            limits::{MAX_BATCH_MOVE_RECIPIENTS, MIN_BATCH_MOVE_RECIPIENTS},
+        },
+        valtype::val::{atomic_val::atomic_val::AtomicVal, short_val::short_val::ShortVal},
+    },
+    inscriptive::registery::account_registery::ACCOUNT_REGISTERY,
+};
+
+impl BatchMove {
+    /// Compact payload decoding for `BatchMove`.
+    /// Decodes a `BatchMove` from a bit stream, with the sender implied by the entry.
+    pub async fn decode_cpe<'a>(
+        bit_stream: &mut bit_vec::Iter<'a>,
+        from: Account,
+        account_registery: &ACCOUNT_REGISTERY,
+    ) -> Result<BatchMove, BatchMoveCPEDecodingError> {
+        // Decode the number of recipients, offset by the minimum number of recipients.
+        let recipients_length = AtomicVal::decode_cpe(
+            bit_stream,
+            (MAX_BATCH_MOVE_RECIPIENTS - MIN_BATCH_MOVE_RECIPIENTS) as u8,
+        )
+        .map_err(BatchMoveCPEDecodingError::RecipientsLengthCPEDecodeError)?
+        .value() as usize
+            + MIN_BATCH_MOVE_RECIPIENTS;
+
+        // Decode the recipients.
+        let mut recipients = Vec::<(Account, u32)>::new();
+        for index in 0..recipients_length {
+            // Decode the receiving account.
+            let to = Account::decode_cpe(bit_stream, account_registery)
+                .await
+                .map_err(|e| BatchMoveCPEDecodingError::ToAccountCPEDecodeError(index, e))?;
+
+            // Decode the amount.
+            let amount = ShortVal::decode_cpe(bit_stream)
+                .map_err(|e| BatchMoveCPEDecodingError::AmountCPEDecodeError(index, e))?
+                .value();
+
+            recipients.push((to, amount));
+        }
+
+        // Construct the batch move.
+        let batch_move = BatchMove::new(from, recipients)
+            .ok_or(BatchMoveCPEDecodingError::BatchMoveConstructionError)?;
+
+        // Return the batch move.
+        Ok(batch_move)
+    }
+}
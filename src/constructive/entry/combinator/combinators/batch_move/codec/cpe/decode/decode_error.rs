@@ -0,0 +1,19 @@
+use crate::constructive::{
+    entity::account::cpe::decode::decode_error::AccountCPEDecodingError,
+    valtype::val::{
+        atomic_val::cpe::decode::decode_error::AtomicValCPEDecodingError,
+        short_val::cpe::decode::decode_error::ShortValCPEDecodingError,
+    },
+};
+
+/// The index of the recipient in the batch move.
+type RecipientIndex = usize;
+
+/// The error type for decoding a `BatchMove` from a bit stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BatchMoveCPEDecodingError {
+    RecipientsLengthCPEDecodeError(AtomicValCPEDecodingError),
+    ToAccountCPEDecodeError(RecipientIndex, AccountCPEDecodingError),
+    AmountCPEDecodeError(RecipientIndex, ShortValCPEDecodingError),
+    BatchMoveConstructionError,
+}
@@ -0,0 +1,49 @@
+use crate::constructive::{
+    entry::combinator::combinators::batch_move::{
+        batch_move::BatchMove,
+        codec::cpe::encode::encode_error::BatchMoveCPEEncodeError,
+        limits::{MAX_BATCH_MOVE_RECIPIENTS, MIN_BATCH_MOVE_RECIPIENTS},
+    },
+    valtype::val::{atomic_val::atomic_val::AtomicVal, short_val::short_val::ShortVal},
+};
+use bit_vec::BitVec;
+
+impl BatchMove {
+    /// Encodes the batch move as a bit vector.
+    pub fn encode_cpe(&self, account_key: [u8; 32]) -> Result<BitVec, BatchMoveCPEEncodeError> {
+        // Initialize empty bit vector.
+        let mut bits = BitVec::new();
+
+        // Match the account key, as the sender is implied by the entry.
+        let from_key = self.from().key().serialize_xonly();
+        if account_key != from_key {
+            return Err(BatchMoveCPEEncodeError::AccountKeyMismatch(
+                account_key,
+                from_key,
+            ));
+        }
+
+        // Number of recipients as atomic value, offset by the minimum number of recipients.
+        let recipients_length_as_atomicval = AtomicVal::new(
+            (self.recipients_len() - MIN_BATCH_MOVE_RECIPIENTS) as u8,
+            (MAX_BATCH_MOVE_RECIPIENTS - MIN_BATCH_MOVE_RECIPIENTS) as u8,
+        );
+
+        // Extend the number of recipients.
+        bits.extend(
+            recipients_length_as_atomicval
+                .encode_cpe()
+                .map_err(BatchMoveCPEEncodeError::RecipientsLengthCPEEncodeError)?,
+        );
+
+        // Extend the recipients.
+        // Registered recipients are encoded by their registery rank.
+        for (to, amount) in self.recipients().iter() {
+            bits.extend(to.encode_cpe());
+            bits.extend(ShortVal::new(*amount).encode_cpe());
+        }
+
+        // Return the bits.
+        Ok(bits)
+    }
+}
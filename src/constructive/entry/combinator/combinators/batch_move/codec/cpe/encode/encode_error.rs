@@ -0,0 +1,12 @@
+use crate::constructive::valtype::val::atomic_val::cpe::encode::encode_error::AtomicValCPEEncodeError;
+
+/// Types for account key.
+type ExpectedAccountKey = [u8; 32];
+type FoundAccountKey = [u8; 32];
+
+/// The error type for encoding a batch move as a CPE.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BatchMoveCPEEncodeError {
+    AccountKeyMismatch(ExpectedAccountKey, FoundAccountKey),
+    RecipientsLengthCPEEncodeError(AtomicValCPEEncodeError),
+}
@@ -0,0 +1,31 @@
+use crate::constructive::entry::combinator::combinator_type::CombinatorType;
+use crate::constructive::entry::combinator::combinators::batch_move::batch_move::BatchMove;
+use crate::operative::Chain;
+use crate::transmutative::hash::Hash;
+use crate::transmutative::hash::HashTag;
+
+/// The sighash for the `BatchMove` combinator.
+impl BatchMove {
+    /// Returns a single sighash covering all outputs of the batch move.
+    pub fn auth_sighash(&self, chain: Chain) -> [u8; 32] {
+        let mut preimage: Vec<u8> = Vec::<u8>::new();
+
+        // Sender key
+        preimage.extend(self.from().key().serialize_xonly());
+
+        // Number of recipients as u32
+        preimage.extend((self.recipients_len() as u32).to_le_bytes());
+
+        // Recipient keys and amounts, in order.
+        for (to, amount) in self.recipients().iter() {
+            preimage.extend(to.key().serialize_xonly());
+            preimage.extend(amount.to_le_bytes());
+        }
+
+        // Hash the preimage
+        preimage.hash(Some(HashTag::SighashCombinator(
+            chain,
+            CombinatorType::BatchMove,
+        )))
+    }
+}
@@ -0,0 +1,5 @@
+/// The maximum number of recipients in a batch move.
+pub const MAX_BATCH_MOVE_RECIPIENTS: usize = 64;
+
+/// The minimum number of recipients in a batch move.
+pub const MIN_BATCH_MOVE_RECIPIENTS: usize = 2;
@@ -0,0 +1,3 @@
+pub mod batch_move;
+pub mod codec;
+pub mod limits;
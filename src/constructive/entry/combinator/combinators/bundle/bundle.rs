@@ -0,0 +1,57 @@
+use super::{
+    bundle_item::BundleItem,
+    limits::{MAX_BUNDLE_LENGTH, MIN_BUNDLE_LENGTH},
+};
+use crate::constructive::entry::combinator::combinators::{call::call::Call, r#move::r#move::Move};
+use crate::transmutative::codec::canonical::canonical::Canonical;
+use serde::{Deserialize, Serialize};
+
+/// An ordered bundle of calls, and optionally moves, executed all-or-nothing.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Bundle {
+    items: Vec<BundleItem>,
+}
+
+impl Bundle {
+    /// Creates a new bundle.
+    ///
+    /// A bundle holds between `MIN_BUNDLE_LENGTH` and `MAX_BUNDLE_LENGTH` items, at least one
+    /// of which is a call.
+    pub fn new(items: Vec<BundleItem>) -> Option<Bundle> {
+        // Check the bundle length.
+        if items.len() < MIN_BUNDLE_LENGTH || items.len() > MAX_BUNDLE_LENGTH {
+            return None;
+        }
+
+        // Check that there is at least one call.
+        if !items.iter().any(|item| item.call().is_some()) {
+            return None;
+        }
+
+        Some(Bundle { items })
+    }
+
+    /// Returns the items, in execution order.
+    pub fn items(&self) -> Vec<BundleItem> {
+        self.items.clone()
+    }
+
+    /// Returns the number of items.
+    pub fn items_len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns the calls, in execution order.
+    pub fn calls(&self) -> Vec<Call> {
+        self.items.iter().filter_map(|item| item.call()).collect()
+    }
+
+    /// Returns the moves, in execution order.
+    pub fn moves(&self) -> Vec<Move> {
+        self.items.iter().filter_map(|item| item.r#move()).collect()
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        self.encode_canonical()
+    }
+}
@@ -0,0 +1,27 @@
+use crate::constructive::entry::combinator::combinators::{call::call::Call, r#move::r#move::Move};
+use serde::{Deserialize, Serialize};
+
+/// An item of a bundle.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BundleItem {
+    Move(Move),
+    Call(Call),
+}
+
+impl BundleItem {
+    /// Returns the call, if the item is a call.
+    pub fn call(&self) -> Option<Call> {
+        match self {
+            BundleItem::Call(call) => Some(call.clone()),
+            BundleItem::Move(_) => None,
+        }
+    }
+
+    /// Returns the move, if the item is a move.
+    pub fn r#move(&self) -> Option<Move> {
+        match self {
+            BundleItem::Move(r#move) => Some(r#move.clone()),
+            BundleItem::Call(_) => None,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    constructive::entry::combinator::combinators::{
+        bundle::{bundle::Bundle, bundle_item::BundleItem},
+        call::call::Call,
+        r#move::r#move::Move,
+    },
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+        writer::CanonicalWriter,
+    },
+};
+
+/// The canonical encoding for `BundleItem`.
+///
+/// An item is tagged 0x00 for a `Move` and 0x01 for a `Call`, followed by its fields.
+impl Canonical for BundleItem {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        match self {
+            BundleItem::Move(r#move) => {
+                bytes.push(0x00);
+                r#move.encode_fields(bytes);
+            }
+            BundleItem::Call(call) => {
+                bytes.push(0x01);
+                call.encode_fields(bytes);
+            }
+        }
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<BundleItem, CanonicalDecodeError> {
+        match reader.read_u8()? {
+            0x00 => Ok(BundleItem::Move(Move::decode_fields(reader)?)),
+            0x01 => Ok(BundleItem::Call(Call::decode_fields(reader)?)),
+            tag => Err(CanonicalDecodeError::InvalidTag(tag)),
+        }
+    }
+}
+
+/// The canonical encoding for `Bundle`.
+impl Canonical for Bundle {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.write_list(&self.items(), |bytes, item| item.encode_fields(bytes));
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Bundle, CanonicalDecodeError> {
+        let items = reader.read_list(BundleItem::decode_fields)?;
+
+        // The bundle length must be in range, with at least one call.
+        Bundle::new(items).ok_or(CanonicalDecodeError::InvalidValue)
+    }
+}
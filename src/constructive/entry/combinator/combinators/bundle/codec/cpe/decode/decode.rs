@@ -0,0 +1,78 @@
+use crate::{
+    constructive::{
+        entity::account::account::Account,
+        entry::combinator::combinators::{
+            bundle::{
+                bundle::Bundle,
+                bundle_item::BundleItem,
+                codec::cpe::decode::decode_error::BundleCPEDecodingError,
+                limits::{MAX_BUNDLE_LENGTH, MIN_BUNDLE_LENGTH},
+            },
+            call::call::Call,
+            r#move::r#move::Move,
+        },
+        valtype::val::atomic_val::atomic_val::AtomicVal,
+    },
+    inscriptive::{registery::registery::REGISTERY, repo::repo::PROGRAMS_REPO},
+};
+
+impl Bundle {
+    /// Compact payload decoding for `Bundle`.
+    /// Decodes a `Bundle` from a bit stream, with the account implied by the entry.
+    pub async fn decode_cpe<'a>(
+        bit_stream: &mut bit_vec::Iter<'a>,
+        account: Account,
+        registery: &REGISTERY,
+        repo: &PROGRAMS_REPO,
+        ops_price_base: u32,
+    ) -> Result<Bundle, BundleCPEDecodingError> {
+        // Decode the bundle length, offset by the minimum bundle length.
+        let bundle_length =
+            AtomicVal::decode_cpe(bit_stream, (MAX_BUNDLE_LENGTH - MIN_BUNDLE_LENGTH) as u8)
+                .map_err(BundleCPEDecodingError::BundleLengthCPEDecodeError)?
+                .value() as usize
+                + MIN_BUNDLE_LENGTH;
+
+        // Get the account registery.
+        let account_registery = {
+            let _registery = registery.lock().await;
+            _registery.account_registery()
+        };
+
+        // Decode the items.
+        let mut items = Vec::<BundleItem>::new();
+        for index in 0..bundle_length {
+            // Decode the item kind.
+            let is_call = bit_stream
+                .next()
+                .ok_or(BundleCPEDecodingError::ItemKindBitCollectError(index))?;
+
+            let item = match is_call {
+                false => BundleItem::Move(
+                    Move::decode_cpe(bit_stream, account, &account_registery)
+                        .await
+                        .map_err(|e| BundleCPEDecodingError::MoveCPEDecodeError(index, e))?,
+                ),
+                true => BundleItem::Call(
+                    Call::decode_cpe(
+                        bit_stream,
+                        account.key().serialize_xonly(),
+                        registery,
+                        repo,
+                        ops_price_base,
+                    )
+                    .await
+                    .map_err(|e| BundleCPEDecodingError::CallCPEDecodeError(index, e))?,
+                ),
+            };
+
+            items.push(item);
+        }
+
+        // Construct the bundle.
+        let bundle = Bundle::new(items).ok_or(BundleCPEDecodingError::BundleConstructionError)?;
+
+        // Return the bundle.
+        Ok(bundle)
+    }
+}
@@ -0,0 +1,20 @@
+use crate::constructive::{
+    entry::combinator::combinators::{
+        call::codec::cpe::decode::decode_error::CallCPEDecodingError,
+        r#move::codec::cpe::decode::decode_error::MoveCPEDecodingError,
+    },
+    valtype::val::atomic_val::cpe::decode::decode_error::AtomicValCPEDecodingError,
+};
+
+/// The index of the item in the bundle.
+type ItemIndex = usize;
+
+/// The error type for decoding a `Bundle` from a bit stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BundleCPEDecodingError {
+    BundleLengthCPEDecodeError(AtomicValCPEDecodingError),
+    ItemKindBitCollectError(ItemIndex),
+    MoveCPEDecodeError(ItemIndex, MoveCPEDecodingError),
+    CallCPEDecodeError(ItemIndex, CallCPEDecodingError),
+    BundleConstructionError,
+}
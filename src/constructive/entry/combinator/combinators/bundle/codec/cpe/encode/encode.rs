@@ -0,0 +1,72 @@
+use crate::{
+    constructive::{
+        entry::combinator::combinators::bundle::{
+            bundle::Bundle,
+            bundle_item::BundleItem,
+            codec::cpe::encode::encode_error::BundleCPEEncodeError,
+            limits::{MAX_BUNDLE_LENGTH, MIN_BUNDLE_LENGTH},
+        },
+        valtype::val::atomic_val::atomic_val::AtomicVal,
+    },
+    inscriptive::{registery::contract_registery::CONTRACT_REGISTERY, repo::repo::PROGRAMS_REPO},
+};
+use bit_vec::BitVec;
+
+impl Bundle {
+    /// Encodes the bundle as a bit vector.
+    pub async fn encode_cpe(
+        &self,
+        account_key: [u8; 32],
+        contract_registery: &CONTRACT_REGISTERY,
+        repo: &PROGRAMS_REPO,
+        ops_price_base: u32,
+    ) -> Result<BitVec, BundleCPEEncodeError> {
+        // Initialize empty bit vector.
+        let mut bits = BitVec::new();
+
+        // Bundle length as atomic value, offset by the minimum bundle length.
+        // A bundle length is bound, so it fits in a few bits rather than a shortval.
+        let bundle_length_as_atomicval = AtomicVal::new(
+            (self.items_len() - MIN_BUNDLE_LENGTH) as u8,
+            (MAX_BUNDLE_LENGTH - MIN_BUNDLE_LENGTH) as u8,
+        );
+
+        // Extend the bundle length.
+        bits.extend(
+            bundle_length_as_atomicval
+                .encode_cpe()
+                .map_err(BundleCPEEncodeError::BundleLengthCPEEncodeError)?,
+        );
+
+        // Extend the items.
+        for (index, item) in self.items().iter().enumerate() {
+            match item {
+                BundleItem::Move(r#move) => {
+                    // Push false for a move.
+                    bits.push(false);
+
+                    // Extend the move.
+                    bits.extend(
+                        r#move
+                            .encode_cpe(account_key)
+                            .map_err(|e| BundleCPEEncodeError::MoveCPEEncodeError(index, e))?,
+                    );
+                }
+                BundleItem::Call(call) => {
+                    // Push true for a call.
+                    bits.push(true);
+
+                    // Extend the call.
+                    bits.extend(
+                        call.encode_cpe(account_key, contract_registery, repo, ops_price_base)
+                            .await
+                            .map_err(|e| BundleCPEEncodeError::CallCPEEncodeError(index, e))?,
+                    );
+                }
+            }
+        }
+
+        // Return the bits.
+        Ok(bits)
+    }
+}
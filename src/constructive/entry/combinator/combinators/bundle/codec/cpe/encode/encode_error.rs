@@ -0,0 +1,18 @@
+use crate::constructive::{
+    entry::combinator::combinators::{
+        call::codec::cpe::encode::encode_error::CallCPEEncodeError,
+        r#move::codec::cpe::encode::encode_error::MoveCPEEncodeError,
+    },
+    valtype::val::atomic_val::cpe::encode::encode_error::AtomicValCPEEncodeError,
+};
+
+/// The index of the item in the bundle.
+type ItemIndex = usize;
+
+/// The error type for encoding a bundle as a CPE.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BundleCPEEncodeError {
+    BundleLengthCPEEncodeError(AtomicValCPEEncodeError),
+    MoveCPEEncodeError(ItemIndex, MoveCPEEncodeError),
+    CallCPEEncodeError(ItemIndex, CallCPEEncodeError),
+}
@@ -0,0 +1,40 @@
+use crate::constructive::entry::combinator::combinator_type::CombinatorType;
+use crate::constructive::entry::combinator::combinators::bundle::{
+    bundle::Bundle, bundle_item::BundleItem,
+};
+use crate::operative::Chain;
+use crate::transmutative::hash::Hash;
+use crate::transmutative::hash::HashTag;
+
+/// The sighash for the `Bundle` combinator.
+impl Bundle {
+    /// Returns the combined sighash of the bundle items.
+    ///
+    /// The calls of the bundle are bound to the entry nonce.
+    pub fn auth_sighash(&self, chain: Chain, nonce: u64) -> [u8; 32] {
+        let mut preimage: Vec<u8> = Vec::<u8>::new();
+
+        // Number of items as u32
+        preimage.extend((self.items_len() as u32).to_le_bytes());
+
+        // Items, each tagged by its kind.
+        for item in self.items().iter() {
+            match item {
+                BundleItem::Move(r#move) => {
+                    preimage.push(0x00);
+                    preimage.extend(r#move.auth_sighash(chain));
+                }
+                BundleItem::Call(call) => {
+                    preimage.push(0x01);
+                    preimage.extend(call.auth_sighash(chain, nonce));
+                }
+            }
+        }
+
+        // Hash the preimage
+        preimage.hash(Some(HashTag::SighashCombinator(
+            chain,
+            CombinatorType::Bundle,
+        )))
+    }
+}
@@ -0,0 +1,5 @@
+/// The maximum number of items in a bundle.
+pub const MAX_BUNDLE_LENGTH: usize = 16;
+
+/// The minimum number of items in a bundle.
+pub const MIN_BUNDLE_LENGTH: usize = 2;
@@ -0,0 +1,4 @@
+pub mod bundle;
+pub mod bundle_item;
+pub mod codec;
+pub mod limits;
@@ -1,4 +1,12 @@
-use crate::constructive::calldata::element::element::CallElement;
+use crate::{
+    constructive::calldata::element::element::CallElement,
+    operative::Chain,
+    transmutative::secp::{
+        into::{IntoPoint, IntoScalar},
+        schnorr::{sign, verify_xonly, Bytes32, SchnorrSigningMode},
+    },
+};
+use secp::{Point, Scalar};
 use serde::{Deserialize, Serialize};
 
 /// The holder of a call.
@@ -18,6 +26,10 @@ pub struct Call {
     pub ops_price_base: u32,
     /// The extra ops price.
     pub ops_price_extra_in: Option<u32>,
+    /// The fee payer key, if the call is sponsored.
+    pub fee_payer: Option<[u8; 32]>,
+    /// The fee payer co-signature over the call sighash, at the entry nonce.
+    pub fee_payer_sig: Option<(Point, Scalar)>,
 }
 
 impl Call {
@@ -30,6 +42,7 @@ impl Call {
         ops_budget: u32,
         ops_price_base: u32,
         ops_price_extra_in: Option<u32>,
+        fee_payer: Option<[u8; 32]>,
     ) -> Self {
         Self {
             account_key,
@@ -39,6 +52,8 @@ impl Call {
             ops_budget,
             ops_price_base,
             ops_price_extra_in,
+            fee_payer,
+            fee_payer_sig: None,
         }
     }
 
@@ -78,12 +93,87 @@ impl Call {
     }
 
     /// Returns the total ops price.
-    pub fn ops_price_total(&self) -> u32 {
-        self.ops_price_base + self.ops_price_extra_in.unwrap_or(0)
+    pub fn ops_price_total(&self) -> u64 {
+        self.ops_price_base as u64 + self.ops_price_extra_in.unwrap_or(0) as u64
     }
 
-    /// Validation from the broader Entry context.
-    pub fn entry_validation(&self, account_key: [u8; 32]) -> bool {
-        self.account_key == account_key
+    /// Returns the most fees the call can be charged; its ops budget at its total ops price.
+    pub fn max_fees(&self) -> u64 {
+        (self.ops_budget as u64).saturating_mul(self.ops_price_total())
+    }
+
+    /// Returns the fee payer key, if the call is sponsored.
+    pub fn fee_payer(&self) -> Option<[u8; 32]> {
+        self.fee_payer
+    }
+
+    /// Returns the key charged for the ops of the call.
+    ///
+    /// This is the fee payer if the call is sponsored, and the account otherwise.
+    pub fn payer_key(&self) -> [u8; 32] {
+        self.fee_payer.unwrap_or(self.account_key)
+    }
+
+    /// Co-signs the call as its fee payer, for the given chain and entry nonce.
+    pub fn sign_as_fee_payer(&mut self, secret_key: [u8; 32], chain: Chain, nonce: u64) -> bool {
+        // The secret key must belong to the fee payer.
+        let fee_payer = match self.fee_payer {
+            Some(fee_payer) => fee_payer,
+            None => return false,
+        };
+        match secret_key.secret_to_public() {
+            Some(public_key) if public_key == fee_payer => {}
+            _ => return false,
+        }
+
+        // Sign the call sighash.
+        let sig = match sign(
+            secret_key,
+            self.auth_sighash(chain, nonce),
+            SchnorrSigningMode::Cube,
+        ) {
+            Some(sig) => sig,
+            None => return false,
+        };
+        let public_nonce = match sig[..32].to_vec().into_point() {
+            Ok(public_nonce) => public_nonce,
+            Err(_) => return false,
+        };
+        let s_com = match sig[32..].to_vec().into_scalar() {
+            Ok(s_com) => s_com,
+            Err(_) => return false,
+        };
+
+        self.fee_payer_sig = Some((public_nonce, s_com));
+        true
+    }
+
+    /// Authenticates the fee payer co-signature for the given chain and entry nonce.
+    /// Calls that are not sponsored pass trivially.
+    pub fn authenticate_fee_payer(&self, chain: Chain, nonce: u64) -> bool {
+        let fee_payer = match self.fee_payer {
+            Some(fee_payer) => fee_payer,
+            None => return true,
+        };
+
+        let (public_nonce, s_com) = match &self.fee_payer_sig {
+            Some(sig) => sig,
+            None => return false,
+        };
+
+        let mut sig = Vec::<u8>::with_capacity(64);
+        sig.extend(public_nonce.serialize_xonly());
+        sig.extend(s_com.serialize());
+        let sig: [u8; 64] = match sig.try_into() {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+
+        verify_xonly(
+            fee_payer,
+            self.auth_sighash(chain, nonce),
+            sig,
+            SchnorrSigningMode::Cube,
+        )
     }
 }
@@ -0,0 +1,44 @@
+use crate::{
+    constructive::{
+        calldata::element::element::CallElement, entry::combinator::combinators::call::call::Call,
+    },
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+        writer::CanonicalWriter,
+    },
+};
+
+/// The canonical encoding for `Call`.
+impl Canonical for Call {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.account_key);
+        bytes.extend(self.contract_id);
+        bytes.push(self.method_index);
+        bytes.write_list(&self.args, |bytes, arg| arg.encode_fields(bytes));
+        bytes.extend(self.ops_budget.to_le_bytes());
+        bytes.extend(self.ops_price_base.to_le_bytes());
+        bytes.write_option(&self.ops_price_extra_in, |bytes, price| {
+            bytes.extend(price.to_le_bytes())
+        });
+        bytes.write_option(&self.fee_payer, |bytes, key| bytes.extend(key));
+        bytes.write_option(&self.fee_payer_sig, |bytes, (nonce, s_com)| {
+            bytes.write_point(nonce);
+            bytes.write_scalar(s_com);
+        });
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Call, CanonicalDecodeError> {
+        Ok(Call {
+            account_key: reader.read_array::<32>()?,
+            contract_id: reader.read_array::<32>()?,
+            method_index: reader.read_u8()?,
+            args: reader.read_list(CallElement::decode_fields)?,
+            ops_budget: reader.read_u32()?,
+            ops_price_base: reader.read_u32()?,
+            ops_price_extra_in: reader.read_option(CanonicalReader::read_u32)?,
+            fee_payer: reader.read_option(CanonicalReader::read_array::<32>)?,
+            fee_payer_sig: reader
+                .read_option(|reader| Ok((reader.read_point()?, reader.read_scalar()?)))?,
+        })
+    }
+}
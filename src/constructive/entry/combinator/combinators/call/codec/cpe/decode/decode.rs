@@ -0,0 +1,165 @@
+use crate::{
+    constructive::{
+        calldata::element::element::CallElement,
+        entry::combinator::combinators::call::{
+            call::Call, codec::cpe::decode::decode_error::CallCPEDecodingError,
+        },
+        valtype::val::{atomic_val::atomic_val::AtomicVal, short_val::short_val::ShortVal},
+    },
+    inscriptive::{registery::registery::REGISTERY, repo::repo::PROGRAMS_REPO},
+    transmutative::secp::into::{IntoPoint, IntoScalar},
+};
+use bit_vec::BitVec;
+
+impl Call {
+    /// Compact payload decoding for `Call`.
+    /// Decodes a `Call` from a bit stream.
+    pub async fn decode_cpe<'a>(
+        bit_stream: &mut bit_vec::Iter<'a>,
+        account_key: [u8; 32],
+        registery: &REGISTERY,
+        repo: &PROGRAMS_REPO,
+        ops_price_base: u32,
+    ) -> Result<Call, CallCPEDecodingError> {
+        // Decode the contract rank.
+        let contract_rank = ShortVal::decode_cpe(bit_stream)
+            .map_err(CallCPEDecodingError::ContractRankCPEDecodeError)?
+            .value();
+
+        // Get the contract registery.
+        let contract_registery = {
+            let _registery = registery.lock().await;
+            _registery.contract_registery()
+        };
+
+        // Retrieve the contract id given the rank.
+        let contract_id = {
+            let _contract_registery = contract_registery.lock().await;
+            _contract_registery.contract_by_rank(contract_rank)
+        }
+        .ok_or(CallCPEDecodingError::ContractNotFoundAtRank(contract_rank))?
+        .contract_id();
+
+        // Retrieve the program.
+        let program = {
+            let _repo = repo.lock().await;
+            _repo.program_by_contract_id(&contract_id)
+        }
+        .ok_or(CallCPEDecodingError::ContractMethodCountNotFoundAtContractId(contract_id))?;
+
+        // Methods length
+        let contract_methods_count: u8 = program.methods_len().try_into().map_err(|_| {
+            CallCPEDecodingError::ContractMethodCountNotFoundAtContractId(contract_id)
+        })?;
+
+        // Decode the method index.
+        let method_index = AtomicVal::decode_cpe(bit_stream, contract_methods_count)
+            .map_err(CallCPEDecodingError::MethodIndexCPEDecodeError)?
+            .value();
+
+        // Retrieve the method.
+        let method = program.method_by_index(method_index).ok_or(
+            CallCPEDecodingError::MethodNotFoundAtIndex(contract_id, method_index),
+        )?;
+
+        // Decode the args.
+        // The args length is implied by the method arg types.
+        let mut args = Vec::<CallElement>::new();
+        for (index, arg_type) in method.arg_types().into_iter().enumerate() {
+            let arg = CallElement::decode_cpe(bit_stream, arg_type, registery)
+                .await
+                .map_err(|e| CallCPEDecodingError::ArgCPEDecodeError(index, e))?;
+            args.push(arg);
+        }
+
+        // Decode the ops budget.
+        let ops_budget = ShortVal::decode_cpe(bit_stream)
+            .map_err(CallCPEDecodingError::OpsBudgetCPEDecodeError)?
+            .value();
+
+        // Decode the ops price extra in.
+        let ops_price_extra_in = match bit_stream
+            .next()
+            .ok_or(CallCPEDecodingError::OpsPriceExtraInPresenceBitCollectError)?
+        {
+            false => None,
+            true => Some(
+                ShortVal::decode_cpe(bit_stream)
+                    .map_err(CallCPEDecodingError::OpsPriceExtraInCPEDecodeError)?
+                    .value(),
+            ),
+        };
+
+        // Decode the fee payer.
+        let fee_payer = match bit_stream
+            .next()
+            .ok_or(CallCPEDecodingError::FeePayerPresenceBitCollectError)?
+        {
+            false => None,
+            true => {
+                // Collect 256 bits.
+                let mut bits = BitVec::new();
+                for _ in 0..256 {
+                    bits.push(
+                        bit_stream
+                            .next()
+                            .ok_or(CallCPEDecodingError::FeePayerBitsCollectError)?,
+                    );
+                }
+
+                // Convert to the fee payer key.
+                let fee_payer: [u8; 32] = bits
+                    .to_bytes()
+                    .try_into()
+                    .map_err(|_| CallCPEDecodingError::FeePayerBitsCollectError)?;
+
+                Some(fee_payer)
+            }
+        };
+
+        // Decode the fee payer co-signature, if the call is sponsored.
+        let fee_payer_sig = match fee_payer {
+            None => None,
+            Some(_) => {
+                // Collect 512 bits.
+                let mut bits = BitVec::new();
+                for _ in 0..512 {
+                    bits.push(
+                        bit_stream
+                            .next()
+                            .ok_or(CallCPEDecodingError::FeePayerSigBitsCollectError)?,
+                    );
+                }
+                let sig = bits.to_bytes();
+
+                // Convert to the nonce and the commitment.
+                let nonce = sig[..32]
+                    .to_vec()
+                    .into_point()
+                    .map_err(|_| CallCPEDecodingError::FeePayerSigParseError)?;
+                let s_com = sig[32..]
+                    .to_vec()
+                    .into_scalar()
+                    .map_err(|_| CallCPEDecodingError::FeePayerSigParseError)?;
+
+                Some((nonce, s_com))
+            }
+        };
+
+        // Construct the call.
+        let mut call = Call::new(
+            account_key,
+            contract_id,
+            method_index,
+            args,
+            ops_budget,
+            ops_price_base,
+            ops_price_extra_in,
+            fee_payer,
+        );
+        call.fee_payer_sig = fee_payer_sig;
+
+        // Return the call.
+        Ok(call)
+    }
+}
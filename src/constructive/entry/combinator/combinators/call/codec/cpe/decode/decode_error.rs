@@ -0,0 +1,35 @@
+use crate::constructive::{
+    calldata::element::cpe::decode::decode_error::CallArgCPEDecodingError,
+    valtype::val::{
+        atomic_val::cpe::decode::decode_error::AtomicValCPEDecodingError,
+        short_val::cpe::decode::decode_error::ShortValCPEDecodingError,
+    },
+};
+
+/// Type alias for the contract rank.
+type ContractRank = u32;
+
+/// Type alias for the method index.
+type MethodIndex = u8;
+
+/// Type alias for the arg index.
+type ArgIndex = usize;
+
+/// The error type for decoding a `Call` from a bit stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CallCPEDecodingError {
+    ContractRankCPEDecodeError(ShortValCPEDecodingError),
+    ContractNotFoundAtRank(ContractRank),
+    ProgramNotFoundAtContractId([u8; 32]),
+    ContractMethodCountNotFoundAtContractId([u8; 32]),
+    MethodIndexCPEDecodeError(AtomicValCPEDecodingError),
+    MethodNotFoundAtIndex([u8; 32], MethodIndex),
+    ArgCPEDecodeError(ArgIndex, CallArgCPEDecodingError),
+    OpsBudgetCPEDecodeError(ShortValCPEDecodingError),
+    OpsPriceExtraInPresenceBitCollectError,
+    OpsPriceExtraInCPEDecodeError(ShortValCPEDecodingError),
+    FeePayerPresenceBitCollectError,
+    FeePayerBitsCollectError,
+    FeePayerSigBitsCollectError,
+    FeePayerSigParseError,
+}
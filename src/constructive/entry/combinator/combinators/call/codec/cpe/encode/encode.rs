@@ -65,8 +65,11 @@ impl Call {
 
         // Extend the args.
         // No need to encode the args length.
-        for arg in self.args.iter() {
-            bits.extend(arg.encode_cpe());
+        for (index, arg) in self.args.iter().enumerate() {
+            bits.extend(
+                arg.encode_cpe()
+                    .map_err(|e| CallCPEEncodeError::ArgCPEEncodeError(index, e))?,
+            );
         }
 
         // Ops budget as shortval
@@ -101,6 +104,30 @@ impl Call {
             }
         }
 
+        // Match the fee payer.
+        match self.fee_payer {
+            None => {
+                // Push false for this field being absent.
+                bits.push(false);
+            }
+            Some(fee_payer) => {
+                // Push true for this field being present.
+                bits.push(true);
+
+                // Extend the fee payer key.
+                bits.extend(BitVec::from_bytes(&fee_payer));
+
+                // A sponsored call must carry the fee payer co-signature.
+                let (nonce, s_com) = self
+                    .fee_payer_sig
+                    .ok_or(CallCPEEncodeError::FeePayerSigMissing(fee_payer))?;
+
+                // Extend the fee payer co-signature.
+                bits.extend(BitVec::from_bytes(&nonce.serialize_xonly()));
+                bits.extend(BitVec::from_bytes(&s_com.serialize()));
+            }
+        }
+
         // Return the bits.
         Ok(bits)
     }
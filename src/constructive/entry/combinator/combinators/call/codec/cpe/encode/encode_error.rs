@@ -1,4 +1,7 @@
-use crate::constructive::valtype::val::atomic_val::cpe::encode::encode_error::AtomicValCPEEncodeError;
+use crate::constructive::{
+    calldata::element::cpe::encode::encode_error::CallArgCPEEncodeError,
+    valtype::val::atomic_val::cpe::encode::encode_error::AtomicValCPEEncodeError,
+};
 
 /// Types for account key.
 type ExpectedAccountKey = [u8; 32];
@@ -15,5 +18,7 @@ pub enum CallCPEEncodeError {
     ContractRankNotFoundAtContractId([u8; 32]),
     ContractMethodCountNotFoundAtContractId([u8; 32]),
     MethodIndexCPEEncodeError(AtomicValCPEEncodeError),
+    ArgCPEEncodeError(usize, CallArgCPEEncodeError),
     BaseOpsPriceMismatch(ExpectedBaseOpsPrice, FoundBaseOpsPrice),
+    FeePayerSigMissing([u8; 32]),
 }
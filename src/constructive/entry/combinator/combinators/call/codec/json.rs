@@ -20,6 +20,7 @@ impl Call {
             "ops_price_base": self.ops_price_base,
             "ops_price_extra_in": self.ops_price_extra_in,
             "ops_price_total": self.ops_price_total(),
+            "fee_payer": self.fee_payer.map(hex::encode),
         });
 
         // Return the value
@@ -1,3 +1,4 @@
+pub mod canonical;
 pub mod cpe;
 pub mod json;
 pub mod sighash;
@@ -1,17 +1,24 @@
 use crate::constructive::entry::combinator::combinator_type::CombinatorType;
 use crate::constructive::entry::combinator::combinators::call::call::Call;
+use crate::operative::Chain;
 use crate::transmutative::hash::Hash;
-use crate::transmutative::{hash::HashTag, secp::authenticable::AuthSighash};
+use crate::transmutative::hash::HashTag;
 
 /// The sighash for the `Call` combinator.
-impl AuthSighash for Call {
+impl Call {
     /// Returns the sighash of the call.
-    fn auth_sighash(&self) -> [u8; 32] {
+    ///
+    /// The sighash is bound to the chain and the entry nonce, so that a fee payer
+    /// co-signature cannot be replayed on another chain or in a later entry.
+    pub fn auth_sighash(&self, chain: Chain, nonce: u64) -> [u8; 32] {
         let mut preimage: Vec<u8> = Vec::<u8>::new();
 
         // Account key
         preimage.extend(self.account_key);
 
+        // Entry nonce
+        preimage.extend(nonce.to_le_bytes());
+
         // Contract id
         preimage.extend(self.contract_id);
 
@@ -43,7 +50,21 @@ impl AuthSighash for Call {
             }
         }
 
+        // Fee payer.
+        match self.fee_payer {
+            Some(fee_payer) => {
+                preimage.push(0x01);
+                preimage.extend(fee_payer);
+            }
+            None => {
+                preimage.push(0x00);
+            }
+        }
+
         // Hash the preimage
-        preimage.hash(Some(HashTag::SighashCombinator(CombinatorType::Call)))
+        preimage.hash(Some(HashTag::SighashCombinator(
+            chain,
+            CombinatorType::Call,
+        )))
     }
 }
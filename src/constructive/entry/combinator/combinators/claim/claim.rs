@@ -0,0 +1,72 @@
+use crate::transmutative::codec::canonical::canonical::Canonical;
+use crate::{
+    constructive::{entity::account::account::Account, txo::vtxo::VTXO},
+    inscriptive::set::vtxo_set::VTXOSet,
+};
+use serde::{Deserialize, Serialize};
+
+/// `Claim` lets an account recover its VTXOs after an operator failure.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Claim {
+    account: Account,
+    claim_vtxos: Vec<VTXO>,
+}
+
+impl Claim {
+    /// Creates a new claim for the given VTXOs.
+    /// Returns `None` if there is nothing to claim, or if a VTXO has no outpoint.
+    pub fn new(account: Account, vtxos: Vec<VTXO>) -> Option<Claim> {
+        if vtxos.is_empty() {
+            return None;
+        }
+
+        if vtxos.iter().any(|vtxo| vtxo.outpoint().is_none()) {
+            return None;
+        }
+
+        let claim = Claim {
+            account,
+            claim_vtxos: vtxos,
+        };
+
+        Some(claim)
+    }
+
+    pub fn account(&self) -> Account {
+        self.account
+    }
+
+    pub fn vtxos(&self) -> Vec<VTXO> {
+        self.claim_vtxos.clone()
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        self.encode_canonical()
+    }
+
+    /// Checks that the claim and all of its VTXOs belong to the account.
+    pub fn validate_account(&self, account: Account) -> bool {
+        if self.account.key() != account.key() {
+            return false;
+        }
+
+        self.claim_vtxos
+            .iter()
+            .all(|vtxo| vtxo.account_key() == account.key())
+    }
+
+    /// Checks that every claimed VTXO is held by the account in the VTXO set.
+    pub fn validate_ownership(&self, account: Account, vtxo_set: &VTXOSet) -> bool {
+        if !self.validate_account(account) {
+            return false;
+        }
+
+        let account_vtxos = vtxo_set.vtxo_set_by_account_key(&account.key());
+
+        self.claim_vtxos.iter().all(|vtxo| {
+            account_vtxos
+                .iter()
+                .any(|held| held.outpoint() == vtxo.outpoint())
+        })
+    }
+}
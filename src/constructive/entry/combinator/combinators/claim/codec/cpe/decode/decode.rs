@@ -0,0 +1,50 @@
+use crate::{
+    constructive::{
+        entity::account::account::Account,
+        entry::combinator::combinators::claim::{
+            claim::Claim, codec::cpe::decode::decode_error::ClaimCPEDecodingError,
+        },
+        txo::vtxo::VTXO,
+    },
+    inscriptive::set::vtxo_set::VTXO_SET,
+};
+
+impl Claim {
+    /// Compact payload decoding for `Claim`.
+    /// Decodes a `Claim` from a bit stream, with the account implied by the entry.
+    pub async fn decode_cpe(
+        bit_stream: &mut bit_vec::Iter<'_>,
+        account: Account,
+        vtxo_set: &VTXO_SET,
+    ) -> Result<Claim, ClaimCPEDecodingError> {
+        // Get the account's VTXOs in canonical order.
+        let account_vtxos = {
+            let _vtxo_set = vtxo_set.lock().await;
+            _vtxo_set.sorted_vtxo_set_by_account_key(&account.key())
+        };
+
+        // Collect the claimed VTXOs, one bit per held VTXO.
+        let mut claim_vtxos = Vec::<VTXO>::new();
+        for (index, vtxo) in account_vtxos.into_iter().enumerate() {
+            let claimed = bit_stream
+                .next()
+                .ok_or(ClaimCPEDecodingError::ClaimBitCollectError(index))?;
+
+            if claimed {
+                claim_vtxos.push(vtxo);
+            }
+        }
+
+        // Check if there are any claimed VTXOs.
+        if claim_vtxos.is_empty() {
+            return Err(ClaimCPEDecodingError::NoVTXOsClaimed);
+        }
+
+        // Construct the `Claim`.
+        let claim = Claim::new(account, claim_vtxos)
+            .ok_or(ClaimCPEDecodingError::ClaimConstructionError)?;
+
+        // Return the `Claim`.
+        Ok(claim)
+    }
+}
@@ -0,0 +1,7 @@
+/// The error type for decoding a `Claim` from a bit stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClaimCPEDecodingError {
+    ClaimBitCollectError(usize),
+    NoVTXOsClaimed,
+    ClaimConstructionError,
+}
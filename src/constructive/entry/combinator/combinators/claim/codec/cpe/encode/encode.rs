@@ -0,0 +1,58 @@
+use crate::{
+    constructive::entry::combinator::combinators::claim::{
+        claim::Claim, codec::cpe::encode::encode_error::ClaimCPEEncodeError,
+    },
+    inscriptive::set::vtxo_set::VTXO_SET,
+};
+use bit_vec::BitVec;
+
+impl Claim {
+    /// Encodes the `Claim` as a bit vector.
+    /// Each of the account's VTXOs, sorted by outpoint, gets one bit telling whether it is claimed.
+    pub async fn encode_cpe(
+        &self,
+        account_key: [u8; 32],
+        vtxo_set: &VTXO_SET,
+    ) -> Result<BitVec, ClaimCPEEncodeError> {
+        // Initialize empty bit vector.
+        let mut bits = BitVec::new();
+
+        // Match the account key, as the account is implied by the entry.
+        let found_key = self.account().key().serialize_xonly();
+        if account_key != found_key {
+            return Err(ClaimCPEEncodeError::AccountKeyMismatch(
+                account_key,
+                found_key,
+            ));
+        }
+
+        // Get the account's VTXOs in canonical order.
+        let account_vtxos = {
+            let _vtxo_set = vtxo_set.lock().await;
+            _vtxo_set.sorted_vtxo_set_by_account_key(&self.account().key())
+        };
+
+        // Every claimed VTXO must be held by the account.
+        let claim_vtxos = self.vtxos();
+        for vtxo in claim_vtxos.iter() {
+            if !account_vtxos
+                .iter()
+                .any(|held| held.outpoint() == vtxo.outpoint())
+            {
+                return Err(ClaimCPEEncodeError::VTXONotFound(vtxo.outpoint()));
+            }
+        }
+
+        // Push the claim bits.
+        for held in account_vtxos.iter() {
+            bits.push(
+                claim_vtxos
+                    .iter()
+                    .any(|vtxo| vtxo.outpoint() == held.outpoint()),
+            );
+        }
+
+        // Return the bits.
+        Ok(bits)
+    }
+}
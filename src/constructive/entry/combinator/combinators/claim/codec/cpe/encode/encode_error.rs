@@ -0,0 +1,8 @@
+use bitcoin::OutPoint;
+
+/// The error type for encoding a `Claim` as a CPE.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClaimCPEEncodeError {
+    AccountKeyMismatch([u8; 32], [u8; 32]),
+    VTXONotFound(Option<OutPoint>),
+}
@@ -0,0 +1,24 @@
+use crate::constructive::entry::combinator::combinators::claim::claim::Claim;
+use serde_json::{json, Value};
+
+impl Claim {
+    /// Returns the claim as a JSON value.
+    pub fn json(&self) -> Value {
+        // Each VTXO as its outpoint and value.
+        let vtxos = self
+            .vtxos()
+            .iter()
+            .map(|vtxo| {
+                json!({
+                    "outpoint": vtxo.outpoint().map(|outpoint| outpoint.to_string()),
+                    "value": vtxo.value(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "account_key": hex::encode(self.account().key().serialize_xonly()),
+            "vtxos": vtxos,
+        })
+    }
+}
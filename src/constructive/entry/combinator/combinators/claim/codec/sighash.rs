@@ -0,0 +1,32 @@
+use crate::{
+    constructive::entry::combinator::{
+        combinator_type::CombinatorType, combinators::claim::claim::Claim,
+    },
+    operative::Chain,
+    transmutative::hash::{Hash, HashTag},
+};
+use bitcoin::hashes::Hash as _;
+
+/// The sighash for the `Claim` combinator.
+impl Claim {
+    pub fn auth_sighash(&self, chain: Chain) -> [u8; 32] {
+        let mut preimage: Vec<u8> = Vec::<u8>::new();
+
+        preimage.extend(self.account().key().serialize_xonly());
+
+        for vtxo in self.vtxos().iter() {
+            match vtxo.outpoint() {
+                Some(outpoint) => {
+                    preimage.extend(outpoint.txid.to_byte_array());
+                    preimage.extend(outpoint.vout.to_le_bytes());
+                }
+                None => return [0; 32],
+            };
+        }
+
+        preimage.hash(Some(HashTag::SighashCombinator(
+            chain,
+            CombinatorType::Claim,
+        )))
+    }
+}
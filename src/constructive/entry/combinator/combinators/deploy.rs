@@ -1,5 +1,8 @@
 use crate::constructive::entity::account::account::Account;
-use crate::transmutative::secp::authenticable::AuthSighash;
+use crate::operative::Chain;
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -16,8 +19,17 @@ impl Deploy {
     }
 }
 
-impl AuthSighash for Deploy {
-    fn auth_sighash(&self) -> [u8; 32] {
+impl Deploy {
+    pub fn auth_sighash(&self, _chain: Chain) -> [u8; 32] {
         [0xffu8; 32]
     }
 }
+
+/// `Deploy` has no fields to encode.
+impl Canonical for Deploy {
+    fn encode_fields(&self, _bytes: &mut Vec<u8>) {}
+
+    fn decode_fields(_reader: &mut CanonicalReader<'_>) -> Result<Deploy, CanonicalDecodeError> {
+        Ok(Deploy::new())
+    }
+}
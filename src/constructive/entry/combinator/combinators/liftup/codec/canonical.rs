@@ -0,0 +1,21 @@
+use crate::{
+    constructive::{entry::combinator::combinators::liftup::liftup::Liftup, txo::lift::Lift},
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+        writer::CanonicalWriter,
+    },
+};
+
+/// The canonical encoding for `Liftup`.
+impl Canonical for Liftup {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.write_list(&self.lift_prevtxos, |bytes, lift| lift.encode_fields(bytes));
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Liftup, CanonicalDecodeError> {
+        let lifts = reader.read_list(Lift::decode_fields)?;
+
+        // Lifts must carry their outpoints.
+        Liftup::new(lifts).ok_or(CanonicalDecodeError::InvalidValue)
+    }
+}
@@ -1,2 +1,3 @@
+pub mod canonical;
 pub mod cpe;
 pub mod sighash;
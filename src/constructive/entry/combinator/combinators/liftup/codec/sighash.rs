@@ -2,16 +2,14 @@ use crate::{
     constructive::entry::combinator::{
         combinator_type::CombinatorType, combinators::liftup::liftup::Liftup,
     },
-    transmutative::{
-        hash::{Hash, HashTag},
-        secp::authenticable::AuthSighash,
-    },
+    operative::Chain,
+    transmutative::hash::{Hash, HashTag},
 };
 use bitcoin::hashes::Hash as _;
 
 /// The sighash for the `Liftup` combinator.
-impl AuthSighash for Liftup {
-    fn auth_sighash(&self) -> [u8; 32] {
+impl Liftup {
+    pub fn auth_sighash(&self, chain: Chain) -> [u8; 32] {
         let mut preimage: Vec<u8> = Vec::<u8>::new();
 
         for prevtxo in self.lift_prevtxos.iter() {
@@ -24,6 +22,9 @@ impl AuthSighash for Liftup {
             }
         }
 
-        preimage.hash(Some(HashTag::SighashCombinator(CombinatorType::Liftup)))
+        preimage.hash(Some(HashTag::SighashCombinator(
+            chain,
+            CombinatorType::Liftup,
+        )))
     }
 }
@@ -1,4 +1,5 @@
 use crate::constructive::txo::lift::Lift;
+use crate::transmutative::codec::canonical::canonical::Canonical;
 use serde::{Deserialize, Serialize};
 
 /// A `Liftup` is a collection of `Lift`s that are being lifted up.
@@ -36,24 +37,6 @@ impl Liftup {
 
     /// Serializes the `Liftup` to a byte vector.
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
-    }
-
-    /// Validation from the broader Entry context.
-    pub fn entry_validation(&self, account_key: [u8; 32]) -> bool {
-        for lift in self.lift_prevtxos.iter() {
-            if let None = lift.outpoint() {
-                return false;
-            }
-
-            if lift.account_key().serialize_xonly() != account_key {
-                return false;
-            }
-        }
-
-        true
+        self.encode_canonical()
     }
 }
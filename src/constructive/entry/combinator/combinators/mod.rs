@@ -1,4 +1,6 @@
 pub mod add;
+pub mod batch_move;
+pub mod bundle;
 pub mod call;
 pub mod claim;
 pub mod deploy;
@@ -0,0 +1,25 @@
+use crate::{
+    constructive::{
+        entity::account::account::Account, entry::combinator::combinators::r#move::r#move::Move,
+    },
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    },
+};
+
+/// The canonical encoding for `Move`.
+impl Canonical for Move {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.from().encode_fields(bytes);
+        self.to().encode_fields(bytes);
+        bytes.extend(self.amount().to_le_bytes());
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Move, CanonicalDecodeError> {
+        let from = Account::decode_fields(reader)?;
+        let to = Account::decode_fields(reader)?;
+        let amount = reader.read_u32()?;
+
+        Ok(Move::new(from, to, amount))
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    constructive::{
+        entity::account::account::Account,
+        entry::combinator::combinators::r#move::{
+            codec::cpe::decode::decode_error::MoveCPEDecodingError, r#move::Move,
+        },
+        valtype::val::short_val::short_val::ShortVal,
+    },
+    inscriptive::registery::account_registery::ACCOUNT_REGISTERY,
+};
+
+impl Move {
+    /// Compact payload decoding for `Move`.
+    /// Decodes a `Move` from a bit stream, with the sender implied by the entry.
+    pub async fn decode_cpe<'a>(
+        bit_stream: &mut bit_vec::Iter<'a>,
+        from: Account,
+        account_registery: &ACCOUNT_REGISTERY,
+    ) -> Result<Move, MoveCPEDecodingError> {
+        // Decode the receiving account.
+        let to = Account::decode_cpe(bit_stream, account_registery)
+            .await
+            .map_err(MoveCPEDecodingError::ToAccountCPEDecodeError)?;
+
+        // Decode the amount.
+        let amount = ShortVal::decode_cpe(bit_stream)
+            .map_err(MoveCPEDecodingError::AmountCPEDecodeError)?
+            .value();
+
+        // Construct the move.
+        let r#move = Move::new(from, to, amount);
+
+        // Return the move.
+        Ok(r#move)
+    }
+}
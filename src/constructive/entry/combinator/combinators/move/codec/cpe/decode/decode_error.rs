@@ -0,0 +1,11 @@
+use crate::constructive::{
+    entity::account::cpe::decode::decode_error::AccountCPEDecodingError,
+    valtype::val::short_val::cpe::decode::decode_error::ShortValCPEDecodingError,
+};
+
+/// The error type for decoding a `Move` from a bit stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MoveCPEDecodingError {
+    ToAccountCPEDecodeError(AccountCPEDecodingError),
+    AmountCPEDecodeError(ShortValCPEDecodingError),
+}
@@ -0,0 +1,33 @@
+use crate::constructive::{
+    entry::combinator::combinators::r#move::{
+        codec::cpe::encode::encode_error::MoveCPEEncodeError, r#move::Move,
+    },
+    valtype::val::short_val::short_val::ShortVal,
+};
+use bit_vec::BitVec;
+
+impl Move {
+    /// Encodes the move as a bit vector.
+    pub fn encode_cpe(&self, account_key: [u8; 32]) -> Result<BitVec, MoveCPEEncodeError> {
+        // Initialize empty bit vector.
+        let mut bits = BitVec::new();
+
+        // Match the account key, as the sender is implied by the entry.
+        let from_key = self.from().key().serialize_xonly();
+        if account_key != from_key {
+            return Err(MoveCPEEncodeError::AccountKeyMismatch(
+                account_key,
+                from_key,
+            ));
+        }
+
+        // Extend the receiving account.
+        bits.extend(self.to().encode_cpe());
+
+        // Extend the amount as shortval.
+        bits.extend(ShortVal::new(self.amount()).encode_cpe());
+
+        // Return the bits.
+        Ok(bits)
+    }
+}
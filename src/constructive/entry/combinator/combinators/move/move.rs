@@ -2,9 +2,10 @@ use crate::{
     constructive::{
         entity::account::account::Account, entry::combinator::combinator_type::CombinatorType,
     },
+    operative::Chain,
     transmutative::{
+        codec::canonical::canonical::Canonical,
         hash::{Hash, HashTag},
-        secp::authenticable::AuthSighash,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -34,25 +35,23 @@ impl Move {
     }
 
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
     }
 
     pub fn validate_account(&self, account: Account) -> bool {
         self.from.key() == account.key()
     }
-}
 
-impl AuthSighash for Move {
-    fn auth_sighash(&self) -> [u8; 32] {
+    pub fn auth_sighash(&self, chain: Chain) -> [u8; 32] {
         let mut preimage: Vec<u8> = Vec::<u8>::new();
 
         preimage.extend(self.from.key().serialize_xonly());
         preimage.extend(self.to.key().serialize_xonly());
         preimage.extend(self.amount.to_le_bytes());
 
-        preimage.hash(Some(HashTag::SighashCombinator(CombinatorType::Move)))
+        preimage.hash(Some(HashTag::SighashCombinator(
+            chain,
+            CombinatorType::Move,
+        )))
     }
 }
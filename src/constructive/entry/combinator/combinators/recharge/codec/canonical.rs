@@ -0,0 +1,23 @@
+use crate::{
+    constructive::{entry::combinator::combinators::recharge::recharge::Recharge, txo::vtxo::VTXO},
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+        writer::CanonicalWriter,
+    },
+};
+
+/// The canonical encoding for `Recharge`.
+impl Canonical for Recharge {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.write_list(&self.recharge_vtxos, |bytes, vtxo| {
+            vtxo.encode_fields(bytes)
+        });
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Recharge, CanonicalDecodeError> {
+        let vtxos = reader.read_list(VTXO::decode_fields)?;
+
+        // VTXOs must carry their outpoints.
+        Recharge::new(vtxos).ok_or(CanonicalDecodeError::InvalidValue)
+    }
+}
@@ -2,16 +2,14 @@ use crate::{
     constructive::entry::combinator::{
         combinator_type::CombinatorType, combinators::recharge::recharge::Recharge,
     },
-    transmutative::{
-        hash::{Hash, HashTag},
-        secp::authenticable::AuthSighash,
-    },
+    operative::Chain,
+    transmutative::hash::{Hash, HashTag},
 };
 use bitcoin::hashes::Hash as _;
 
 /// The sighash for the `Recharge` combinator.
-impl AuthSighash for Recharge {
-    fn auth_sighash(&self) -> [u8; 32] {
+impl Recharge {
+    pub fn auth_sighash(&self, chain: Chain) -> [u8; 32] {
         let mut preimage: Vec<u8> = Vec::<u8>::new();
 
         for vtxo in self.recharge_vtxos.iter() {
@@ -24,6 +22,9 @@ impl AuthSighash for Recharge {
             };
         }
 
-        preimage.hash(Some(HashTag::SighashCombinator(CombinatorType::Recharge)))
+        preimage.hash(Some(HashTag::SighashCombinator(
+            chain,
+            CombinatorType::Recharge,
+        )))
     }
 }
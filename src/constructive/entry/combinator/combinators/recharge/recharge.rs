@@ -1,4 +1,5 @@
 use crate::constructive::txo::vtxo::VTXO;
+use crate::transmutative::codec::canonical::canonical::Canonical;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -31,24 +32,6 @@ impl Recharge {
     }
 
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
-    }
-
-    /// Validation from the broader Entry context.
-    pub fn entry_validation(&self, account_key: [u8; 32]) -> bool {
-        for vtxo in self.recharge_vtxos.iter() {
-            if let None = vtxo.outpoint() {
-                return false;
-            }
-
-            if vtxo.account_key().serialize_xonly() != account_key {
-                return false;
-            }
-        }
-
-        true
+        self.encode_canonical()
     }
 }
@@ -1,3 +1,6 @@
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -5,9 +8,15 @@ pub struct Reserved {}
 
 impl Reserved {
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
+    }
+}
+
+/// `Reserved` has no fields to encode.
+impl Canonical for Reserved {
+    fn encode_fields(&self, _bytes: &mut Vec<u8>) {}
+
+    fn decode_fields(_reader: &mut CanonicalReader<'_>) -> Result<Reserved, CanonicalDecodeError> {
+        Ok(Reserved {})
     }
 }
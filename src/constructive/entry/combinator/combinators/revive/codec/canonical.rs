@@ -0,0 +1,26 @@
+use crate::{
+    constructive::{
+        entity::account::account::Account, entry::combinator::combinators::revive::revive::Revive,
+        txo::vtxo::VTXO,
+    },
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+        writer::CanonicalWriter,
+    },
+};
+
+/// The canonical encoding for `Revive`.
+impl Canonical for Revive {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.account().encode_fields(bytes);
+        bytes.write_list(&self.vtxos(), |bytes, vtxo| vtxo.encode_fields(bytes));
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Revive, CanonicalDecodeError> {
+        let account = Account::decode_fields(reader)?;
+        let vtxos = reader.read_list(VTXO::decode_fields)?;
+
+        // At least one VTXO, each carrying its outpoint.
+        Revive::new(account, vtxos).ok_or(CanonicalDecodeError::InvalidValue)
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{
+    constructive::{
+        entity::account::account::Account,
+        entry::combinator::combinators::revive::{
+            codec::cpe::decode::decode_error::ReviveCPEDecodingError, revive::Revive,
+        },
+    },
+    inscriptive::set::vtxo_set::VTXO_SET,
+};
+
+impl Revive {
+    /// Decodes `Revive` from a compact bit stream.
+    pub async fn decode_cpe<'a>(
+        _bit_stream: &mut bit_vec::Iter<'a>,
+        account: Account,
+        vtxo_set: &VTXO_SET,
+        current_bitcoin_height: u32,
+    ) -> Result<Revive, ReviveCPEDecodingError> {
+        // Decoding Revive does not involve any bit stream iteration.
+        // We simply retrieve *all* revivable VTXOs associated with the account from the local storage.
+
+        // Get the VTXOs to revive.
+        let revivable_vtxos = {
+            let _vtxo_set = vtxo_set.lock().await;
+            _vtxo_set.vtxos_to_revive(&account.key(), current_bitcoin_height)
+        };
+
+        // Check if there are any revivable VTXOs.
+        if revivable_vtxos.is_empty() {
+            return Err(ReviveCPEDecodingError::NoRevivableVTXOsFound);
+        }
+
+        // Construct the revive.
+        let revive = Revive::new(account, revivable_vtxos)
+            .ok_or(ReviveCPEDecodingError::ReviveConstructionError)?;
+
+        // Return the revive.
+        Ok(revive)
+    }
+}
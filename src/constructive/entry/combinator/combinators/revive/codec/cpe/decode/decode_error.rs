@@ -0,0 +1,6 @@
+/// The error type for decoding a `Revive` from a compact bit vector.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReviveCPEDecodingError {
+    ReviveConstructionError,
+    NoRevivableVTXOsFound,
+}
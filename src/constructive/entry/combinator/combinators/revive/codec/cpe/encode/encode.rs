@@ -0,0 +1,13 @@
+use crate::constructive::entry::combinator::combinators::revive::revive::Revive;
+use bit_vec::BitVec;
+
+impl Revive {
+    /// Encodes the `Revive` combinator into a compact bit vector.
+    pub fn encode_cpe(&self) -> BitVec {
+        // We encode nothing for the revive combinator.
+        // The decoder will retrieve *all* swept vtxos directly from the local storage.
+
+        // Return an empty bit vector.
+        BitVec::new()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod canonical;
+pub mod cpe;
+pub mod sighash;
@@ -0,0 +1,33 @@
+use crate::{
+    constructive::entry::combinator::{
+        combinator_type::CombinatorType, combinators::revive::revive::Revive,
+    },
+    operative::Chain,
+    transmutative::hash::{Hash, HashTag},
+};
+use bitcoin::hashes::Hash as _;
+
+/// The sighash for the `Revive` combinator.
+impl Revive {
+    pub fn auth_sighash(&self, chain: Chain) -> [u8; 32] {
+        let mut preimage: Vec<u8> = Vec::<u8>::new();
+
+        preimage.extend(self.account().key().serialize_xonly());
+
+        for vtxo in self.vtxos().iter() {
+            match (vtxo.outpoint(), vtxo.value()) {
+                (Some(outpoint), Some(value)) => {
+                    preimage.extend(outpoint.txid.to_byte_array());
+                    preimage.extend(outpoint.vout.to_le_bytes());
+                    preimage.extend(value.to_le_bytes());
+                }
+                _ => return [0; 32],
+            };
+        }
+
+        preimage.hash(Some(HashTag::SighashCombinator(
+            chain,
+            CombinatorType::Revive,
+        )))
+    }
+}
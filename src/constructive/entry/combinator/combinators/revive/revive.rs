@@ -0,0 +1,117 @@
+use crate::transmutative::codec::canonical::canonical::Canonical;
+use crate::{
+    constructive::{entity::account::account::Account, txn::ext::OutpointExt, txo::vtxo::VTXO},
+    inscriptive::set::vtxo_set::VTXOSet,
+    transmutative::hash::{Hash, HashTag},
+};
+use bitcoin::{hashes::Hash as _, OutPoint, Txid};
+use secp::Point;
+use serde::{Deserialize, Serialize};
+
+/// `Revive` brings the value of expired VTXOs, swept by the operator, back into a new VTXO.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Revive {
+    account: Account,
+    revive_vtxos: Vec<VTXO>,
+}
+
+impl Revive {
+    /// Creates a new revive for the given expired VTXOs.
+    /// Returns `None` if there is nothing to revive, or if a VTXO has no outpoint.
+    pub fn new(account: Account, vtxos: Vec<VTXO>) -> Option<Revive> {
+        if vtxos.is_empty() {
+            return None;
+        }
+
+        if vtxos.iter().any(|vtxo| vtxo.outpoint().is_none()) {
+            return None;
+        }
+
+        let revive = Revive {
+            account,
+            revive_vtxos: vtxos,
+        };
+
+        Some(revive)
+    }
+
+    pub fn account(&self) -> Account {
+        self.account
+    }
+
+    pub fn vtxos(&self) -> Vec<VTXO> {
+        self.revive_vtxos.clone()
+    }
+
+    /// Returns the total value to revive.
+    pub fn value(&self) -> u64 {
+        self.revive_vtxos
+            .iter()
+            .map(|vtxo| vtxo.value().unwrap_or(0))
+            .sum()
+    }
+
+    /// Returns the new VTXO the revived value is brought back into, held by the account.
+    ///
+    /// Its outpoint is derived from the outpoints of the revived VTXOs, which can only be revived
+    /// once, so that every node derives the same outpoint for it.
+    pub fn revived_vtxo(
+        &self,
+        operator_key: Point,
+        at_rollup_height: u32,
+        at_bitcoin_height: u32,
+    ) -> Option<VTXO> {
+        let mut preimage = Vec::<u8>::new();
+
+        for vtxo in self.revive_vtxos.iter() {
+            preimage.extend(vtxo.outpoint()?.bytes_36());
+        }
+
+        let txid = Txid::from_byte_array(preimage.hash(Some(HashTag::RevivedVTXO)));
+
+        let revived_vtxo = VTXO::new(
+            self.account.key(),
+            operator_key,
+            Some(OutPoint::new(txid, 0)),
+            Some(self.value()),
+            Some(at_rollup_height),
+            Some(at_bitcoin_height),
+        );
+
+        Some(revived_vtxo)
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        self.encode_canonical()
+    }
+
+    /// Checks that the revive and all of its VTXOs belong to the account.
+    pub fn validate_account(&self, account: Account) -> bool {
+        if self.account.key() != account.key() {
+            return false;
+        }
+
+        self.revive_vtxos
+            .iter()
+            .all(|vtxo| vtxo.account_key() == account.key())
+    }
+
+    /// Checks every VTXO against the VTXO set and the operator sweep records.
+    /// Each must have expired, been swept by the operator, and not been revived already.
+    pub fn validate_revival(
+        &self,
+        account: Account,
+        vtxo_set: &VTXOSet,
+        current_bitcoin_height: u32,
+    ) -> bool {
+        if !self.validate_account(account) {
+            return false;
+        }
+
+        let revivable_vtxos = vtxo_set.vtxos_to_revive(&account.key(), current_bitcoin_height);
+
+        self.revive_vtxos
+            .iter()
+            .all(|vtxo| revivable_vtxos.iter().any(|revivable| revivable == vtxo))
+    }
+}
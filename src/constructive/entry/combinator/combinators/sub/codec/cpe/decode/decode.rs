@@ -0,0 +1,27 @@
+use crate::constructive::{
+    entity::account::account::Account,
+    entry::combinator::combinators::sub::{
+        codec::cpe::decode::decode_error::SubCPEDecodingError, sub::Sub,
+    },
+    valtype::val::short_val::short_val::ShortVal,
+};
+
+impl Sub {
+    /// Compact payload decoding for `Sub`.
+    /// Decodes a `Sub` from a bit stream, with the account implied by the entry.
+    pub fn decode_cpe(
+        bit_stream: &mut bit_vec::Iter<'_>,
+        account: Account,
+    ) -> Result<Sub, SubCPEDecodingError> {
+        // Decode the amount.
+        let amount = ShortVal::decode_cpe(bit_stream)
+            .map_err(SubCPEDecodingError::AmountCPEDecodeError)?
+            .value();
+
+        // Construct the `Sub`.
+        let sub = Sub::new(account, amount);
+
+        // Return the `Sub`.
+        Ok(sub)
+    }
+}
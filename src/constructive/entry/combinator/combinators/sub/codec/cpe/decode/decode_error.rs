@@ -0,0 +1,7 @@
+use crate::constructive::valtype::val::short_val::cpe::decode::decode_error::ShortValCPEDecodingError;
+
+/// The error type for decoding a `Sub` from a bit stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubCPEDecodingError {
+    AmountCPEDecodeError(ShortValCPEDecodingError),
+}
@@ -0,0 +1,30 @@
+use crate::constructive::{
+    entry::combinator::combinators::sub::{
+        codec::cpe::encode::encode_error::SubCPEEncodeError, sub::Sub,
+    },
+    valtype::val::short_val::short_val::ShortVal,
+};
+use bit_vec::BitVec;
+
+impl Sub {
+    /// Encodes the `Sub` as a bit vector.
+    pub fn encode_cpe(&self, account_key: [u8; 32]) -> Result<BitVec, SubCPEEncodeError> {
+        // Initialize empty bit vector.
+        let mut bits = BitVec::new();
+
+        // Match the account key, as the account is implied by the entry.
+        let found_key = self.account().key().serialize_xonly();
+        if account_key != found_key {
+            return Err(SubCPEEncodeError::AccountKeyMismatch(
+                account_key,
+                found_key,
+            ));
+        }
+
+        // Extend the amount as shortval.
+        bits.extend(ShortVal::new(self.amount()).encode_cpe());
+
+        // Return the bits.
+        Ok(bits)
+    }
+}
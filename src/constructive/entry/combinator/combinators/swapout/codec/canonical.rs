@@ -0,0 +1,58 @@
+use crate::{
+    constructive::{
+        entity::account::account::Account,
+        entry::combinator::combinators::swapout::swapout::{Swapout, SwapoutType},
+    },
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    },
+};
+
+/// The canonical encoding for `Swapout`.
+///
+/// The output type is tagged 0x00 for `P2WPKH`, 0x01 for `P2WSH` and 0x02 for `P2TR`,
+/// followed by the witness program.
+impl Canonical for Swapout {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.account().encode_fields(bytes);
+        bytes.extend(self.amount().to_le_bytes());
+        match self.swapout_type() {
+            SwapoutType::P2WPKH(witness_program) => {
+                bytes.push(0x00);
+                bytes.extend(witness_program);
+            }
+            SwapoutType::P2WSH(witness_program) => {
+                bytes.push(0x01);
+                bytes.extend(witness_program);
+            }
+            SwapoutType::P2TR(taproot_key) => {
+                bytes.push(0x02);
+                bytes.extend(taproot_key);
+            }
+        }
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Swapout, CanonicalDecodeError> {
+        let account = Account::decode_fields(reader)?;
+        let amount = reader.read_u32()?;
+
+        match reader.read_u8()? {
+            0x00 => Ok(Swapout::new_p2wpkh(
+                account,
+                amount,
+                reader.read_array::<20>()?,
+            )),
+            0x01 => Ok(Swapout::new_p2wsh(
+                account,
+                amount,
+                reader.read_array::<32>()?,
+            )),
+            0x02 => Ok(Swapout::new_p2tr(
+                account,
+                amount,
+                reader.read_array::<32>()?,
+            )),
+            tag => Err(CanonicalDecodeError::InvalidTag(tag)),
+        }
+    }
+}
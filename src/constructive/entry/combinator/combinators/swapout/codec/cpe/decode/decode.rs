@@ -0,0 +1,79 @@
+use crate::constructive::{
+    entity::account::account::Account,
+    entry::combinator::combinators::swapout::{
+        codec::cpe::decode::decode_error::SwapoutCPEDecodingError, swapout::Swapout,
+    },
+    valtype::val::short_val::short_val::ShortVal,
+};
+use bit_vec::BitVec;
+
+impl Swapout {
+    /// Compact payload decoding for `Swapout`.
+    /// Decodes a `Swapout` from a bit stream, with the account implied by the entry.
+    pub fn decode_cpe(
+        bit_stream: &mut bit_vec::Iter<'_>,
+        account: Account,
+    ) -> Result<Swapout, SwapoutCPEDecodingError> {
+        // Decode the amount.
+        let amount = ShortVal::decode_cpe(bit_stream)
+            .map_err(SwapoutCPEDecodingError::AmountCPEDecodeError)?
+            .value();
+
+        // Decode the swapout type.
+        // b:1 => P2TR, b:00 => P2WPKH, b:01 => P2WSH.
+        let is_p2tr = bit_stream
+            .next()
+            .ok_or(SwapoutCPEDecodingError::SwapoutTypeBitsCollectError)?;
+        let is_p2wsh = match is_p2tr {
+            true => false,
+            false => bit_stream
+                .next()
+                .ok_or(SwapoutCPEDecodingError::SwapoutTypeBitsCollectError)?,
+        };
+
+        // Witness program length in bytes.
+        let witness_program_length = match (is_p2tr, is_p2wsh) {
+            (false, false) => 20,
+            _ => 32,
+        };
+
+        // Collect the witness program bits.
+        let mut witness_program_bits = BitVec::new();
+        for _ in 0..witness_program_length * 8 {
+            witness_program_bits.push(
+                bit_stream
+                    .next()
+                    .ok_or(SwapoutCPEDecodingError::WitnessProgramBitsCollectError)?,
+            );
+        }
+        let witness_program = witness_program_bits.to_bytes();
+
+        // Construct the swapout.
+        let swapout = match (is_p2tr, is_p2wsh) {
+            (true, _) => Swapout::new_p2tr(
+                account,
+                amount,
+                witness_program
+                    .try_into()
+                    .map_err(|_| SwapoutCPEDecodingError::WitnessProgramBitsCollectError)?,
+            ),
+            (false, true) => Swapout::new_p2wsh(
+                account,
+                amount,
+                witness_program
+                    .try_into()
+                    .map_err(|_| SwapoutCPEDecodingError::WitnessProgramBitsCollectError)?,
+            ),
+            (false, false) => Swapout::new_p2wpkh(
+                account,
+                amount,
+                witness_program
+                    .try_into()
+                    .map_err(|_| SwapoutCPEDecodingError::WitnessProgramBitsCollectError)?,
+            ),
+        };
+
+        // Return the swapout.
+        Ok(swapout)
+    }
+}
@@ -0,0 +1,9 @@
+use crate::constructive::valtype::val::short_val::cpe::decode::decode_error::ShortValCPEDecodingError;
+
+/// The error type for decoding a `Swapout` from a bit stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SwapoutCPEDecodingError {
+    AmountCPEDecodeError(ShortValCPEDecodingError),
+    SwapoutTypeBitsCollectError,
+    WitnessProgramBitsCollectError,
+}
@@ -0,0 +1,42 @@
+use crate::constructive::{
+    entry::combinator::combinators::swapout::{
+        codec::cpe::encode::encode_error::SwapoutCPEEncodeError,
+        swapout::{Swapout, SwapoutType},
+    },
+    valtype::val::short_val::short_val::ShortVal,
+};
+use bit_vec::BitVec;
+
+impl Swapout {
+    /// Encodes the `Swapout` as a bit vector.
+    pub fn encode_cpe(&self, account_key: [u8; 32]) -> Result<BitVec, SwapoutCPEEncodeError> {
+        // Initialize empty bit vector.
+        let mut bits = BitVec::new();
+
+        // Match the account key, as the account is implied by the entry.
+        let found_key = self.account().key().serialize_xonly();
+        if account_key != found_key {
+            return Err(SwapoutCPEEncodeError::AccountKeyMismatch(
+                account_key,
+                found_key,
+            ));
+        }
+
+        // Extend the amount as shortval.
+        bits.extend(ShortVal::new(self.amount()).encode_cpe());
+
+        // Extend the swapout type.
+        // b:1 => P2TR, b:00 => P2WPKH, b:01 => P2WSH.
+        match self.swapout_type() {
+            SwapoutType::P2TR(_) => bits.push(true),
+            SwapoutType::P2WPKH(_) => bits.extend([false, false]),
+            SwapoutType::P2WSH(_) => bits.extend([false, true]),
+        }
+
+        // Extend the witness program.
+        bits.extend(BitVec::from_bytes(self.witness_program()));
+
+        // Return the bits.
+        Ok(bits)
+    }
+}
@@ -1,11 +1,10 @@
+use crate::transmutative::codec::canonical::canonical::Canonical;
 use crate::{
     constructive::{
         entity::account::account::Account, entry::combinator::combinator_type::CombinatorType,
     },
-    transmutative::{
-        hash::{Hash, HashTag},
-        secp::authenticable::AuthSighash,
-    },
+    operative::Chain,
+    transmutative::hash::{Hash, HashTag},
 };
 use serde::{Deserialize, Serialize};
 
@@ -132,10 +131,7 @@ impl Swapout {
 
     /// Serializes the swapout to a byte vector.
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
     }
 
     /// Validates the account for the swapout.
@@ -144,16 +140,17 @@ impl Swapout {
     pub fn validate_account(&self, account: Account) -> bool {
         self.account.key() == account.key()
     }
-}
 
-impl AuthSighash for Swapout {
-    fn auth_sighash(&self) -> [u8; 32] {
+    pub fn auth_sighash(&self, chain: Chain) -> [u8; 32] {
         let mut preimage: Vec<u8> = Vec::<u8>::new();
 
         preimage.extend(self.account.key().serialize_xonly());
         preimage.extend(self.amount.to_le_bytes());
         preimage.extend(self.witness_program());
 
-        preimage.hash(Some(HashTag::SighashCombinator(CombinatorType::Swapout)))
+        preimage.hash(Some(HashTag::SighashCombinator(
+            chain,
+            CombinatorType::Swapout,
+        )))
     }
 }
@@ -1,8 +1,10 @@
 use super::combinator::{
     combinator::Combinator,
     combinators::{
-        add::Add, call::call::Call, liftup::liftup::Liftup, r#move::Move,
-        recharge::recharge::Recharge, reserved::Reserved, sub::Sub, swapout::Swapout,
+        add::add::Add, batch_move::batch_move::BatchMove, bundle::bundle::Bundle, call::call::Call,
+        claim::claim::Claim, liftup::liftup::Liftup, r#move::r#move::Move,
+        recharge::recharge::Recharge, reserved::Reserved, revive::revive::Revive, sub::sub::Sub,
+        swapout::swapout::Swapout,
     },
 };
 use crate::{
@@ -11,12 +13,18 @@ use crate::{
         entry::branches::{
             uppermost_left_branch::uppermost_left_branch::UppermostLeftBranch,
             uppermost_right_branch::{
-                transactive_branch::transactive_branch::TransactiveBranch,
+                transactive_branch::{
+                    call_branch::call_branch::CallBranch, move_branch::move_branch::MoveBranch,
+                    transactive_branch::TransactiveBranch,
+                },
                 upper_right_branch::{
                     liquidity_branch::liquidity_branch::LiquidityBranch,
                     right_branch::{
                         lower_left_branch::lower_left_branch::LowerLeftBranch,
-                        lower_right_branch::lower_right_branch::LowerRightBranch,
+                        lower_right_branch::{
+                            lower_right_branch::LowerRightBranch,
+                            recovery_branch::recovery_branch::RecoveryBranch,
+                        },
                         right_branch::RightBranch,
                     },
                     upper_right_branch::UpperRightBranch,
@@ -25,30 +33,35 @@ use crate::{
             },
         },
     },
-    transmutative::{
-        hash::{Hash, HashTag},
-        secp::authenticable::AuthSighash,
-    },
+    operative::Chain,
+    transmutative::hash::{Hash, HashTag},
 };
+use crate::transmutative::codec::canonical::canonical::Canonical;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// The entry represents a transaction, containing one or more combinators.
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Entry {
     account: Account,
+    // Per-account nonce; an entry is only valid at the account's next nonce.
+    nonce: u64,
     uppermost_left_branch: Option<UppermostLeftBranch>,
     uppermost_right_branch: Option<UppermostRightBranch>,
 }
 
 impl Entry {
     /// Creates a new entry.
-    fn new(
+    ///
+    /// The nonce defaults to zero, the first nonce of an account; see `set_nonce`.
+    pub fn new(
         account: Account,
         uppermost_left_branch: Option<UppermostLeftBranch>,
         uppermost_right_branch: Option<UppermostRightBranch>,
     ) -> Entry {
         Self {
             account,
+            nonce: 0,
             uppermost_left_branch,
             uppermost_right_branch,
         }
@@ -57,7 +70,7 @@ impl Entry {
     // Liftup and/or recharge ONLY.
     pub fn new_nop(account: Account, liftup: Option<Liftup>, recharge: Option<Recharge>) -> Entry {
         let uppermost_left_branch = {
-            if liftup.is_some() && recharge.is_some() {
+            if liftup.is_some() || recharge.is_some() {
                 Some(UppermostLeftBranch::new(liftup, recharge))
             } else {
                 None
@@ -84,7 +97,28 @@ impl Entry {
         };
 
         let uppermost_right_branch = Some(UppermostRightBranch::TransactiveBranch(
-            TransactiveBranch::Move(r#move),
+            TransactiveBranch::MoveBranch(MoveBranch::Move(r#move)),
+        ));
+        Self::new(account, uppermost_left_branch, uppermost_right_branch)
+    }
+
+    /// Creates a new batch move entry.
+    pub fn new_batch_move(
+        account: Account,
+        batch_move: BatchMove,
+        liftup: Option<Liftup>,
+        recharge: Option<Recharge>,
+    ) -> Entry {
+        let uppermost_left_branch = {
+            if liftup.is_some() || recharge.is_some() {
+                Some(UppermostLeftBranch::new(liftup, recharge))
+            } else {
+                None
+            }
+        };
+
+        let uppermost_right_branch = Some(UppermostRightBranch::TransactiveBranch(
+            TransactiveBranch::MoveBranch(MoveBranch::BatchMove(batch_move)),
         ));
         Self::new(account, uppermost_left_branch, uppermost_right_branch)
     }
@@ -103,7 +137,27 @@ impl Entry {
             }
         };
         let uppermost_right_branch = Some(UppermostRightBranch::TransactiveBranch(
-            TransactiveBranch::Call(call),
+            TransactiveBranch::CallBranch(CallBranch::Call(call)),
+        ));
+        Self::new(account, uppermost_left_branch, uppermost_right_branch)
+    }
+
+    /// Creates a new bundle entry.
+    pub fn new_bundle(
+        account: Account,
+        bundle: Bundle,
+        liftup: Option<Liftup>,
+        recharge: Option<Recharge>,
+    ) -> Entry {
+        let uppermost_left_branch = {
+            if liftup.is_some() || recharge.is_some() {
+                Some(UppermostLeftBranch::new(liftup, recharge))
+            } else {
+                None
+            }
+        };
+        let uppermost_right_branch = Some(UppermostRightBranch::TransactiveBranch(
+            TransactiveBranch::CallBranch(CallBranch::Bundle(bundle)),
         ));
         Self::new(account, uppermost_left_branch, uppermost_right_branch)
     }
@@ -170,6 +224,50 @@ impl Entry {
         Self::new(account, uppermost_left_branch, uppermost_right_branch)
     }
 
+    /// Creates a new claim entry.
+    pub fn new_claim(
+        account: Account,
+        claim: Claim,
+        liftup: Option<Liftup>,
+        recharge: Option<Recharge>,
+    ) -> Entry {
+        let uppermost_left_branch = {
+            if liftup.is_some() || recharge.is_some() {
+                Some(UppermostLeftBranch::new(liftup, recharge))
+            } else {
+                None
+            }
+        };
+        let uppermost_right_branch = Some(UppermostRightBranch::UpperRightBranch(
+            UpperRightBranch::RightBranch(RightBranch::LowerRightBranch(
+                LowerRightBranch::RecoveryBranch(RecoveryBranch::Claim(claim)),
+            )),
+        ));
+        Self::new(account, uppermost_left_branch, uppermost_right_branch)
+    }
+
+    /// Creates a new revive entry.
+    pub fn new_revive(
+        account: Account,
+        revive: Revive,
+        liftup: Option<Liftup>,
+        recharge: Option<Recharge>,
+    ) -> Entry {
+        let uppermost_left_branch = {
+            if liftup.is_some() || recharge.is_some() {
+                Some(UppermostLeftBranch::new(liftup, recharge))
+            } else {
+                None
+            }
+        };
+        let uppermost_right_branch = Some(UppermostRightBranch::UpperRightBranch(
+            UpperRightBranch::RightBranch(RightBranch::LowerRightBranch(
+                LowerRightBranch::RecoveryBranch(RecoveryBranch::Revive(revive)),
+            )),
+        ));
+        Self::new(account, uppermost_left_branch, uppermost_right_branch)
+    }
+
     /// Creates a new reserved entry.
     pub fn new_reserved(
         account: Account,
@@ -197,6 +295,26 @@ impl Entry {
         self.account.clone()
     }
 
+    /// Returns the nonce of the entry.
+    pub fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// Sets the nonce of the entry.
+    pub fn set_nonce(&mut self, nonce: u64) {
+        self.nonce = nonce;
+    }
+
+    /// Returns the uppermost left branch of the entry.
+    pub fn uppermost_left_branch(&self) -> Option<UppermostLeftBranch> {
+        self.uppermost_left_branch.clone()
+    }
+
+    /// Returns the uppermost right branch of the entry.
+    pub fn uppermost_right_branch(&self) -> Option<UppermostRightBranch> {
+        self.uppermost_right_branch.clone()
+    }
+
     /// Returns the liftup of the entry.
     pub fn liftup(&self) -> Option<Liftup> {
         let uppermost_left_branch = match &self.uppermost_left_branch {
@@ -227,99 +345,59 @@ impl Entry {
         Some(uppermost_right_branch.main_combinator())
     }
 
-    pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
-    }
-
-    /// Validates the account of the entry.
-    pub fn validate_account(&self) -> bool {
-        let account = self.account();
-        let account_key = account.key().serialize_xonly();
-
-        if let None = self.uppermost_left_branch {
-            if let None = self.uppermost_right_branch {
-                return false;
-            }
-        }
+    /// Returns the most each key can be charged by the entry, in amounts spent and fees, by x-only key.
+    ///
+    /// The fees of a call are charged to its fee payer if sponsored, and to the account otherwise.
+    pub fn max_spends(&self) -> HashMap<[u8; 32], u64> {
+        let account_key = self.account.key().serialize_xonly();
 
-        if let Some(uppermost_left_branch) = &self.uppermost_left_branch {
-            if let Some(liftup) = &uppermost_left_branch.liftup {
-                if !liftup.entry_validation(account_key) {
-                    return false;
-                }
-            }
+        let mut max_spends = HashMap::<[u8; 32], u64>::new();
+        let mut spend = |key: [u8; 32], amount: u64| {
+            let max_spend = max_spends.entry(key).or_insert(0);
+            *max_spend = max_spend.saturating_add(amount);
+        };
 
-            if let Some(recharge) = &uppermost_left_branch.recharge {
-                if !recharge.entry_validation(account_key) {
-                    return false;
+        match self.main_combinator() {
+            Some(Combinator::Move(r#move)) => spend(account_key, r#move.amount() as u64),
+            Some(Combinator::BatchMove(batch_move)) => {
+                for (_, amount) in batch_move.recipients().iter() {
+                    spend(account_key, *amount as u64);
                 }
             }
-        }
-
-        if let Some(main_combinator) = self.main_combinator() {
-            match main_combinator {
-                Combinator::Move(r#move) => {
-                    if !r#move.validate_account(account) {
-                        return false;
-                    }
-                }
-                Combinator::Call(call) => {
-                    if !call.entry_validation(account_key) {
-                        return false;
-                    }
-                }
-                Combinator::Add(add) => {
-                    if !add.validate_account(account) {
-                        return false;
-                    }
-                }
-                Combinator::Sub(sub) => {
-                    if !sub.validate_account(account) {
-                        return false;
-                    }
+            Some(Combinator::Call(call)) => spend(call.payer_key(), call.max_fees()),
+            Some(Combinator::Bundle(bundle)) => {
+                for r#move in bundle.moves().iter() {
+                    spend(account_key, r#move.amount() as u64);
                 }
-                Combinator::Deploy(deploy) => {
-                    if !deploy.validate_account(account) {
-                        return false;
-                    }
-                }
-                Combinator::Swapout(swapout) => {
-                    if !swapout.validate_account(account) {
-                        return false;
-                    }
-                }
-                Combinator::Revive(revive) => {
-                    if !revive.validate_account(account) {
-                        return false;
-                    }
-                }
-                Combinator::Claim(claim) => {
-                    if !claim.validate_account(account) {
-                        return false;
-                    }
+                for call in bundle.calls().iter() {
+                    spend(call.payer_key(), call.max_fees());
                 }
-                // Reserved fails the validation.
-                Combinator::Reserved(_) => return false,
-                // Main combinator cannot be of liftup or recharge.
-                Combinator::Liftup(_) => return false,
-                Combinator::Recharge(_) => return false,
             }
+            Some(Combinator::Add(add)) => spend(account_key, add.amount() as u64),
+            Some(Combinator::Swapout(swapout)) => spend(account_key, swapout.amount() as u64),
+            _ => {}
         }
 
-        true
+        max_spends
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        self.encode_canonical()
     }
-}
 
-impl AuthSighash for Entry {
-    fn auth_sighash(&self) -> [u8; 32] {
+    /// Returns the sighash of the entry.
+    ///
+    /// The sighash is bound to the chain and the nonce, so that a signed entry cannot be replayed
+    /// on another chain, or again on the same chain.
+    pub fn sighash(&self, chain: Chain) -> [u8; 32] {
         let mut preimage: Vec<u8> = Vec::<u8>::new();
 
         // Account
         preimage.extend(self.account.key().serialize_xonly());
 
+        // Nonce
+        preimage.extend(self.nonce.to_le_bytes());
+
         match &self.uppermost_left_branch {
             Some(uppermost_left_branch) => {
                 preimage.push(0x01);
@@ -327,7 +405,7 @@ impl AuthSighash for Entry {
                 match &uppermost_left_branch.liftup {
                     Some(liftup) => {
                         preimage.push(0x01);
-                        preimage.extend(liftup.auth_sighash());
+                        preimage.extend(liftup.auth_sighash(chain));
                     }
                     None => preimage.push(0x00),
                 }
@@ -335,7 +413,7 @@ impl AuthSighash for Entry {
                 match &uppermost_left_branch.recharge {
                     Some(recharge) => {
                         preimage.push(0x01);
-                        preimage.extend(recharge.auth_sighash());
+                        preimage.extend(recharge.auth_sighash(chain));
                     }
                     None => preimage.push(0x00),
                 }
@@ -348,14 +426,20 @@ impl AuthSighash for Entry {
                 preimage.push(0x01);
 
                 match &uppermost_right_branch.main_combinator() {
-                    Combinator::Move(r#move) => preimage.extend(r#move.auth_sighash()),
-                    Combinator::Call(call) => preimage.extend(call.auth_sighash()),
-                    Combinator::Add(add) => preimage.extend(add.auth_sighash()),
-                    Combinator::Sub(sub) => preimage.extend(sub.auth_sighash()),
-                    Combinator::Deploy(deploy) => preimage.extend(deploy.auth_sighash()),
-                    Combinator::Swapout(swapout) => preimage.extend(swapout.auth_sighash()),
-                    Combinator::Revive(revive) => preimage.extend(revive.auth_sighash()),
-                    Combinator::Claim(claim) => preimage.extend(claim.auth_sighash()),
+                    Combinator::Move(r#move) => preimage.extend(r#move.auth_sighash(chain)),
+                    Combinator::BatchMove(batch_move) => {
+                        preimage.extend(batch_move.auth_sighash(chain))
+                    }
+                    Combinator::Call(call) => preimage.extend(call.auth_sighash(chain, self.nonce)),
+                    Combinator::Bundle(bundle) => {
+                        preimage.extend(bundle.auth_sighash(chain, self.nonce))
+                    }
+                    Combinator::Add(add) => preimage.extend(add.auth_sighash(chain)),
+                    Combinator::Sub(sub) => preimage.extend(sub.auth_sighash(chain)),
+                    Combinator::Deploy(deploy) => preimage.extend(deploy.auth_sighash(chain)),
+                    Combinator::Swapout(swapout) => preimage.extend(swapout.auth_sighash(chain)),
+                    Combinator::Revive(revive) => preimage.extend(revive.auth_sighash(chain)),
+                    Combinator::Claim(claim) => preimage.extend(claim.auth_sighash(chain)),
                     // Reserved is not covered.
                     Combinator::Reserved(_) => return [0xffu8; 32],
                     // Liftup and recharge belong to the uppermost left branch.
@@ -366,6 +450,6 @@ impl AuthSighash for Entry {
             None => preimage.push(0x00),
         }
 
-        preimage.hash(Some(HashTag::SighashEntry))
+        preimage.hash(Some(HashTag::SighashEntry(chain)))
     }
 }
@@ -1,3 +1,5 @@
 pub mod branches;
+pub mod codec;
 pub mod combinator;
 pub mod entry;
+pub mod validation;
@@ -0,0 +1,3 @@
+pub mod validate;
+pub mod validation_ctx;
+pub mod validation_error;
@@ -0,0 +1,406 @@
+use crate::{
+    constructive::{
+        entry::{
+            combinator::{
+                combinator::Combinator,
+                combinators::{
+                    call::call::Call, liftup::liftup::Liftup, recharge::recharge::Recharge,
+                },
+            },
+            entry::Entry,
+            validation::{
+                validation_ctx::EntryValidationCtx, validation_error::EntryValidationError,
+            },
+        },
+        taproot::P2TR,
+    },
+    inscriptive::registery::{
+        account_registery::ACCOUNT_REGISTERY, contract_registery::CONTRACT_REGISTERY,
+    },
+    operative::Chain,
+};
+use bitcoin::OutPoint;
+use std::collections::HashSet;
+
+impl Entry {
+    /// Validates the entry against a validation context.
+    ///
+    /// This is the single set of rules an entry must pass to be included in a batch,
+    /// shared by the node and the coordinator. The account signature is not part of it: the
+    /// coordinator authenticates it on the commit carrying the entry, and nodes verify the session
+    /// signature covering the entry sighash before validating the entries of a session payload.
+    pub async fn validate(&self, ctx: &EntryValidationCtx) -> Result<(), EntryValidationError> {
+        // #1 Structure validation.
+        self.validate_structure()?;
+
+        // #2 Account validation.
+        self.validate_account_ctx(ctx).await?;
+
+        // #3 Branch account consistency.
+        self.validate_branch_accounts()?;
+
+        // #4 Fee payer co-signature validation.
+        self.validate_fee_payer_signatures(ctx.chain())?;
+
+        // #5 Liftup validation.
+        let lifted_value = match self.liftup() {
+            Some(liftup) => self.validate_liftup(&liftup, ctx).await?,
+            None => 0,
+        };
+
+        // #6 Recharge validation.
+        if let Some(recharge) = self.recharge() {
+            self.validate_recharge(&recharge, ctx).await?;
+        }
+
+        // #7 Main combinator validation.
+        self.validate_main_combinator(ctx).await?;
+
+        // #8 Amount and fee validation.
+        self.validate_spends(ctx, lifted_value).await?;
+
+        Ok(())
+    }
+
+    /// Checks that the entry is not empty, and that its main combinator is a valid one.
+    fn validate_structure(&self) -> Result<(), EntryValidationError> {
+        if self.uppermost_left_branch().is_none() && self.uppermost_right_branch().is_none() {
+            return Err(EntryValidationError::EmptyEntry);
+        }
+
+        match self.main_combinator() {
+            // Main combinator cannot be of liftup or recharge.
+            Some(Combinator::Liftup(_)) | Some(Combinator::Recharge(_)) => {
+                Err(EntryValidationError::InvalidMainCombinator)
+            }
+            // Reserved fails the validation.
+            Some(Combinator::Reserved(_)) => Err(EntryValidationError::ReservedCombinator),
+            // Deploy is not yet supported.
+            Some(Combinator::Deploy(_)) => Err(EntryValidationError::UnimplementedCombinator),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the account against the blacklist and the account registery.
+    async fn validate_account_ctx(
+        &self,
+        ctx: &EntryValidationCtx,
+    ) -> Result<(), EntryValidationError> {
+        let account = self.account();
+
+        // Account keys are even.
+        if account.is_odd_key() {
+            return Err(EntryValidationError::OddAccountKey);
+        }
+
+        // Blacklist check.
+        {
+            let blacklist_dir = ctx.blacklist_dir();
+            let _blacklist_dir = blacklist_dir.lock().await;
+            if let Some(until) = _blacklist_dir.check_blacklist(account) {
+                return Err(EntryValidationError::BlacklistedUntil(until));
+            }
+        }
+
+        let account_registery: ACCOUNT_REGISTERY = {
+            let registery = ctx.registery();
+            let _registery = registery.lock().await;
+            _registery.account_registery()
+        };
+
+        let _account_registery = account_registery.lock().await;
+
+        // Registery index check.
+        // A given registery index must match the one the account is registered at.
+        if let Some(given_registery_index) = account.registery_index() {
+            let local_registery_index = match _account_registery.account_by_key(account.key()) {
+                Some(account) => account.registery_index(),
+                None => None,
+            };
+
+            if local_registery_index != Some(given_registery_index) {
+                return Err(EntryValidationError::InvalidAccountRegisteryIndex);
+            }
+        }
+
+        // Nonce check.
+        // The entry must be signed for the next nonce of the account, otherwise it is a replay.
+        let next_nonce = _account_registery.nonce_by_key(account.key());
+        if self.nonce() != next_nonce {
+            return Err(EntryValidationError::InvalidNonce(next_nonce));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that every branch of the entry belongs to the account.
+    fn validate_branch_accounts(&self) -> Result<(), EntryValidationError> {
+        let account = self.account();
+        let account_key = account.key().serialize_xonly();
+
+        if let Some(liftup) = self.liftup() {
+            if liftup
+                .lifts()
+                .iter()
+                .any(|lift| lift.account_key() != account.key())
+            {
+                return Err(EntryValidationError::LiftAccountMismatch);
+            }
+        }
+
+        if let Some(recharge) = self.recharge() {
+            if recharge
+                .vtxos()
+                .iter()
+                .any(|vtxo| vtxo.account_key() != account.key())
+            {
+                return Err(EntryValidationError::RechargeAccountMismatch);
+            }
+        }
+
+        let is_valid = match self.main_combinator() {
+            Some(Combinator::Move(r#move)) => r#move.validate_account(account),
+            Some(Combinator::BatchMove(batch_move)) => batch_move.validate_account(account),
+            Some(Combinator::Call(call)) => call.account_key() == account_key,
+            Some(Combinator::Bundle(bundle)) => {
+                bundle
+                    .moves()
+                    .iter()
+                    .all(|r#move| r#move.validate_account(account))
+                    && bundle
+                        .calls()
+                        .iter()
+                        .all(|call| call.account_key() == account_key)
+            }
+            Some(Combinator::Add(add)) => add.validate_account(account),
+            Some(Combinator::Sub(sub)) => sub.validate_account(account),
+            Some(Combinator::Swapout(swapout)) => swapout.validate_account(account),
+            Some(Combinator::Revive(revive)) => revive.validate_account(account),
+            Some(Combinator::Claim(claim)) => claim.validate_account(account),
+            _ => true,
+        };
+
+        if !is_valid {
+            return Err(EntryValidationError::CombinatorAccountMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Checks the fee payer co-signatures of the sponsored calls.
+    fn validate_fee_payer_signatures(&self, chain: Chain) -> Result<(), EntryValidationError> {
+        let account_key = self.account().key().serialize_xonly();
+
+        for call in entry_calls(self).iter() {
+            // A sponsored call must be paid by another key.
+            if call.fee_payer() == Some(account_key) {
+                return Err(EntryValidationError::SelfSponsoredCall);
+            }
+
+            if !call.authenticate_fee_payer(chain, self.nonce()) {
+                return Err(EntryValidationError::InvalidFeePayerSignature);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks every lift against the UTXO set. Returns the total value lifted.
+    async fn validate_liftup(
+        &self,
+        liftup: &Liftup,
+        ctx: &EntryValidationCtx,
+    ) -> Result<u64, EntryValidationError> {
+        let account_key = self.account().key();
+
+        let utxo_set = ctx.utxo_set();
+        let _utxo_set = utxo_set.lock().await;
+
+        let mut outpoints = HashSet::<OutPoint>::new();
+        let mut lifted_value: u64 = 0;
+
+        for lift in liftup.lifts().iter() {
+            // The operator key cannot be the account key.
+            if lift.operator_key() == account_key {
+                return Err(EntryValidationError::InvalidLiftOperatorKey);
+            }
+
+            let outpoint = lift
+                .outpoint()
+                .ok_or(EntryValidationError::MissingLiftOutpoint)?;
+
+            if !outpoints.insert(outpoint) {
+                return Err(EntryValidationError::DuplicateLiftOutpoint(outpoint));
+            }
+
+            // The outpoint must be unspent.
+            let txout = _utxo_set
+                .txout_by_outpoint(&outpoint)
+                .ok_or(EntryValidationError::UnknownLiftOutpoint(outpoint))?;
+
+            // The outpoint must be locked to the lift.
+            match lift.spk() {
+                Some(spk) if spk == txout.script_pubkey.to_bytes() => {}
+                _ => return Err(EntryValidationError::LiftScriptPubKeyMismatch(outpoint)),
+            }
+
+            // The lift value, if given, must match the outpoint value.
+            let value = txout.value.to_sat();
+            if let Some(lift_value) = lift.value() {
+                if lift_value != value {
+                    return Err(EntryValidationError::LiftValueMismatch(outpoint));
+                }
+            }
+
+            lifted_value = lifted_value
+                .checked_add(value)
+                .ok_or(EntryValidationError::AmountOverflow)?;
+        }
+
+        Ok(lifted_value)
+    }
+
+    /// Checks that every recharged VTXO is held by the account, and is due for a recharge.
+    async fn validate_recharge(
+        &self,
+        recharge: &Recharge,
+        ctx: &EntryValidationCtx,
+    ) -> Result<(), EntryValidationError> {
+        let rechargeable_vtxos = {
+            let vtxo_set = ctx.vtxo_set();
+            let _vtxo_set = vtxo_set.lock().await;
+            _vtxo_set.vtxos_to_recharge(&self.account().key(), ctx.current_bitcoin_height())
+        };
+
+        let mut outpoints = HashSet::<OutPoint>::new();
+
+        for vtxo in recharge.vtxos().iter() {
+            let outpoint = vtxo
+                .outpoint()
+                .ok_or(EntryValidationError::MissingRechargeOutpoint)?;
+
+            if !outpoints.insert(outpoint) {
+                return Err(EntryValidationError::DuplicateRechargeOutpoint(outpoint));
+            }
+
+            if !rechargeable_vtxos
+                .iter()
+                .any(|rechargeable| rechargeable == vtxo)
+            {
+                return Err(EntryValidationError::UnrechargeableVTXO(outpoint));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the main combinator against the registery and the VTXO set.
+    async fn validate_main_combinator(
+        &self,
+        ctx: &EntryValidationCtx,
+    ) -> Result<(), EntryValidationError> {
+        let account = self.account();
+
+        // Amounts must be non-zero.
+        let has_zero_amount = match self.main_combinator() {
+            Some(Combinator::Move(r#move)) => r#move.amount() == 0,
+            Some(Combinator::BatchMove(batch_move)) => batch_move
+                .recipients()
+                .iter()
+                .any(|(_, amount)| *amount == 0),
+            Some(Combinator::Bundle(bundle)) => {
+                bundle.moves().iter().any(|r#move| r#move.amount() == 0)
+            }
+            Some(Combinator::Add(add)) => add.amount() == 0,
+            Some(Combinator::Sub(sub)) => sub.amount() == 0,
+            Some(Combinator::Swapout(swapout)) => swapout.amount() == 0,
+            _ => false,
+        };
+
+        if has_zero_amount {
+            return Err(EntryValidationError::ZeroAmount);
+        }
+
+        match self.main_combinator() {
+            Some(Combinator::BatchMove(batch_move)) if batch_move.total_amount().is_none() => {
+                return Err(EntryValidationError::AmountOverflow);
+            }
+            Some(Combinator::Claim(claim)) => {
+                let vtxo_set = ctx.vtxo_set();
+                let _vtxo_set = vtxo_set.lock().await;
+                if !claim.validate_ownership(account, &_vtxo_set) {
+                    return Err(EntryValidationError::InvalidClaimVTXOs);
+                }
+            }
+            // Revived VTXOs must have expired and been swept by the operator.
+            Some(Combinator::Revive(revive)) => {
+                let vtxo_set = ctx.vtxo_set();
+                let _vtxo_set = vtxo_set.lock().await;
+                if !revive.validate_revival(account, &_vtxo_set, ctx.current_bitcoin_height()) {
+                    return Err(EntryValidationError::InvalidReviveVTXOs);
+                }
+            }
+            _ => {}
+        }
+
+        // Called contracts must be registered.
+        let calls = entry_calls(self);
+        if !calls.is_empty() {
+            let contract_registery: CONTRACT_REGISTERY = {
+                let registery = ctx.registery();
+                let _registery = registery.lock().await;
+                _registery.contract_registery()
+            };
+
+            let _contract_registery = contract_registery.lock().await;
+            for call in calls.iter() {
+                if !_contract_registery.is_registered(call.contract_id()) {
+                    return Err(EntryValidationError::UnregisteredContract(
+                        call.contract_id(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that every key charged by the entry can cover its spends,
+    /// net of what other entries of the batch already spend from it.
+    /// The value lifted up by the entry is spendable by the account.
+    async fn validate_spends(
+        &self,
+        ctx: &EntryValidationCtx,
+        lifted_value: u64,
+    ) -> Result<(), EntryValidationError> {
+        let account_key = self.account().key().serialize_xonly();
+
+        let vtxo_set = ctx.vtxo_set();
+        let _vtxo_set = vtxo_set.lock().await;
+
+        for (key, max_spend) in self.max_spends().iter() {
+            let mut balance = _vtxo_set.balance_by_account_key(*key);
+
+            if *key == account_key {
+                balance = balance.saturating_add(lifted_value);
+            }
+
+            let available = balance.saturating_sub(ctx.pending_spend(*key));
+
+            if *max_spend > available {
+                return Err(EntryValidationError::InsufficientBalance(*key));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the calls of the entry, in execution order.
+fn entry_calls(entry: &Entry) -> Vec<Call> {
+    match entry.main_combinator() {
+        Some(Combinator::Call(call)) => vec![call],
+        Some(Combinator::Bundle(bundle)) => bundle.calls(),
+        _ => vec![],
+    }
+}
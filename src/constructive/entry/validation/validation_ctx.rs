@@ -0,0 +1,94 @@
+use crate::{
+    inscriptive::{
+        blacklist::BLIST_DIRECTORY,
+        registery::registery::REGISTERY,
+        set::{utxo_set::UTXO_SET, vtxo_set::VTXO_SET},
+    },
+    operative::Chain,
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// The context an `Entry` is validated against.
+///
+/// The node and the coordinator construct the same context from their local stores,
+/// so that an entry passes on one if and only if it passes on the other.
+pub struct EntryValidationCtx {
+    chain: Chain,
+    registery: REGISTERY,
+    vtxo_set: VTXO_SET,
+    utxo_set: UTXO_SET,
+    blacklist_dir: BLIST_DIRECTORY,
+    current_bitcoin_height: u32,
+    // Amounts already spent by other entries of the same batch, by x-only key.
+    pending_spends: HashMap<[u8; 32], u64>,
+}
+
+impl EntryValidationCtx {
+    /// Creates a new entry validation context.
+    pub fn new(
+        chain: Chain,
+        registery: &REGISTERY,
+        vtxo_set: &VTXO_SET,
+        utxo_set: &UTXO_SET,
+        blacklist_dir: &BLIST_DIRECTORY,
+        current_bitcoin_height: u32,
+    ) -> EntryValidationCtx {
+        EntryValidationCtx {
+            chain,
+            registery: Arc::clone(registery),
+            vtxo_set: Arc::clone(vtxo_set),
+            utxo_set: Arc::clone(utxo_set),
+            blacklist_dir: Arc::clone(blacklist_dir),
+            current_bitcoin_height,
+            pending_spends: HashMap::<[u8; 32], u64>::new(),
+        }
+    }
+
+    /// Returns the chain entries are signed for.
+    pub fn chain(&self) -> Chain {
+        self.chain
+    }
+
+    /// Returns the registery.
+    pub fn registery(&self) -> REGISTERY {
+        Arc::clone(&self.registery)
+    }
+
+    /// Returns the VTXO set.
+    pub fn vtxo_set(&self) -> VTXO_SET {
+        Arc::clone(&self.vtxo_set)
+    }
+
+    /// Returns the UTXO set.
+    pub fn utxo_set(&self) -> UTXO_SET {
+        Arc::clone(&self.utxo_set)
+    }
+
+    /// Returns the blacklist directory.
+    pub fn blacklist_dir(&self) -> BLIST_DIRECTORY {
+        Arc::clone(&self.blacklist_dir)
+    }
+
+    /// Returns the current Bitcoin height.
+    pub fn current_bitcoin_height(&self) -> u32 {
+        self.current_bitcoin_height
+    }
+
+    /// Sets the current Bitcoin height, to the height a session is anchored to.
+    pub fn set_current_bitcoin_height(&mut self, current_bitcoin_height: u32) {
+        self.current_bitcoin_height = current_bitcoin_height;
+    }
+
+    /// Returns the amount already spent by other entries of the batch from a given x-only key.
+    pub fn pending_spend(&self, key: [u8; 32]) -> u64 {
+        self.pending_spends.get(&key).copied().unwrap_or(0)
+    }
+
+    /// Adds the spends of another entry of the batch, typically its `max_spends`.
+    pub fn add_pending_spends(&mut self, spends: &HashMap<[u8; 32], u64>) {
+        for (key, amount) in spends.iter() {
+            let pending_spend = self.pending_spends.entry(*key).or_insert(0);
+            *pending_spend = pending_spend.saturating_add(*amount);
+        }
+    }
+}
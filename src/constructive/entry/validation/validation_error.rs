@@ -0,0 +1,169 @@
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
+use bitcoin::OutPoint;
+use serde::{Deserialize, Serialize};
+
+/// The error type for validating an `Entry` against its context.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EntryValidationError {
+    // Entry errors.
+    EmptyEntry,
+    OddAccountKey,
+    BlacklistedUntil(u64),
+    InvalidAccountRegisteryIndex,
+    // The expected nonce.
+    InvalidNonce(u64),
+    // Main combinator errors.
+    InvalidMainCombinator,
+    ReservedCombinator,
+    UnimplementedCombinator,
+    CombinatorAccountMismatch,
+    // Call errors.
+    SelfSponsoredCall,
+    InvalidFeePayerSignature,
+    UnregisteredContract([u8; 32]),
+    // Liftup errors.
+    LiftAccountMismatch,
+    InvalidLiftOperatorKey,
+    MissingLiftOutpoint,
+    DuplicateLiftOutpoint(OutPoint),
+    UnknownLiftOutpoint(OutPoint),
+    LiftScriptPubKeyMismatch(OutPoint),
+    LiftValueMismatch(OutPoint),
+    // Recharge errors.
+    RechargeAccountMismatch,
+    MissingRechargeOutpoint,
+    DuplicateRechargeOutpoint(OutPoint),
+    UnrechargeableVTXO(OutPoint),
+    // Recovery errors.
+    InvalidClaimVTXOs,
+    InvalidReviveVTXOs,
+    // Amount and fee errors.
+    ZeroAmount,
+    AmountOverflow,
+    // The x-only key that cannot cover its spends.
+    InsufficientBalance([u8; 32]),
+}
+
+/// The canonical encoding for `EntryValidationError`: the variant tag, followed by its value.
+impl Canonical for EntryValidationError {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        match self {
+            EntryValidationError::EmptyEntry => bytes.push(0x00),
+            EntryValidationError::OddAccountKey => bytes.push(0x01),
+            EntryValidationError::BlacklistedUntil(value) => {
+                bytes.push(0x02);
+                value.encode_fields(bytes);
+            }
+            EntryValidationError::InvalidAccountRegisteryIndex => bytes.push(0x03),
+            EntryValidationError::InvalidNonce(value) => {
+                bytes.push(0x04);
+                value.encode_fields(bytes);
+            }
+            EntryValidationError::InvalidMainCombinator => bytes.push(0x05),
+            EntryValidationError::ReservedCombinator => bytes.push(0x06),
+            EntryValidationError::UnimplementedCombinator => bytes.push(0x07),
+            EntryValidationError::CombinatorAccountMismatch => bytes.push(0x08),
+            EntryValidationError::SelfSponsoredCall => bytes.push(0x09),
+            EntryValidationError::InvalidFeePayerSignature => bytes.push(0x0a),
+            EntryValidationError::UnregisteredContract(value) => {
+                bytes.push(0x0b);
+                value.encode_fields(bytes);
+            }
+            EntryValidationError::LiftAccountMismatch => bytes.push(0x0c),
+            EntryValidationError::InvalidLiftOperatorKey => bytes.push(0x0d),
+            EntryValidationError::MissingLiftOutpoint => bytes.push(0x0e),
+            EntryValidationError::DuplicateLiftOutpoint(value) => {
+                bytes.push(0x0f);
+                value.encode_fields(bytes);
+            }
+            EntryValidationError::UnknownLiftOutpoint(value) => {
+                bytes.push(0x10);
+                value.encode_fields(bytes);
+            }
+            EntryValidationError::LiftScriptPubKeyMismatch(value) => {
+                bytes.push(0x11);
+                value.encode_fields(bytes);
+            }
+            EntryValidationError::LiftValueMismatch(value) => {
+                bytes.push(0x12);
+                value.encode_fields(bytes);
+            }
+            EntryValidationError::RechargeAccountMismatch => bytes.push(0x13),
+            EntryValidationError::MissingRechargeOutpoint => bytes.push(0x14),
+            EntryValidationError::DuplicateRechargeOutpoint(value) => {
+                bytes.push(0x15);
+                value.encode_fields(bytes);
+            }
+            EntryValidationError::UnrechargeableVTXO(value) => {
+                bytes.push(0x16);
+                value.encode_fields(bytes);
+            }
+            EntryValidationError::InvalidClaimVTXOs => bytes.push(0x17),
+            EntryValidationError::InvalidReviveVTXOs => bytes.push(0x18),
+            EntryValidationError::ZeroAmount => bytes.push(0x19),
+            EntryValidationError::AmountOverflow => bytes.push(0x1a),
+            EntryValidationError::InsufficientBalance(value) => {
+                bytes.push(0x1b);
+                value.encode_fields(bytes);
+            }
+        }
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<EntryValidationError, CanonicalDecodeError> {
+        match reader.read_u8()? {
+            0x00 => Ok(EntryValidationError::EmptyEntry),
+            0x01 => Ok(EntryValidationError::OddAccountKey),
+            0x02 => Ok(EntryValidationError::BlacklistedUntil(u64::decode_fields(
+                reader,
+            )?)),
+            0x03 => Ok(EntryValidationError::InvalidAccountRegisteryIndex),
+            0x04 => Ok(EntryValidationError::InvalidNonce(u64::decode_fields(
+                reader,
+            )?)),
+            0x05 => Ok(EntryValidationError::InvalidMainCombinator),
+            0x06 => Ok(EntryValidationError::ReservedCombinator),
+            0x07 => Ok(EntryValidationError::UnimplementedCombinator),
+            0x08 => Ok(EntryValidationError::CombinatorAccountMismatch),
+            0x09 => Ok(EntryValidationError::SelfSponsoredCall),
+            0x0a => Ok(EntryValidationError::InvalidFeePayerSignature),
+            0x0b => Ok(EntryValidationError::UnregisteredContract(
+                <[u8; 32]>::decode_fields(reader)?,
+            )),
+            0x0c => Ok(EntryValidationError::LiftAccountMismatch),
+            0x0d => Ok(EntryValidationError::InvalidLiftOperatorKey),
+            0x0e => Ok(EntryValidationError::MissingLiftOutpoint),
+            0x0f => Ok(EntryValidationError::DuplicateLiftOutpoint(
+                OutPoint::decode_fields(reader)?,
+            )),
+            0x10 => Ok(EntryValidationError::UnknownLiftOutpoint(
+                OutPoint::decode_fields(reader)?,
+            )),
+            0x11 => Ok(EntryValidationError::LiftScriptPubKeyMismatch(
+                OutPoint::decode_fields(reader)?,
+            )),
+            0x12 => Ok(EntryValidationError::LiftValueMismatch(
+                OutPoint::decode_fields(reader)?,
+            )),
+            0x13 => Ok(EntryValidationError::RechargeAccountMismatch),
+            0x14 => Ok(EntryValidationError::MissingRechargeOutpoint),
+            0x15 => Ok(EntryValidationError::DuplicateRechargeOutpoint(
+                OutPoint::decode_fields(reader)?,
+            )),
+            0x16 => Ok(EntryValidationError::UnrechargeableVTXO(
+                OutPoint::decode_fields(reader)?,
+            )),
+            0x17 => Ok(EntryValidationError::InvalidClaimVTXOs),
+            0x18 => Ok(EntryValidationError::InvalidReviveVTXOs),
+            0x19 => Ok(EntryValidationError::ZeroAmount),
+            0x1a => Ok(EntryValidationError::AmountOverflow),
+            0x1b => Ok(EntryValidationError::InsufficientBalance(
+                <[u8; 32]>::decode_fields(reader)?,
+            )),
+            tag => Err(CanonicalDecodeError::InvalidTag(tag)),
+        }
+    }
+}
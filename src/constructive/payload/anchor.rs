@@ -0,0 +1,54 @@
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
+use serde::{Deserialize, Serialize};
+
+/// The Bitcoin block a session is anchored to.
+///
+/// The coordinator anchors a session to its Bitcoin sync height when the session starts, and
+/// commits the anchor in the session payload, so that every node resolves the same Bitcoin height
+/// and timestamp for the session regardless of its own sync progress.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionAnchor {
+    // The anchored Bitcoin height.
+    bitcoin_height: u32,
+    // The timestamp of the block header at the anchored height.
+    timestamp: u32,
+}
+
+impl SessionAnchor {
+    /// Creates a new session anchor.
+    pub fn new(bitcoin_height: u32, timestamp: u32) -> SessionAnchor {
+        SessionAnchor {
+            bitcoin_height,
+            timestamp,
+        }
+    }
+
+    /// Returns the anchored Bitcoin height.
+    pub fn bitcoin_height(&self) -> u32 {
+        self.bitcoin_height
+    }
+
+    /// Returns the timestamp of the block header at the anchored height.
+    pub fn timestamp(&self) -> u32 {
+        self.timestamp
+    }
+}
+
+/// The canonical encoding for `SessionAnchor`: the anchored Bitcoin height, followed by the timestamp.
+impl Canonical for SessionAnchor {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.bitcoin_height.encode_fields(bytes);
+        self.timestamp.encode_fields(bytes);
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<SessionAnchor, CanonicalDecodeError> {
+        Ok(SessionAnchor {
+            bitcoin_height: u32::decode_fields(reader)?,
+            timestamp: u32::decode_fields(reader)?,
+        })
+    }
+}
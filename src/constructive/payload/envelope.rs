@@ -0,0 +1,131 @@
+use crate::constructive::payload::{
+    envelope_error::SessionPayloadEnvelopeError, payload::SessionPayload,
+};
+use bitcoin::{
+    opcodes::{
+        all::{OP_ENDIF, OP_IF},
+        OP_FALSE,
+    },
+    script::{Builder, Instruction, PushBytesBuf},
+    Block, Script, ScriptBuf, Transaction,
+};
+
+/// Protocol tag pushed right after `OP_FALSE OP_IF` to mark a session payload envelope.
+pub const ENVELOPE_TAG: [u8; 4] = *b"cube";
+
+/// Maximum size of a single envelope push (`MAX_SCRIPT_ELEMENT_SIZE`).
+pub const MAX_ENVELOPE_CHUNK_SIZE: usize = 520;
+
+/// Maximum size of a serialized session payload.
+/// Leaves headroom below the 400,000 weight unit standardness limit for the rest of the pool transaction.
+pub const MAX_SESSION_PAYLOAD_SIZE: usize = 390_000;
+
+impl SessionPayload {
+    /// Splits the serialized payload into envelope chunks within the push size limit.
+    pub fn envelope_chunks(&self) -> Result<Vec<Vec<u8>>, SessionPayloadEnvelopeError> {
+        let bytes = self.serialize();
+
+        // Check the payload size.
+        if bytes.len() > MAX_SESSION_PAYLOAD_SIZE {
+            return Err(SessionPayloadEnvelopeError::PayloadTooLarge(bytes.len()));
+        }
+
+        // Split the payload into chunks.
+        let chunks = bytes
+            .chunks(MAX_ENVELOPE_CHUNK_SIZE)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+
+        // Return the chunks.
+        Ok(chunks)
+    }
+
+    /// Returns the taproot envelope carrying the payload:
+    /// `OP_FALSE OP_IF <tag> <chunk #0> .. <chunk #n> OP_ENDIF`.
+    ///
+    /// The envelope is a no-op and is meant to be appended to a tapleaf script.
+    pub fn envelope(&self) -> Result<ScriptBuf, SessionPayloadEnvelopeError> {
+        let chunks = self.envelope_chunks()?;
+
+        let mut builder = Builder::new()
+            .push_opcode(OP_FALSE)
+            .push_opcode(OP_IF)
+            .push_slice(ENVELOPE_TAG);
+
+        for (index, chunk) in chunks.into_iter().enumerate() {
+            let push = PushBytesBuf::try_from(chunk)
+                .map_err(|_| SessionPayloadEnvelopeError::ChunkPushError(index))?;
+            builder = builder.push_slice(push);
+        }
+
+        Ok(builder.push_opcode(OP_ENDIF).into_script())
+    }
+
+    /// Finds and reassembles the session payloads enveloped in a tapscript.
+    /// Malformed envelopes are skipped.
+    pub fn from_tapscript(script: &Script) -> Vec<SessionPayload> {
+        let mut payloads = Vec::<SessionPayload>::new();
+
+        let instructions = match script.instructions().collect::<Result<Vec<_>, _>>() {
+            Ok(instructions) => instructions,
+            Err(_) => return payloads,
+        };
+
+        let mut index = 0;
+        while index + 2 < instructions.len() {
+            // Look for the `OP_FALSE OP_IF <tag>` opening.
+            let opening = match instructions[index..index + 3] {
+                [Instruction::PushBytes(op_false), Instruction::Op(op_if), Instruction::PushBytes(tag)] => {
+                    op_false.is_empty() && op_if == OP_IF && tag.as_bytes() == ENVELOPE_TAG
+                }
+                _ => false,
+            };
+
+            if !opening {
+                index += 1;
+                continue;
+            }
+
+            // Reassemble the chunks until `OP_ENDIF`.
+            let mut bytes = Vec::<u8>::new();
+            let mut closed = false;
+            index += 3;
+            while index < instructions.len() {
+                match instructions[index] {
+                    Instruction::PushBytes(push) => bytes.extend(push.as_bytes()),
+                    Instruction::Op(op) => {
+                        closed = op == OP_ENDIF;
+                        break;
+                    }
+                }
+                index += 1;
+            }
+
+            if closed {
+                if let Ok(payload) = SessionPayload::deserialize(&bytes) {
+                    payloads.push(payload);
+                }
+            }
+        }
+
+        payloads
+    }
+
+    /// Finds and reassembles the session payloads enveloped in a transaction's input witnesses.
+    pub fn from_tx(tx: &Transaction) -> Vec<SessionPayload> {
+        tx.input
+            .iter()
+            .filter_map(|txin| txin.witness.tapscript())
+            .flat_map(SessionPayload::from_tapscript)
+            .collect()
+    }
+
+    /// Finds and reassembles the session payloads enveloped in a block, in transaction order.
+    pub fn from_block(block: &Block) -> Vec<SessionPayload> {
+        block
+            .txdata
+            .iter()
+            .flat_map(SessionPayload::from_tx)
+            .collect()
+    }
+}
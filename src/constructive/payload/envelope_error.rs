@@ -0,0 +1,6 @@
+/// The error type for wrapping a `SessionPayload` into a taproot envelope.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionPayloadEnvelopeError {
+    PayloadTooLarge(usize),
+    ChunkPushError(usize),
+}
@@ -0,0 +1,5 @@
+pub mod anchor;
+pub mod envelope;
+pub mod envelope_error;
+pub mod payload;
+pub mod payload_error;
@@ -0,0 +1,404 @@
+use crate::{
+    constructive::{
+        entry::{entry::Entry, validation::validation_ctx::EntryValidationCtx},
+        payload::{
+            anchor::SessionAnchor,
+            payload_error::{
+                SessionPayloadConstructionError, SessionPayloadDeserializeError,
+                SessionPayloadEntriesDecodeError,
+            },
+        },
+        txn::txholder::TxHolder,
+    },
+    inscriptive::{
+        epoch::dir::EPOCH_DIRECTORY, registery::registery::REGISTERY, repo::repo::PROGRAMS_REPO,
+        set::vtxo_set::VTXO_SET,
+    },
+    operative::Chain,
+    transmutative::{
+        hash::{Hash, HashTag},
+        musig::keyagg::MusigKeyAggCtx,
+        secp::schnorr::{self, SchnorrSigningMode},
+    },
+};
+use bit_vec::BitVec;
+use secp::Point;
+use std::collections::HashSet;
+
+/// The current session payload version.
+pub const SESSION_PAYLOAD_VERSION: u8 = 1;
+
+/// Length of the session payload header: version (1), session id (32), operator signature (64),
+/// session signature (64), base ops price (4), anchored Bitcoin height (4), anchored timestamp (4),
+/// and entry count (4).
+pub const SESSION_PAYLOAD_HEADER_LEN: usize = 1 + 32 + 64 + 64 + 4 + 4 + 4 + 4;
+
+/// A finalized session as written to the Bitcoin data-availability layer.
+///
+/// Entries are carried as a single CPE bit stream, padded with zero bits to the next byte.
+/// The payload is signed by the operator quorum, so that nodes only sync payloads the operators
+/// finalized, and carries the session signature the accounts co-signed their entries with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionPayload {
+    version: u8,
+    session_id: [u8; 32],
+    operator_sig: [u8; 64],
+    // MuSig signature of the entry accounts and the operator quorum over the session auth message.
+    session_sig: [u8; 64],
+    // Base ops price the session calls were priced at.
+    base_ops_price: u32,
+    // Bitcoin block the session is anchored to.
+    anchor: SessionAnchor,
+    entry_count: u32,
+    entries: Vec<u8>,
+}
+
+impl SessionPayload {
+    /// Creates a new session payload from an already CPE-encoded entry stream.
+    pub fn new(
+        session_id: [u8; 32],
+        operator_sig: [u8; 64],
+        session_sig: [u8; 64],
+        base_ops_price: u32,
+        anchor: SessionAnchor,
+        entry_count: u32,
+        entries: Vec<u8>,
+    ) -> SessionPayload {
+        SessionPayload {
+            version: SESSION_PAYLOAD_VERSION,
+            session_id,
+            operator_sig,
+            session_sig,
+            base_ops_price,
+            anchor,
+            entry_count,
+            entries,
+        }
+    }
+
+    /// Creates a new session payload by CPE-encoding the given ordered entries.
+    ///
+    /// The payload is unsigned until `set_session_sig` is called with the session signature, and
+    /// `set_operator_sig` with the operator quorum signature over its `signing_message`.
+    pub async fn from_entries(
+        session_id: [u8; 32],
+        entries: &[Entry],
+        registery: &REGISTERY,
+        repo: &PROGRAMS_REPO,
+        vtxo_set: &VTXO_SET,
+        base_ops_price: u32,
+        anchor: SessionAnchor,
+    ) -> Result<SessionPayload, SessionPayloadConstructionError> {
+        // Check the entry count.
+        let entry_count: u32 = entries
+            .len()
+            .try_into()
+            .map_err(|_| SessionPayloadConstructionError::EntryCountOverflow(entries.len()))?;
+
+        // Encode the entries back to back.
+        let mut bits = BitVec::new();
+        for (index, entry) in entries.iter().enumerate() {
+            let entry_bits = entry
+                .encode_cpe(registery, repo, vtxo_set, base_ops_price)
+                .await
+                .map_err(|e| SessionPayloadConstructionError::EntryCPEEncodeError(index, e))?;
+            bits.extend(entry_bits);
+        }
+
+        // Construct the payload.
+        let payload = SessionPayload::new(
+            session_id,
+            [0x00u8; 64],
+            [0x00u8; 64],
+            base_ops_price,
+            anchor,
+            entry_count,
+            bits.to_bytes(),
+        );
+
+        // Return the payload.
+        Ok(payload)
+    }
+
+    /// Returns the payload version.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the session id.
+    pub fn session_id(&self) -> [u8; 32] {
+        self.session_id
+    }
+
+    /// Returns the operator aggregate signature.
+    pub fn operator_sig(&self) -> [u8; 64] {
+        self.operator_sig
+    }
+
+    /// Sets the operator aggregate signature.
+    pub fn set_operator_sig(&mut self, operator_sig: [u8; 64]) {
+        self.operator_sig = operator_sig;
+    }
+
+    /// Returns the session signature.
+    pub fn session_sig(&self) -> [u8; 64] {
+        self.session_sig
+    }
+
+    /// Sets the session signature.
+    pub fn set_session_sig(&mut self, session_sig: [u8; 64]) {
+        self.session_sig = session_sig;
+    }
+
+    /// Returns the message the operator quorum signs: the payload with the operator signature
+    /// left out.
+    pub fn signing_message(&self) -> [u8; 32] {
+        let mut preimage =
+            Vec::<u8>::with_capacity(SESSION_PAYLOAD_HEADER_LEN - 64 + self.entries.len());
+        preimage.push(self.version);
+        preimage.extend(self.session_id);
+        preimage.extend(self.session_sig);
+        preimage.extend(self.base_ops_price.to_be_bytes());
+        preimage.extend(self.anchor.bitcoin_height().to_be_bytes());
+        preimage.extend(self.anchor.timestamp().to_be_bytes());
+        preimage.extend(self.entry_count.to_be_bytes());
+        preimage.extend(&self.entries);
+
+        preimage.hash(Some(HashTag::SessionPayloadMessage))
+    }
+
+    /// Verifies that the payload is signed by the given operator quorum key.
+    pub fn verify(&self, quorum_key: [u8; 32]) -> bool {
+        schnorr::verify_xonly(
+            quorum_key,
+            self.signing_message(),
+            self.operator_sig,
+            SchnorrSigningMode::BIP340,
+        )
+    }
+
+    /// Returns the message the entry accounts and the operator quorum co-sign for a session: the
+    /// payload version, the session id, the base ops price, the anchor, and the sighash of each
+    /// entry, in order.
+    pub fn auth_msg(
+        session_id: [u8; 32],
+        chain: Chain,
+        base_ops_price: u32,
+        anchor: SessionAnchor,
+        entries: &[Entry],
+    ) -> [u8; 32] {
+        let mut preimage = Vec::<u8>::new();
+        preimage.push(SESSION_PAYLOAD_VERSION);
+        preimage.extend(session_id);
+        preimage.extend(base_ops_price.to_be_bytes());
+        preimage.extend(anchor.bitcoin_height().to_be_bytes());
+        preimage.extend(anchor.timestamp().to_be_bytes());
+
+        for (index, entry) in entries.iter().enumerate() {
+            preimage.extend((index as u32).to_le_bytes());
+            preimage.extend(entry.sighash(chain));
+        }
+
+        preimage.hash(Some(HashTag::PayloadAuth))
+    }
+
+    /// Verifies that the given entries were co-signed by their accounts and the operator quorum
+    /// key in this session.
+    pub fn verify_session_sig(&self, chain: Chain, entries: &[Entry], quorum_key: Point) -> bool {
+        let mut keys: Vec<Point> = entries.iter().map(|entry| entry.account().key()).collect();
+        keys.push(quorum_key);
+
+        let key_agg_ctx = match MusigKeyAggCtx::new(&keys, None) {
+            Some(key_agg_ctx) => key_agg_ctx,
+            None => return false,
+        };
+
+        let message = SessionPayload::auth_msg(
+            self.session_id,
+            chain,
+            self.base_ops_price,
+            self.anchor,
+            entries,
+        );
+
+        schnorr::verify_xonly(
+            key_agg_ctx.agg_key().serialize_xonly(),
+            message,
+            self.session_sig,
+            SchnorrSigningMode::BIP340,
+        )
+    }
+
+    /// Returns the base ops price the session calls were priced at.
+    pub fn base_ops_price(&self) -> u32 {
+        self.base_ops_price
+    }
+
+    /// Returns the Bitcoin block the session is anchored to.
+    pub fn anchor(&self) -> SessionAnchor {
+        self.anchor
+    }
+
+    /// Returns the number of entries.
+    pub fn entry_count(&self) -> u32 {
+        self.entry_count
+    }
+
+    /// Returns the CPE-encoded entry stream.
+    pub fn entries(&self) -> Vec<u8> {
+        self.entries.clone()
+    }
+
+    /// Decodes the ordered entries from the CPE-encoded entry stream.
+    ///
+    /// The payload must be verified against the operator quorum key first.
+    /// The entries are decoded at the anchored Bitcoin height, the session signature is verified
+    /// against their accounts and the quorum key, and each entry is then validated against the
+    /// context anchored to the same height, with the spends of the entries before it pending.
+    /// An account carries at most one entry per payload.
+    pub async fn decode_entries(
+        &self,
+        txholder: &mut TxHolder,
+        epoch_dir: &EPOCH_DIRECTORY,
+        repo: &PROGRAMS_REPO,
+        validation_ctx: &mut EntryValidationCtx,
+        quorum_key: Point,
+    ) -> Result<Vec<Entry>, SessionPayloadEntriesDecodeError> {
+        let bits = BitVec::from_bytes(&self.entries);
+        let mut bit_stream = bits.iter();
+
+        // The entries are validated at the anchored height.
+        validation_ctx.set_current_bitcoin_height(self.anchor.bitcoin_height());
+
+        let registery = validation_ctx.registery();
+        let vtxo_set = validation_ctx.vtxo_set();
+
+        // Decode the entries one by one.
+        let mut entries = Vec::<Entry>::new();
+        let mut account_keys = HashSet::<Point>::new();
+        for index in 0..self.entry_count as usize {
+            let entry = Entry::decode_cpe(
+                &mut bit_stream,
+                txholder,
+                epoch_dir,
+                &registery,
+                repo,
+                &vtxo_set,
+                self.anchor.bitcoin_height(),
+                self.base_ops_price,
+            )
+            .await
+            .map_err(|e| SessionPayloadEntriesDecodeError::EntryCPEDecodeError(index, e))?;
+
+            // An account cannot carry a second entry at the same nonce.
+            if !account_keys.insert(entry.account().key()) {
+                return Err(SessionPayloadEntriesDecodeError::DuplicateAccount(index));
+            }
+
+            entries.push(entry);
+        }
+
+        // Only the zero padding may remain.
+        if bit_stream.len() >= 8 || bit_stream.any(|bit| bit) {
+            return Err(SessionPayloadEntriesDecodeError::TrailingBits);
+        }
+
+        // The accounts signed their entries through the session signature.
+        if !self.verify_session_sig(validation_ctx.chain(), &entries, quorum_key) {
+            return Err(SessionPayloadEntriesDecodeError::InvalidSessionSignature);
+        }
+
+        // Validate the entries in order.
+        for (index, entry) in entries.iter().enumerate() {
+            entry
+                .validate(validation_ctx)
+                .await
+                .map_err(|e| SessionPayloadEntriesDecodeError::EntryValidationError(index, e))?;
+
+            // The entry spends are pending against the next entries.
+            validation_ctx.add_pending_spends(&entry.max_spends());
+        }
+
+        // Return the entries.
+        Ok(entries)
+    }
+
+    /// Serializes the payload into bytes.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut bytes = Vec::<u8>::with_capacity(SESSION_PAYLOAD_HEADER_LEN + self.entries.len());
+        bytes.push(self.version);
+        bytes.extend(self.session_id);
+        bytes.extend(self.operator_sig);
+        bytes.extend(self.session_sig);
+        bytes.extend(self.base_ops_price.to_be_bytes());
+        bytes.extend(self.anchor.bitcoin_height().to_be_bytes());
+        bytes.extend(self.anchor.timestamp().to_be_bytes());
+        bytes.extend(self.entry_count.to_be_bytes());
+        bytes.extend(&self.entries);
+        bytes
+    }
+
+    /// Deserializes the payload from bytes.
+    pub fn deserialize(bytes: &[u8]) -> Result<SessionPayload, SessionPayloadDeserializeError> {
+        // Check the version first, as the layout depends on it.
+        let version = *bytes
+            .first()
+            .ok_or(SessionPayloadDeserializeError::EmptyPayload)?;
+        if version != SESSION_PAYLOAD_VERSION {
+            return Err(SessionPayloadDeserializeError::UnsupportedVersion(version));
+        }
+
+        // Check the header length.
+        if bytes.len() < SESSION_PAYLOAD_HEADER_LEN {
+            return Err(SessionPayloadDeserializeError::HeaderLengthError(
+                bytes.len(),
+            ));
+        }
+
+        let session_id: [u8; 32] = bytes[1..33]
+            .try_into()
+            .map_err(|_| SessionPayloadDeserializeError::HeaderLengthError(bytes.len()))?;
+        let operator_sig: [u8; 64] = bytes[33..97]
+            .try_into()
+            .map_err(|_| SessionPayloadDeserializeError::HeaderLengthError(bytes.len()))?;
+        let session_sig: [u8; 64] = bytes[97..161]
+            .try_into()
+            .map_err(|_| SessionPayloadDeserializeError::HeaderLengthError(bytes.len()))?;
+        let base_ops_price = u32::from_be_bytes(
+            bytes[161..165]
+                .try_into()
+                .map_err(|_| SessionPayloadDeserializeError::HeaderLengthError(bytes.len()))?,
+        );
+        let bitcoin_height = u32::from_be_bytes(
+            bytes[165..169]
+                .try_into()
+                .map_err(|_| SessionPayloadDeserializeError::HeaderLengthError(bytes.len()))?,
+        );
+        let timestamp = u32::from_be_bytes(
+            bytes[169..173]
+                .try_into()
+                .map_err(|_| SessionPayloadDeserializeError::HeaderLengthError(bytes.len()))?,
+        );
+        let entry_count = u32::from_be_bytes(
+            bytes[173..177]
+                .try_into()
+                .map_err(|_| SessionPayloadDeserializeError::HeaderLengthError(bytes.len()))?,
+        );
+        let entries = bytes[SESSION_PAYLOAD_HEADER_LEN..].to_vec();
+
+        // Construct the payload.
+        let payload = SessionPayload {
+            version,
+            session_id,
+            operator_sig,
+            session_sig,
+            base_ops_price,
+            anchor: SessionAnchor::new(bitcoin_height, timestamp),
+            entry_count,
+            entries,
+        };
+
+        // Return the payload.
+        Ok(payload)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::constructive::entry::{
+    codec::cpe::{
+        decode::decode_error::EntryCPEDecodingError, encode::encode_error::EntryCPEEncodeError,
+    },
+    validation::validation_error::EntryValidationError,
+};
+
+/// The error type for constructing a `SessionPayload` from entries.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionPayloadConstructionError {
+    EntryCountOverflow(usize),
+    EntryCPEEncodeError(usize, EntryCPEEncodeError),
+    SessionSigMissing,
+}
+
+/// The error type for deserializing a `SessionPayload` from bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionPayloadDeserializeError {
+    EmptyPayload,
+    UnsupportedVersion(u8),
+    HeaderLengthError(usize),
+}
+
+/// The error type for decoding the entries of a `SessionPayload`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionPayloadEntriesDecodeError {
+    EntryCPEDecodeError(usize, EntryCPEDecodingError),
+    EntryValidationError(usize, EntryValidationError),
+    DuplicateAccount(usize),
+    TrailingBits,
+    InvalidSessionSignature,
+}
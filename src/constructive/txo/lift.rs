@@ -1,4 +1,8 @@
 use crate::constructive::taproot::{TapLeaf, TapRoot, P2TR};
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    writer::CanonicalWriter,
+};
 use crate::transmutative::codec::csv::{CSVEncode, CSVFlag};
 use crate::transmutative::musig::keyagg::MusigKeyAggCtx;
 use crate::transmutative::secp::into::IntoScalar;
@@ -34,10 +38,7 @@ impl Lift {
     }
 
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
     }
 
     pub fn account_key(&self) -> Point {
@@ -118,6 +119,28 @@ impl Lift {
     }
 }
 
+impl Canonical for Lift {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.write_point(&self.account);
+        bytes.write_point(&self.operator);
+        bytes.write_option(&self.outpoint, |bytes, outpoint| {
+            bytes.write_outpoint(outpoint)
+        });
+        bytes.write_option(&self.value, |bytes, value| {
+            bytes.extend(value.to_le_bytes())
+        });
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Lift, CanonicalDecodeError> {
+        Ok(Lift {
+            account: reader.read_point()?,
+            operator: reader.read_point()?,
+            outpoint: reader.read_option(CanonicalReader::read_outpoint)?,
+            value: reader.read_option(CanonicalReader::read_u64)?,
+        })
+    }
+}
+
 impl P2TR for Lift {
     fn taproot(&self) -> Option<TapRoot> {
         //// Inner Key: (Self + Operator)
@@ -1,8 +1,12 @@
 use crate::constructive::taproot::{TapLeaf, TapRoot, P2TR};
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    writer::CanonicalWriter,
+};
 use crate::transmutative::codec::csv::{CSVEncode, CSVFlag};
 use crate::transmutative::musig::keyagg::MusigKeyAggCtx;
 use crate::transmutative::secp::into::IntoScalar;
-use bitcoin::OutPoint;
+use bitcoin::{OutPoint, TxIn};
 use secp::Point;
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +16,9 @@ type Bytes = Vec<u8>;
 // a VTXO expires in 90 days, so this is %50 of the total lifespan.
 const RECHARGE_HEIGHT_OFFSET: u32 = 144 * 45;
 
+// A VTXO expires 90 days after it is created, as per the `CSVFlag::CSVThreeMonths` sweep path.
+const EXPIRY_HEIGHT_OFFSET: u32 = 144 * 90;
+
 /// VTXO (Virtual Transaction Output) is a Bitcoin transaction output that is held by a user, but is not confirmed on the chain.
 ///
 /// See: https://ark-protocol.org/intro/vtxos/index.html
@@ -45,12 +52,9 @@ impl VTXO {
         }
     }
 
-    /// Serializes the VTXO into a vector of bytes.
+    /// Serializes the VTXO into a vector of bytes, in the canonical encoding.
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
     }
 
     /// Returns the account key of the VTXO.
@@ -126,21 +130,83 @@ impl VTXO {
         // Return the result.
         Some(is_rechargeable)
     }
-}
 
-impl P2TR for VTXO {
-    fn taproot(&self) -> Option<TapRoot> {
-        //// Inner Key: (Self + Operator)
-        let agg_inner_key = self.agg_inner_key()?;
+    /// Returns whether the VTXO has passed its three-month expiry, after which the operator sweeps it.
+    pub fn is_expired(&self, current_bitcoin_height: u32) -> Option<bool> {
+        // Get the Bitcoin block height at which the VTXO was confirmed.
+        let at_bitcoin_height = self.at_bitcoin_height()?;
 
-        //// Sweep Path: (Operator after 3 months)
+        // Calculate the height at which the VTXO expires.
+        let expiry_height = at_bitcoin_height.checked_add(EXPIRY_HEIGHT_OFFSET)?;
+
+        // Return the result.
+        Some(current_bitcoin_height >= expiry_height)
+    }
+
+    /// Returns the sweep path leaf of the VTXO, spendable after three months.
+    fn sweep_path(&self) -> TapLeaf {
         let mut sweep_path_script = Vec::<u8>::new();
         sweep_path_script.extend(Bytes::csv_script(CSVFlag::CSVThreeMonths)); // Relative Timelock
         sweep_path_script.push(0x20); // OP_PUSHDATA_32
         sweep_path_script.extend(self.account_key().serialize_xonly()); // Account Key 32-bytes
         sweep_path_script.push(0xac); // OP_CHECKSIG
 
-        let sweep_path = TapLeaf::new(sweep_path_script);
+        TapLeaf::new(sweep_path_script)
+    }
+
+    /// Returns whether the given transaction input sweeps the VTXO,
+    /// that is, spends its outpoint through the sweep path.
+    pub fn is_swept_by(&self, txn_input: &TxIn) -> bool {
+        // The input must spend the VTXO's outpoint.
+        if self.outpoint() != Some(txn_input.previous_output) {
+            return false;
+        }
+
+        // And it must do so through the sweep path.
+        match txn_input.witness.tapscript() {
+            Some(tap_script) => tap_script.as_bytes() == self.sweep_path().tap_script().as_slice(),
+            None => false,
+        }
+    }
+}
+
+impl Canonical for VTXO {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.write_point(&self.account);
+        bytes.write_point(&self.operator);
+        bytes.write_option(&self.outpoint, |bytes, outpoint| {
+            bytes.write_outpoint(outpoint)
+        });
+        bytes.write_option(&self.value, |bytes, value| {
+            bytes.extend(value.to_le_bytes())
+        });
+        bytes.write_option(&self.at_rollup_height, |bytes, height| {
+            bytes.extend(height.to_le_bytes())
+        });
+        bytes.write_option(&self.at_bitcoin_height, |bytes, height| {
+            bytes.extend(height.to_le_bytes())
+        });
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<VTXO, CanonicalDecodeError> {
+        Ok(VTXO {
+            account: reader.read_point()?,
+            operator: reader.read_point()?,
+            outpoint: reader.read_option(CanonicalReader::read_outpoint)?,
+            value: reader.read_option(CanonicalReader::read_u64)?,
+            at_rollup_height: reader.read_option(CanonicalReader::read_u32)?,
+            at_bitcoin_height: reader.read_option(CanonicalReader::read_u32)?,
+        })
+    }
+}
+
+impl P2TR for VTXO {
+    fn taproot(&self) -> Option<TapRoot> {
+        //// Inner Key: (Self + Operator)
+        let agg_inner_key = self.agg_inner_key()?;
+
+        //// Sweep Path: (Operator after 3 months)
+        let sweep_path = self.sweep_path();
 
         Some(TapRoot::key_and_script_path_single(
             agg_inner_key,
@@ -1,5 +1,5 @@
 use crate::executive::exec::accountant::{
-    accountant_error::{InsertAllocError, InsertPaymentError, PayListError},
+    accountant_error::{InsertAllocError, InsertFeeError, InsertPaymentError, PayListError},
     accountant_record::AccountantRecord,
 };
 use std::collections::HashMap;
@@ -10,11 +10,16 @@ type AccountKey = [u8; 32];
 /// The type of payable allocation amount.
 type PayableAllocAmount = u32;
 
+/// The type of fee amount.
+type FeeAmount = u64;
+
 /// A keeper for payments.
 pub struct Accountant {
     allocs: HashMap<AccountKey, PayableAllocAmount>,
     records: Vec<AccountantRecord>,
-    records_backup: Vec<AccountantRecord>,
+    savepoints: Vec<usize>,
+    fees: HashMap<AccountKey, FeeAmount>,
+    fee_reserves: HashMap<AccountKey, FeeAmount>,
 }
 
 impl Accountant {
@@ -23,13 +28,34 @@ impl Accountant {
         Self {
             allocs: HashMap::<AccountKey, PayableAllocAmount>::new(),
             records: Vec::new(),
-            records_backup: Vec::new(),
+            savepoints: Vec::new(),
+            fees: HashMap::<AccountKey, FeeAmount>::new(),
+            fee_reserves: HashMap::<AccountKey, FeeAmount>::new(),
+        }
+    }
+
+    /// Pushes a savepoint at the current records length.
+    ///
+    /// Records are append-only, so a savepoint only needs to remember the length.
+    pub fn push_savepoint(&mut self) {
+        self.savepoints.push(self.records.len());
+    }
+
+    /// Commits the innermost savepoint, keeping the records inserted since it was pushed.
+    pub fn commit_savepoint(&mut self) {
+        self.savepoints.pop();
+    }
+
+    /// Reverts the records inserted since the innermost savepoint was pushed, and pops it.
+    pub fn revert_to_savepoint(&mut self) {
+        if let Some(records_len) = self.savepoints.pop() {
+            self.records.truncate(records_len);
         }
     }
 
-    /// Backups the checks.
-    pub fn backup(&mut self) {
-        self.records_backup = self.records.clone();
+    /// Returns the number of open savepoints.
+    pub fn savepoints_len(&self) -> usize {
+        self.savepoints.len()
     }
 
     /// Inserts an allocation. No overlapping allocations are allowed.
@@ -81,15 +107,47 @@ impl Accountant {
         Ok(())
     }
 
-    /// Restores the checks by swapping the checks and backup vectors.
-    pub fn rollback_last(&mut self) {
-        self.records = self.records_backup.clone();
+    /// Reserves the most fees a call can be charged from the key paying for its ops.
+    ///
+    /// The payer funds the reservation whether or not the call passes, so reservations are not
+    /// subject to savepoints. Whatever is not charged is owed back to the payer.
+    pub fn reserve_fees(&mut self, payer_key: [u8; 32], amount: u64) -> Result<(), InsertFeeError> {
+        let fee_reserve = self.fee_reserves.entry(payer_key).or_insert(0);
+        *fee_reserve = fee_reserve
+            .checked_add(amount)
+            .ok_or(InsertFeeError::FeeOverflowError)?;
+
+        Ok(())
+    }
+
+    /// Releases a fee reservation made for a call rejected before it is executed.
+    pub fn release_fees(&mut self, payer_key: [u8; 32], amount: u64) {
+        if let Some(fee_reserve) = self.fee_reserves.get_mut(&payer_key) {
+            *fee_reserve = fee_reserve.saturating_sub(amount);
+            if *fee_reserve == 0 {
+                self.fee_reserves.remove(&payer_key);
+            }
+        }
+    }
+
+    /// Charges a fee to the key paying for the ops of a call.
+    ///
+    /// Fees are charged whether or not the call passes, so they are not subject to savepoints.
+    pub fn insert_fee(&mut self, payer_key: [u8; 32], amount: u64) -> Result<(), InsertFeeError> {
+        let fee = self.fees.entry(payer_key).or_insert(0);
+        *fee = fee
+            .checked_add(amount)
+            .ok_or(InsertFeeError::FeeOverflowError)?;
+
+        Ok(())
     }
 
-    /// Reverses all checks by emptying the checks and backup vectors.
+    /// Reverses all checks and fees by emptying the records, savepoints, fees and fee reservations.
     pub fn rollback_all(&mut self) {
         self.records = Vec::<AccountantRecord>::new();
-        self.records_backup = Vec::<AccountantRecord>::new();
+        self.savepoints = Vec::<usize>::new();
+        self.fees = HashMap::<AccountKey, FeeAmount>::new();
+        self.fee_reserves = HashMap::<AccountKey, FeeAmount>::new();
     }
 
     /// Returns list of account and amount pairs who are allocated money.
@@ -97,48 +155,54 @@ impl Accountant {
         self.allocs.clone()
     }
 
+    /// Returns list of payer and amount pairs who are charged fees.
+    pub fn fee_list(&self) -> HashMap<[u8; 32], u64> {
+        self.fees.clone()
+    }
+
+    /// Returns list of account and amount pairs who fund the session.
+    ///
+    /// Each account funds its allocation and its fee reservation, which are debited from its
+    /// balance when the pay list is settled.
+    pub fn debit_list(&self) -> Result<HashMap<[u8; 32], u64>, PayListError> {
+        let mut debit_list = HashMap::<[u8; 32], u64>::new();
+
+        // Iterate allocs, for each account collect their debit.
+        for (key, amount) in self.allocs.iter() {
+            *debit_list.entry(*key).or_insert(0) += *amount as u64;
+        }
+
+        // Iterate fee reservations, for each payer collect their debit.
+        for (key, amount) in self.fee_reserves.iter() {
+            let debit = debit_list.entry(*key).or_insert(0);
+            *debit = debit
+                .checked_add(*amount)
+                .ok_or(PayListError::BalanceOverflowError)?;
+        }
+
+        Ok(debit_list)
+    }
+
     /// Returns list of account and amount pairs who are owed money.
-    pub fn pay_list(&self) -> Result<HashMap<[u8; 32], u32>, PayListError> {
+    ///
+    /// Each account is owed its allocation and its fee reservation, minus what it paid, plus what it
+    /// was paid, minus the fees it was charged.
+    pub fn pay_list(&self) -> Result<HashMap<[u8; 32], u64>, PayListError> {
         // Create a new HashMap to store sum of payments.
-        let mut pay_list_ = HashMap::<[u8; 32], i32>::new();
+        let mut pay_list_ = HashMap::<[u8; 32], i128>::new();
 
         // Iterate allocs, for each account collect their change.
         for (key, amount) in self.allocs.iter() {
-            match pay_list_.get(key) {
-                Some(balance) => {
-                    pay_list_.insert(*key, balance + *amount as i32);
-                }
-                None => {
-                    pay_list_.insert(*key, *amount as i32);
-                }
-            }
+            *pay_list_.entry(*key).or_insert(0) += *amount as i128;
         }
 
         // Iterate checks, for each account collect their change.
         for record in self.records.iter() {
-            let from_key = record.from();
-            let to_key = record.to();
-            let amount = record.amount();
-
             // Deduct from payers.
-            match pay_list_.get(&from_key) {
-                Some(balance) => {
-                    pay_list_.insert(from_key, balance - amount as i32);
-                }
-                None => {
-                    pay_list_.insert(from_key, -(amount as i32));
-                }
-            }
+            *pay_list_.entry(record.from()).or_insert(0) -= record.amount() as i128;
 
             // Add to payees.
-            match pay_list_.get(&to_key) {
-                Some(balance) => {
-                    pay_list_.insert(to_key, balance + amount as i32);
-                }
-                None => {
-                    pay_list_.insert(to_key, amount as i32);
-                }
-            }
+            *pay_list_.entry(record.to()).or_insert(0) += record.amount() as i128;
         }
 
         // If at least one negative balance is encountered, return an inflation error.
@@ -146,14 +210,30 @@ impl Accountant {
             return Err(PayListError::InflationEncounteredError);
         }
 
+        // Iterate fee reservations, for each payer collect their change.
+        for (key, amount) in self.fee_reserves.iter() {
+            *pay_list_.entry(*key).or_insert(0) += *amount as i128;
+        }
+
+        // Iterate fees, for each payer deduct the fees charged.
+        for (key, amount) in self.fees.iter() {
+            *pay_list_.entry(*key).or_insert(0) -= *amount as i128;
+        }
+
+        // If at least one payer is charged more than it reserved, return a fee error.
+        if pay_list_.values().any(|balance| *balance < 0) {
+            return Err(PayListError::FeeReserveExceededError);
+        }
+
         // Prune the zero balances.
         pay_list_.retain(|_, balance| *balance != 0);
 
-        // Convert the balances to u32.
-        let pay_list = pay_list_
-            .iter()
-            .map(|(key, balance)| (*key, *balance as u32))
-            .collect();
+        // Convert the balances to u64.
+        let mut pay_list = HashMap::<[u8; 32], u64>::new();
+        for (key, balance) in pay_list_ {
+            let balance = u64::try_from(balance).map_err(|_| PayListError::BalanceOverflowError)?;
+            pay_list.insert(key, balance);
+        }
 
         // Return the final paid list.
         Ok(pay_list)
@@ -14,9 +14,20 @@ pub enum InsertPaymentError {
     AllocationExceededError,
 }
 
+/// Error type for inserting fees.
+#[derive(Debug, Clone)]
+pub enum InsertFeeError {
+    /// The fee overflows.
+    FeeOverflowError,
+}
+
 /// Error type for summing payments.
 #[derive(Debug, Clone)]
 pub enum PayListError {
     /// The payment is not valid.
     InflationEncounteredError,
+    /// A payer is charged more fees than it reserved.
+    FeeReserveExceededError,
+    /// A balance exceeds the u64 range.
+    BalanceOverflowError,
 }
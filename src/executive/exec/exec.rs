@@ -1,8 +1,9 @@
-use super::{caller::Caller, exec_error::ExecutionError};
+use super::{caller::Caller, exec_error::ExecutionError, trace::ExecTrace};
 use crate::{
     executive::{
-        exec::accountant::accountant::Accountant,
+        exec::{accountant::accountant::Accountant, transient::transient::TransientStorage},
         opcode::{
+            opcode::Opcode,
             opcodes::{
                 altstack::{op_fromaltstack::OP_FROMALTSTACK, op_toaltstack::OP_TOALTSTACK},
                 arithmetic::{
@@ -16,6 +17,7 @@ use crate::{
                     op_numequalverify::OP_NUMEQUALVERIFY, op_numnotequal::OP_NUMNOTEQUAL,
                     op_rshift::OP_RSHIFT, op_sub::OP_SUB, op_within::OP_WITHIN,
                 },
+                beacon::op_beacon::OP_BEACON,
                 bitwise::{
                     op_and::OP_AND, op_equal::OP_EQUAL, op_equalverify::OP_EQUALVERIFY,
                     op_invert::OP_INVERT, op_or::OP_OR, op_reverse::OP_REVERSE, op_xor::OP_XOR,
@@ -27,9 +29,11 @@ use crate::{
                 },
                 digest::{
                     op_blake2bvar::OP_BLAKE2BVAR, op_blake2svar::OP_BLAKE2SVAR,
-                    op_hash160::OP_HASH160, op_hash256::OP_HASH256, op_ripemd160::OP_RIPEMD160,
-                    op_sha1::OP_SHA1, op_sha256::OP_SHA256, op_taggedhash::OP_TAGGEDHASH,
+                    op_hash160::OP_HASH160, op_hash256::OP_HASH256, op_keccak256::OP_KECCAK256,
+                    op_merkleverify::OP_MERKLEVERIFY, op_ripemd160::OP_RIPEMD160, op_sha1::OP_SHA1,
+                    op_sha256::OP_SHA256, op_taggedhash::OP_TAGGEDHASH,
                 },
+                factory::op_deploy::OP_DEPLOY,
                 flow::{
                     op_else::OP_ELSE, op_endif::OP_ENDIF, op_fail::OP_FAIL, op_if::OP_IF,
                     op_nop::OP_NOP, op_notif::OP_NOTIF, op_returnall::OP_RETURNALL,
@@ -55,13 +59,15 @@ use crate::{
                 },
                 signature::{
                     op_checkblssig::OP_CHECKBLSSIG, op_checkblssigagg::OP_CHECKBLSSIGAGG,
-                    op_checkschnorrsig::OP_CHECKSCHNORRSIG,
+                    op_checkecdsasig::OP_CHECKECDSASIG, op_checkschnorrsig::OP_CHECKSCHNORRSIG,
                     op_checkschnorrsigbip340::OP_CHECKSCHNORRSIGBIP340,
+                    op_ecdsarecover::OP_ECDSARECOVER,
                 },
                 splice::{
                     op_cat::OP_CAT, op_left::OP_LEFT, op_right::OP_RIGHT, op_size::OP_SIZE,
                     op_split::OP_SPLIT,
                 },
+                spv::op_checkbtcinclusion::OP_CHECKBTCINCLUSION,
                 stack::{
                     op_2drop::OP_2DROP, op_2dup::OP_2DUP, op_2over::OP_2OVER, op_2rot::OP_2ROT,
                     op_2swap::OP_2SWAP, op_3dup::OP_3DUP, op_depth::OP_DEPTH, op_drop::OP_DROP,
@@ -70,13 +76,17 @@ use crate::{
                     op_tuck::OP_TUCK,
                 },
                 storage::{op_sread::OP_SREAD, op_swrite::OP_SWRITE},
+                transient::{op_tread::OP_TREAD, op_twrite::OP_TWRITE},
             },
-            opcode::Opcode,
+            ops::OpsSchedule,
         },
         program::method::method_type::MethodType,
         stack::{stack_holder::StackHolder, stack_item::StackItem},
     },
-    inscriptive::{repo::repo::PROGRAMS_REPO, state::state_holder::STATE_HOLDER},
+    inscriptive::{
+        beacon::dir::BEACON_DIRECTORY, repo::repo::PROGRAMS_REPO, rollup::dir::ROLLUP_DIRECTORY,
+        state::state_holder::STATE_HOLDER,
+    },
 };
 
 /// The type of the external ops counter.
@@ -114,9 +124,24 @@ pub async fn execute(
     state_holder: &STATE_HOLDER,
     // The programs repo.
     programs_repo: &PROGRAMS_REPO,
+    // The rollup directory, for Bitcoin block headers.
+    rollup_dir: &ROLLUP_DIRECTORY,
+    // The Bitcoin height the execution is anchored to.
+    bitcoin_height: u64,
+    // The randomness beacon directory.
+    beacon_dir: &BEACON_DIRECTORY,
+    // The session height the execution belongs to.
+    session_height: u64,
     // Accountant.
     accountant: &mut Accountant,
+    // Transient storage.
+    transient_storage: &mut TransientStorage,
+    // The execution trace, used to locate and charge failed calls.
+    trace: &mut ExecTrace,
 ) -> Result<(Vec<StackItem>, InternalOpsCounter, ExternalOpsCounter), ExecutionError> {
+    // Enter the call frame.
+    trace.enter(contract_id, method_index);
+
     // Get the program by contract id.
     let program = {
         let _programs_repo = programs_repo.lock().await;
@@ -139,8 +164,11 @@ pub async fn execute(
         // Internal methods are *valid* if its originated from the contract itself.
         // And *invalid* if originated from an external source.
         MethodType::Internal => {
-            // Return an error if the call is not internal or the caller is an account.
-            if !internal || caller.is_account() {
+            // Return an error if the call is not internal. Only OP_CALL makes internal calls, and
+            // it calls back into the same contract, so this alone proves the call originated from
+            // the contract itself. The caller is carried over unchanged through OP_CALL, so it is
+            // an account whenever an account called the contract, and must not be checked.
+            if !internal {
                 return Err(ExecutionError::InvalidInternalCallError);
             }
         }
@@ -203,6 +231,7 @@ pub async fn execute(
         ops_price,
         internal_ops_counter,
         external_ops_counter,
+        OpsSchedule::at_height(session_height),
         arg_values,
     ) {
         Ok(stack_holder) => stack_holder,
@@ -210,7 +239,16 @@ pub async fn execute(
     };
 
     // Execute the program method.
-    for opcode in program_method.script().iter() {
+    for (pc, opcode) in program_method.script().iter().enumerate() {
+        // Step the trace, so that a failure can be located and the call still charged.
+        trace.step(
+            pc as u32,
+            opcode,
+            stack_holder.ops_schedule().base_ops(opcode),
+            stack_holder.internal_ops_counter(),
+            stack_holder.external_ops_counter(),
+        );
+
         match opcode {
             // Data push opcodes.
             Opcode::OP_FALSE(OP_FALSE) => {
@@ -626,6 +664,14 @@ pub async fn execute(
                 OP_BLAKE2SVAR::execute(&mut stack_holder)
                     .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
             }
+            Opcode::OP_KECCAK256(OP_KECCAK256) => {
+                OP_KECCAK256::execute(&mut stack_holder)
+                    .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
+            }
+            Opcode::OP_MERKLEVERIFY(OP_MERKLEVERIFY) => {
+                OP_MERKLEVERIFY::execute(&mut stack_holder)
+                    .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
+            }
             // Secp opcodes.
             Opcode::OP_SECPSCALARADD(OP_SECPSCALARADD) => {
                 OP_SECPSCALARADD::execute(&mut stack_holder)
@@ -672,6 +718,14 @@ pub async fn execute(
                 OP_CHECKBLSSIGAGG::execute(&mut stack_holder)
                     .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
             }
+            Opcode::OP_CHECKECDSASIG(OP_CHECKECDSASIG) => {
+                OP_CHECKECDSASIG::execute(&mut stack_holder)
+                    .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
+            }
+            Opcode::OP_ECDSARECOVER(OP_ECDSARECOVER) => {
+                OP_ECDSARECOVER::execute(&mut stack_holder)
+                    .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
+            }
             // Call info opcodes.
             Opcode::OP_CALLER(OP_CALLER) => {
                 OP_CALLER::execute(&mut stack_holder)
@@ -705,8 +759,11 @@ pub async fn execute(
                     OP_CALL::execute(&mut stack_holder)
                         .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
 
+                // Open a savepoint frame for the call.
+                open_call_frame(state_holder, programs_repo, accountant, transient_storage).await;
+
                 // Call the internal contract.
-                return Box::pin(execute(
+                let call_result = Box::pin(execute(
                     true,        // Internal call.
                     caller,      // Caller remains unchanged for internal calls.
                     contract_id, // Contract ID is the same as the current contract id.
@@ -719,9 +776,28 @@ pub async fn execute(
                     stack_holder.external_ops_counter(), // Remainder of the external ops counter passed to the next call.
                     state_holder,
                     programs_repo,
+                    rollup_dir,
+                    bitcoin_height,
+                    beacon_dir,
+                    session_height,
                     accountant,
+                    transient_storage,
+                    trace,
                 ))
                 .await;
+
+                // Commit the call frame on success, or revert it on failure.
+                close_call_frame(
+                    state_holder,
+                    programs_repo,
+                    accountant,
+                    transient_storage,
+                    call_result.is_ok(),
+                )
+                .await;
+
+                // Resume the caller with the call result, or a failure flag if the call failed.
+                resume_caller(&mut stack_holder, call_result, ops_budget, trace)?;
             }
 
             Opcode::OP_CALLEXT(_) => {
@@ -743,8 +819,11 @@ pub async fn execute(
                 // The caller for the next call is the current contract id.
                 let caller = Caller::new_contract(contract_id);
 
+                // Open a savepoint frame for the call.
+                open_call_frame(state_holder, programs_repo, accountant, transient_storage).await;
+
                 // Call the external contract.
-                return Box::pin(execute(
+                let call_result = Box::pin(execute(
                     false, // External call.
                     caller,
                     contract_id_to_be_called,
@@ -757,9 +836,28 @@ pub async fn execute(
                     stack_holder.external_ops_counter(), // Remainder of the external ops counter passed to the next call.
                     state_holder,
                     programs_repo,
+                    rollup_dir,
+                    bitcoin_height,
+                    beacon_dir,
+                    session_height,
                     accountant,
+                    transient_storage,
+                    trace,
                 ))
                 .await;
+
+                // Commit the call frame on success, or revert it on failure.
+                close_call_frame(
+                    state_holder,
+                    programs_repo,
+                    accountant,
+                    transient_storage,
+                    call_result.is_ok(),
+                )
+                .await;
+
+                // Resume the caller with the call result, or a failure flag if the call failed.
+                resume_caller(&mut stack_holder, call_result, ops_budget, trace)?;
             }
             // Payment opcodes.
             Opcode::OP_PAYABLEALLOC(OP_PAYABLEALLOC) => {
@@ -802,6 +900,33 @@ pub async fn execute(
                     .await
                     .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
             }
+            // Transient storage opcodes.
+            Opcode::OP_TWRITE(OP_TWRITE) => {
+                OP_TWRITE::execute(&mut stack_holder, transient_storage)
+                    .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
+            }
+            Opcode::OP_TREAD(OP_TREAD) => {
+                OP_TREAD::execute(&mut stack_holder, transient_storage)
+                    .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
+            }
+            // Factory opcodes.
+            Opcode::OP_DEPLOY(OP_DEPLOY) => {
+                OP_DEPLOY::execute(&mut stack_holder, programs_repo)
+                    .await
+                    .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
+            }
+            // Beacon opcodes.
+            Opcode::OP_BEACON(OP_BEACON) => {
+                OP_BEACON::execute(&mut stack_holder, beacon_dir, session_height)
+                    .await
+                    .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
+            }
+            // SPV opcodes.
+            Opcode::OP_CHECKBTCINCLUSION(OP_CHECKBTCINCLUSION) => {
+                OP_CHECKBTCINCLUSION::execute(&mut stack_holder, rollup_dir, bitcoin_height)
+                    .await
+                    .map_err(|error| ExecutionError::OpcodeExecutionError(error))?;
+            }
             _ => {
                 return Err(ExecutionError::ReservedOpcodeEncounteredError);
             }
@@ -810,3 +935,107 @@ pub async fn execute(
 
     return Err(ExecutionError::MethodNotReturnedAnyItemsError);
 }
+
+/// Resumes the caller after a nested call.
+///
+/// On success, the items returned by the callee are pushed to the caller's stack in the order they
+/// were on the callee's stack, followed by a true flag. On failure, the frame has already been
+/// reverted, so only a false flag is pushed. Either way, the caller is charged for the ops the
+/// callee consumed.
+fn resume_caller(
+    stack_holder: &mut StackHolder,
+    call_result: Result<(Vec<StackItem>, InternalOpsCounter, ExternalOpsCounter), ExecutionError>,
+    ops_budget: u32,
+    trace: &mut ExecTrace,
+) -> Result<(), ExecutionError> {
+    // Exit the callee frame.
+    trace.exit();
+
+    // Get the return items and the internal ops counter the callee ended at.
+    let (return_items, callee_ops_counter, success) = match call_result {
+        Ok((return_items, internal_ops_counter, _)) => (return_items, internal_ops_counter, true),
+        Err(_) => (vec![], trace.ops_charged(ops_budget), false),
+    };
+
+    // Charge the caller for the ops consumed by the callee.
+    let callee_ops = callee_ops_counter.saturating_sub(stack_holder.internal_ops_counter());
+    stack_holder
+        .increment_ops(callee_ops)
+        .map_err(ExecutionError::OpcodeExecutionError)?;
+
+    // Push the return items to the stack. They are returned top item first, so they are pushed
+    // in reverse to keep the callee's top item on top.
+    for item in return_items.into_iter().rev() {
+        stack_holder
+            .push(item)
+            .map_err(ExecutionError::OpcodeExecutionError)?;
+    }
+
+    // Push the success flag to the stack.
+    let flag = match success {
+        true => StackItem::true_item(),
+        false => StackItem::false_item(),
+    };
+
+    stack_holder
+        .push(flag)
+        .map_err(ExecutionError::OpcodeExecutionError)
+}
+
+/// Opens a savepoint frame in the state holder, the programs repo, the accountant and the
+/// transient storage for a nested call.
+async fn open_call_frame(
+    state_holder: &STATE_HOLDER,
+    programs_repo: &PROGRAMS_REPO,
+    accountant: &mut Accountant,
+    transient_storage: &mut TransientStorage,
+) {
+    {
+        let mut _state_holder = state_holder.lock().await;
+        _state_holder.push_savepoint();
+    }
+
+    {
+        let mut _programs_repo = programs_repo.lock().await;
+        _programs_repo.push_savepoint();
+    }
+
+    accountant.push_savepoint();
+    transient_storage.push_savepoint();
+}
+
+/// Closes the innermost savepoint frame, committing it on success and reverting it otherwise.
+async fn close_call_frame(
+    state_holder: &STATE_HOLDER,
+    programs_repo: &PROGRAMS_REPO,
+    accountant: &mut Accountant,
+    transient_storage: &mut TransientStorage,
+    success: bool,
+) {
+    {
+        let mut _state_holder = state_holder.lock().await;
+        match success {
+            true => _state_holder.commit_savepoint(),
+            false => _state_holder.revert_to_savepoint(),
+        }
+    }
+
+    {
+        let mut _programs_repo = programs_repo.lock().await;
+        match success {
+            true => _programs_repo.commit_savepoint(),
+            false => _programs_repo.revert_to_savepoint(),
+        }
+    }
+
+    match success {
+        true => {
+            accountant.commit_savepoint();
+            transient_storage.commit_savepoint();
+        }
+        false => {
+            accountant.revert_to_savepoint();
+            transient_storage.revert_to_savepoint();
+        }
+    }
+}
@@ -1,23 +1,39 @@
 use crate::{
-    constructive::entry::combinator::combinators::call::call::Call,
+    constructive::entry::combinator::combinators::{
+        bundle::{bundle::Bundle, bundle_item::BundleItem},
+        call::call::Call,
+        r#move::r#move::Move,
+    },
     executive::{
         exec::{
-            accountant::{accountant::Accountant, accountant_error::PayListError},
+            accountant::{
+                accountant::Accountant,
+                accountant_error::{InsertFeeError, PayListError},
+            },
             caller::Caller,
             exec::execute,
-            exec_error::ExecutionError,
+            exec_error::{ExecCtxSaveError, ExecutionError},
+            profiler::OpsProfiler,
+            receipt::CallReceipt,
+            trace::ExecTrace,
+            transient::transient::TransientStorage,
         },
         stack::stack_item::StackItem,
     },
-    inscriptive::{repo::repo::PROGRAMS_REPO, state::state_holder::STATE_HOLDER},
+    inscriptive::{
+        beacon::dir::BEACON_DIRECTORY, registery::contract_registery::CONTRACT_REGISTERY,
+        repo::repo::PROGRAMS_REPO, rollup::dir::ROLLUP_DIRECTORY, set::vtxo_set::VTXO_SET,
+        state::state_holder::STATE_HOLDER,
+    },
 };
+use secp::Point;
 use std::{collections::HashMap, sync::Arc};
 
 /// The type of the ops spent.
 type OpsSpent = u32;
 
 /// The type of the fees spent.
-type FeesSpent = u32;
+type FeesSpent = u64;
 
 /// The context of a program execution.
 pub struct ExecCtx {
@@ -25,16 +41,30 @@ pub struct ExecCtx {
     state_holder: STATE_HOLDER,
     // The programs repo.
     programs_repo: PROGRAMS_REPO,
+    // The rollup directory.
+    rollup_dir: ROLLUP_DIRECTORY,
     // The accountant.
     accountant: Accountant,
+    // The transient storage.
+    transient_storage: TransientStorage,
     // External ops counter.
     external_ops_counter: u32,
     // The base ops price.
     base_ops_price: u32,
     // The timestamp.
     timestamp: u64,
-    // Passed calls.
-    passed_calls: Vec<(Call, OpsSpent, FeesSpent)>,
+    // The Bitcoin height the session is anchored to.
+    bitcoin_height: u64,
+    // The randomness beacon directory.
+    beacon_dir: BEACON_DIRECTORY,
+    // The session height.
+    session_height: u64,
+    // Executed calls, passed or failed, with their receipts.
+    calls: Vec<(Call, CallReceipt)>,
+    // Moves made by passed bundles.
+    moves: Vec<Move>,
+    // The ops profiler, if calls are profiled.
+    profiler: Option<OpsProfiler>,
 }
 
 impl ExecCtx {
@@ -42,22 +72,72 @@ impl ExecCtx {
     pub fn new(
         state_holder: &STATE_HOLDER,
         programs_repo: &PROGRAMS_REPO,
+        rollup_dir: &ROLLUP_DIRECTORY,
         base_ops_price: u32,
         timestamp: u64,
+        bitcoin_height: u64,
+        beacon_dir: &BEACON_DIRECTORY,
+        session_height: u64,
     ) -> Self {
         Self {
             state_holder: Arc::clone(state_holder),
             programs_repo: Arc::clone(programs_repo),
+            rollup_dir: Arc::clone(rollup_dir),
             accountant: Accountant::new(),
+            transient_storage: TransientStorage::new(),
             external_ops_counter: 0,
             base_ops_price,
             timestamp,
-            passed_calls: Vec::<(Call, OpsSpent, FeesSpent)>::new(),
+            bitcoin_height,
+            beacon_dir: Arc::clone(beacon_dir),
+            session_height,
+            calls: Vec::<(Call, CallReceipt)>::new(),
+            moves: Vec::<Move>::new(),
+            profiler: None,
         }
     }
 
     /// Executes and inserts a call.
+    ///
+    /// The ops price of the call is checked, and the most fees the call can be charged are
+    /// reserved from its payer first. A call rejected by these checks is neither reserved for nor
+    /// inserted. A call that fails during execution has its changes reverted, but is still inserted
+    /// with a receipt charging the ops it consumed up to and including the failing opcode. The
+    /// error is then returned.
     pub async fn exec_insert_call(&mut self, call: Call) -> Result<(), ExecutionError> {
+        // Check the ops price of the call.
+        self.check_ops_price(&call)?;
+
+        // Reserve the most fees the call can be charged from its payer.
+        self.reserve_fees(&call)?;
+
+        self.exec_insert_reserved_call(call).await
+    }
+
+    /// Checks the base ops price of a call against the session, and returns its total ops price.
+    fn check_ops_price(&self, call: &Call) -> Result<u32, ExecutionError> {
+        // Check if the base ops price is the same as the base ops price of the call.
+        if call.ops_price_base() != self.base_ops_price {
+            return Err(ExecutionError::BaseOpsPriceMismatchError);
+        }
+
+        // The ops price is the total ops price of the call (base + extra).
+        u32::try_from(call.ops_price_total()).map_err(|_| ExecutionError::OpsPriceOverflowError)
+    }
+
+    /// Reserves the most fees a call can be charged from its payer.
+    fn reserve_fees(&mut self, call: &Call) -> Result<(), ExecutionError> {
+        self.accountant
+            .reserve_fees(call.payer_key(), call.max_fees())
+            .map_err(ExecutionError::AccountantFeeInsertionError)
+    }
+
+    /// Executes and inserts a call whose fees are already reserved.
+    ///
+    /// A failed call is always receipted with its failure reason and the ops it reached. If its fees
+    /// cannot be charged, it is receipted with no fees spent, and the fee error is returned instead
+    /// of the call error, which remains in the receipt.
+    async fn exec_insert_reserved_call(&mut self, call: Call) -> Result<(), ExecutionError> {
         // This is an external call.
         let internal = false;
 
@@ -83,13 +163,8 @@ impl ExecCtx {
         // The ops budget is the ops budget of the call.
         let ops_budget = call.ops_budget();
 
-        // Check if the base ops price is the same as the base ops price of the call.
-        if call.ops_price_base() != self.base_ops_price {
-            return Err(ExecutionError::BaseOpsPriceMismatchError);
-        }
-
-        // The ops price is the total ops price of the call (base + extra).
-        let ops_price = call.ops_price_total();
+        // The ops price is checked before the fees are reserved.
+        let ops_price = self.check_ops_price(&call)?;
 
         // Internal ops counter is 0.
         let internal_ops_counter = 0;
@@ -100,20 +175,50 @@ impl ExecCtx {
         // State holder.
         let state_holder = &self.state_holder;
 
-        // Pre-execution state backup.
+        // Push a state savepoint for the execution.
         {
             let mut _state_holder = state_holder.lock().await;
-            _state_holder.pre_execution();
+            _state_holder.push_savepoint();
         }
 
         // Programs repo.
         let programs_repo = &self.programs_repo;
 
+        // Push a programs repo savepoint for the execution.
+        {
+            let mut _programs_repo = programs_repo.lock().await;
+            _programs_repo.push_savepoint();
+        }
+
+        // Rollup directory.
+        let rollup_dir = &self.rollup_dir;
+
+        // The Bitcoin height is the anchored height of the execution context.
+        let bitcoin_height = self.bitcoin_height;
+
+        // Beacon directory.
+        let beacon_dir = &self.beacon_dir;
+
+        // The session height is the session height of the execution context.
+        let session_height = self.session_height;
+
         // Accountant.
         let accountant = &mut self.accountant;
 
-        // Backup the accountant.
-        accountant.backup();
+        // Push an accountant savepoint for the execution.
+        accountant.push_savepoint();
+
+        // Transient storage.
+        let transient_storage = &mut self.transient_storage;
+
+        // Push a transient storage savepoint for the execution.
+        transient_storage.push_savepoint();
+
+        // The execution trace, to locate and charge the call in case it fails.
+        let mut trace = match self.profiler.take() {
+            Some(profiler) => ExecTrace::new_profiled(profiler),
+            None => ExecTrace::new(),
+        };
 
         // Execution.
         let exectuion_result = execute(
@@ -129,54 +234,246 @@ impl ExecCtx {
             external_ops_counter,
             state_holder,
             programs_repo,
+            rollup_dir,
+            bitcoin_height,
+            beacon_dir,
+            session_height,
             accountant,
+            transient_storage,
+            &mut trace,
         )
-        .await;
+        .await
+        .and_then(|(return_items, ops_spent, new_external_ops_counter)| {
+            // Stack must end with exactly one item and it must be true.
+            match return_items.len() {
+                // Stack must end with exactly one item.
+                1 => {
+                    // And that item must be exactly true.
+                    if !return_items[0].is_true() {
+                        return Err(ExecutionError::ReturnErrorFromStackError(
+                            return_items[0].clone(),
+                        ));
+                    }
+                }
+                // If other than one item, return an error.
+                _ => {
+                    return Err(ExecutionError::InvalidStackEndingError);
+                }
+            }
+
+            Ok((ops_spent, new_external_ops_counter))
+        })
+        .and_then(|(ops_spent, new_external_ops_counter)| {
+            // Charge the fees to the payer of the call.
+            let fees_spent = Self::charge_fees(accountant, &call, ops_spent)?;
+
+            Ok((ops_spent, new_external_ops_counter, fees_spent))
+        });
 
         match exectuion_result {
-            Ok((return_items, ops_spent, new_external_ops_counter)) => {
-                // Stack must end with exactly one item and it must be true.
-                match return_items.len() {
-                    // Stack must end with exactly one item.
-                    1 => {
-                        // And that item must be exactly true.
-                        if !return_items[0].is_true() {
-                            return Err(ExecutionError::ReturnErrorFromStackError(
-                                return_items[0].clone(),
-                            ));
-                        }
-                    }
-                    // If other than one item, return an error.
-                    _ => {
-                        return Err(ExecutionError::InvalidStackEndingError);
-                    }
+            Ok((ops_spent, new_external_ops_counter, fees_spent)) => {
+                // Commit the state savepoint.
+                {
+                    let mut _state_holder = state_holder.lock().await;
+                    _state_holder.commit_savepoint();
+                }
+
+                // Commit the programs repo savepoint.
+                {
+                    let mut _programs_repo = programs_repo.lock().await;
+                    _programs_repo.commit_savepoint();
                 }
 
-                let fees_spent = ops_spent * self.base_ops_price;
+                // Commit the accountant savepoint.
+                accountant.commit_savepoint();
+
+                // Commit the transient storage savepoint.
+                transient_storage.commit_savepoint();
 
                 // Update the external ops counter.
                 self.external_ops_counter = new_external_ops_counter;
 
+                // Hand the profiler back, if any.
+                self.profiler = trace.end_profiled(ops_spent, new_external_ops_counter);
+
                 // Insert the call.
-                self.passed_calls.push((call, ops_spent, fees_spent));
+                self.calls
+                    .push((call, CallReceipt::new_passed(ops_spent, fees_spent)));
 
                 // Return Ok.
                 Ok(())
             }
             Err(error) => {
-                // Rollback the state.
+                // Revert the state to the savepoint.
                 {
                     let mut _state_holder = state_holder.lock().await;
-                    _state_holder.rollback_last();
+                    _state_holder.revert_to_savepoint();
+                }
+
+                // Revert the programs repo to the savepoint.
+                {
+                    let mut _programs_repo = programs_repo.lock().await;
+                    _programs_repo.revert_to_savepoint();
                 }
 
-                // Rollback the accountant.
-                accountant.rollback_last();
+                // Revert the accountant to the savepoint.
+                accountant.revert_to_savepoint();
 
-                // Return the error.
+                // Revert the transient storage to the savepoint.
+                transient_storage.revert_to_savepoint();
+
+                // Locate the error within the call path.
+                let error = trace.locate(error);
+
+                // The call is still charged for the ops it consumed, including the failing opcode.
+                let ops_reached = trace.ops_charged(ops_budget);
+
+                // Charge the fees to the payer of the call. If they cannot be charged, the call is
+                // still receipted and its ops still counted, but with no fees spent.
+                let fees_charged = Self::charge_fees(accountant, &call, ops_reached);
+                let fees_spent = fees_charged.as_ref().copied().unwrap_or(0);
+
+                // Update the external ops counter.
+                self.external_ops_counter = external_ops_counter + ops_reached;
+
+                // Hand the profiler back, if any.
+                self.profiler = trace.end_profiled(ops_reached, self.external_ops_counter);
+
+                // Insert the call with the failure reason.
+                self.calls.push((
+                    call,
+                    CallReceipt::new_failed(ops_reached, fees_spent, error.clone()),
+                ));
+
+                // Return the fee error if the fees could not be charged, or the call error otherwise.
+                fees_charged.and(Err(error))
+            }
+        }
+    }
+
+    /// Charges the fees of the ops spent by a call, at its total ops price, to its payer.
+    fn charge_fees(
+        accountant: &mut Accountant,
+        call: &Call,
+        ops_spent: u32,
+    ) -> Result<FeesSpent, ExecutionError> {
+        let fees_spent = (ops_spent as u64)
+            .checked_mul(call.ops_price_total())
+            .ok_or(ExecutionError::AccountantFeeInsertionError(
+                InsertFeeError::FeeOverflowError,
+            ))?;
+
+        accountant
+            .insert_fee(call.payer_key(), fees_spent)
+            .map_err(ExecutionError::AccountantFeeInsertionError)?;
+
+        Ok(fees_spent)
+    }
+
+    /// Executes and inserts a bundle, all-or-nothing.
+    ///
+    /// Items are executed in order. If any call fails, the changes made by the whole bundle are
+    /// reverted, and the calls that passed before it are re-receipted as failed. Every call is still
+    /// charged for the ops it consumed. The error is then returned with the failing item index.
+    pub async fn exec_insert_bundle(&mut self, bundle: Bundle) -> Result<(), ExecutionError> {
+        // The calls of the bundle.
+        let calls = bundle.calls();
+
+        // Check the ops price of all calls before reserving for or executing any.
+        for call in calls.iter() {
+            self.check_ops_price(call)?;
+        }
+
+        // Reserve the most fees all calls can be charged, including those not reached.
+        // The reservations are released if any of them cannot be made.
+        for (index, call) in calls.iter().enumerate() {
+            if let Err(error) = self.reserve_fees(call) {
+                for reserved_call in calls[..index].iter() {
+                    self.accountant
+                        .release_fees(reserved_call.payer_key(), reserved_call.max_fees());
+                }
                 return Err(error);
             }
         }
+
+        // Push savepoints for the bundle, enclosing the savepoints of its calls.
+        {
+            let mut _state_holder = self.state_holder.lock().await;
+            _state_holder.push_savepoint();
+        }
+        {
+            let mut _programs_repo = self.programs_repo.lock().await;
+            _programs_repo.push_savepoint();
+        }
+        self.accountant.push_savepoint();
+        self.transient_storage.push_savepoint();
+
+        // The calls and moves inserted before the bundle.
+        let calls_len = self.calls.len();
+        let moves_len = self.moves.len();
+
+        // Execute the items in order.
+        let mut bundle_result = Ok(());
+        for (index, item) in bundle.items().into_iter().enumerate() {
+            match item {
+                // Moves are not checked against balances here: entry validation already reserved
+                // them against the sender's balance, and they settle after all calls in `save_all`.
+                BundleItem::Move(r#move) => self.moves.push(r#move),
+                BundleItem::Call(call) => {
+                    if let Err(error) = self.exec_insert_reserved_call(call).await {
+                        bundle_result = Err((index, error));
+                        break;
+                    }
+                }
+            }
+        }
+
+        match bundle_result {
+            Ok(()) => {
+                // Commit the bundle savepoints.
+                {
+                    let mut _state_holder = self.state_holder.lock().await;
+                    _state_holder.commit_savepoint();
+                }
+                {
+                    let mut _programs_repo = self.programs_repo.lock().await;
+                    _programs_repo.commit_savepoint();
+                }
+                self.accountant.commit_savepoint();
+                self.transient_storage.commit_savepoint();
+
+                Ok(())
+            }
+            Err((index, error)) => {
+                // Revert to the bundle savepoints.
+                {
+                    let mut _state_holder = self.state_holder.lock().await;
+                    _state_holder.revert_to_savepoint();
+                }
+                {
+                    let mut _programs_repo = self.programs_repo.lock().await;
+                    _programs_repo.revert_to_savepoint();
+                }
+                self.accountant.revert_to_savepoint();
+                self.transient_storage.revert_to_savepoint();
+
+                // Discard the moves of the bundle.
+                self.moves.truncate(moves_len);
+
+                // The calls that passed before the failing one are reverted, but still charged.
+                for (_, receipt) in self.calls[calls_len..].iter_mut() {
+                    if receipt.is_passed() {
+                        *receipt = CallReceipt::new_failed(
+                            receipt.ops_spent(),
+                            receipt.fees_spent(),
+                            ExecutionError::BundleRevertedError(index),
+                        );
+                    }
+                }
+
+                Err(ExecutionError::BundleItemError(index, Box::new(error)))
+            }
+        }
     }
 
     /// Flushes all the passed calls.
@@ -187,33 +484,300 @@ impl ExecCtx {
             _state_holder.rollback_all();
         }
 
+        // Rollback the deployed programs.
+        {
+            let mut _programs_repo = self.programs_repo.lock().await;
+            _programs_repo.rollback_all();
+        }
+
         // Rollback the accountant.
         self.accountant.rollback_all();
 
+        // Clear the transient storage.
+        self.transient_storage.clear();
+
         // Set the external ops counter to zero.
         self.external_ops_counter = 0;
 
-        // Clear the passed calls.
-        self.passed_calls.clear();
+        // Clear the calls.
+        self.calls.clear();
+
+        // Clear the moves.
+        self.moves.clear();
+    }
+
+    /// Settles the pay list, then saves the states updated and the programs deployed by all the
+    /// passed calls, applies the settlement to the VTXO set, registers the deployed contracts in the
+    /// contract registery, and returns the settled pay list.
+    ///
+    /// The settlement debits each account its allocations, fee reservations and the moves it made,
+    /// and credits it the pay list and the moves it received, with new VTXOs held under the operator
+    /// key at the session and its anchored Bitcoin height. The fees charged are what is debited but
+    /// not credited back. Moves of passed bundles thus settle after all calls, never mid-bundle.
+    ///
+    /// The writes are committed together: nothing is saved if the pay list cannot be settled or an
+    /// account cannot cover its debit, and the writes made so far are undone if a later one fails.
+    /// The registration comes last, so that a registered contract always has its program saved.
+    pub async fn save_all(
+        &mut self,
+        contract_registery: &CONTRACT_REGISTERY,
+        vtxo_set: &VTXO_SET,
+        operator_key: Point,
+    ) -> Result<HashMap<[u8; 32], u64>, ExecCtxSaveError> {
+        // #1 Settle the pay list, with the fees deducted from their payers.
+        let pay_list = self.pay_list().map_err(ExecCtxSaveError::PayListError)?;
+
+        // #2 Collect the debit and credit lists, with the moves of passed bundles.
+        let debit_list = self.debit_list().map_err(ExecCtxSaveError::PayListError)?;
+        let credit_list = self.credit_list().map_err(ExecCtxSaveError::PayListError)?;
+
+        // #3 Compute the settlement, checking that every account covers its debit.
+        let (spent_vtxos, created_vtxos) = {
+            let (at_rollup_height, at_bitcoin_height) = match (
+                u32::try_from(self.session_height),
+                u32::try_from(self.bitcoin_height),
+            ) {
+                (Ok(rollup_height), Ok(bitcoin_height)) => (rollup_height, bitcoin_height),
+                _ => return Err(ExecCtxSaveError::VTXOSettlementError),
+            };
+
+            let _vtxo_set = vtxo_set.lock().await;
+            _vtxo_set
+                .settlement(
+                    &debit_list,
+                    &credit_list,
+                    operator_key,
+                    at_rollup_height,
+                    at_bitcoin_height,
+                )
+                .ok_or(ExecCtxSaveError::InsufficientBalanceError)?
+        };
+
+        // #4 Collect the deployed contract ids.
+        let deployed_contract_ids = {
+            let _programs_repo = self.programs_repo.lock().await;
+            _programs_repo.pending_deployments()
+        };
+
+        // #5 Save the states, keeping the saved values to restore on a later failure.
+        let saved_values = {
+            let mut _state_holder = self.state_holder.lock().await;
+            let saved_values = _state_holder.saved_values();
+            _state_holder
+                .save_all_executions()
+                .map_err(ExecCtxSaveError::StateHolderSaveError)?;
+            saved_values
+        };
+
+        // #6 Save the deployed programs.
+        let programs_saved = {
+            let mut _programs_repo = self.programs_repo.lock().await;
+            _programs_repo.save_all_deployments()
+        };
+        if let Err(err) = programs_saved {
+            self.restore_states(&saved_values).await;
+            return Err(ExecCtxSaveError::ProgramsRepoSaveError(err));
+        }
+
+        // #7 Apply the settlement.
+        let settled = {
+            let mut _vtxo_set = vtxo_set.lock().await;
+            _vtxo_set.apply_settlement(&spent_vtxos, &created_vtxos)
+        };
+        if !settled {
+            self.remove_programs(&deployed_contract_ids).await;
+            self.restore_states(&saved_values).await;
+            return Err(ExecCtxSaveError::VTXOSettlementError);
+        }
+
+        // #8 Register the deployed contracts.
+        let registered = deployed_contract_ids.is_empty() || {
+            let mut _contract_registery = contract_registery.lock().await;
+            _contract_registery.batch_update(deployed_contract_ids.clone(), HashMap::new())
+        };
+        if !registered {
+            {
+                let mut _vtxo_set = vtxo_set.lock().await;
+                let _ = _vtxo_set.apply_settlement(&created_vtxos, &spent_vtxos);
+            }
+            self.remove_programs(&deployed_contract_ids).await;
+            self.restore_states(&saved_values).await;
+            return Err(ExecCtxSaveError::ContractRegisteryUpdateError);
+        }
+
+        Ok(pay_list)
+    }
+
+    /// Removes the saved programs, undoing a save.
+    async fn remove_programs(&self, contract_ids: &[[u8; 32]]) {
+        let mut _programs_repo = self.programs_repo.lock().await;
+        let _ = _programs_repo.remove_multi(contract_ids);
+    }
+
+    /// Restores the saved values of the states, undoing a save.
+    async fn restore_states(
+        &self,
+        saved_values: &HashMap<[u8; 32], HashMap<Vec<u8>, Option<Vec<u8>>>>,
+    ) {
+        let mut _state_holder = self.state_holder.lock().await;
+        let _ = _state_holder.restore_values(saved_values);
     }
 
     /// Returns the pay list.
-    pub fn pay_list(&self) -> Result<HashMap<[u8; 32], u32>, PayListError> {
+    pub fn pay_list(&self) -> Result<HashMap<[u8; 32], u64>, PayListError> {
         self.accountant.pay_list()
     }
 
+    /// Returns the debit list, by account.
+    ///
+    /// Each account funds its allocation and its fee reservation, plus the moves it made in passed
+    /// bundles.
+    pub fn debit_list(&self) -> Result<HashMap<[u8; 32], u64>, PayListError> {
+        let mut debit_list = self.accountant.debit_list()?;
+
+        // Iterate moves, for each sender collect their debit.
+        for r#move in self.moves.iter() {
+            let debit = debit_list
+                .entry(r#move.from().key().serialize_xonly())
+                .or_insert(0);
+            *debit = debit
+                .checked_add(r#move.amount() as u64)
+                .ok_or(PayListError::BalanceOverflowError)?;
+        }
+
+        Ok(debit_list)
+    }
+
+    /// Returns the credit list, by account.
+    ///
+    /// Each account is credited what it is owed in the pay list, plus the moves it received in
+    /// passed bundles.
+    pub fn credit_list(&self) -> Result<HashMap<[u8; 32], u64>, PayListError> {
+        let mut credit_list = self.accountant.pay_list()?;
+
+        // Iterate moves, for each recipient collect their credit.
+        for r#move in self.moves.iter() {
+            let credit = credit_list
+                .entry(r#move.to().key().serialize_xonly())
+                .or_insert(0);
+            *credit = credit
+                .checked_add(r#move.amount() as u64)
+                .ok_or(PayListError::BalanceOverflowError)?;
+        }
+
+        Ok(credit_list)
+    }
+
+    /// Returns the payable allocations, by account.
+    pub fn allocs(&self) -> HashMap<[u8; 32], u32> {
+        self.accountant.allocs()
+    }
+
+    /// Returns the fee list, by payer.
+    pub fn fee_list(&self) -> HashMap<[u8; 32], u64> {
+        self.accountant.fee_list()
+    }
+
     /// Returns the passed calls length.
     pub fn passed_calls_len(&self) -> usize {
-        self.passed_calls.len()
+        self.calls
+            .iter()
+            .filter(|(_, receipt)| receipt.is_passed())
+            .count()
     }
 
     /// Returns the passed calls.
     pub fn passed_calls(&self) -> Vec<(Call, OpsSpent, FeesSpent)> {
-        self.passed_calls.clone()
+        self.calls
+            .iter()
+            .filter(|(_, receipt)| receipt.is_passed())
+            .map(|(call, receipt)| (call.clone(), receipt.ops_spent(), receipt.fees_spent()))
+            .collect()
+    }
+
+    /// Returns the failed calls with their receipts.
+    pub fn failed_calls(&self) -> Vec<(Call, CallReceipt)> {
+        self.calls
+            .iter()
+            .filter(|(_, receipt)| !receipt.is_passed())
+            .cloned()
+            .collect()
+    }
+
+    /// Returns all the executed calls with their receipts, in execution order.
+    pub fn receipts(&self) -> Vec<(Call, CallReceipt)> {
+        self.calls.clone()
+    }
+
+    /// Returns the moves made by passed bundles, in execution order.
+    pub fn moves(&self) -> Vec<Move> {
+        self.moves.clone()
+    }
+
+    /// Profiles the ops spent by the subsequent calls.
+    pub fn enable_profiler(&mut self) {
+        if self.profiler.is_none() {
+            self.profiler = Some(OpsProfiler::new());
+        }
+    }
+
+    /// Returns the ops profiler, if calls are profiled.
+    pub fn profiler(&self) -> Option<&OpsProfiler> {
+        self.profiler.as_ref()
+    }
+
+    /// Stops profiling and returns the ops profiler, if calls were profiled.
+    pub fn take_profiler(&mut self) -> Option<OpsProfiler> {
+        self.profiler.take()
+    }
+
+    /// Sets the timestamp of the subsequent calls.
+    pub fn set_timestamp(&mut self, timestamp: u64) {
+        self.timestamp = timestamp;
+    }
+
+    /// Sets the Bitcoin height the subsequent calls are anchored to.
+    pub fn set_bitcoin_height(&mut self, bitcoin_height: u64) {
+        self.bitcoin_height = bitcoin_height;
+    }
+
+    /// Sets the session height of the subsequent calls.
+    pub fn set_session_height(&mut self, session_height: u64) {
+        self.session_height = session_height;
+    }
+
+    /// Sets the base ops price of the subsequent calls.
+    pub fn set_base_ops_price(&mut self, base_ops_price: u32) {
+        self.base_ops_price = base_ops_price;
     }
 
     /// Returns the external ops counter.
     pub fn external_ops_counter(&self) -> u32 {
         self.external_ops_counter
     }
+
+    /// Returns the base ops price.
+    pub fn base_ops_price(&self) -> u32 {
+        self.base_ops_price
+    }
+
+    /// Returns the Bitcoin height the session is anchored to.
+    pub fn bitcoin_height(&self) -> u64 {
+        self.bitcoin_height
+    }
+
+    /// Returns the session height.
+    pub fn session_height(&self) -> u64 {
+        self.session_height
+    }
+
+    /// Returns the total ops spent by all the executed calls, passed or failed.
+    ///
+    /// This is what the session records in the price directory to derive the next base ops price.
+    pub fn ops_spent(&self) -> u64 {
+        self.calls
+            .iter()
+            .map(|(_, receipt)| receipt.ops_spent() as u64)
+            .sum()
+    }
 }
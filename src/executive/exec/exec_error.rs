@@ -1,6 +1,11 @@
-use crate::executive::{
-    exec::accountant::accountant_error::InsertAllocError,
-    stack::{stack_error::StackError, stack_item::StackItem},
+use crate::{
+    executive::{
+        exec::accountant::accountant_error::{InsertAllocError, InsertFeeError, PayListError},
+        stack::{stack_error::StackError, stack_item::StackItem},
+    },
+    inscriptive::{
+        repo::repo_error::RepoInsertError, state::state_holder_error::StateHolderSaveError,
+    },
 };
 use std::fmt;
 
@@ -43,6 +48,48 @@ pub enum ExecutionError {
     InvalidStackEndingError,
     /// Base ops price mismatch error.
     BaseOpsPriceMismatchError,
+    /// Total ops price exceeds the stack ops price range error.
+    OpsPriceOverflowError,
+    /// Accountant fee insertion error.
+    AccountantFeeInsertionError(InsertFeeError),
+    /// Revert error, located within the call path.
+    RevertError(Box<RevertContext>, Box<ExecutionError>),
+    /// Bundle item failed error, at the index of the failing item.
+    BundleItemError(usize, Box<ExecutionError>),
+    /// Bundle reverted error, by the failing item at the index.
+    BundleRevertedError(usize),
+}
+
+/// The location an execution failed at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevertContext {
+    /// The contract id of the failing contract.
+    pub contract_id: [u8; 32],
+    /// The method index of the failing method.
+    pub method_index: u8,
+    /// The program counter of the failing opcode, if the method reached any.
+    pub pc: Option<u32>,
+    /// The failing opcode, if the method reached any.
+    pub opcode: Option<String>,
+    /// The call path through `OP_CALL`/`OP_CALLEXT`, outermost first.
+    pub call_path: Vec<([u8; 32], u8)>,
+}
+
+impl ExecutionError {
+    /// Returns the human-readable revert reason of an `OP_RETURNERR` payload, if it is UTF-8.
+    pub fn revert_reason(&self) -> Option<String> {
+        match self {
+            ExecutionError::ReturnErrorFromStackError(item) => {
+                match std::str::from_utf8(item.bytes()) {
+                    Ok(reason) if !reason.is_empty() => Some(reason.to_string()),
+                    _ => None,
+                }
+            }
+            ExecutionError::RevertError(_, error) => error.revert_reason(),
+            ExecutionError::BundleItemError(_, error) => error.revert_reason(),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for ExecutionError {
@@ -75,9 +122,10 @@ impl fmt::Display for ExecutionError {
             ExecutionError::ExternalCallAttemptAsInternalError => {
                 write!(f, "External call attempt as internal call")
             }
-            ExecutionError::ReturnErrorFromStackError(error) => {
-                write!(f, "Return error from stack: {:?}", error)
-            }
+            ExecutionError::ReturnErrorFromStackError(error) => match self.revert_reason() {
+                Some(reason) => write!(f, "Return error from stack: {}", reason),
+                None => write!(f, "Return error from stack: {:?}", error),
+            },
             ExecutionError::ReservedOpcodeEncounteredError => {
                 write!(f, "Reserved opcode encountered")
             }
@@ -102,6 +150,88 @@ impl fmt::Display for ExecutionError {
             ExecutionError::BaseOpsPriceMismatchError => {
                 write!(f, "Base ops price mismatch")
             }
+            ExecutionError::OpsPriceOverflowError => {
+                write!(f, "Ops price overflow")
+            }
+            ExecutionError::AccountantFeeInsertionError(error) => {
+                write!(f, "Accountant fee insertion error: {:?}", error)
+            }
+            ExecutionError::RevertError(context, error) => {
+                write!(f, "{} at {}", error, context)
+            }
+            ExecutionError::BundleItemError(index, error) => {
+                write!(f, "Bundle item {} failed: {}", index, error)
+            }
+            ExecutionError::BundleRevertedError(index) => {
+                write!(f, "Reverted by bundle item {} failing", index)
+            }
+        }
+    }
+}
+
+impl fmt::Display for RevertContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "contract {} method {}",
+            hex::encode(self.contract_id),
+            self.method_index
+        )?;
+
+        if let (Some(pc), Some(opcode)) = (self.pc, &self.opcode) {
+            write!(f, " pc {} ({})", pc, opcode)?;
+        }
+
+        let call_path = self
+            .call_path
+            .iter()
+            .map(|(contract_id, method_index)| {
+                format!("{}:{}", hex::encode(contract_id), method_index)
+            })
+            .collect::<Vec<String>>();
+
+        write!(f, " via {}", call_path.join(" -> "))
+    }
+}
+
+/// The execution context save error.
+#[derive(Debug, Clone)]
+pub enum ExecCtxSaveError {
+    /// Pay list settlement error.
+    PayListError(PayListError),
+    /// Programs repo save error.
+    ProgramsRepoSaveError(RepoInsertError),
+    /// Contract registery update error.
+    ContractRegisteryUpdateError,
+    /// State holder save error.
+    StateHolderSaveError(StateHolderSaveError),
+    /// An account cannot cover its debit.
+    InsufficientBalanceError,
+    /// VTXO settlement error.
+    VTXOSettlementError,
+}
+
+impl fmt::Display for ExecCtxSaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecCtxSaveError::PayListError(error) => {
+                write!(f, "Pay list error: {:?}", error)
+            }
+            ExecCtxSaveError::ProgramsRepoSaveError(error) => {
+                write!(f, "Programs repo save error: {:?}", error)
+            }
+            ExecCtxSaveError::ContractRegisteryUpdateError => {
+                write!(f, "Contract registery update error")
+            }
+            ExecCtxSaveError::StateHolderSaveError(error) => {
+                write!(f, "State holder save error: {:?}", error)
+            }
+            ExecCtxSaveError::InsufficientBalanceError => {
+                write!(f, "Insufficient balance error")
+            }
+            ExecCtxSaveError::VTXOSettlementError => {
+                write!(f, "VTXO settlement error")
+            }
         }
     }
 }
@@ -3,3 +3,7 @@ pub mod caller;
 pub mod exec;
 pub mod exec_ctx;
 pub mod exec_error;
+pub mod profiler;
+pub mod receipt;
+pub mod trace;
+pub mod transient;
@@ -0,0 +1,329 @@
+use crate::executive::opcode::opcode::Opcode;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// The ops counter a profile is read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpsCounterKind {
+    /// The internal ops counter, bound by the ops budget of the call.
+    Internal,
+    /// The external ops counter, bound by the ops limit of the session.
+    External,
+}
+
+/// The ops aggregated for a single script offset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpsProfileEntry {
+    /// The contract id.
+    pub contract_id: [u8; 32],
+    /// The method index.
+    pub method_index: u8,
+    /// The script offset.
+    pub pc: u32,
+    /// The opcode kind, e.g. `OP_ADD`.
+    pub opcode: String,
+    /// The number of times the opcode was reached.
+    pub hits: u64,
+    /// The internal ops spent by the opcode.
+    pub internal_ops: u64,
+    /// The external ops spent by the opcode.
+    pub external_ops: u64,
+}
+
+/// The opcode reached last, whose ops are not yet known.
+#[derive(Debug, Clone)]
+struct PendingStep {
+    // The folded call path the opcode was reached at.
+    folded_path: String,
+    // The key of the profile entry.
+    key: ([u8; 32], u8, u32),
+    // The internal ops counter before the opcode.
+    internal_ops_counter: u32,
+    // The external ops counter before the opcode.
+    external_ops_counter: u32,
+}
+
+/// Aggregates the ops spent by contract, method, opcode kind and script offset over many calls.
+///
+/// The ops of an opcode are the difference between the counters before it and before the opcode
+/// that follows it, so the ops of a nested call are attributed to the opcodes of the callee.
+#[derive(Debug, Clone)]
+pub struct OpsProfiler {
+    // Profile entries by contract id, method index and script offset.
+    entries: HashMap<([u8; 32], u8, u32), OpsProfileEntry>,
+    // Internal and external ops by folded call stack.
+    stacks: HashMap<String, (u64, u64)>,
+    // The number of calls profiled.
+    calls: u64,
+    // The opcode reached last.
+    pending: Option<PendingStep>,
+}
+
+impl OpsProfiler {
+    /// Creates a new, empty profiler.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            stacks: HashMap::new(),
+            calls: 0,
+            pending: None,
+        }
+    }
+
+    /// Begins profiling a new call.
+    pub fn begin_call(&mut self) {
+        self.pending = None;
+        self.calls += 1;
+    }
+
+    /// Records an opcode reached at the given call path, with the counters before it.
+    pub fn record_step(
+        &mut self,
+        call_path: &[([u8; 32], u8)],
+        pc: u32,
+        opcode: &Opcode,
+        internal_ops_counter: u32,
+        external_ops_counter: u32,
+    ) {
+        // Settle the ops of the previous opcode.
+        self.settle(internal_ops_counter, external_ops_counter);
+
+        let (contract_id, method_index) = match call_path.last() {
+            Some(frame) => *frame,
+            None => return,
+        };
+
+        // The opcode kind, without its push data.
+        let opcode_kind = opcode
+            .to_string()
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        let key = (contract_id, method_index, pc);
+
+        let entry = self.entries.entry(key).or_insert_with(|| OpsProfileEntry {
+            contract_id,
+            method_index,
+            pc,
+            opcode: opcode_kind.clone(),
+            hits: 0,
+            internal_ops: 0,
+            external_ops: 0,
+        });
+        entry.hits += 1;
+
+        // Fold the call path into a flame graph stack.
+        let mut folded_path = call_path
+            .iter()
+            .map(|(contract_id, method_index)| {
+                format!("{}:{}", hex::encode(contract_id), method_index)
+            })
+            .collect::<Vec<String>>()
+            .join(";");
+        folded_path.push_str(&format!(";{}@{}", opcode_kind, pc));
+
+        self.pending = Some(PendingStep {
+            folded_path,
+            key,
+            internal_ops_counter,
+            external_ops_counter,
+        });
+    }
+
+    /// Ends profiling the current call, with the counters it ended at.
+    pub fn end_call(&mut self, internal_ops_counter: u32, external_ops_counter: u32) {
+        self.settle(internal_ops_counter, external_ops_counter);
+    }
+
+    /// Attributes the ops spent since the opcode reached last to it.
+    fn settle(&mut self, internal_ops_counter: u32, external_ops_counter: u32) {
+        let pending = match self.pending.take() {
+            Some(pending) => pending,
+            None => return,
+        };
+
+        let internal_ops = internal_ops_counter.saturating_sub(pending.internal_ops_counter) as u64;
+        let external_ops = external_ops_counter.saturating_sub(pending.external_ops_counter) as u64;
+
+        if let Some(entry) = self.entries.get_mut(&pending.key) {
+            entry.internal_ops += internal_ops;
+            entry.external_ops += external_ops;
+        }
+
+        let stack = self.stacks.entry(pending.folded_path).or_insert((0, 0));
+        stack.0 += internal_ops;
+        stack.1 += external_ops;
+    }
+
+    /// Returns the number of calls profiled.
+    pub fn calls(&self) -> u64 {
+        self.calls
+    }
+
+    /// Returns the profile entries, most internal ops first.
+    pub fn entries(&self) -> Vec<OpsProfileEntry> {
+        let mut entries = self.entries.values().cloned().collect::<Vec<_>>();
+
+        entries.sort_by(|a, b| {
+            b.internal_ops
+                .cmp(&a.internal_ops)
+                .then(a.contract_id.cmp(&b.contract_id))
+                .then(a.method_index.cmp(&b.method_index))
+                .then(a.pc.cmp(&b.pc))
+        });
+
+        entries
+    }
+
+    /// Returns the ops spent by the given counter, by contract id.
+    pub fn ops_by_contract(&self, counter: OpsCounterKind) -> HashMap<[u8; 32], u64> {
+        let mut ops = HashMap::new();
+        for entry in self.entries.values() {
+            *ops.entry(entry.contract_id).or_insert(0) += entry.ops(counter);
+        }
+        ops
+    }
+
+    /// Returns the ops spent by the given counter, by contract id and method index.
+    pub fn ops_by_method(&self, counter: OpsCounterKind) -> HashMap<([u8; 32], u8), u64> {
+        let mut ops = HashMap::new();
+        for entry in self.entries.values() {
+            *ops.entry((entry.contract_id, entry.method_index))
+                .or_insert(0) += entry.ops(counter);
+        }
+        ops
+    }
+
+    /// Returns the ops spent by the given counter, by opcode kind.
+    pub fn ops_by_opcode(&self, counter: OpsCounterKind) -> HashMap<String, u64> {
+        let mut ops = HashMap::new();
+        for entry in self.entries.values() {
+            *ops.entry(entry.opcode.clone()).or_insert(0) += entry.ops(counter);
+        }
+        ops
+    }
+
+    /// Returns the profile report as a JSON value.
+    pub fn json(&self) -> Value {
+        let external_ops = self.ops_by_contract(OpsCounterKind::External);
+        let mut contracts = self
+            .ops_by_contract(OpsCounterKind::Internal)
+            .into_iter()
+            .map(|(contract_id, internal_ops)| {
+                let external_ops = external_ops[&contract_id];
+                (contract_id, internal_ops, external_ops)
+            })
+            .collect::<Vec<_>>();
+        contracts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let external_ops = self.ops_by_method(OpsCounterKind::External);
+        let mut methods = self
+            .ops_by_method(OpsCounterKind::Internal)
+            .into_iter()
+            .map(|(method, internal_ops)| {
+                let external_ops = external_ops[&method];
+                (method, internal_ops, external_ops)
+            })
+            .collect::<Vec<_>>();
+        methods.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let external_ops = self.ops_by_opcode(OpsCounterKind::External);
+        let mut opcodes = self
+            .ops_by_opcode(OpsCounterKind::Internal)
+            .into_iter()
+            .map(|(opcode, internal_ops)| {
+                let external_ops = external_ops[&opcode];
+                (opcode, internal_ops, external_ops)
+            })
+            .collect::<Vec<_>>();
+        opcodes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let value = json!({
+            "calls": self.calls,
+            "internal_ops": self.total_ops(OpsCounterKind::Internal),
+            "external_ops": self.total_ops(OpsCounterKind::External),
+            "contracts": contracts
+                .iter()
+                .map(|(contract_id, internal_ops, external_ops)| json!({
+                    "contract_id": hex::encode(contract_id),
+                    "internal_ops": internal_ops,
+                    "external_ops": external_ops,
+                }))
+                .collect::<Vec<_>>(),
+            "methods": methods
+                .iter()
+                .map(|((contract_id, method_index), internal_ops, external_ops)| json!({
+                    "contract_id": hex::encode(contract_id),
+                    "method_index": method_index,
+                    "internal_ops": internal_ops,
+                    "external_ops": external_ops,
+                }))
+                .collect::<Vec<_>>(),
+            "opcodes": opcodes
+                .iter()
+                .map(|(opcode, internal_ops, external_ops)| json!({
+                    "opcode": opcode,
+                    "internal_ops": internal_ops,
+                    "external_ops": external_ops,
+                }))
+                .collect::<Vec<_>>(),
+            "offsets": self
+                .entries()
+                .iter()
+                .map(|entry| json!({
+                    "contract_id": hex::encode(entry.contract_id),
+                    "method_index": entry.method_index,
+                    "pc": entry.pc,
+                    "opcode": entry.opcode,
+                    "hits": entry.hits,
+                    "internal_ops": entry.internal_ops,
+                    "external_ops": entry.external_ops,
+                }))
+                .collect::<Vec<_>>(),
+        });
+
+        // Return the value
+        value
+    }
+
+    /// Returns the profile of the given counter in the folded stack format flame graph tools read.
+    ///
+    /// Each line is a `;` separated call stack of `<contract id>:<method index>` frames ending in an
+    /// `<opcode>@<pc>` frame, followed by the ops spent there. Stacks with no ops are omitted.
+    pub fn folded(&self, counter: OpsCounterKind) -> String {
+        let mut lines = self
+            .stacks
+            .iter()
+            .filter_map(|(stack, (internal_ops, external_ops))| {
+                let ops = match counter {
+                    OpsCounterKind::Internal => *internal_ops,
+                    OpsCounterKind::External => *external_ops,
+                };
+                match ops {
+                    0 => None,
+                    _ => Some(format!("{} {}", stack, ops)),
+                }
+            })
+            .collect::<Vec<String>>();
+        lines.sort();
+
+        lines.join("\n")
+    }
+
+    /// Returns the total ops spent by the given counter.
+    pub fn total_ops(&self, counter: OpsCounterKind) -> u64 {
+        self.entries.values().map(|entry| entry.ops(counter)).sum()
+    }
+}
+
+impl OpsProfileEntry {
+    /// Returns the ops spent by the given counter.
+    pub fn ops(&self, counter: OpsCounterKind) -> u64 {
+        match counter {
+            OpsCounterKind::Internal => self.internal_ops,
+            OpsCounterKind::External => self.external_ops,
+        }
+    }
+}
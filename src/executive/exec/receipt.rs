@@ -0,0 +1,55 @@
+use super::exec_error::ExecutionError;
+
+/// The receipt of an executed call.
+///
+/// Failed calls are receipted too; they are charged for the ops they consumed up to and including
+/// the failing opcode, and carry the reason they failed.
+#[derive(Debug, Clone)]
+pub struct CallReceipt {
+    // The ops spent.
+    ops_spent: u32,
+    // The fees spent.
+    fees_spent: u64,
+    // The reason the call failed, if it did.
+    failure_reason: Option<ExecutionError>,
+}
+
+impl CallReceipt {
+    /// Creates a receipt for a passed call.
+    pub fn new_passed(ops_spent: u32, fees_spent: u64) -> Self {
+        Self {
+            ops_spent,
+            fees_spent,
+            failure_reason: None,
+        }
+    }
+
+    /// Creates a receipt for a failed call.
+    pub fn new_failed(ops_spent: u32, fees_spent: u64, failure_reason: ExecutionError) -> Self {
+        Self {
+            ops_spent,
+            fees_spent,
+            failure_reason: Some(failure_reason),
+        }
+    }
+
+    /// Returns the ops spent.
+    pub fn ops_spent(&self) -> u32 {
+        self.ops_spent
+    }
+
+    /// Returns the fees spent.
+    pub fn fees_spent(&self) -> u64 {
+        self.fees_spent
+    }
+
+    /// Returns the reason the call failed, if it did.
+    pub fn failure_reason(&self) -> Option<ExecutionError> {
+        self.failure_reason.clone()
+    }
+
+    /// Returns whether the call passed.
+    pub fn is_passed(&self) -> bool {
+        self.failure_reason.is_none()
+    }
+}
@@ -0,0 +1,159 @@
+use super::{
+    exec_error::{ExecutionError, RevertContext},
+    profiler::OpsProfiler,
+};
+use crate::executive::opcode::opcode::Opcode;
+
+/// A frame in the call path of an execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallFrame {
+    /// The contract id of the called contract.
+    pub contract_id: [u8; 32],
+    /// The method index of the called contract.
+    pub method_index: u8,
+    /// The program counter of the last opcode reached, if any.
+    pub pc: Option<u32>,
+    /// The last opcode reached, if any.
+    pub opcode: Option<String>,
+}
+
+/// Traces an execution through its nested calls, so that a failure can be located and charged.
+///
+/// Calls made through `OP_CALL` and `OP_CALLEXT` push a frame that is exited once the callee
+/// returns or fails, so the last frame is always the one being executed.
+#[derive(Debug, Clone)]
+pub struct ExecTrace {
+    // The call path, outermost first.
+    frames: Vec<CallFrame>,
+    // The internal ops counter reached so far.
+    ops_reached: u32,
+    // The base ops cost of the opcode being executed.
+    step_ops: u32,
+    // The ops profiler, if the execution is profiled.
+    profiler: Option<OpsProfiler>,
+}
+
+impl ExecTrace {
+    /// Creates a new, empty execution trace.
+    pub fn new() -> Self {
+        Self {
+            frames: Vec::new(),
+            ops_reached: 0,
+            step_ops: 0,
+            profiler: None,
+        }
+    }
+
+    /// Creates a new, empty execution trace that feeds the given profiler.
+    pub fn new_profiled(mut profiler: OpsProfiler) -> Self {
+        profiler.begin_call();
+
+        Self {
+            frames: Vec::new(),
+            ops_reached: 0,
+            step_ops: 0,
+            profiler: Some(profiler),
+        }
+    }
+
+    /// Enters a new call frame.
+    pub fn enter(&mut self, contract_id: [u8; 32], method_index: u8) {
+        self.frames.push(CallFrame {
+            contract_id,
+            method_index,
+            pc: None,
+            opcode: None,
+        });
+    }
+
+    /// Exits the current call frame, returning to its caller.
+    pub fn exit(&mut self) {
+        self.frames.pop();
+    }
+
+    /// Steps the current frame to the given opcode, with its base ops cost and the ops counters
+    /// reached before it.
+    pub fn step(
+        &mut self,
+        pc: u32,
+        opcode: &Opcode,
+        opcode_ops: u32,
+        internal_ops_counter: u32,
+        external_ops_counter: u32,
+    ) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.pc = Some(pc);
+            frame.opcode = Some(opcode.to_string());
+        }
+
+        self.ops_reached = internal_ops_counter;
+        self.step_ops = opcode_ops;
+
+        if let Some(profiler) = self.profiler.as_mut() {
+            let call_path = self
+                .frames
+                .iter()
+                .map(|frame| (frame.contract_id, frame.method_index))
+                .collect::<Vec<_>>();
+
+            profiler.record_step(
+                &call_path,
+                pc,
+                opcode,
+                internal_ops_counter,
+                external_ops_counter,
+            );
+        }
+    }
+
+    /// Ends the trace with the ops counters the execution ended at, and returns its profiler.
+    pub fn end_profiled(
+        &mut self,
+        internal_ops_counter: u32,
+        external_ops_counter: u32,
+    ) -> Option<OpsProfiler> {
+        let mut profiler = self.profiler.take()?;
+        profiler.end_call(internal_ops_counter, external_ops_counter);
+        Some(profiler)
+    }
+
+    /// Returns the call path, outermost first.
+    pub fn frames(&self) -> &Vec<CallFrame> {
+        &self.frames
+    }
+
+    /// Returns the internal ops counter reached so far.
+    pub fn ops_reached(&self) -> u32 {
+        self.ops_reached
+    }
+
+    /// Returns the ops a failed execution is charged: the ops reached, plus the base cost of the
+    /// opcode it failed at, capped at the ops budget.
+    pub fn ops_charged(&self, ops_budget: u32) -> u32 {
+        self.ops_reached
+            .saturating_add(self.step_ops)
+            .min(ops_budget)
+    }
+
+    /// Wraps the given error with the location the execution failed at.
+    pub fn locate(&self, error: ExecutionError) -> ExecutionError {
+        match self.frames.last() {
+            Some(frame) => {
+                let context = RevertContext {
+                    contract_id: frame.contract_id,
+                    method_index: frame.method_index,
+                    pc: frame.pc,
+                    opcode: frame.opcode.clone(),
+                    call_path: self
+                        .frames
+                        .iter()
+                        .map(|frame| (frame.contract_id, frame.method_index))
+                        .collect(),
+                };
+
+                ExecutionError::RevertError(Box::new(context), Box::new(error))
+            }
+            None => error,
+        }
+    }
+}
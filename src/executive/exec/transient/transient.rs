@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+/// Contract ID: 32-byte unique identifier.
+#[allow(non_camel_case_types)]
+type CONTRACT_ID = [u8; 32];
+
+/// Transient key.
+#[allow(non_camel_case_types)]
+type TRANSIENT_KEY = Vec<u8>;
+
+/// Transient value.
+#[allow(non_camel_case_types)]
+type TRANSIENT_VALUE = Vec<u8>;
+
+/// Transient storage scoped to an execution context.
+///
+/// Values outlive individual call frames, but are never persisted, and are rolled back
+/// together with the state.
+pub struct TransientStorage {
+    /// Transient values: CONTRACT_ID -> { TRANSIENT_KEY -> TRANSIENT_VALUE }
+    values: HashMap<CONTRACT_ID, HashMap<TRANSIENT_KEY, TRANSIENT_VALUE>>,
+    /// Stack of transient values savepoints, the last one being the innermost.
+    savepoints: Vec<HashMap<CONTRACT_ID, HashMap<TRANSIENT_KEY, TRANSIENT_VALUE>>>,
+}
+
+impl TransientStorage {
+    /// Creates a new, empty transient storage.
+    pub fn new() -> Self {
+        Self {
+            values: HashMap::<CONTRACT_ID, HashMap<TRANSIENT_KEY, TRANSIENT_VALUE>>::new(),
+            savepoints: Vec::<HashMap<CONTRACT_ID, HashMap<TRANSIENT_KEY, TRANSIENT_VALUE>>>::new(),
+        }
+    }
+
+    /// Get the value by key and contract ID.
+    pub fn get_value(
+        &self,
+        key: &TRANSIENT_KEY,
+        contract_id: &CONTRACT_ID,
+    ) -> Option<TRANSIENT_VALUE> {
+        self.values
+            .get(contract_id)
+            .and_then(|values| values.get(key).cloned())
+    }
+
+    /// Inserts or updates a value by key and contract ID.
+    pub fn insert_value(
+        &mut self,
+        contract_id: &CONTRACT_ID,
+        key: &TRANSIENT_KEY,
+        value: &TRANSIENT_VALUE,
+    ) {
+        self.values
+            .entry(*contract_id)
+            .or_default()
+            .insert(key.clone(), value.clone());
+    }
+
+    /// Returns the byte size of the contract's transient storage.
+    pub fn contract_size(&self, contract_id: &CONTRACT_ID) -> u32 {
+        match self.values.get(contract_id) {
+            Some(values) => values.iter().map(|(k, v)| (k.len() + v.len()) as u32).sum(),
+            None => 0,
+        }
+    }
+
+    /// Pushes a savepoint of the transient values.
+    pub fn push_savepoint(&mut self) {
+        self.savepoints.push(self.values.clone());
+    }
+
+    /// Commits the innermost savepoint, keeping the values written since it was pushed.
+    pub fn commit_savepoint(&mut self) {
+        self.savepoints.pop();
+    }
+
+    /// Reverts the values written since the innermost savepoint was pushed, and pops it.
+    pub fn revert_to_savepoint(&mut self) {
+        if let Some(savepoint) = self.savepoints.pop() {
+            self.values = savepoint;
+        }
+    }
+
+    /// Returns the number of open savepoints.
+    pub fn savepoints_len(&self) -> usize {
+        self.savepoints.len()
+    }
+
+    /// Clears all transient values and savepoints.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.savepoints.clear();
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{
+    constructive::{
+        calldata::element::element::CallElement, entry::combinator::combinators::call::call::Call,
+    },
+    executive::{
+        exec::{
+            accountant::accountant_error::PayListError, exec_ctx::ExecCtx,
+            exec_error::ExecutionError, profiler::OpsProfiler,
+        },
+        program::program::Program,
+        stack::limits::OPS_LIMIT,
+    },
+    inscriptive::{
+        beacon::dir::{BeaconDirectory, BEACON_DIRECTORY},
+        repo::{
+            repo::{ProgramsRepo, PROGRAMS_REPO},
+            repo_error::RepoInsertError,
+        },
+        rollup::dir::RollupDirectory,
+        state::state_holder::{StateHolder, STATE_HOLDER},
+    },
+    transmutative::noist::beacon::RandomnessBeacon,
+};
+use std::collections::HashMap;
+
+/// The result of a call made through the test harness.
+#[derive(Debug, Clone)]
+pub struct HarnessCallResult {
+    /// The ops spent by the call.
+    pub ops_spent: u32,
+    /// The fees charged for the call.
+    pub fees_spent: u64,
+}
+
+/// An in-process harness for testing contracts.
+///
+/// The harness runs calls through an execution context backed by an in-memory programs repo and
+/// state, so that contracts can be deployed, called and inspected from `tests/` without touching
+/// `db/`. Calls go through the same checks as in a session, and the harness session is never saved.
+pub struct TestHarness {
+    // The programs repo.
+    programs_repo: PROGRAMS_REPO,
+    // The state holder.
+    state_holder: STATE_HOLDER,
+    // The randomness beacon directory.
+    beacon_dir: BEACON_DIRECTORY,
+    // The execution context the calls are made in.
+    exec_ctx: ExecCtx,
+    // The account the calls are made from.
+    account_key: [u8; 32],
+    // The timestamp.
+    timestamp: u64,
+    // The ops budget of each call.
+    ops_budget: u32,
+}
+
+impl TestHarness {
+    /// Creates a new harness with an empty programs repo and state.
+    pub fn new() -> Option<Self> {
+        let programs_repo = ProgramsRepo::new_in_memory().ok()?;
+        let state_holder = StateHolder::new_in_memory().ok()?;
+        let rollup_dir = RollupDirectory::new_in_memory()?;
+        let beacon_dir = BeaconDirectory::new_in_memory()?;
+
+        let exec_ctx = ExecCtx::new(
+            &state_holder,
+            &programs_repo,
+            &rollup_dir,
+            1,
+            0,
+            0,
+            &beacon_dir,
+            0,
+        );
+
+        let harness = Self {
+            programs_repo,
+            state_holder,
+            beacon_dir,
+            exec_ctx,
+            account_key: [0x00; 32],
+            timestamp: 0,
+            ops_budget: OPS_LIMIT,
+        };
+
+        Some(harness)
+    }
+
+    /// Deploys a program and returns its contract id.
+    pub async fn deploy(&mut self, program: &Program) -> Result<[u8; 32], RepoInsertError> {
+        let contract_id = program.contract_id();
+
+        let mut programs = HashMap::new();
+        programs.insert(contract_id, program.to_owned());
+
+        let mut _programs_repo = self.programs_repo.lock().await;
+        _programs_repo.insert_multi(&programs)?;
+
+        Ok(contract_id)
+    }
+
+    /// Makes the subsequent calls from the given account.
+    pub fn impersonate(&mut self, account_key: [u8; 32]) {
+        self.account_key = account_key;
+    }
+
+    /// Sets the timestamp.
+    pub fn set_timestamp(&mut self, timestamp: u64) {
+        self.timestamp = timestamp;
+        self.exec_ctx.set_timestamp(timestamp);
+    }
+
+    /// Advances the timestamp by the given number of seconds.
+    pub fn advance_timestamp(&mut self, seconds: u64) {
+        self.set_timestamp(self.timestamp + seconds);
+    }
+
+    /// Sets the Bitcoin height.
+    pub fn set_bitcoin_height(&mut self, bitcoin_height: u64) {
+        self.exec_ctx.set_bitcoin_height(bitcoin_height);
+    }
+
+    /// Sets the session height.
+    pub fn set_session_height(&mut self, session_height: u64) {
+        self.exec_ctx.set_session_height(session_height);
+    }
+
+    /// Sets the ops budget of each call.
+    pub fn set_ops_budget(&mut self, ops_budget: u32) {
+        self.ops_budget = ops_budget;
+    }
+
+    /// Sets the ops price of each call.
+    pub fn set_ops_price(&mut self, ops_price: u32) {
+        self.exec_ctx.set_base_ops_price(ops_price);
+    }
+
+    /// Inserts a randomness beacon signed by the given operator quorum key.
+    pub async fn insert_beacon(&mut self, beacon: &RandomnessBeacon, quorum_key: [u8; 32]) -> bool {
+        let mut _beacon_dir = self.beacon_dir.lock().await;
+        _beacon_dir.insert_beacon(beacon, quorum_key)
+    }
+
+    /// Returns the message the operator quorum signs for the beacon of the given session height.
+    pub async fn beacon_message(&self, session_height: u64) -> [u8; 32] {
+        let _beacon_dir = self.beacon_dir.lock().await;
+        _beacon_dir.beacon_message(session_height)
+    }
+
+    /// Calls a contract method from the impersonated account.
+    ///
+    /// Changes made by a failing call are reverted, and the error is located within the call path.
+    pub async fn call(
+        &mut self,
+        contract_id: [u8; 32],
+        method_index: u8,
+        args: Vec<CallElement>,
+    ) -> Result<HarnessCallResult, ExecutionError> {
+        let call = Call::new(
+            self.account_key,
+            contract_id,
+            method_index,
+            args,
+            self.ops_budget,
+            self.exec_ctx.base_ops_price(),
+            None,
+            None,
+        );
+
+        self.exec_ctx.exec_insert_call(call).await?;
+
+        // The call passed, so its receipt is the last one.
+        let (_, receipt) = self.exec_ctx.receipts().pop().unwrap(); // Safe because the call was just inserted.
+
+        Ok(HarnessCallResult {
+            ops_spent: receipt.ops_spent(),
+            fees_spent: receipt.fees_spent(),
+        })
+    }
+
+    /// Profiles the ops spent by the subsequent calls.
+    pub fn enable_profiler(&mut self) {
+        self.exec_ctx.enable_profiler();
+    }
+
+    /// Returns the ops profiler, if calls are profiled.
+    pub fn profiler(&self) -> Option<&OpsProfiler> {
+        self.exec_ctx.profiler()
+    }
+
+    /// Returns the storage value of a contract by key.
+    pub async fn storage_value(&self, contract_id: [u8; 32], key: &[u8]) -> Option<Vec<u8>> {
+        let _state_holder = self.state_holder.lock().await;
+        _state_holder.get_value(&key.to_vec(), &contract_id)
+    }
+
+    /// Returns the payments made so far, by recipient, including the unspent fee reserves.
+    pub fn pay_list(&self) -> Result<HashMap<[u8; 32], u64>, PayListError> {
+        self.exec_ctx.pay_list()
+    }
+
+    /// Returns the payable allocations made so far, by account.
+    pub fn allocs(&self) -> HashMap<[u8; 32], u32> {
+        self.exec_ctx.allocs()
+    }
+}
@@ -1,4 +1,5 @@
 pub mod exec;
+pub mod harness;
 pub mod opcode;
 pub mod program;
 pub mod stack;
@@ -1,4 +1,5 @@
 use super::compiler_error::{OpcodeCompileError, OpcodeDecompileError};
+use crate::executive::opcode::opcode::Opcode;
 use crate::executive::opcode::opcodes::altstack::op_fromaltstack::OP_FROMALTSTACK;
 use crate::executive::opcode::opcodes::altstack::op_toaltstack::OP_TOALTSTACK;
 use crate::executive::opcode::opcodes::arithmetic::op_0notequal::OP_0NOTEQUAL;
@@ -27,6 +28,7 @@ use crate::executive::opcode::opcodes::arithmetic::op_numnotequal::OP_NUMNOTEQUA
 use crate::executive::opcode::opcodes::arithmetic::op_rshift::OP_RSHIFT;
 use crate::executive::opcode::opcodes::arithmetic::op_sub::OP_SUB;
 use crate::executive::opcode::opcodes::arithmetic::op_within::OP_WITHIN;
+use crate::executive::opcode::opcodes::beacon::op_beacon::OP_BEACON;
 use crate::executive::opcode::opcodes::bitwise::op_and::OP_AND;
 use crate::executive::opcode::opcodes::bitwise::op_equal::OP_EQUAL;
 use crate::executive::opcode::opcodes::bitwise::op_equalverify::OP_EQUALVERIFY;
@@ -45,10 +47,13 @@ use crate::executive::opcode::opcodes::digest::op_blake2bvar::OP_BLAKE2BVAR;
 use crate::executive::opcode::opcodes::digest::op_blake2svar::OP_BLAKE2SVAR;
 use crate::executive::opcode::opcodes::digest::op_hash160::OP_HASH160;
 use crate::executive::opcode::opcodes::digest::op_hash256::OP_HASH256;
+use crate::executive::opcode::opcodes::digest::op_keccak256::OP_KECCAK256;
+use crate::executive::opcode::opcodes::digest::op_merkleverify::OP_MERKLEVERIFY;
 use crate::executive::opcode::opcodes::digest::op_ripemd160::OP_RIPEMD160;
 use crate::executive::opcode::opcodes::digest::op_sha1::OP_SHA1;
 use crate::executive::opcode::opcodes::digest::op_sha256::OP_SHA256;
 use crate::executive::opcode::opcodes::digest::op_taggedhash::OP_TAGGEDHASH;
+use crate::executive::opcode::opcodes::factory::op_deploy::OP_DEPLOY;
 use crate::executive::opcode::opcodes::flow::op_else::OP_ELSE;
 use crate::executive::opcode::opcodes::flow::op_endif::OP_ENDIF;
 use crate::executive::opcode::opcodes::flow::op_fail::OP_FAIL;
@@ -98,13 +103,16 @@ use crate::executive::opcode::opcodes::secp::op_secpscalaradd::OP_SECPSCALARADD;
 use crate::executive::opcode::opcodes::secp::op_secpscalarmul::OP_SECPSCALARMUL;
 use crate::executive::opcode::opcodes::signature::op_checkblssig::OP_CHECKBLSSIG;
 use crate::executive::opcode::opcodes::signature::op_checkblssigagg::OP_CHECKBLSSIGAGG;
+use crate::executive::opcode::opcodes::signature::op_checkecdsasig::OP_CHECKECDSASIG;
 use crate::executive::opcode::opcodes::signature::op_checkschnorrsig::OP_CHECKSCHNORRSIG;
 use crate::executive::opcode::opcodes::signature::op_checkschnorrsigbip340::OP_CHECKSCHNORRSIGBIP340;
+use crate::executive::opcode::opcodes::signature::op_ecdsarecover::OP_ECDSARECOVER;
 use crate::executive::opcode::opcodes::splice::op_cat::OP_CAT;
 use crate::executive::opcode::opcodes::splice::op_left::OP_LEFT;
 use crate::executive::opcode::opcodes::splice::op_right::OP_RIGHT;
 use crate::executive::opcode::opcodes::splice::op_size::OP_SIZE;
 use crate::executive::opcode::opcodes::splice::op_split::OP_SPLIT;
+use crate::executive::opcode::opcodes::spv::op_checkbtcinclusion::OP_CHECKBTCINCLUSION;
 use crate::executive::opcode::opcodes::stack::op_2drop::OP_2DROP;
 use crate::executive::opcode::opcodes::stack::op_2dup::OP_2DUP;
 use crate::executive::opcode::opcodes::stack::op_2over::OP_2OVER;
@@ -124,7 +132,8 @@ use crate::executive::opcode::opcodes::stack::op_swap::OP_SWAP;
 use crate::executive::opcode::opcodes::stack::op_tuck::OP_TUCK;
 use crate::executive::opcode::opcodes::storage::op_sread::OP_SREAD;
 use crate::executive::opcode::opcodes::storage::op_swrite::OP_SWRITE;
-use crate::executive::opcode::opcode::Opcode;
+use crate::executive::opcode::opcodes::transient::op_tread::OP_TREAD;
+use crate::executive::opcode::opcodes::transient::op_twrite::OP_TWRITE;
 
 /// A trait for compiling and decompiling an opcode.
 pub trait OpcodeCompiler {
@@ -248,6 +257,8 @@ impl OpcodeCompiler for Opcode {
             Opcode::OP_TAGGEDHASH(_) => Ok(OP_TAGGEDHASH::bytecode()),
             Opcode::OP_BLAKE2BVAR(_) => Ok(OP_BLAKE2BVAR::bytecode()),
             Opcode::OP_BLAKE2SVAR(_) => Ok(OP_BLAKE2SVAR::bytecode()),
+            Opcode::OP_KECCAK256(_) => Ok(OP_KECCAK256::bytecode()),
+            Opcode::OP_MERKLEVERIFY(_) => Ok(OP_MERKLEVERIFY::bytecode()),
             // Secp
             Opcode::OP_SECPSCALARADD(_) => Ok(OP_SECPSCALARADD::bytecode()),
             Opcode::OP_SECPSCALARMUL(_) => Ok(OP_SECPSCALARMUL::bytecode()),
@@ -261,6 +272,8 @@ impl OpcodeCompiler for Opcode {
             Opcode::OP_CHECKSCHNORRSIGBIP340(_) => Ok(OP_CHECKSCHNORRSIGBIP340::bytecode()),
             Opcode::OP_CHECKBLSSIG(_) => Ok(OP_CHECKBLSSIG::bytecode()),
             Opcode::OP_CHECKBLSSIGAGG(_) => Ok(OP_CHECKBLSSIGAGG::bytecode()),
+            Opcode::OP_CHECKECDSASIG(_) => Ok(OP_CHECKECDSASIG::bytecode()),
+            Opcode::OP_ECDSARECOVER(_) => Ok(OP_ECDSARECOVER::bytecode()),
             // Call info
             Opcode::OP_CALLER(_) => Ok(OP_CALLER::bytecode()),
             Opcode::OP_OPSBUDGET(_) => Ok(OP_OPSBUDGET::bytecode()),
@@ -282,6 +295,15 @@ impl OpcodeCompiler for Opcode {
             // Storage
             Opcode::OP_SWRITE(_) => Ok(OP_SWRITE::bytecode()),
             Opcode::OP_SREAD(_) => Ok(OP_SREAD::bytecode()),
+            // SPV
+            Opcode::OP_CHECKBTCINCLUSION(_) => Ok(OP_CHECKBTCINCLUSION::bytecode()),
+            // Transient storage
+            Opcode::OP_TWRITE(_) => Ok(OP_TWRITE::bytecode()),
+            Opcode::OP_TREAD(_) => Ok(OP_TREAD::bytecode()),
+            // Factory
+            Opcode::OP_DEPLOY(_) => Ok(OP_DEPLOY::bytecode()),
+            // Beacon
+            Opcode::OP_BEACON(_) => Ok(OP_BEACON::bytecode()),
         }
     }
 
@@ -517,6 +539,22 @@ impl OpcodeCompiler for Opcode {
             // Storage
             0xc7 => Ok(Opcode::OP_SWRITE(OP_SWRITE)),
             0xc8 => Ok(Opcode::OP_SREAD(OP_SREAD)),
+            // Extended digest
+            0xc9 => Ok(Opcode::OP_KECCAK256(OP_KECCAK256)),
+            // Extended digital signatures
+            0xca => Ok(Opcode::OP_CHECKECDSASIG(OP_CHECKECDSASIG)),
+            0xcb => Ok(Opcode::OP_ECDSARECOVER(OP_ECDSARECOVER)),
+            // Merkle proofs
+            0xcc => Ok(Opcode::OP_MERKLEVERIFY(OP_MERKLEVERIFY)),
+            // SPV
+            0xcd => Ok(Opcode::OP_CHECKBTCINCLUSION(OP_CHECKBTCINCLUSION)),
+            // Transient storage
+            0xce => Ok(Opcode::OP_TWRITE(OP_TWRITE)),
+            0xcf => Ok(Opcode::OP_TREAD(OP_TREAD)),
+            // Factory
+            0xd0 => Ok(Opcode::OP_DEPLOY(OP_DEPLOY)),
+            // Beacon
+            0xd1 => Ok(Opcode::OP_BEACON(OP_BEACON)),
             // Undefined
             _ => Err(OpcodeDecompileError::UndefinedOpcodeError),
         }
@@ -1,8 +1,12 @@
 #![allow(non_camel_case_types)]
 
 use crate::executive::opcode::opcodes::{
+    beacon::op_beacon::OP_BEACON,
+    factory::op_deploy::OP_DEPLOY,
     payment::op_pay::OP_PAY,
+    spv::op_checkbtcinclusion::OP_CHECKBTCINCLUSION,
     storage::{op_sread::OP_SREAD, op_swrite::OP_SWRITE},
+    transient::{op_tread::OP_TREAD, op_twrite::OP_TWRITE},
 };
 
 use super::opcodes::{
@@ -28,7 +32,8 @@ use super::opcodes::{
     },
     digest::{
         op_blake2bvar::OP_BLAKE2BVAR, op_blake2svar::OP_BLAKE2SVAR, op_hash160::OP_HASH160,
-        op_hash256::OP_HASH256, op_ripemd160::OP_RIPEMD160, op_sha1::OP_SHA1, op_sha256::OP_SHA256,
+        op_hash256::OP_HASH256, op_keccak256::OP_KECCAK256, op_merkleverify::OP_MERKLEVERIFY,
+        op_ripemd160::OP_RIPEMD160, op_sha1::OP_SHA1, op_sha256::OP_SHA256,
         op_taggedhash::OP_TAGGEDHASH,
     },
     flow::{
@@ -58,7 +63,8 @@ use super::opcodes::{
     },
     signature::{
         op_checkblssig::OP_CHECKBLSSIG, op_checkblssigagg::OP_CHECKBLSSIGAGG,
-        op_checkschnorrsig::OP_CHECKSCHNORRSIG, op_checkschnorrsigbip340::OP_CHECKSCHNORRSIGBIP340,
+        op_checkecdsasig::OP_CHECKECDSASIG, op_checkschnorrsig::OP_CHECKSCHNORRSIG,
+        op_checkschnorrsigbip340::OP_CHECKSCHNORRSIGBIP340, op_ecdsarecover::OP_ECDSARECOVER,
     },
     splice::{
         op_cat::OP_CAT, op_left::OP_LEFT, op_right::OP_RIGHT, op_size::OP_SIZE, op_split::OP_SPLIT,
@@ -181,6 +187,8 @@ pub enum Opcode {
     OP_TAGGEDHASH(OP_TAGGEDHASH),
     OP_BLAKE2BVAR(OP_BLAKE2BVAR),
     OP_BLAKE2SVAR(OP_BLAKE2SVAR),
+    OP_KECCAK256(OP_KECCAK256),
+    OP_MERKLEVERIFY(OP_MERKLEVERIFY),
     // Secp
     OP_SECPSCALARADD(OP_SECPSCALARADD),
     OP_SECPSCALARMUL(OP_SECPSCALARMUL),
@@ -194,6 +202,8 @@ pub enum Opcode {
     OP_CHECKSCHNORRSIGBIP340(OP_CHECKSCHNORRSIGBIP340),
     OP_CHECKBLSSIG(OP_CHECKBLSSIG),
     OP_CHECKBLSSIGAGG(OP_CHECKBLSSIGAGG),
+    OP_CHECKECDSASIG(OP_CHECKECDSASIG),
+    OP_ECDSARECOVER(OP_ECDSARECOVER),
     // Call info
     OP_CALLER(OP_CALLER),
     OP_OPSBUDGET(OP_OPSBUDGET),
@@ -215,6 +225,15 @@ pub enum Opcode {
     // Storage
     OP_SWRITE(OP_SWRITE),
     OP_SREAD(OP_SREAD),
+    // SPV
+    OP_CHECKBTCINCLUSION(OP_CHECKBTCINCLUSION),
+    // Transient storage
+    OP_TWRITE(OP_TWRITE),
+    OP_TREAD(OP_TREAD),
+    // Factory
+    OP_DEPLOY(OP_DEPLOY),
+    // Beacon
+    OP_BEACON(OP_BEACON),
 }
 
 impl Display for Opcode {
@@ -328,6 +347,8 @@ impl Display for Opcode {
             Opcode::OP_TAGGEDHASH(_) => write!(f, "OP_TAGGEDHASH"),
             Opcode::OP_BLAKE2BVAR(_) => write!(f, "OP_BLAKE2BVAR"),
             Opcode::OP_BLAKE2SVAR(_) => write!(f, "OP_BLAKE2SVAR"),
+            Opcode::OP_KECCAK256(_) => write!(f, "OP_KECCAK256"),
+            Opcode::OP_MERKLEVERIFY(_) => write!(f, "OP_MERKLEVERIFY"),
             // Secp
             Opcode::OP_SECPSCALARADD(_) => write!(f, "OP_SECPSCALARADD"),
             Opcode::OP_SECPSCALARMUL(_) => write!(f, "OP_SECPSCALARMUL"),
@@ -341,6 +362,8 @@ impl Display for Opcode {
             Opcode::OP_CHECKSCHNORRSIGBIP340(_) => write!(f, "OP_CHECKSCHNORRSIGBIP340"),
             Opcode::OP_CHECKBLSSIG(_) => write!(f, "OP_CHECKBLSSIG"),
             Opcode::OP_CHECKBLSSIGAGG(_) => write!(f, "OP_CHECKBLSSIGAGG"),
+            Opcode::OP_CHECKECDSASIG(_) => write!(f, "OP_CHECKECDSASIG"),
+            Opcode::OP_ECDSARECOVER(_) => write!(f, "OP_ECDSARECOVER"),
             // Call info
             Opcode::OP_CALLER(_) => write!(f, "OP_CALLER"),
             Opcode::OP_OPSBUDGET(_) => write!(f, "OP_OPSBUDGET"),
@@ -362,6 +385,15 @@ impl Display for Opcode {
             // Storage
             Opcode::OP_SWRITE(_) => write!(f, "OP_SWRITE"),
             Opcode::OP_SREAD(_) => write!(f, "OP_SREAD"),
+            // SPV
+            Opcode::OP_CHECKBTCINCLUSION(_) => write!(f, "OP_CHECKBTCINCLUSION"),
+            // Transient storage
+            Opcode::OP_TWRITE(_) => write!(f, "OP_TWRITE"),
+            Opcode::OP_TREAD(_) => write!(f, "OP_TREAD"),
+            // Factory
+            Opcode::OP_DEPLOY(_) => write!(f, "OP_DEPLOY"),
+            // Beacon
+            Opcode::OP_BEACON(_) => write!(f, "OP_BEACON"),
         }
     }
 }
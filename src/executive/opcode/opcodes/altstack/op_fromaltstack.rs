@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_FROMALTSTACK_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// Puts the input onto the top of the main stack. Removes it from the alt stack.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,7 +16,7 @@ impl OP_FROMALTSTACK {
         let last_item = stack_holder.alt_stack_pop()?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_FROMALTSTACK_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_fromaltstack)?;
 
         // Push the last item to the main stack.
         stack_holder.push(last_item)?;
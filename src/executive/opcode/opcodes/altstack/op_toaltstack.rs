@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_TOALTSTACK_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// Puts the input onto the top of the alt stack. Removes it from the main stack.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,7 +16,7 @@ impl OP_TOALTSTACK {
         let last_item = stack_holder.pop()?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_TOALTSTACK_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_toaltstack)?;
 
         // Push the last item to the alt stack.
         stack_holder.alt_stack_push(last_item)?;
@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_0NOTEQUAL_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Returns 0 if the input is 0. 1 otherwise.
@@ -25,7 +24,7 @@ impl OP_0NOTEQUAL {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_0NOTEQUAL_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_0notequal)?;
 
         Ok(())
     }
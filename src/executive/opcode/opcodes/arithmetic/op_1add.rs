@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_1ADD_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::{SafeConverter, StackItemUintExt, StackUint},
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::{SafeConverter, StackItemUintExt, StackUint},
 };
 
 /// 1 is added to the input.
@@ -49,7 +46,7 @@ impl OP_1ADD {
         };
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_1ADD_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_1add)?;
 
         Ok(())
     }
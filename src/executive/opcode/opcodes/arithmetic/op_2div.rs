@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_2DIV_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::{SafeConverter, StackItemUintExt, StackUint},
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::{SafeConverter, StackItemUintExt, StackUint},
 };
 
 /// Divides the top item on the main stack by 2. Returns the modulo and division result.
@@ -42,7 +39,7 @@ impl OP_2DIV {
         stack_holder.push(StackItem::true_item())?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_2DIV_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_2div)?;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_2MUL_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::{SafeConverter, StackItemUintExt, StackUint},
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::{SafeConverter, StackItemUintExt, StackUint},
 };
 
 /// The input is multiplied by 2.
@@ -49,7 +46,7 @@ impl OP_2MUL {
         };
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_2MUL_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_2mul)?;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_ADDMOD_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::{StackItemUintExt, StackUint},
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::{StackItemUintExt, StackUint},
 };
 
 /// Adds two items on the main stack and returns the result modulo MAX::U256.
@@ -38,7 +35,7 @@ impl OP_ADDMOD {
         let result = StackItem::from_stack_uint(StackUint::addmod(&item_1_uint, &item_2_uint));
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_ADDMOD_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_addmod)?;
 
         // Push the result to the main stack.
         stack_holder.push(result)?;
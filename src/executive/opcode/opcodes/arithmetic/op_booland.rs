@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_BOOLAND_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// If both a and b are not 0, the output is 1. Otherwise 0.
@@ -29,7 +28,7 @@ impl OP_BOOLAND {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_BOOLAND_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_booland)?;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_DIV_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::{StackItemUintExt, StackUint},
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::{StackItemUintExt, StackUint},
 };
 
 /// Divides two items on the main stack. Returns the modulo and division result.
@@ -62,7 +59,7 @@ impl OP_DIV {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_DIV_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_div)?;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_GREATERTHAN_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::StackItemUintExt,
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::StackItemUintExt,
 };
 
 /// Returns 1 if a is greater than b, 0 otherwise.
@@ -43,7 +40,7 @@ impl OP_GREATERTHAN {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_GREATERTHAN_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_greaterthan)?;
 
         Ok(())
     }
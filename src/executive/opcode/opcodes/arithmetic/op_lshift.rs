@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_LSHIFT_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Shifts a left b bits.
@@ -78,7 +77,7 @@ impl OP_LSHIFT {
         stack_holder.push(StackItem::new(result))?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_LSHIFT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_lshift)?;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_MIN_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::StackItemUintExt,
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::StackItemUintExt,
 };
 
 /// Returns the smaller of a and b.
@@ -43,7 +40,7 @@ impl OP_MIN {
         stack_holder.push(StackItem::from_stack_uint(smaller))?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_MIN_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_min)?;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_MUL_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::StackItemUintExt,
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::StackItemUintExt,
 };
 
 /// Multiplies two items on the main stack.
@@ -58,7 +55,7 @@ impl OP_MUL {
         };
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_MUL_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_mul)?;
 
         Ok(())
     }
@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_NOT_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// If the input is 0 or 1, it is flipped. Otherwise the output will be 0.
@@ -25,7 +24,7 @@ impl OP_NOT {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_NOT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_not)?;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_NUMEQUAL_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::StackItemUintExt,
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::StackItemUintExt,
 };
 
 /// Returns 1 if the numbers are equal, 0 otherwise.
@@ -44,7 +41,7 @@ impl OP_NUMEQUAL {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_NUMEQUAL_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_numequal)?;
 
         Ok(())
     }
@@ -1,10 +1,7 @@
-use crate::executive::{
-    opcode::ops::OP_NUMEQUALVERIFY_OPS,
-    stack::{
-        stack_error::{MandatoryError, StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_uint::StackItemUintExt,
-    },
+use crate::executive::stack::{
+    stack_error::{MandatoryError, StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_uint::StackItemUintExt,
 };
 
 /// Same as OP_NUMEQUAL, but runs OP_VERIFY afterward.
@@ -43,7 +40,7 @@ impl OP_NUMEQUALVERIFY {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_NUMEQUALVERIFY_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_numequalverify)?;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_NUMNOTEQUAL_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::StackItemUintExt,
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::StackItemUintExt,
 };
 
 /// Returns 1 if the numbers are not equal, 0 otherwise.
@@ -43,7 +40,7 @@ impl OP_NUMNOTEQUAL {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_NUMNOTEQUAL_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_numnotequal)?;
 
         Ok(())
     }
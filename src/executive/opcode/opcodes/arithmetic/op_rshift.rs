@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_RSHIFT_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Shifts a right b bits.
@@ -79,7 +78,7 @@ impl OP_RSHIFT {
         stack_holder.push(StackItem::new(result))?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_RSHIFT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_rshift)?;
 
         Ok(())
     }
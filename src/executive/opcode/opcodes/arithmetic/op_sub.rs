@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_SUB_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::StackItemUintExt,
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::StackItemUintExt,
 };
 
 /// Subtracts two items on the main stack.
@@ -58,7 +55,7 @@ impl OP_SUB {
         };
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_SUB_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_sub)?;
 
         Ok(())
     }
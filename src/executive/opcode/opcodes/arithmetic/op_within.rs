@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_WITHIN_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::StackItemUintExt,
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::StackItemUintExt,
 };
 
 /// Returns 1 if x is within the specified range (left-inclusive), 0 otherwise.
@@ -51,7 +48,7 @@ impl OP_WITHIN {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_WITHIN_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_within)?;
 
         Ok(())
     }
@@ -0,0 +1,76 @@
+use crate::{
+    executive::stack::{
+        stack_error::{BeaconError, StackError, StackUintError},
+        stack_holder::StackHolder,
+        stack_item::StackItem,
+        stack_uint::{SafeConverter, StackItemUintExt},
+    },
+    inscriptive::beacon::dir::BEACON_DIRECTORY,
+};
+
+/// Pushes the randomness of a finalized session's beacon.
+///
+/// A call executed in session `N` may only read the beacons of sessions `0..N`, as the beacon
+/// of session `N` is derived only once its entries are final. Contracts that need unbiased
+/// randomness should commit in one session and read the beacon of that same session in a
+/// later one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct OP_BEACON;
+
+impl OP_BEACON {
+    pub async fn execute(
+        stack_holder: &mut StackHolder,
+        beacon_dir: &BEACON_DIRECTORY,
+        session_height: u64,
+    ) -> Result<(), StackError> {
+        // If this is not the active execution, return immediately.
+        if !stack_holder.active_execution() {
+            return Ok(());
+        }
+
+        // Pop the beacon session height from the main stack.
+        let height_item = stack_holder.pop()?;
+
+        // Convert the height to a u64.
+        let beacon_height = height_item
+            .to_stack_uint()
+            .ok_or(StackError::StackUintError(
+                StackUintError::StackUintConversionError,
+            ))?
+            .to_u64()
+            .ok_or(StackError::StackUintError(
+                StackUintError::StackUintConversionError,
+            ))?;
+
+        // Only the beacons of the sessions prior to the current one can be read.
+        if beacon_height >= session_height {
+            return Err(StackError::BeaconError(
+                BeaconError::BeaconSessionNotFinalized(beacon_height),
+            ));
+        }
+
+        // Get the beacon.
+        let beacon = {
+            let _beacon_dir = beacon_dir.lock().await;
+            _beacon_dir
+                .beacon(beacon_height)
+                .ok_or(StackError::BeaconError(BeaconError::BeaconNotFound(
+                    beacon_height,
+                )))?
+        };
+
+        // Push the randomness to the main stack.
+        stack_holder.push(StackItem::new(beacon.randomness().to_vec()))?;
+
+        // Increment the ops counter.
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_beacon)?;
+
+        Ok(())
+    }
+
+    /// Returns the bytecode for the `OP_BEACON` opcode (0xd1).
+    pub fn bytecode() -> Vec<u8> {
+        vec![0xd1]
+    }
+}
@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_EQUAL_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Returns 1 if the inputs are exactly equal, 0 otherwise.
@@ -26,7 +25,7 @@ impl OP_EQUAL {
         };
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_EQUAL_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_equal)?;
 
         // Push the item to the main stack.
         stack_holder.push(item_to_push)?;
@@ -1,9 +1,6 @@
-use crate::executive::{
-    opcode::ops::OP_EQUALVERIFY_OPS,
-    stack::{
-        stack_error::{MandatoryError, StackError},
-        stack_holder::StackHolder,
-    },
+use crate::executive::stack::{
+    stack_error::{MandatoryError, StackError},
+    stack_holder::StackHolder,
 };
 
 /// Same as OP_EQUAL, but runs OP_VERIFY afterward.
@@ -30,7 +27,7 @@ impl OP_EQUALVERIFY {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_EQUALVERIFY_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_equalverify)?;
 
         Ok(())
     }
@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_INVERT_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Flips all of the bits in the input.
@@ -25,7 +24,7 @@ impl OP_INVERT {
         stack_holder.push(StackItem::new(inverted_item))?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_INVERT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_invert)?;
 
         Ok(())
     }
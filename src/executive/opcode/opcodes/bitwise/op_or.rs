@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_OR_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Boolean or between each bit in the inputs.
@@ -33,7 +32,7 @@ impl OP_OR {
         stack_holder.push(StackItem::new(or_item))?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_OR_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_or)?;
 
         Ok(())
     }
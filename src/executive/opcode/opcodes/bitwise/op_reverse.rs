@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_REVERSE_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Reverses the byte order of the popped stack item.
@@ -28,7 +27,7 @@ impl OP_REVERSE {
         stack_holder.push(StackItem::new(reversed_bytes))?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_REVERSE_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_reverse)?;
 
         Ok(())
     }
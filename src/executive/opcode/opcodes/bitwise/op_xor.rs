@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_XOR_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Boolean exclusive or between each bit in the inputs.
@@ -33,7 +32,7 @@ impl OP_XOR {
         stack_holder.push(StackItem::new(xor_item))?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_XOR_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_xor)?;
 
         Ok(())
     }
@@ -10,9 +10,6 @@ use crate::executive::stack::{
 #[allow(non_camel_case_types)]
 pub struct OP_CALL;
 
-/// The number of ops for the `OP_CALL` opcode.
-pub const CALL_OPS: u32 = 5;
-
 /// Method index to be called.
 type MethodIndexToBeCalled = u8;
 /// Call arguments.
@@ -70,7 +67,7 @@ impl OP_CALL {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(CALL_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_call)?;
 
         Ok((method_index_as_u8, arguments))
     }
@@ -10,9 +10,6 @@ use crate::executive::stack::{
 #[allow(non_camel_case_types)]
 pub struct OP_CALLEXT;
 
-/// The number of ops for the `OP_CALLEXT` opcode.
-pub const CALLEXT_OPS: u32 = 50;
-
 /// Contract id to be called.
 type ContractIdToBeCalled = [u8; 32];
 /// Method index to be called.
@@ -81,7 +78,7 @@ impl OP_CALLEXT {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(CALLEXT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_callext)?;
 
         Ok((contract_id_bytes, method_index_as_u8, arguments))
     }
@@ -8,9 +8,6 @@ use crate::executive::{
 #[allow(non_camel_case_types)]
 pub struct OP_CALLER;
 
-/// The number of ops for the `OP_CALLER` opcode.
-pub const CALLER_OPS: u32 = 1;
-
 impl OP_CALLER {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -37,7 +34,7 @@ impl OP_CALLER {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(CALLER_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_caller)?;
 
         Ok(())
     }
@@ -10,9 +10,6 @@ use crate::executive::stack::{
 #[allow(non_camel_case_types)]
 pub struct OP_OPSBUDGET;
 
-/// The number of ops for the `OP_OPSBUDGET` opcode.
-pub const OPSBUDGET_OPS: u32 = 1;
-
 impl OP_OPSBUDGET {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -33,7 +30,7 @@ impl OP_OPSBUDGET {
         stack_holder.push(ops_budget_as_stack_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OPSBUDGET_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_opsbudget)?;
 
         Ok(())
     }
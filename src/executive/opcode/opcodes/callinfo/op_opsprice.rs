@@ -10,9 +10,6 @@ use crate::executive::stack::{
 #[allow(non_camel_case_types)]
 pub struct OP_OPSPRICE;
 
-/// The number of ops for the `OP_OPSPRICE` opcode.
-pub const OPSPRICE_OPS: u32 = 1;
-
 impl OP_OPSPRICE {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -33,7 +30,7 @@ impl OP_OPSPRICE {
         stack_holder.push(ops_price_as_stack_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OPSPRICE_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_opsprice)?;
 
         Ok(())
     }
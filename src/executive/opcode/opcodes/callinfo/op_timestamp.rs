@@ -10,9 +10,6 @@ use crate::executive::stack::{
 #[allow(non_camel_case_types)]
 pub struct OP_TIMESTAMP;
 
-/// The number of ops for the `OP_TIMESTAMP` opcode.
-pub const TIMESTAMP_OPS: u32 = 1;
-
 impl OP_TIMESTAMP {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -33,7 +30,7 @@ impl OP_TIMESTAMP {
         stack_holder.push(timestamp_as_stack_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(TIMESTAMP_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_timestamp)?;
 
         Ok(())
     }
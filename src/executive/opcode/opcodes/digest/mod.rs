@@ -2,6 +2,8 @@ pub mod op_blake2bvar;
 pub mod op_blake2svar;
 pub mod op_hash160;
 pub mod op_hash256;
+pub mod op_keccak256;
+pub mod op_merkleverify;
 pub mod op_ripemd160;
 pub mod op_sha1;
 pub mod op_sha256;
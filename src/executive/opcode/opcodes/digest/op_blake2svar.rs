@@ -1,3 +1,4 @@
+use crate::executive::opcode::ops::OpsSchedule;
 use crate::executive::stack::stack_uint::StackItemUintExt;
 use crate::executive::stack::{
     stack_error::{StackError, StackUintError},
@@ -54,7 +55,10 @@ impl OP_BLAKE2SVAR {
         hasher.finalize_variable(&mut output_buffer).unwrap();
 
         // Increment the ops counter.
-        stack_holder.increment_ops(calculate_ops(output_size_as_usize as u32))?;
+        stack_holder.increment_ops(calculate_ops(
+            stack_holder.ops_schedule(),
+            output_size_as_usize as u32,
+        ))?;
 
         // Push the hash back to the main stack.
         stack_holder.push(StackItem::new(output_buffer))?;
@@ -68,11 +72,8 @@ impl OP_BLAKE2SVAR {
     }
 }
 
-const BLAKE2SVAR_OPS_BASE: u32 = 10;
-const BLAKE2SVAR_OPS_MULTIPLIER: u32 = 1;
-
 // Calculate the number of ops for a OP_BLAKE2SVAR opcode.
-fn calculate_ops(output_size: u32) -> u32 {
+fn calculate_ops(ops_schedule: &OpsSchedule, output_size: u32) -> u32 {
     // Return the number of ops.
-    BLAKE2SVAR_OPS_BASE + (BLAKE2SVAR_OPS_MULTIPLIER * output_size)
+    ops_schedule.op_blake2svar_base + (ops_schedule.op_blake2svar_multiplier * output_size)
 }
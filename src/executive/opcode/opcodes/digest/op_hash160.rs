@@ -10,9 +10,6 @@ use bitcoin::hashes::Hash;
 #[allow(non_camel_case_types)]
 pub struct OP_HASH160;
 
-/// The number of ops for the `OP_HASH160` opcode.
-pub const HASH160_OPS: u32 = 72;
-
 impl OP_HASH160 {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -32,7 +29,7 @@ impl OP_HASH160 {
         let ripemd160_hash = ripemd160::Hash::hash(&sha256_hash).to_byte_array().to_vec();
 
         // Increment the ops counter.
-        stack_holder.increment_ops(HASH160_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_hash160)?;
 
         // Push the hash back to the main stack.
         stack_holder.push(StackItem::new(ripemd160_hash))?;
@@ -9,9 +9,6 @@ use bitcoin::hashes::Hash;
 #[allow(non_camel_case_types)]
 pub struct OP_HASH256;
 
-/// The number of ops for the `OP_HASH256` opcode.
-pub const HASH256_OPS: u32 = 84;
-
 impl OP_HASH256 {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -31,7 +28,7 @@ impl OP_HASH256 {
         let second_hash = sha256::Hash::hash(&first_hash).to_byte_array().to_vec();
 
         // Increment the ops counter.
-        stack_holder.increment_ops(HASH256_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_hash256)?;
 
         // Push the hash back to the main stack.
         stack_holder.push(StackItem::new(second_hash))?;
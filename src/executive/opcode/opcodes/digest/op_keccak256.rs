@@ -0,0 +1,37 @@
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
+};
+use crate::transmutative::hash::keccak256;
+
+/// The input is hashed using Keccak-256.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct OP_KECCAK256;
+
+impl OP_KECCAK256 {
+    pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
+        // If this is not the active execution, return immediately.
+        if !stack_holder.active_execution() {
+            return Ok(());
+        }
+
+        // Pop the preimage from the main stack.
+        let preimage = stack_holder.pop()?;
+
+        // Hash the item using Keccak-256.
+        let hash = keccak256(preimage.bytes()).to_vec();
+
+        // Increment the ops counter.
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_keccak256)?;
+
+        // Push the hash back to the main stack.
+        stack_holder.push(StackItem::new(hash))?;
+
+        Ok(())
+    }
+
+    /// Returns the bytecode for the `OP_KECCAK256` opcode (0xc9).
+    pub fn bytecode() -> Vec<u8> {
+        vec![0xc9]
+    }
+}
@@ -0,0 +1,173 @@
+use crate::executive::opcode::ops::OpsSchedule;
+use crate::executive::stack::{
+    stack_error::{MerkleError, StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::{SafeConverter, StackItemUintExt},
+};
+use crate::transmutative::hash::{sha256, Hash, HashTag};
+use blake2::digest::{Update, VariableOutput};
+use blake2::Blake2bVar;
+
+/// The maximum depth of a Merkle proof.
+pub const MERKLE_PROOF_MAX_DEPTH: u32 = 32;
+
+/// The hash function used to combine two Merkle nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MerkleHashMode {
+    /// SHA-256 (empty mode item).
+    SHA256,
+    /// BLAKE2b with a 32-byte output (0x01).
+    BLAKE2b,
+    /// Tagged hash with the given tag (0x02 followed by the tag bytes).
+    Tagged(Vec<u8>),
+}
+
+impl MerkleHashMode {
+    /// Parses the hash mode from a stack item.
+    pub fn from_item(item: &StackItem) -> Option<MerkleHashMode> {
+        let bytes = item.bytes();
+
+        match bytes.first() {
+            None => Some(MerkleHashMode::SHA256),
+            Some(0x01) if bytes.len() == 1 => Some(MerkleHashMode::BLAKE2b),
+            Some(0x02) if bytes.len() > 1 => Some(MerkleHashMode::Tagged(bytes[1..].to_vec())),
+            _ => None,
+        }
+    }
+
+    /// Hashes two Merkle nodes into their parent node.
+    pub fn hash_nodes(&self, left: &[u8], right: &[u8]) -> [u8; 32] {
+        let mut preimage = Vec::<u8>::with_capacity(64);
+        preimage.extend(left);
+        preimage.extend(right);
+
+        match self {
+            MerkleHashMode::SHA256 => sha256(&preimage),
+            MerkleHashMode::BLAKE2b => {
+                let mut hasher = Blake2bVar::new(32).expect("32 is a valid BLAKE2b output size.");
+                hasher.update(&preimage);
+
+                let mut output = [0u8; 32];
+                hasher
+                    .finalize_variable(&mut output)
+                    .expect("Output buffer matches the output size.");
+                output
+            }
+            MerkleHashMode::Tagged(tag) => preimage.hash(Some(HashTag::CustomBytes(tag.clone()))),
+        }
+    }
+}
+
+/// Verifies a Merkle inclusion proof of a leaf against a root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct OP_MERKLEVERIFY;
+
+impl OP_MERKLEVERIFY {
+    pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
+        // If this is not the active execution, return immediately.
+        if !stack_holder.active_execution() {
+            return Ok(());
+        }
+
+        // Pop the hash mode from the main stack.
+        let mode_item = stack_holder.pop()?;
+
+        // Pop the root from the main stack.
+        let root_item = stack_holder.pop()?;
+
+        // Pop the leaf index from the main stack.
+        let index_item = stack_holder.pop()?;
+
+        // Pop the proof path from the main stack.
+        let path_item = stack_holder.pop()?;
+
+        // Pop the leaf from the main stack.
+        let leaf_item = stack_holder.pop()?;
+
+        // Parse the hash mode.
+        let mode = MerkleHashMode::from_item(&mode_item)
+            .ok_or(StackError::MerkleError(MerkleError::InvalidMerkleHashMode))?;
+
+        // Convert the root to bytes.
+        let root: [u8; 32] = root_item
+            .bytes()
+            .try_into()
+            .map_err(|_| StackError::MerkleError(MerkleError::InvalidMerkleRootBytes))?;
+
+        // Convert the leaf to bytes.
+        let leaf: [u8; 32] = leaf_item
+            .bytes()
+            .try_into()
+            .map_err(|_| StackError::MerkleError(MerkleError::InvalidMerkleLeafBytes))?;
+
+        // The path is a concatenation of 32-byte sibling nodes, from the leaf level up.
+        if path_item.len() % 32 != 0 {
+            return Err(StackError::MerkleError(
+                MerkleError::InvalidMerkleProofLength,
+            ));
+        }
+
+        // Get the proof depth.
+        let depth = path_item.len() / 32;
+
+        // Check if the proof depth is valid.
+        if depth > MERKLE_PROOF_MAX_DEPTH {
+            return Err(StackError::MerkleError(
+                MerkleError::MerkleProofDepthExceeded,
+            ));
+        }
+
+        // Convert the index to a u64.
+        let index = index_item
+            .to_stack_uint()
+            .ok_or(StackError::StackUintError(
+                StackUintError::StackUintConversionError,
+            ))?
+            .to_u64()
+            .ok_or(StackError::StackUintError(
+                StackUintError::StackUintConversionError,
+            ))?;
+
+        // The index must point to a leaf within the tree.
+        if index >= (1u64 << depth) {
+            return Err(StackError::MerkleError(MerkleError::MerkleIndexOutOfRange));
+        }
+
+        // Increment the ops counter before hashing.
+        stack_holder.increment_ops(calculate_ops(stack_holder.ops_schedule(), depth))?;
+
+        // Walk the path up to the root.
+        let mut node = leaf;
+        for (level, sibling) in path_item.bytes().chunks(32).enumerate() {
+            // The index bit at this level tells whether the node is a left or right child.
+            node = match (index >> level) & 1 {
+                0 => mode.hash_nodes(&node, sibling),
+                _ => mode.hash_nodes(sibling, &node),
+            };
+        }
+
+        // Get the result item.
+        let result_item = match node == root {
+            true => StackItem::true_item(),
+            false => StackItem::false_item(),
+        };
+
+        // Push the result to the main stack.
+        stack_holder.push(result_item)?;
+
+        Ok(())
+    }
+
+    /// Returns the bytecode for the `OP_MERKLEVERIFY` opcode (0xcc).
+    pub fn bytecode() -> Vec<u8> {
+        vec![0xcc]
+    }
+}
+
+// Calculate the number of ops for a OP_MERKLEVERIFY opcode.
+fn calculate_ops(ops_schedule: &OpsSchedule, depth: u32) -> u32 {
+    // Return the number of ops.
+    ops_schedule.op_merkleverify_base + (ops_schedule.op_merkleverify_multiplier * depth)
+}
@@ -9,9 +9,6 @@ use bitcoin::hashes::Hash;
 #[allow(non_camel_case_types)]
 pub struct OP_RIPEMD160;
 
-/// The number of ops for the `OP_RIPEMD160` opcode.
-pub const RIPEMD160_OPS: u32 = 30;
-
 impl OP_RIPEMD160 {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -28,7 +25,7 @@ impl OP_RIPEMD160 {
             .to_vec();
 
         // Increment the ops counter.
-        stack_holder.increment_ops(RIPEMD160_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_ripemd160)?;
 
         // Push the hash back to the main stack.
         stack_holder.push(StackItem::new(hash))?;
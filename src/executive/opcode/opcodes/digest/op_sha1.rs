@@ -9,9 +9,6 @@ use bitcoin::hashes::Hash;
 #[allow(non_camel_case_types)]
 pub struct OP_SHA1;
 
-/// The number of ops for the `OP_SHA1` opcode.
-pub const SHA1_OPS: u32 = 30;
-
 impl OP_SHA1 {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -26,7 +23,7 @@ impl OP_SHA1 {
         let hash = sha1::Hash::hash(preimage.bytes()).to_byte_array().to_vec();
 
         // Increment the ops counter.
-        stack_holder.increment_ops(SHA1_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_sha1)?;
 
         // Push the hash back to the main stack.
         stack_holder.push(StackItem::new(hash))?;
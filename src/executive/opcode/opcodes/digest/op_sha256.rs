@@ -9,9 +9,6 @@ use bitcoin::hashes::Hash;
 #[allow(non_camel_case_types)]
 pub struct OP_SHA256;
 
-/// The number of ops for the `OP_SHA256` opcode.
-pub const SHA256_OPS: u32 = 42;
-
 impl OP_SHA256 {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -28,7 +25,7 @@ impl OP_SHA256 {
             .to_vec();
 
         // Increment the ops counter.
-        stack_holder.increment_ops(SHA256_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_sha256)?;
 
         // Push the hash back to the main stack.
         stack_holder.push(StackItem::new(hash))?;
@@ -1,3 +1,4 @@
+use crate::executive::opcode::ops::OpsSchedule;
 use crate::executive::stack::{
     stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
@@ -32,7 +33,7 @@ impl OP_TAGGEDHASH {
         };
 
         // Increment the ops counter.
-        stack_holder.increment_ops(calculate_ops(preimage.len()))?;
+        stack_holder.increment_ops(calculate_ops(stack_holder.ops_schedule(), preimage.len()))?;
 
         // Push the hash back to the main stack.
         stack_holder.push(StackItem::new(hash.to_vec()))?;
@@ -46,12 +47,10 @@ impl OP_TAGGEDHASH {
     }
 }
 
-const TAGGEDHASH_OPS_BASE: u32 = 10;
-const TAGGEDHASH_OPS_MULTIPLIER: u32 = 1;
 const TAGGEDHASH_OPS_OUTPUT_LEN: u32 = 32;
 
 // Calculate the number of ops for a OP_TAGGEDHASH opcode.
-fn calculate_ops(preimage_len: u32) -> u32 {
+fn calculate_ops(ops_schedule: &OpsSchedule, preimage_len: u32) -> u32 {
     // Calculate the gap between the preimage length and the output length.
     let gap = match TAGGEDHASH_OPS_OUTPUT_LEN.checked_sub(preimage_len) {
         Some(gap) => gap,
@@ -59,5 +58,5 @@ fn calculate_ops(preimage_len: u32) -> u32 {
     };
 
     // Return the number of ops.
-    TAGGEDHASH_OPS_BASE + (TAGGEDHASH_OPS_MULTIPLIER * gap)
+    ops_schedule.op_taggedhash_base + (ops_schedule.op_taggedhash_multiplier * gap)
 }
@@ -0,0 +1,106 @@
+use crate::{
+    executive::{
+        opcode::ops::OpsSchedule,
+        program::{compiler::compiler::ProgramCompiler, program::Program},
+        stack::{
+            stack_error::{DeployError, StackError},
+            stack_holder::StackHolder,
+            stack_item::StackItem,
+        },
+    },
+    inscriptive::repo::repo::PROGRAMS_REPO,
+};
+
+/// Deploys a new contract from the running contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct OP_DEPLOY;
+
+impl OP_DEPLOY {
+    pub async fn execute(
+        stack_holder: &mut StackHolder,
+        programs_repo: &PROGRAMS_REPO,
+    ) -> Result<(), StackError> {
+        // If this is not the active execution, return immediately.
+        if !stack_holder.active_execution() {
+            return Ok(());
+        }
+
+        // Pop the salt from the main stack.
+        let salt_item = stack_holder.pop()?;
+
+        // Pop the bytecode source from the main stack.
+        let source_item = stack_holder.pop()?;
+
+        // Pop the bytecode, or the memory key holding it, from the main stack.
+        let code_item = stack_holder.pop()?;
+
+        // Convert the salt to bytes.
+        let salt: [u8; 32] = salt_item
+            .bytes()
+            .try_into()
+            .map_err(|_| StackError::DeployError(DeployError::InvalidDeploySaltBytes))?;
+
+        // Get the program bytecode.
+        let bytecode: Vec<u8> = match source_item.bytes() {
+            // Empty source: the bytecode is the stack item itself.
+            [] => code_item.bytes().to_vec(),
+            // 0x01 source: the stack item is the memory key holding the bytecode.
+            [0x01] => stack_holder
+                .memory()
+                .get(code_item.bytes())
+                .cloned()
+                .ok_or(StackError::DeployError(
+                    DeployError::DeployBytecodeNotFoundInMemory,
+                ))?,
+            _ => {
+                return Err(StackError::DeployError(DeployError::InvalidDeploySource));
+            }
+        };
+
+        // Increment the ops counter before decompiling.
+        stack_holder.increment_ops(calculate_ops(
+            stack_holder.ops_schedule(),
+            bytecode.len() as u32,
+        ))?;
+
+        // Decompile the program, which also runs the method validations.
+        let mut bytecode_stream = bytecode.into_iter();
+        let program = Program::decompile(&mut bytecode_stream)
+            .map_err(|e| StackError::DeployError(DeployError::ProgramDecompileError(e)))?;
+
+        // The bytecode must be consumed entirely.
+        if bytecode_stream.next().is_some() {
+            return Err(StackError::DeployError(DeployError::TrailingBytecode));
+        }
+
+        // Derive the contract id from the deployer contract id, the salt and the program.
+        let contract_id = program.factory_contract_id(stack_holder.contract_id(), salt);
+
+        // Insert the program ephemerally, to be saved along with the session.
+        {
+            let mut _programs_repo = programs_repo.lock().await;
+            _programs_repo
+                .insert_ephemeral(&contract_id, &program)
+                .map_err(|_| {
+                    StackError::DeployError(DeployError::ContractAlreadyDeployed(contract_id))
+                })?;
+        }
+
+        // Push the new contract id to the main stack.
+        stack_holder.push(StackItem::new(contract_id.to_vec()))?;
+
+        Ok(())
+    }
+
+    /// Returns the bytecode for the `OP_DEPLOY` opcode (0xd0).
+    pub fn bytecode() -> Vec<u8> {
+        vec![0xd0]
+    }
+}
+
+// Calculate the number of ops for a OP_DEPLOY opcode.
+fn calculate_ops(ops_schedule: &OpsSchedule, bytecode_length: u32) -> u32 {
+    // Return the number of ops.
+    ops_schedule.op_deploy_base + (ops_schedule.op_deploy_multiplier * bytecode_length)
+}
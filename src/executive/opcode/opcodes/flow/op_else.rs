@@ -1,10 +1,7 @@
-use crate::executive::{
-    opcode::ops::OP_ELSE_OPS,
-    stack::{
-        flow::{flow_encounter::FlowEncounter, flow_status::FlowStatus},
-        stack_error::StackError,
-        stack_holder::StackHolder,
-    },
+use crate::executive::stack::{
+    flow::{flow_encounter::FlowEncounter, flow_status::FlowStatus},
+    stack_error::StackError,
+    stack_holder::StackHolder,
 };
 
 /// The `OP_ELSE` opcode.
@@ -38,7 +35,7 @@ impl OP_ELSE {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_ELSE_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_else)?;
 
         Ok(())
     }
@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_FAIL_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// Fails the execution.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -16,7 +13,7 @@ impl OP_FAIL {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_FAIL_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_fail)?;
 
         // Fail the execution.
         Err(StackError::FailError)
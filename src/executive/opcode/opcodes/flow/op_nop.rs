@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_NOP_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// The `OP_NOP` opcode.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,7 +12,7 @@ impl OP_NOP {
             return Ok(());
         }
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_NOP_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_nop)?;
 
         Ok(())
     }
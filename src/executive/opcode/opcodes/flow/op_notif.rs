@@ -1,10 +1,7 @@
-use crate::executive::{
-    opcode::ops::OP_NOTIF_OPS,
-    stack::{
-        flow::{flow_encounter::FlowEncounter, flow_status::FlowStatus},
-        stack_error::StackError,
-        stack_holder::StackHolder,
-    },
+use crate::executive::stack::{
+    flow::{flow_encounter::FlowEncounter, flow_status::FlowStatus},
+    stack_error::StackError,
+    stack_holder::StackHolder,
 };
 
 /// The `OP_NOTIF` opcode.
@@ -15,7 +12,7 @@ pub struct OP_NOTIF;
 impl OP_NOTIF {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_NOTIF_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_notif)?;
 
         // If this is not the active execution, return.
         if !stack_holder.active_execution() {
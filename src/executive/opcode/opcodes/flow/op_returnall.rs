@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_RETURNALL_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Returns all items from the main stack.
@@ -27,7 +26,7 @@ impl OP_RETURNALL {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_RETURNALL_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_returnall)?;
 
         Ok(items)
     }
@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_RETURNERR_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Returns an error item from the stack.
@@ -19,7 +18,7 @@ impl OP_RETURNERR {
         let error_item = stack_holder.pop()?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_RETURNERR_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_returnerr)?;
 
         Ok(error_item)
     }
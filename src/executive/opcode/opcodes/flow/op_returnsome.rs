@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_RETURNSOME_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::{SafeConverter, StackItemUintExt},
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::{SafeConverter, StackItemUintExt},
 };
 
 /// Returns some number of items from the main stack.
@@ -41,7 +38,7 @@ impl OP_RETURNSOME {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_RETURNSOME_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_returnsome)?;
 
         Ok(items)
     }
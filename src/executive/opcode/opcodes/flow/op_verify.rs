@@ -1,9 +1,6 @@
-use crate::executive::{
-    opcode::ops::OP_VERIFY_OPS,
-    stack::{
-        stack_error::{MandatoryError, StackError},
-        stack_holder::StackHolder,
-    },
+use crate::executive::stack::{
+    stack_error::{MandatoryError, StackError},
+    stack_holder::StackHolder,
 };
 
 /// Pops an item from the main stack and checks if it is true. Fails if it is not.
@@ -29,7 +26,7 @@ impl OP_VERIFY {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_VERIFY_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_verify)?;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_MFREE_OPS,
-    stack::{
-        limits::{MAX_KEY_LENGTH, MIN_KEY_LENGTH},
-        stack_error::{MemoryError, StackError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-    },
+use crate::executive::stack::{
+    limits::{MAX_KEY_LENGTH, MIN_KEY_LENGTH},
+    stack_error::{MemoryError, StackError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
 };
 
 /// The `OP_MFREE` opcode.
@@ -42,7 +39,7 @@ impl OP_MFREE {
         };
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_MFREE_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_mfree)?;
 
         // Push result to stack.
         stack_holder.push(sweep_result_item)?;
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_MREAD_OPS,
-    stack::{
-        limits::{MAX_KEY_LENGTH, MIN_KEY_LENGTH},
-        stack_error::{MemoryError, StackError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-    },
+use crate::executive::stack::{
+    limits::{MAX_KEY_LENGTH, MIN_KEY_LENGTH},
+    stack_error::{MemoryError, StackError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
 };
 
 /// The `OP_MREAD` opcode.
@@ -42,7 +39,7 @@ impl OP_MREAD {
         };
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_MREAD_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_mread)?;
 
         // Push result to stack.
         stack_holder.push(value)?;
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_MWRITE_OPS,
-    stack::{
-        limits::{MAX_CONTRACT_MEMORY_SIZE, MAX_KEY_LENGTH, MIN_KEY_LENGTH, MIN_VALUE_LENGTH},
-        stack_error::{MemoryError, StackError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-    },
+use crate::executive::stack::{
+    limits::{MAX_CONTRACT_MEMORY_SIZE, MAX_KEY_LENGTH, MIN_KEY_LENGTH, MIN_VALUE_LENGTH},
+    stack_error::{MemoryError, StackError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
 };
 
 /// The `OP_MWRITE` opcode.
@@ -67,7 +64,7 @@ impl OP_MWRITE {
             };
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_MWRITE_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_mwrite)?;
 
         // Update the contract's memory size.
         stack_holder.update_memory_size(new_contract_memory_size);
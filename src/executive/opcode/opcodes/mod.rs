@@ -1,9 +1,11 @@
 pub mod altstack;
 pub mod arithmetic;
+pub mod beacon;
 pub mod bitwise;
 pub mod call;
 pub mod callinfo;
 pub mod digest;
+pub mod factory;
 pub mod flow;
 pub mod memory;
 pub mod payment;
@@ -12,5 +14,7 @@ pub mod reserved;
 pub mod secp;
 pub mod signature;
 pub mod splice;
+pub mod spv;
 pub mod stack;
 pub mod storage;
+pub mod transient;
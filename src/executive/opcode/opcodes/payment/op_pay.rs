@@ -15,9 +15,6 @@ use crate::executive::{
 #[allow(non_camel_case_types)]
 pub struct OP_PAY;
 
-/// The number of ops for the `OP_PAY` opcode.
-pub const PAY_OPS: u32 = 10;
-
 impl OP_PAY {
     pub fn execute(
         stack_holder: &mut StackHolder,
@@ -76,7 +73,7 @@ impl OP_PAY {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(PAY_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_pay)?;
 
         Ok(())
     }
@@ -10,9 +10,6 @@ use crate::executive::stack::{
 #[allow(non_camel_case_types)]
 pub struct OP_PAYABLEALLOC;
 
-/// The number of ops for the `OP_PAYABLEALLOC` opcode.
-pub const PAYABLEALLOC_OPS: u32 = 1;
-
 impl OP_PAYABLEALLOC {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -35,7 +32,7 @@ impl OP_PAYABLEALLOC {
         stack_holder.push(allocated_payable_amount_as_stack_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(PAYABLEALLOC_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_payablealloc)?;
 
         Ok(())
     }
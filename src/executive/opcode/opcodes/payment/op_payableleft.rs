@@ -10,9 +10,6 @@ use crate::executive::stack::{
 #[allow(non_camel_case_types)]
 pub struct OP_PAYABLELEFT;
 
-/// The number of ops for the `OP_PAYABLELEFT` opcode.
-pub const PAYABLELEFT_OPS: u32 = 1;
-
 impl OP_PAYABLELEFT {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -34,7 +31,7 @@ impl OP_PAYABLELEFT {
         stack_holder.push(payable_left_amount_as_stack_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(PAYABLELEFT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_payableleft)?;
 
         Ok(())
     }
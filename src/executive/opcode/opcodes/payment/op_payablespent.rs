@@ -10,9 +10,6 @@ use crate::executive::stack::{
 #[allow(non_camel_case_types)]
 pub struct OP_PAYABLESPENT;
 
-/// The number of ops for the `OP_PAYABLESPENT` opcode.
-pub const PAYABLESPENT_OPS: u32 = 1;
-
 impl OP_PAYABLESPENT {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -34,7 +31,7 @@ impl OP_PAYABLESPENT {
         stack_holder.push(spent_payable_amount_as_stack_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(PAYABLESPENT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_payablespent)?;
 
         Ok(())
     }
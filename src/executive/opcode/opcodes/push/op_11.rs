@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_11_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Pushes number 11 (0x0b) to the main stack.
@@ -19,7 +18,7 @@ impl OP_11 {
         let item_to_push = StackItem::new(vec![0x0b]);
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_11_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_11)?;
 
         // Push the item to the main stack.
         stack_holder.push(item_to_push)?;
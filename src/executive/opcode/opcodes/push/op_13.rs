@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_13_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Pushes number 13 (0x0d) to the main stack.
@@ -19,7 +18,7 @@ impl OP_13 {
         let item_to_push = StackItem::new(vec![0x0d]);
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_13_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_13)?;
 
         // Push the item to the main stack.
         stack_holder.push(item_to_push)?;
@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_FALSE_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Pushes number 0, also known as false (empty byte array) to the main stack.
@@ -19,7 +18,7 @@ impl OP_FALSE {
         let item_to_push = StackItem::new(vec![]);
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_FALSE_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_false)?;
 
         // Push the item to the main stack.
         stack_holder.push(item_to_push)?;
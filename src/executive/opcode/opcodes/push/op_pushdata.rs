@@ -15,6 +15,7 @@ use super::op_8::OP_8;
 use super::op_9::OP_9;
 use super::op_false::OP_FALSE;
 use super::op_true::OP_TRUE;
+use crate::executive::opcode::ops::OpsSchedule;
 use crate::executive::stack::{
     limits::MAX_STACK_ITEM_SIZE, stack_error::StackError, stack_holder::StackHolder,
     stack_item::StackItem,
@@ -48,7 +49,7 @@ impl OP_PUSHDATA {
         stack_holder.push(item_to_push)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(calculate_ops(data_len))?;
+        stack_holder.increment_ops(calculate_ops(stack_holder.ops_schedule(), data_len))?;
 
         Ok(())
     }
@@ -171,10 +172,7 @@ impl OP_PUSHDATA {
     }
 }
 
-const PUSHDATA_OPS_BASE: u32 = 1;
-const PUSHDATA_OPS_MULTIPLIER: u32 = 1;
-
 // Calculate the number of ops for a push data opcode.
-fn calculate_ops(data_len: u32) -> u32 {
-    PUSHDATA_OPS_BASE + (PUSHDATA_OPS_MULTIPLIER * data_len)
+fn calculate_ops(ops_schedule: &OpsSchedule, data_len: u32) -> u32 {
+    ops_schedule.op_pushdata_base + (ops_schedule.op_pushdata_multiplier * data_len)
 }
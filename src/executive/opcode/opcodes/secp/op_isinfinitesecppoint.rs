@@ -10,9 +10,6 @@ use secp::MaybePoint;
 #[allow(non_camel_case_types)]
 pub struct OP_ISINFINITESECPPOINT;
 
-/// The number of ops for the `OP_ISINFINITESECPPOINT` opcode.
-pub const ISINFINITESECPPOINT_OPS: u32 = 1;
-
 impl OP_ISINFINITESECPPOINT {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -39,7 +36,7 @@ impl OP_ISINFINITESECPPOINT {
         stack_holder.push(result_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(ISINFINITESECPPOINT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_isinfinitesecppoint)?;
 
         Ok(())
     }
@@ -10,9 +10,6 @@ use crate::executive::stack::{
 #[allow(non_camel_case_types)]
 pub struct OP_ISZEROSECPSCALAR;
 
-/// The number of ops for the `OP_ISZEROSECPSCALAR` opcode.
-pub const ISZEROSECPSCALAR_OPS: u32 = 1;
-
 impl OP_ISZEROSECPSCALAR {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -42,7 +39,7 @@ impl OP_ISZEROSECPSCALAR {
         stack_holder.push(result_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(ISZEROSECPSCALAR_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_iszerosecpscalar)?;
 
         Ok(())
     }
@@ -8,9 +8,6 @@ use secp::Point;
 #[allow(non_camel_case_types)]
 pub struct OP_PUSHSECPGENERATORPOINT;
 
-/// The number of ops for the `OP_PUSHSECPGENERATORPOINT` opcode.
-pub const PUSHSECPGENERATORPOINT_OPS: u32 = 1;
-
 impl OP_PUSHSECPGENERATORPOINT {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -29,7 +26,7 @@ impl OP_PUSHSECPGENERATORPOINT {
         stack_holder.push(generator_point_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(PUSHSECPGENERATORPOINT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_pushsecpgeneratorpoint)?;
 
         Ok(())
     }
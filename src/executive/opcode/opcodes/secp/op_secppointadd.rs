@@ -10,9 +10,6 @@ use secp::MaybePoint;
 #[allow(non_camel_case_types)]
 pub struct OP_SECPPOINTADD;
 
-/// The number of ops for the `OP_SECPPOINTADD` opcode.
-pub const SECPSPOINTADD_OPS: u32 = 10;
-
 impl OP_SECPPOINTADD {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -48,7 +45,7 @@ impl OP_SECPPOINTADD {
         stack_holder.push(addition_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(SECPSPOINTADD_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_secppointadd)?;
 
         Ok(())
     }
@@ -11,9 +11,6 @@ use secp::MaybePoint;
 #[allow(non_camel_case_types)]
 pub struct OP_SECPPOINTMUL;
 
-/// The number of ops for the `OP_SECPPOINTMUL` opcode.
-pub const SECPPOINTMUL_OPS: u32 = 10;
-
 impl OP_SECPPOINTMUL {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -52,7 +49,7 @@ impl OP_SECPPOINTMUL {
         stack_holder.push(multiplication_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(SECPPOINTMUL_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_secppointmul)?;
 
         Ok(())
     }
@@ -10,9 +10,6 @@ use crate::executive::stack::{
 #[allow(non_camel_case_types)]
 pub struct OP_SECPSCALARADD;
 
-/// The number of ops for the `OP_SECPSCALARADD` opcode.
-pub const SECPSCALARADD_OPS: u32 = 3;
-
 impl OP_SECPSCALARADD {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -57,7 +54,7 @@ impl OP_SECPSCALARADD {
         stack_holder.push(addition_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(SECPSCALARADD_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_secpscalaradd)?;
 
         Ok(())
     }
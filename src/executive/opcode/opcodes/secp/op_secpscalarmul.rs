@@ -10,9 +10,6 @@ use crate::executive::stack::{
 #[allow(non_camel_case_types)]
 pub struct OP_SECPSCALARMUL;
 
-/// The number of ops for the `OP_SECPSCALARMUL` opcode.
-pub const SECPSCALARMUL_OPS: u32 = 5;
-
 impl OP_SECPSCALARMUL {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -57,7 +54,7 @@ impl OP_SECPSCALARMUL {
         stack_holder.push(multiplication_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(SECPSCALARMUL_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_secpscalarmul)?;
 
         Ok(())
     }
@@ -1,4 +1,6 @@
 pub mod op_checkblssigagg;
 pub mod op_checkblssig;
+pub mod op_checkecdsasig;
 pub mod op_checkschnorrsig;
 pub mod op_checkschnorrsigbip340;
+pub mod op_ecdsarecover;
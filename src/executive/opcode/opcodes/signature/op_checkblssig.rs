@@ -12,9 +12,6 @@ use crate::{
 #[allow(non_camel_case_types)]
 pub struct OP_CHECKBLSSIG;
 
-/// The number of ops for the `OP_CHECKBLSSIG` opcode.
-pub const CHECKBLSSIG_OPS: u32 = 100;
-
 impl OP_CHECKBLSSIG {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -62,7 +59,7 @@ impl OP_CHECKBLSSIG {
         stack_holder.push(result_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(CHECKBLSSIG_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_checkblssig)?;
 
         Ok(())
     }
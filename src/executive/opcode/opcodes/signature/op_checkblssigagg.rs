@@ -1,9 +1,12 @@
 use crate::{
-    executive::stack::{
-        stack_error::{BLSError, StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::StackItemUintExt,
+    executive::{
+        opcode::ops::OpsSchedule,
+        stack::{
+            stack_error::{BLSError, StackError, StackUintError},
+            stack_holder::StackHolder,
+            stack_item::StackItem,
+            stack_uint::StackItemUintExt,
+        },
     },
     transmutative::bls::{key::BLSPublicKey, verify::bls_verify_aggregate},
 };
@@ -85,7 +88,7 @@ impl OP_CHECKBLSSIGAGG {
         stack_holder.push(result_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(calculate_ops(count as u32))?;
+        stack_holder.increment_ops(calculate_ops(stack_holder.ops_schedule(), count as u32))?;
 
         Ok(())
     }
@@ -96,11 +99,8 @@ impl OP_CHECKBLSSIGAGG {
     }
 }
 
-const CHECKBLSSIGAGG_OPS_BASE: u32 = 100;
-const CHECKBLSSIGAGG_OPS_MULTIPLIER: u32 = 50;
-
 // Calculate the number of ops for a CHECKBLSSIGAGG opcode.
-fn calculate_ops(count: u32) -> u32 {
+fn calculate_ops(ops_schedule: &OpsSchedule, count: u32) -> u32 {
     // Return the number of ops.
-    CHECKBLSSIGAGG_OPS_BASE + (CHECKBLSSIGAGG_OPS_MULTIPLIER * count)
+    ops_schedule.op_checkblssigagg_base + (ops_schedule.op_checkblssigagg_multiplier * count)
 }
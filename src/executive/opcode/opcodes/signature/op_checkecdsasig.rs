@@ -0,0 +1,74 @@
+use crate::{
+    executive::stack::{
+        stack_error::{ECDSAError, StackError},
+        stack_holder::StackHolder,
+        stack_item::StackItem,
+    },
+    transmutative::secp::ecdsa,
+};
+
+/// Checks a secp256k1 ECDSA signature against a key and a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct OP_CHECKECDSASIG;
+
+impl OP_CHECKECDSASIG {
+    pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
+        // If this is not the active execution, return immediately.
+        if !stack_holder.active_execution() {
+            return Ok(());
+        }
+
+        // Pop public key from the stack.
+        let public_key = stack_holder.pop()?;
+
+        // Pop message from the stack.
+        let message = stack_holder.pop()?;
+
+        // Pop signature from the stack.
+        let signature = stack_holder.pop()?;
+
+        // Check the public key length.
+        // NOTE: This can be a 33-byte compressed or a 65-byte uncompressed public key.
+        if public_key.len() != 33 && public_key.len() != 65 {
+            return Err(StackError::ECDSAError(
+                ECDSAError::InvalidECDSAPublicKeyBytes,
+            ));
+        }
+
+        // Convert message to bytes.
+        let message_bytes: [u8; 32] = message
+            .bytes()
+            .try_into()
+            .map_err(|_| StackError::ECDSAError(ECDSAError::InvalidECDSAMessageBytes))?;
+
+        // Convert signature to bytes.
+        // NOTE: The signature is the 64-byte compact (r || s) encoding.
+        let signature_bytes: [u8; 64] = signature
+            .bytes()
+            .try_into()
+            .map_err(|_| StackError::ECDSAError(ECDSAError::InvalidECDSASignatureBytes))?;
+
+        // Verify the signature.
+        let verify_result = ecdsa::verify(public_key.bytes(), message_bytes, signature_bytes);
+
+        // Get the result item.
+        let result_item = match verify_result {
+            true => StackItem::true_item(),
+            false => StackItem::false_item(),
+        };
+
+        // Push the results to the main stack.
+        stack_holder.push(result_item)?;
+
+        // Increment the ops counter.
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_checkecdsasig)?;
+
+        Ok(())
+    }
+
+    /// Returns the bytecode for the `OP_CHECKECDSASIG` opcode (0xca).
+    pub fn bytecode() -> Vec<u8> {
+        vec![0xca]
+    }
+}
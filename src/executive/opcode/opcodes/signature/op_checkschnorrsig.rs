@@ -12,9 +12,6 @@ use crate::{
 #[allow(non_camel_case_types)]
 pub struct OP_CHECKSCHNORRSIG;
 
-/// The number of ops for the `OP_CHECKSCHNORRSIG` opcode.
-pub const CHECKSCHNORRSIG_OPS: u32 = 100;
-
 impl OP_CHECKSCHNORRSIG {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -107,7 +104,7 @@ impl OP_CHECKSCHNORRSIG {
         stack_holder.push(result_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(CHECKSCHNORRSIG_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_checkschnorrsig)?;
 
         Ok(())
     }
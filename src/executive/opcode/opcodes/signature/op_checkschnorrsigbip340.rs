@@ -12,9 +12,6 @@ use crate::{
 #[allow(non_camel_case_types)]
 pub struct OP_CHECKSCHNORRSIGBIP340;
 
-/// The number of ops for the `OP_CHECKSCHNORRSIGBIP340` opcode.
-pub const CHECKSCHNORRSIGBIP340_OPS: u32 = 100;
-
 impl OP_CHECKSCHNORRSIGBIP340 {
     pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
         // If this is not the active execution, return immediately.
@@ -108,7 +105,7 @@ impl OP_CHECKSCHNORRSIGBIP340 {
         stack_holder.push(result_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(CHECKSCHNORRSIGBIP340_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_checkschnorrsigbip340)?;
 
         Ok(())
     }
@@ -0,0 +1,67 @@
+use crate::{
+    executive::stack::{
+        stack_error::{ECDSAError, StackError},
+        stack_holder::StackHolder,
+        stack_item::StackItem,
+    },
+    transmutative::secp::ecdsa,
+};
+
+/// Recovers the secp256k1 public key from an ECDSA signature and a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct OP_ECDSARECOVER;
+
+impl OP_ECDSARECOVER {
+    pub fn execute(stack_holder: &mut StackHolder) -> Result<(), StackError> {
+        // If this is not the active execution, return immediately.
+        if !stack_holder.active_execution() {
+            return Ok(());
+        }
+
+        // Pop message from the stack.
+        let message = stack_holder.pop()?;
+
+        // Pop signature from the stack.
+        let signature = stack_holder.pop()?;
+
+        // Convert message to bytes.
+        let message_bytes: [u8; 32] = message
+            .bytes()
+            .try_into()
+            .map_err(|_| StackError::ECDSAError(ECDSAError::InvalidECDSAMessageBytes))?;
+
+        // Convert signature to bytes.
+        // NOTE: The signature is the 65-byte recoverable (r || s || v) encoding.
+        let signature_bytes: [u8; 65] = signature
+            .bytes()
+            .try_into()
+            .map_err(|_| StackError::ECDSAError(ECDSAError::InvalidECDSASignatureBytes))?;
+
+        // Check the recovery id.
+        if !matches!(signature_bytes[64], 0 | 1 | 27 | 28) {
+            return Err(StackError::ECDSAError(ECDSAError::InvalidECDSARecoveryId));
+        }
+
+        // Get the result item.
+        // NOTE: An unrecoverable signature results in a false (empty) item. High-S signatures are
+        // not rejected, to match Ethereum's `ecrecover`.
+        let result_item = match ecdsa::recover(message_bytes, signature_bytes) {
+            Some(public_key) => StackItem::new(public_key.to_vec()),
+            None => StackItem::false_item(),
+        };
+
+        // Push the result to the main stack.
+        stack_holder.push(result_item)?;
+
+        // Increment the ops counter.
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_ecdsarecover)?;
+
+        Ok(())
+    }
+
+    /// Returns the bytecode for the `OP_ECDSARECOVER` opcode (0xcb).
+    pub fn bytecode() -> Vec<u8> {
+        vec![0xcb]
+    }
+}
@@ -1,6 +1,5 @@
-use crate::executive::{
-    opcode::ops::OP_CAT_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem},
+use crate::executive::stack::{
+    stack_error::StackError, stack_holder::StackHolder, stack_item::StackItem,
 };
 
 /// Concatenates two items on the main stack.
@@ -30,7 +29,7 @@ impl OP_CAT {
         stack_holder.push(StackItem::new(joined))?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_CAT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_cat)?;
 
         Ok(())
     }
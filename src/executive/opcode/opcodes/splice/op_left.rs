@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_LEFT_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::{SafeConverter, StackItemUintExt},
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::{SafeConverter, StackItemUintExt},
 };
 
 /// Keeps only characters left of the specified point in a string.
@@ -58,7 +55,7 @@ impl OP_LEFT {
         stack_holder.push(StackItem::new(left_slice))?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_LEFT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_left)?;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_RIGHT_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::{SafeConverter, StackItemUintExt},
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::{SafeConverter, StackItemUintExt},
 };
 
 /// Keeps only characters right of the specified point in a string.
@@ -58,7 +55,7 @@ impl OP_RIGHT {
         stack_holder.push(StackItem::new(right_slice))?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_RIGHT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_right)?;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_SIZE_OPS,
-    stack::{
-        stack_error::StackError,
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::{SafeConverter, StackItemUintExt, StackUint},
-    },
+use crate::executive::stack::{
+    stack_error::StackError,
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::{SafeConverter, StackItemUintExt, StackUint},
 };
 
 /// Pushes the string length of the top element of the stack (without popping it).
@@ -36,7 +33,7 @@ impl OP_SIZE {
         stack_holder.push(item_size_stack_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_SIZE_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_size)?;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_SPLIT_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::{SafeConverter, StackItemUintExt},
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::{SafeConverter, StackItemUintExt},
 };
 
 /// Splits the byte array into two stack items at the index.
@@ -64,7 +61,7 @@ impl OP_SPLIT {
         stack_holder.push(right_slice)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_SPLIT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_split)?;
 
         Ok(())
     }
@@ -0,0 +1,202 @@
+use crate::{
+    executive::{
+        opcode::ops::OpsSchedule,
+        stack::{
+            stack_error::{SPVError, StackError, StackUintError},
+            stack_holder::StackHolder,
+            stack_item::StackItem,
+            stack_uint::{SafeConverter, StackItemUintExt},
+        },
+    },
+    inscriptive::rollup::dir::ROLLUP_DIRECTORY,
+};
+use bitcoin::hashes::{sha256d, Hash};
+use bitcoin::{consensus::deserialize, Transaction};
+
+/// The minimum number of confirmations a block must have, relative to the
+/// session's anchored Bitcoin height, for its header to be used.
+pub const SPV_MIN_CONFIRMATIONS: u64 = 6;
+
+/// The maximum depth of a Bitcoin Merkle branch.
+pub const SPV_BRANCH_MAX_DEPTH: u32 = 24;
+
+/// Checks whether a Bitcoin transaction is included in the block at a given height.
+///
+/// Block headers are only indexed from the chain's sync start height, so transactions in blocks
+/// below it cannot be checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct OP_CHECKBTCINCLUSION;
+
+impl OP_CHECKBTCINCLUSION {
+    pub async fn execute(
+        stack_holder: &mut StackHolder,
+        rollup_dir: &ROLLUP_DIRECTORY,
+        bitcoin_height: u64,
+    ) -> Result<(), StackError> {
+        // If this is not the active execution, return immediately.
+        if !stack_holder.active_execution() {
+            return Ok(());
+        }
+
+        // Pop the block height from the main stack.
+        let height_item = stack_holder.pop()?;
+
+        // Pop the transaction index from the main stack.
+        let index_item = stack_holder.pop()?;
+
+        // Pop the Merkle branch from the main stack.
+        let branch_item = stack_holder.pop()?;
+
+        // Pop the raw transaction or txid from the main stack.
+        let tx_item = stack_holder.pop()?;
+
+        // Convert the height to a u64.
+        let height = height_item
+            .to_stack_uint()
+            .ok_or(StackError::StackUintError(
+                StackUintError::StackUintConversionError,
+            ))?
+            .to_u64()
+            .ok_or(StackError::StackUintError(
+                StackUintError::StackUintConversionError,
+            ))?;
+
+        // The block must be buried deep enough under the anchored height, so that
+        // every node resolves the same header regardless of its own sync progress.
+        match height.checked_add(SPV_MIN_CONFIRMATIONS) {
+            Some(confirmed_height) if confirmed_height <= bitcoin_height + 1 => {}
+            _ => {
+                return Err(StackError::SPVError(SPVError::SPVHeightNotConfirmed(
+                    height,
+                )))
+            }
+        }
+
+        // The branch is a concatenation of 32-byte sibling hashes, from the leaf level up.
+        if branch_item.len() % 32 != 0 {
+            return Err(StackError::SPVError(SPVError::InvalidSPVBranchLength));
+        }
+
+        // Get the branch depth.
+        let depth = branch_item.len() / 32;
+
+        // Check if the branch depth is valid.
+        if depth > SPV_BRANCH_MAX_DEPTH {
+            return Err(StackError::SPVError(SPVError::SPVBranchDepthExceeded));
+        }
+
+        // Convert the index to a u64.
+        let index = index_item
+            .to_stack_uint()
+            .ok_or(StackError::StackUintError(
+                StackUintError::StackUintConversionError,
+            ))?
+            .to_u64()
+            .ok_or(StackError::StackUintError(
+                StackUintError::StackUintConversionError,
+            ))?;
+
+        // The index must point to a leaf within the tree.
+        if index >= (1u64 << depth) {
+            return Err(StackError::SPVError(SPVError::SPVIndexOutOfRange));
+        }
+
+        // Get the txid, in internal byte order.
+        let txid: [u8; 32] = match tx_item.len() {
+            // A 32-byte item is treated as the txid itself.
+            32 => tx_item
+                .bytes()
+                .try_into()
+                .map_err(|_| StackError::SPVError(SPVError::InvalidSPVTxBytes))?,
+            // 64-byte transactions are rejected, as they can be mistaken for inner Merkle nodes.
+            64 => return Err(StackError::SPVError(SPVError::InvalidSPVTxBytes)),
+            // Otherwise, the item is a raw transaction.
+            _ => {
+                let tx: Transaction = deserialize(tx_item.bytes())
+                    .map_err(|_| StackError::SPVError(SPVError::InvalidSPVTxBytes))?;
+                tx.compute_txid().to_byte_array()
+            }
+        };
+
+        // Increment the ops counter before hashing.
+        stack_holder.increment_ops(calculate_ops(
+            stack_holder.ops_schedule(),
+            depth,
+            tx_item.len() as u32,
+        ))?;
+
+        // Get the block header at the given height.
+        let header = {
+            let _rollup_dir = rollup_dir.lock().await;
+            _rollup_dir
+                .header(height)
+                .ok_or(StackError::SPVError(SPVError::SPVHeaderNotFound(height)))?
+        };
+
+        // Compute the Merkle root from the branch.
+        let merkle_root = btc_merkle_root(txid, branch_item.bytes(), index);
+
+        // Get the result item.
+        let result_item = match merkle_root == header.merkle_root.to_byte_array() {
+            true => StackItem::true_item(),
+            false => StackItem::false_item(),
+        };
+
+        // Push the result to the main stack.
+        stack_holder.push(result_item)?;
+
+        Ok(())
+    }
+
+    /// Returns the bytecode for the `OP_CHECKBTCINCLUSION` opcode (0xcd).
+    pub fn bytecode() -> Vec<u8> {
+        vec![0xcd]
+    }
+}
+
+/// Computes the Bitcoin Merkle root from a txid, its Merkle branch and its index in the block.
+///
+/// The txid and the branch hashes are in internal byte order.
+pub fn btc_merkle_root(txid: [u8; 32], branch: &[u8], index: u64) -> [u8; 32] {
+    let mut node = txid;
+
+    for (level, sibling) in branch.chunks(32).enumerate() {
+        let mut preimage = Vec::<u8>::with_capacity(64);
+
+        // The index bit at this level tells whether the node is a left or right child.
+        match (index >> level) & 1 {
+            0 => {
+                preimage.extend(node);
+                preimage.extend(sibling);
+            }
+            _ => {
+                preimage.extend(sibling);
+                preimage.extend(node);
+            }
+        }
+
+        node = sha256d::Hash::hash(&preimage).to_byte_array();
+    }
+
+    node
+}
+
+const CHECKBTCINCLUSION_TX_BYTES_PER_OP: u32 = 64;
+
+// Calculate the number of ops for a OP_CHECKBTCINCLUSION opcode.
+fn calculate_ops(ops_schedule: &OpsSchedule, depth: u32, tx_len: u32) -> u32 {
+    // Raw transactions are hashed into a txid first.
+    let tx_ops = match tx_len {
+        32 => 0,
+        _ => {
+            ops_schedule.op_checkbtcinclusion_multiplier
+                + (tx_len / CHECKBTCINCLUSION_TX_BYTES_PER_OP)
+        }
+    };
+
+    // Return the number of ops.
+    ops_schedule.op_checkbtcinclusion_base
+        + (ops_schedule.op_checkbtcinclusion_multiplier * depth)
+        + tx_ops
+}
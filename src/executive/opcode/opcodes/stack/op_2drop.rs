@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_2DROP_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// Removes the top two stack items.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -20,7 +17,7 @@ impl OP_2DROP {
         stack_holder.pop()?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_2DROP_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_2drop)?;
 
         Ok(())
     }
@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_2OVER_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// Copies the pair of items two spaces back in the stack to the front.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -28,7 +25,7 @@ impl OP_2OVER {
         stack_holder.push(third_to_top_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_2OVER_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_2over)?;
 
         Ok(())
     }
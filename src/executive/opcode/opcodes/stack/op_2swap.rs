@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_2SWAP_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// Swaps the top two pairs of items.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -34,7 +31,7 @@ impl OP_2SWAP {
         stack_holder.push(third_to_top_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_2SWAP_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_2swap)?;
 
         Ok(())
     }
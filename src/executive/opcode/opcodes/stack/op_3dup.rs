@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_3DUP_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// Duplicates the top three stack items.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -34,7 +31,7 @@ impl OP_3DUP {
         stack_holder.push(last_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_3DUP_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_3dup)?;
 
         Ok(())
     }
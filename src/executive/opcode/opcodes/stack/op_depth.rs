@@ -1,11 +1,8 @@
-use crate::executive::{
-    opcode::ops::OP_DEPTH_OPS,
-    stack::{
-        stack_error::StackError,
-        stack_holder::StackHolder,
-        stack_item::StackItem,
-        stack_uint::{SafeConverter, StackItemUintExt, StackUint},
-    },
+use crate::executive::stack::{
+    stack_error::StackError,
+    stack_holder::StackHolder,
+    stack_item::StackItem,
+    stack_uint::{SafeConverter, StackItemUintExt, StackUint},
 };
 
 /// Puts the number of stack items onto the stack.
@@ -30,7 +27,7 @@ impl OP_DEPTH {
         let depth_as_stack_item = StackItem::from_stack_uint(depth_as_stack_uint);
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_DEPTH_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_depth)?;
 
         // Push the depth to the main stack.
         stack_holder.push(depth_as_stack_item)?;
@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_DROP_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// Drops the last item from the main stack.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,7 +16,7 @@ impl OP_DROP {
         stack_holder.pop()?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_DROP_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_drop)?;
 
         Ok(())
     }
@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_DUP_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// Duplicates the last item on the main stack.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,7 +16,7 @@ impl OP_DUP {
         let last_item = stack_holder.last_item()?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_DUP_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_dup)?;
 
         // Push the cloned value back to the main stack.
         stack_holder.push(last_item)?;
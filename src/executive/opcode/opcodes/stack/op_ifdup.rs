@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_IFDUP_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// Duplicates the last item on the main stack if it is not zero.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -28,7 +25,7 @@ impl OP_IFDUP {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_IFDUP_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_ifdup)?;
 
         Ok(())
     }
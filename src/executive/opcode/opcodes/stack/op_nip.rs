@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_NIP_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// Removes the second-to-top stack item.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,7 +16,7 @@ impl OP_NIP {
         stack_holder.remove_item_by_depth(1)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_NIP_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_nip)?;
 
         Ok(())
     }
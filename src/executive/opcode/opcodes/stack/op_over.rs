@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_OVER_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// Copies the second-to-top stack item to the top.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -22,7 +19,7 @@ impl OP_OVER {
         stack_holder.push(second_to_top_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_OVER_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_over)?;
 
         Ok(())
     }
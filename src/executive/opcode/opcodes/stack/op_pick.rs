@@ -1,10 +1,7 @@
-use crate::executive::{
-    opcode::ops::OP_PICK_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_uint::StackItemUintExt,
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_uint::StackItemUintExt,
 };
 
 /// Retrieves an item from the main stack by cloning it to the top of the stack.
@@ -31,7 +28,7 @@ impl OP_PICK {
         let item = stack_holder.item_by_depth(pick_depth.as_u32())?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_PICK_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_pick)?;
 
         // Push the item onto the stack.
         stack_holder.push(item)?;
@@ -1,10 +1,7 @@
-use crate::executive::{
-    opcode::ops::OP_ROLL_OPS,
-    stack::{
-        stack_error::{StackError, StackUintError},
-        stack_holder::StackHolder,
-        stack_uint::StackItemUintExt,
-    },
+use crate::executive::stack::{
+    stack_error::{StackError, StackUintError},
+    stack_holder::StackHolder,
+    stack_uint::StackItemUintExt,
 };
 
 /// Rolls an item from the main stack to the top of the stack.
@@ -31,7 +28,7 @@ impl OP_ROLL {
         let item = stack_holder.item_by_depth(pick_depth.as_u32())?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_ROLL_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_roll)?;
 
         // Remove the item at the pick depth.
         stack_holder.remove_item_by_depth(pick_depth.as_u32())?;
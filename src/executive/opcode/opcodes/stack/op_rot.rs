@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_ROT_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// The 3rd item down the stack is moved to the top.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -25,7 +22,7 @@ impl OP_ROT {
         stack_holder.push(third_to_top_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_ROT_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_rot)?;
 
         Ok(())
     }
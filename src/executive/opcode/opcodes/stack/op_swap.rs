@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_SWAP_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// The top two items on the stack are swapped.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -25,7 +22,7 @@ impl OP_SWAP {
         stack_holder.push(second_to_top_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_SWAP_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_swap)?;
 
         Ok(())
     }
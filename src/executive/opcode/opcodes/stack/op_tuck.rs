@@ -1,7 +1,4 @@
-use crate::executive::{
-    opcode::ops::OP_TUCK_OPS,
-    stack::{stack_error::StackError, stack_holder::StackHolder},
-};
+use crate::executive::stack::{stack_error::StackError, stack_holder::StackHolder};
 
 /// The item at the top of the stack is copied and inserted before the second-to-top item.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -31,7 +28,7 @@ impl OP_TUCK {
         stack_holder.push(last_item)?;
 
         // Increment the ops counter.
-        stack_holder.increment_ops(OP_TUCK_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_tuck)?;
 
         Ok(())
     }
@@ -13,9 +13,6 @@ use crate::{
 #[allow(non_camel_case_types)]
 pub struct OP_SREAD;
 
-/// The number of ops for the `OP_SREAD` opcode.
-pub const SREAD_OPS: u32 = 50;
-
 impl OP_SREAD {
     pub async fn execute(
         stack_holder: &mut StackHolder,
@@ -53,7 +50,7 @@ impl OP_SREAD {
         }
 
         // Increment the ops counter.
-        stack_holder.increment_ops(SREAD_OPS)?;
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_sread)?;
 
         Ok(())
     }
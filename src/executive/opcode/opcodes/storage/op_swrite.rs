@@ -1,8 +1,11 @@
 use crate::{
-    executive::stack::{
-        limits::{MAX_KEY_LENGTH, MIN_KEY_LENGTH, MIN_VALUE_LENGTH},
-        stack_error::{StackError, StorageError},
-        stack_holder::StackHolder,
+    executive::{
+        opcode::ops::OpsSchedule,
+        stack::{
+            limits::{MAX_KEY_LENGTH, MIN_KEY_LENGTH, MIN_VALUE_LENGTH},
+            stack_error::{StackError, StorageError},
+            stack_holder::StackHolder,
+        },
     },
     inscriptive::state::state_holder::STATE_HOLDER,
 };
@@ -55,7 +58,11 @@ impl OP_SWRITE {
         }
 
         // Calculate the number of ops.
-        let ops = calculate_ops(key.len() as u32, value.len() as u32);
+        let ops = calculate_ops(
+            stack_holder.ops_schedule(),
+            key.len() as u32,
+            value.len() as u32,
+        );
 
         // Increment the ops counter.
         stack_holder.increment_ops(ops)?;
@@ -69,12 +76,9 @@ impl OP_SWRITE {
     }
 }
 
-const SWRITE_OPS_BASE: u32 = 50;
-const SWRITE_OPS_MULTIPLIER: u32 = 1;
-
 // Calculate the number of ops for a SWRITE opcode.
-fn calculate_ops(key_length: u32, value_length: u32) -> u32 {
+fn calculate_ops(ops_schedule: &OpsSchedule, key_length: u32, value_length: u32) -> u32 {
     let total_length = key_length + value_length;
     // Return the number of ops.
-    SWRITE_OPS_BASE + (SWRITE_OPS_MULTIPLIER * total_length)
+    ops_schedule.op_swrite_base + (ops_schedule.op_swrite_multiplier * total_length)
 }
@@ -0,0 +1,2 @@
+pub mod op_tread;
+pub mod op_twrite;
@@ -0,0 +1,60 @@
+use crate::executive::{
+    exec::transient::transient::TransientStorage,
+    stack::{
+        limits::{MAX_KEY_LENGTH, MIN_KEY_LENGTH},
+        stack_error::{StackError, TransientError},
+        stack_holder::StackHolder,
+        stack_item::StackItem,
+    },
+};
+
+/// The `OP_TREAD` opcode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct OP_TREAD;
+
+impl OP_TREAD {
+    pub fn execute(
+        stack_holder: &mut StackHolder,
+        transient_storage: &TransientStorage,
+    ) -> Result<(), StackError> {
+        // If this is not the active execution, return immediately.
+        if !stack_holder.active_execution() {
+            return Ok(());
+        }
+
+        // Pop key
+        let key = stack_holder.pop()?;
+
+        // Make sure key is within the valid length range (1 to 40 bytes).
+        if key.len() < MIN_KEY_LENGTH || key.len() > MAX_KEY_LENGTH {
+            return Err(StackError::TransientError(
+                TransientError::InvalidTransientKeyLength(key.len() as u8),
+            ));
+        }
+
+        // Read from transient storage.
+        let read_value =
+            transient_storage.get_value(&key.bytes().to_vec(), &stack_holder.contract_id());
+
+        // Push the read value to the main stack.
+        match read_value {
+            Some(read_value) => {
+                stack_holder.push(StackItem::new(read_value))?;
+            }
+            None => {
+                stack_holder.push(StackItem::false_item())?;
+            }
+        }
+
+        // Increment the ops counter.
+        stack_holder.increment_ops(stack_holder.ops_schedule().op_tread)?;
+
+        Ok(())
+    }
+
+    /// Returns the bytecode for the `OP_TREAD` opcode (0xcf).
+    pub fn bytecode() -> Vec<u8> {
+        vec![0xcf]
+    }
+}
@@ -0,0 +1,87 @@
+use crate::executive::{
+    exec::transient::transient::TransientStorage,
+    opcode::ops::OpsSchedule,
+    stack::{
+        limits::{MAX_CONTRACT_TRANSIENT_SIZE, MAX_KEY_LENGTH, MIN_KEY_LENGTH, MIN_VALUE_LENGTH},
+        stack_error::{StackError, TransientError},
+        stack_holder::StackHolder,
+    },
+};
+
+/// The `OP_TWRITE` opcode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct OP_TWRITE;
+
+impl OP_TWRITE {
+    pub fn execute(
+        stack_holder: &mut StackHolder,
+        transient_storage: &mut TransientStorage,
+    ) -> Result<(), StackError> {
+        // If this is not the active execution, return immediately.
+        if !stack_holder.active_execution() {
+            return Ok(());
+        }
+
+        // Pop key
+        let key = stack_holder.pop()?;
+
+        // Make sure key is within the valid length range (1 to 40 bytes).
+        if key.len() < MIN_KEY_LENGTH || key.len() > MAX_KEY_LENGTH {
+            return Err(StackError::TransientError(
+                TransientError::InvalidTransientKeyLength(key.len() as u8),
+            ));
+        }
+
+        // Pop value
+        let value = stack_holder.pop()?;
+
+        // Make sure value is within the valid length range (1 to 4095 bytes).
+        // NOTE: The maximum length of the value is bound by the stack item size limit.
+        if value.len() < MIN_VALUE_LENGTH {
+            return Err(StackError::TransientError(
+                TransientError::InvalidTransientValueLength(value.len() as u8),
+            ));
+        }
+
+        let contract_id = stack_holder.contract_id();
+        let key_bytes = key.bytes().to_vec();
+
+        // Get the contract's transient storage size, excluding the value being overwritten.
+        let contract_transient_size = transient_storage.contract_size(&contract_id)
+            - match transient_storage.get_value(&key_bytes, &contract_id) {
+                Some(existing_value) => key.len() + existing_value.len() as u32,
+                None => 0,
+            };
+
+        // Make sure the new transient storage size is within the limit.
+        if contract_transient_size + key.len() + value.len() >= MAX_CONTRACT_TRANSIENT_SIZE {
+            return Err(StackError::TransientError(
+                TransientError::ContractTransientSizeLimitExceeded,
+            ));
+        }
+
+        // Calculate the number of ops.
+        let ops = calculate_ops(stack_holder.ops_schedule(), key.len(), value.len());
+
+        // Increment the ops counter.
+        stack_holder.increment_ops(ops)?;
+
+        // Write to transient storage.
+        transient_storage.insert_value(&contract_id, &key_bytes, &value.bytes().to_vec());
+
+        Ok(())
+    }
+
+    /// Returns the bytecode for the `OP_TWRITE` opcode (0xce).
+    pub fn bytecode() -> Vec<u8> {
+        vec![0xce]
+    }
+}
+
+// Calculate the number of ops for a TWRITE opcode.
+fn calculate_ops(ops_schedule: &OpsSchedule, key_length: u32, value_length: u32) -> u32 {
+    let total_length = key_length + value_length;
+    // Return the number of ops.
+    ops_schedule.op_twrite_base + (ops_schedule.op_twrite_multiplier * total_length)
+}
@@ -1,104 +1,548 @@
-// Data push
-pub const OP_FALSE_OPS: u32 = 1;
-pub const OP_TRUE_OPS: u32 = 1;
-pub const OP_2_OPS: u32 = 1;
-pub const OP_3_OPS: u32 = 1;
-pub const OP_4_OPS: u32 = 1;
-pub const OP_5_OPS: u32 = 1;
-pub const OP_6_OPS: u32 = 1;
-pub const OP_7_OPS: u32 = 1;
-pub const OP_8_OPS: u32 = 1;
-pub const OP_9_OPS: u32 = 1;
-pub const OP_10_OPS: u32 = 1;
-pub const OP_11_OPS: u32 = 1;
-pub const OP_12_OPS: u32 = 1;
-pub const OP_13_OPS: u32 = 1;
-pub const OP_14_OPS: u32 = 1;
-pub const OP_15_OPS: u32 = 1;
-pub const OP_16_OPS: u32 = 1;
-
-// Flow
-pub const OP_NOP_OPS: u32 = 1;
-pub const OP_RETURNERR_OPS: u32 = 1;
-pub const OP_IF_OPS: u32 = 1;
-pub const OP_NOTIF_OPS: u32 = 1;
-pub const OP_ELSE_OPS: u32 = 1;
-pub const OP_ENDIF_OPS: u32 = 1;
-pub const OP_VERIFY_OPS: u32 = 1;
-pub const OP_RETURNALL_OPS: u32 = 1;
-pub const OP_RETURNSOME_OPS: u32 = 1;
-pub const OP_FAIL_OPS: u32 = 1;
-
-// Alt stack
-pub const OP_FROMALTSTACK_OPS: u32 = 1;
-pub const OP_TOALTSTACK_OPS: u32 = 1;
-
-// Stack operations
-pub const OP_2DROP_OPS: u32 = 2;
-pub const OP_2DUP_OPS: u32 = 2;
-pub const OP_3DUP_OPS: u32 = 3;
-pub const OP_2OVER_OPS: u32 = 2;
-pub const OP_2ROT_OPS: u32 = 2;
-pub const OP_2SWAP_OPS: u32 = 2;
-pub const OP_IFDUP_OPS: u32 = 1;
-pub const OP_DEPTH_OPS: u32 = 1;
-pub const OP_DROP_OPS: u32 = 1;
-pub const OP_DUP_OPS: u32 = 1;
-pub const OP_NIP_OPS: u32 = 1;
-pub const OP_OVER_OPS: u32 = 1;
-pub const OP_PICK_OPS: u32 = 1;
-pub const OP_ROLL_OPS: u32 = 1;
-pub const OP_ROT_OPS: u32 = 1;
-pub const OP_SWAP_OPS: u32 = 1;
-pub const OP_TUCK_OPS: u32 = 1;
-
-// Splice
-pub const OP_CAT_OPS: u32 = 2;
-pub const OP_SPLIT_OPS: u32 = 2;
-pub const OP_LEFT_OPS: u32 = 2;
-pub const OP_RIGHT_OPS: u32 = 2;
-pub const OP_SIZE_OPS: u32 = 1;
-
-// Bitwise
-pub const OP_INVERT_OPS: u32 = 2;
-pub const OP_AND_OPS: u32 = 2;
-pub const OP_OR_OPS: u32 = 2;
-pub const OP_XOR_OPS: u32 = 2;
-pub const OP_EQUAL_OPS: u32 = 1;
-pub const OP_EQUALVERIFY_OPS: u32 = 2;
-pub const OP_REVERSE_OPS: u32 = 3;
-
-// Arithmetic
-pub const OP_1ADD_OPS: u32 = 3;
-pub const OP_1SUB_OPS: u32 = 3;
-pub const OP_2MUL_OPS: u32 = 5;
-pub const OP_2DIV_OPS: u32 = 5;
-pub const OP_ADDMOD_OPS: u32 = 3;
-pub const OP_MULMOD_OPS: u32 = 3;
-pub const OP_NOT_OPS: u32 = 1;
-pub const OP_0NOTEQUAL_OPS: u32 = 1;
-pub const OP_ADD_OPS: u32 = 3;
-pub const OP_SUB_OPS: u32 = 3;
-pub const OP_MUL_OPS: u32 = 5;
-pub const OP_DIV_OPS: u32 = 5;
-pub const OP_LSHIFT_OPS: u32 = 3;
-pub const OP_RSHIFT_OPS: u32 = 3;
-pub const OP_BOOLAND_OPS: u32 = 2;
-pub const OP_BOOLOR_OPS: u32 = 2;
-pub const OP_NUMEQUAL_OPS: u32 = 1;
-pub const OP_NUMEQUALVERIFY_OPS: u32 = 2;
-pub const OP_NUMNOTEQUAL_OPS: u32 = 1;
-pub const OP_LESSTHAN_OPS: u32 = 1;
-pub const OP_GREATERTHAN_OPS: u32 = 1;
-pub const OP_LESSTHANOREQUAL_OPS: u32 = 1;
-pub const OP_GREATERTHANOREQUAL_OPS: u32 = 1;
-pub const OP_MIN_OPS: u32 = 1;
-pub const OP_MAX_OPS: u32 = 1;
-pub const OP_WITHIN_OPS: u32 = 1;
-
-// Crypto
-
-// Memory
-pub const OP_MREAD_OPS: u32 = 5;
-pub const OP_MWRITE_OPS: u32 = 5;
-pub const OP_MFREE_OPS: u32 = 1;
+use super::opcode::Opcode;
+
+/// A versioned schedule of the ops each opcode costs.
+///
+/// Opcodes read their costs from the schedule active at the session height being executed,
+/// rather than from hard-coded constants. A re-tuned schedule is added with a new version and
+/// a later activation height, so that historical calls re-execute with the costs they were
+/// originally charged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpsSchedule {
+    /// The schedule version.
+    pub version: u32,
+    /// The session height the schedule activates at.
+    pub activation_height: u64,
+
+    // Data push
+    pub op_false: u32,
+    pub op_true: u32,
+    pub op_2: u32,
+    pub op_3: u32,
+    pub op_4: u32,
+    pub op_5: u32,
+    pub op_6: u32,
+    pub op_7: u32,
+    pub op_8: u32,
+    pub op_9: u32,
+    pub op_10: u32,
+    pub op_11: u32,
+    pub op_12: u32,
+    pub op_13: u32,
+    pub op_14: u32,
+    pub op_15: u32,
+    pub op_16: u32,
+    pub op_pushdata_base: u32,
+    pub op_pushdata_multiplier: u32,
+
+    // Flow
+    pub op_nop: u32,
+    pub op_returnerr: u32,
+    pub op_if: u32,
+    pub op_notif: u32,
+    pub op_else: u32,
+    pub op_endif: u32,
+    pub op_verify: u32,
+    pub op_returnall: u32,
+    pub op_returnsome: u32,
+    pub op_fail: u32,
+
+    // Alt stack
+    pub op_fromaltstack: u32,
+    pub op_toaltstack: u32,
+
+    // Stack operations
+    pub op_2drop: u32,
+    pub op_2dup: u32,
+    pub op_3dup: u32,
+    pub op_2over: u32,
+    pub op_2rot: u32,
+    pub op_2swap: u32,
+    pub op_ifdup: u32,
+    pub op_depth: u32,
+    pub op_drop: u32,
+    pub op_dup: u32,
+    pub op_nip: u32,
+    pub op_over: u32,
+    pub op_pick: u32,
+    pub op_roll: u32,
+    pub op_rot: u32,
+    pub op_swap: u32,
+    pub op_tuck: u32,
+
+    // Splice
+    pub op_cat: u32,
+    pub op_split: u32,
+    pub op_left: u32,
+    pub op_right: u32,
+    pub op_size: u32,
+
+    // Bitwise
+    pub op_invert: u32,
+    pub op_and: u32,
+    pub op_or: u32,
+    pub op_xor: u32,
+    pub op_equal: u32,
+    pub op_equalverify: u32,
+    pub op_reverse: u32,
+
+    // Arithmetic
+    pub op_1add: u32,
+    pub op_1sub: u32,
+    pub op_2mul: u32,
+    pub op_2div: u32,
+    pub op_addmod: u32,
+    pub op_mulmod: u32,
+    pub op_not: u32,
+    pub op_0notequal: u32,
+    pub op_add: u32,
+    pub op_sub: u32,
+    pub op_mul: u32,
+    pub op_div: u32,
+    pub op_lshift: u32,
+    pub op_rshift: u32,
+    pub op_booland: u32,
+    pub op_boolor: u32,
+    pub op_numequal: u32,
+    pub op_numequalverify: u32,
+    pub op_numnotequal: u32,
+    pub op_lessthan: u32,
+    pub op_greaterthan: u32,
+    pub op_lessthanorequal: u32,
+    pub op_greaterthanorequal: u32,
+    pub op_min: u32,
+    pub op_max: u32,
+    pub op_within: u32,
+
+    // Memory
+    pub op_mread: u32,
+    pub op_mwrite: u32,
+    pub op_mfree: u32,
+
+    // Digest
+    pub op_blake2bvar_base: u32,
+    pub op_blake2bvar_multiplier: u32,
+    pub op_blake2svar_base: u32,
+    pub op_blake2svar_multiplier: u32,
+    pub op_hash160: u32,
+    pub op_hash256: u32,
+    pub op_keccak256: u32,
+    pub op_merkleverify_base: u32,
+    pub op_merkleverify_multiplier: u32,
+    pub op_ripemd160: u32,
+    pub op_sha1: u32,
+    pub op_sha256: u32,
+    pub op_taggedhash_base: u32,
+    pub op_taggedhash_multiplier: u32,
+
+    // Signature
+    pub op_checkblssig: u32,
+    pub op_checkblssigagg_base: u32,
+    pub op_checkblssigagg_multiplier: u32,
+    pub op_checkecdsasig: u32,
+    pub op_checkschnorrsig: u32,
+    pub op_checkschnorrsigbip340: u32,
+    pub op_ecdsarecover: u32,
+
+    // Secp
+    pub op_isinfinitesecppoint: u32,
+    pub op_iszerosecpscalar: u32,
+    pub op_pushsecpgeneratorpoint: u32,
+    pub op_secppointadd: u32,
+    pub op_secppointmul: u32,
+    pub op_secpscalaradd: u32,
+    pub op_secpscalarmul: u32,
+
+    // Call
+    pub op_call: u32,
+    pub op_callext: u32,
+
+    // Call info
+    pub op_caller: u32,
+    pub op_opsbudget: u32,
+    pub op_opscounter: u32,
+    pub op_opsprice: u32,
+    pub op_timestamp: u32,
+
+    // Payment
+    pub op_pay: u32,
+    pub op_payablealloc: u32,
+    pub op_payableleft: u32,
+    pub op_payablespent: u32,
+
+    // Storage
+    pub op_sread: u32,
+    pub op_swrite_base: u32,
+    pub op_swrite_multiplier: u32,
+
+    // Transient storage
+    pub op_tread: u32,
+    pub op_twrite_base: u32,
+    pub op_twrite_multiplier: u32,
+
+    // Factory
+    pub op_deploy_base: u32,
+    pub op_deploy_multiplier: u32,
+
+    // SPV
+    pub op_checkbtcinclusion_base: u32,
+    pub op_checkbtcinclusion_multiplier: u32,
+
+    // Beacon
+    pub op_beacon: u32,
+}
+
+/// The initial ops schedule.
+pub const OPS_SCHEDULE_V1: OpsSchedule = OpsSchedule {
+    version: 1,
+    activation_height: 0,
+
+    // Data push
+    op_false: 1,
+    op_true: 1,
+    op_2: 1,
+    op_3: 1,
+    op_4: 1,
+    op_5: 1,
+    op_6: 1,
+    op_7: 1,
+    op_8: 1,
+    op_9: 1,
+    op_10: 1,
+    op_11: 1,
+    op_12: 1,
+    op_13: 1,
+    op_14: 1,
+    op_15: 1,
+    op_16: 1,
+    op_pushdata_base: 1,
+    op_pushdata_multiplier: 1,
+
+    // Flow
+    op_nop: 1,
+    op_returnerr: 1,
+    op_if: 1,
+    op_notif: 1,
+    op_else: 1,
+    op_endif: 1,
+    op_verify: 1,
+    op_returnall: 1,
+    op_returnsome: 1,
+    op_fail: 1,
+
+    // Alt stack
+    op_fromaltstack: 1,
+    op_toaltstack: 1,
+
+    // Stack operations
+    op_2drop: 2,
+    op_2dup: 2,
+    op_3dup: 3,
+    op_2over: 2,
+    op_2rot: 2,
+    op_2swap: 2,
+    op_ifdup: 1,
+    op_depth: 1,
+    op_drop: 1,
+    op_dup: 1,
+    op_nip: 1,
+    op_over: 1,
+    op_pick: 1,
+    op_roll: 1,
+    op_rot: 1,
+    op_swap: 1,
+    op_tuck: 1,
+
+    // Splice
+    op_cat: 2,
+    op_split: 2,
+    op_left: 2,
+    op_right: 2,
+    op_size: 1,
+
+    // Bitwise
+    op_invert: 2,
+    op_and: 2,
+    op_or: 2,
+    op_xor: 2,
+    op_equal: 1,
+    op_equalverify: 2,
+    op_reverse: 3,
+
+    // Arithmetic
+    op_1add: 3,
+    op_1sub: 3,
+    op_2mul: 5,
+    op_2div: 5,
+    op_addmod: 3,
+    op_mulmod: 3,
+    op_not: 1,
+    op_0notequal: 1,
+    op_add: 3,
+    op_sub: 3,
+    op_mul: 5,
+    op_div: 5,
+    op_lshift: 3,
+    op_rshift: 3,
+    op_booland: 2,
+    op_boolor: 2,
+    op_numequal: 1,
+    op_numequalverify: 2,
+    op_numnotequal: 1,
+    op_lessthan: 1,
+    op_greaterthan: 1,
+    op_lessthanorequal: 1,
+    op_greaterthanorequal: 1,
+    op_min: 1,
+    op_max: 1,
+    op_within: 1,
+
+    // Memory
+    op_mread: 5,
+    op_mwrite: 5,
+    op_mfree: 1,
+
+    // Digest
+    op_blake2bvar_base: 10,
+    op_blake2bvar_multiplier: 1,
+    op_blake2svar_base: 10,
+    op_blake2svar_multiplier: 1,
+    op_hash160: 72,
+    op_hash256: 84,
+    op_keccak256: 42,
+    op_merkleverify_base: 10,
+    op_merkleverify_multiplier: 42,
+    op_ripemd160: 30,
+    op_sha1: 30,
+    op_sha256: 42,
+    op_taggedhash_base: 10,
+    op_taggedhash_multiplier: 1,
+
+    // Signature
+    op_checkblssig: 100,
+    op_checkblssigagg_base: 100,
+    op_checkblssigagg_multiplier: 50,
+    op_checkecdsasig: 100,
+    op_checkschnorrsig: 100,
+    op_checkschnorrsigbip340: 100,
+    op_ecdsarecover: 150,
+
+    // Secp
+    op_isinfinitesecppoint: 1,
+    op_iszerosecpscalar: 1,
+    op_pushsecpgeneratorpoint: 1,
+    op_secppointadd: 10,
+    op_secppointmul: 10,
+    op_secpscalaradd: 3,
+    op_secpscalarmul: 5,
+
+    // Call
+    op_call: 5,
+    op_callext: 50,
+
+    // Call info
+    op_caller: 1,
+    op_opsbudget: 1,
+    op_opscounter: 1,
+    op_opsprice: 1,
+    op_timestamp: 1,
+
+    // Payment
+    op_pay: 10,
+    op_payablealloc: 1,
+    op_payableleft: 1,
+    op_payablespent: 1,
+
+    // Storage
+    op_sread: 50,
+    op_swrite_base: 50,
+    op_swrite_multiplier: 1,
+
+    // Transient storage
+    op_tread: 20,
+    op_twrite_base: 20,
+    op_twrite_multiplier: 1,
+
+    // Factory
+    op_deploy_base: 500,
+    op_deploy_multiplier: 2,
+
+    // SPV
+    op_checkbtcinclusion_base: 100,
+    op_checkbtcinclusion_multiplier: 84,
+
+    // Beacon
+    op_beacon: 50,
+};
+
+/// All the ops schedules, ordered by activation height.
+pub static OPS_SCHEDULES: [OpsSchedule; 1] = [OPS_SCHEDULE_V1];
+
+impl OpsSchedule {
+    /// Returns the ops schedule active at the given session height.
+    pub fn at_height(session_height: u64) -> &'static OpsSchedule {
+        OpsSchedule::active_in(&OPS_SCHEDULES, session_height)
+    }
+
+    /// Returns the schedule active at the given session height, out of a list of schedules
+    /// ordered by activation height. Falls back to the first schedule below its activation.
+    pub fn active_in(schedules: &[OpsSchedule], session_height: u64) -> &OpsSchedule {
+        schedules
+            .iter()
+            .rev()
+            .find(|schedule| schedule.activation_height <= session_height)
+            .unwrap_or(&schedules[0])
+    }
+
+    /// Returns the ops schedule of the given version.
+    pub fn by_version(version: u32) -> Option<&'static OpsSchedule> {
+        OPS_SCHEDULES
+            .iter()
+            .find(|schedule| schedule.version == version)
+    }
+
+    /// Returns the flat ops cost of the given opcode, or the base cost of opcodes that also
+    /// charge per byte. Reserved opcodes cost nothing, as they fail without executing.
+    pub fn base_ops(&self, opcode: &Opcode) -> u32 {
+        match opcode {
+            Opcode::OP_FALSE(_) => self.op_false,
+            Opcode::OP_PUSHDATA(_) => self.op_pushdata_base,
+            Opcode::OP_TRUE(_) => self.op_true,
+            Opcode::OP_2(_) => self.op_2,
+            Opcode::OP_3(_) => self.op_3,
+            Opcode::OP_4(_) => self.op_4,
+            Opcode::OP_5(_) => self.op_5,
+            Opcode::OP_6(_) => self.op_6,
+            Opcode::OP_7(_) => self.op_7,
+            Opcode::OP_8(_) => self.op_8,
+            Opcode::OP_9(_) => self.op_9,
+            Opcode::OP_10(_) => self.op_10,
+            Opcode::OP_11(_) => self.op_11,
+            Opcode::OP_12(_) => self.op_12,
+            Opcode::OP_13(_) => self.op_13,
+            Opcode::OP_14(_) => self.op_14,
+            Opcode::OP_15(_) => self.op_15,
+            Opcode::OP_16(_) => self.op_16,
+            Opcode::OP_NOP(_) => self.op_nop,
+            Opcode::OP_RETURNERR(_) => self.op_returnerr,
+            Opcode::OP_IF(_) => self.op_if,
+            Opcode::OP_NOTIF(_) => self.op_notif,
+            Opcode::OP_ELSE(_) => self.op_else,
+            Opcode::OP_ENDIF(_) => self.op_endif,
+            Opcode::OP_VERIFY(_) => self.op_verify,
+            Opcode::OP_RETURNALL(_) => self.op_returnall,
+            Opcode::OP_RETURNSOME(_) => self.op_returnsome,
+            Opcode::OP_FAIL(_) => self.op_fail,
+            Opcode::OP_TOALTSTACK(_) => self.op_toaltstack,
+            Opcode::OP_FROMALTSTACK(_) => self.op_fromaltstack,
+            Opcode::OP_2DROP(_) => self.op_2drop,
+            Opcode::OP_2DUP(_) => self.op_2dup,
+            Opcode::OP_3DUP(_) => self.op_3dup,
+            Opcode::OP_2OVER(_) => self.op_2over,
+            Opcode::OP_2ROT(_) => self.op_2rot,
+            Opcode::OP_2SWAP(_) => self.op_2swap,
+            Opcode::OP_IFDUP(_) => self.op_ifdup,
+            Opcode::OP_DEPTH(_) => self.op_depth,
+            Opcode::OP_DROP(_) => self.op_drop,
+            Opcode::OP_DUP(_) => self.op_dup,
+            Opcode::OP_NIP(_) => self.op_nip,
+            Opcode::OP_OVER(_) => self.op_over,
+            Opcode::OP_PICK(_) => self.op_pick,
+            Opcode::OP_ROLL(_) => self.op_roll,
+            Opcode::OP_ROT(_) => self.op_rot,
+            Opcode::OP_SWAP(_) => self.op_swap,
+            Opcode::OP_TUCK(_) => self.op_tuck,
+            Opcode::OP_CAT(_) => self.op_cat,
+            Opcode::OP_SPLIT(_) => self.op_split,
+            Opcode::OP_LEFT(_) => self.op_left,
+            Opcode::OP_RIGHT(_) => self.op_right,
+            Opcode::OP_SIZE(_) => self.op_size,
+            Opcode::OP_INVERT(_) => self.op_invert,
+            Opcode::OP_AND(_) => self.op_and,
+            Opcode::OP_OR(_) => self.op_or,
+            Opcode::OP_XOR(_) => self.op_xor,
+            Opcode::OP_EQUAL(_) => self.op_equal,
+            Opcode::OP_EQUALVERIFY(_) => self.op_equalverify,
+            Opcode::OP_REVERSE(_) => self.op_reverse,
+            Opcode::OP_1ADD(_) => self.op_1add,
+            Opcode::OP_1SUB(_) => self.op_1sub,
+            Opcode::OP_2MUL(_) => self.op_2mul,
+            Opcode::OP_2DIV(_) => self.op_2div,
+            Opcode::OP_ADDMOD(_) => self.op_addmod,
+            Opcode::OP_MULMOD(_) => self.op_mulmod,
+            Opcode::OP_NOT(_) => self.op_not,
+            Opcode::OP_0NOTEQUAL(_) => self.op_0notequal,
+            Opcode::OP_ADD(_) => self.op_add,
+            Opcode::OP_SUB(_) => self.op_sub,
+            Opcode::OP_MUL(_) => self.op_mul,
+            Opcode::OP_DIV(_) => self.op_div,
+            Opcode::OP_LSHIFT(_) => self.op_lshift,
+            Opcode::OP_RSHIFT(_) => self.op_rshift,
+            Opcode::OP_BOOLAND(_) => self.op_booland,
+            Opcode::OP_BOOLOR(_) => self.op_boolor,
+            Opcode::OP_NUMEQUAL(_) => self.op_numequal,
+            Opcode::OP_NUMEQUALVERIFY(_) => self.op_numequalverify,
+            Opcode::OP_NUMNOTEQUAL(_) => self.op_numnotequal,
+            Opcode::OP_LESSTHAN(_) => self.op_lessthan,
+            Opcode::OP_GREATERTHAN(_) => self.op_greaterthan,
+            Opcode::OP_LESSTHANOREQUAL(_) => self.op_lessthanorequal,
+            Opcode::OP_GREATERTHANOREQUAL(_) => self.op_greaterthanorequal,
+            Opcode::OP_MIN(_) => self.op_min,
+            Opcode::OP_MAX(_) => self.op_max,
+            Opcode::OP_WITHIN(_) => self.op_within,
+            Opcode::OP_RIPEMD160(_) => self.op_ripemd160,
+            Opcode::OP_SHA1(_) => self.op_sha1,
+            Opcode::OP_SHA256(_) => self.op_sha256,
+            Opcode::OP_HASH160(_) => self.op_hash160,
+            Opcode::OP_HASH256(_) => self.op_hash256,
+            Opcode::OP_TAGGEDHASH(_) => self.op_taggedhash_base,
+            Opcode::OP_BLAKE2BVAR(_) => self.op_blake2bvar_base,
+            Opcode::OP_BLAKE2SVAR(_) => self.op_blake2svar_base,
+            Opcode::OP_KECCAK256(_) => self.op_keccak256,
+            Opcode::OP_MERKLEVERIFY(_) => self.op_merkleverify_base,
+            Opcode::OP_SECPSCALARADD(_) => self.op_secpscalaradd,
+            Opcode::OP_SECPSCALARMUL(_) => self.op_secpscalarmul,
+            Opcode::OP_SECPPOINTADD(_) => self.op_secppointadd,
+            Opcode::OP_SECPPOINTMUL(_) => self.op_secppointmul,
+            Opcode::OP_PUSHSECPGENERATORPOINT(_) => self.op_pushsecpgeneratorpoint,
+            Opcode::OP_ISZEROSECPSCALAR(_) => self.op_iszerosecpscalar,
+            Opcode::OP_ISINFINITESECPPOINT(_) => self.op_isinfinitesecppoint,
+            Opcode::OP_CHECKSCHNORRSIG(_) => self.op_checkschnorrsig,
+            Opcode::OP_CHECKSCHNORRSIGBIP340(_) => self.op_checkschnorrsigbip340,
+            Opcode::OP_CHECKBLSSIG(_) => self.op_checkblssig,
+            Opcode::OP_CHECKBLSSIGAGG(_) => self.op_checkblssigagg_base,
+            Opcode::OP_CHECKECDSASIG(_) => self.op_checkecdsasig,
+            Opcode::OP_ECDSARECOVER(_) => self.op_ecdsarecover,
+            Opcode::OP_CALLER(_) => self.op_caller,
+            Opcode::OP_OPSBUDGET(_) => self.op_opsbudget,
+            Opcode::OP_OPSCOUNTER(_) => self.op_opscounter,
+            Opcode::OP_OPSPRICE(_) => self.op_opsprice,
+            Opcode::OP_TIMESTAMP(_) => self.op_timestamp,
+            Opcode::OP_CALL(_) => self.op_call,
+            Opcode::OP_CALLEXT(_) => self.op_callext,
+            Opcode::OP_PAYABLEALLOC(_) => self.op_payablealloc,
+            Opcode::OP_PAYABLESPENT(_) => self.op_payablespent,
+            Opcode::OP_PAYABLELEFT(_) => self.op_payableleft,
+            Opcode::OP_PAY(_) => self.op_pay,
+            Opcode::OP_MWRITE(_) => self.op_mwrite,
+            Opcode::OP_MREAD(_) => self.op_mread,
+            Opcode::OP_MFREE(_) => self.op_mfree,
+            Opcode::OP_SWRITE(_) => self.op_swrite_base,
+            Opcode::OP_SREAD(_) => self.op_sread,
+            Opcode::OP_CHECKBTCINCLUSION(_) => self.op_checkbtcinclusion_base,
+            Opcode::OP_TWRITE(_) => self.op_twrite_base,
+            Opcode::OP_TREAD(_) => self.op_tread,
+            Opcode::OP_DEPLOY(_) => self.op_deploy_base,
+            Opcode::OP_BEACON(_) => self.op_beacon,
+            Opcode::OP_RESERVED_1(_)
+            | Opcode::OP_RESERVED_2(_)
+            | Opcode::OP_RESERVED_3(_)
+            | Opcode::OP_RESERVED_4(_)
+            | Opcode::OP_RESERVED_5(_) => 0,
+        }
+    }
+
+    /// Returns the latest ops schedule.
+    pub fn latest() -> &'static OpsSchedule {
+        &OPS_SCHEDULES[OPS_SCHEDULES.len() - 1]
+    }
+}
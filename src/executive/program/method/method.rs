@@ -7,14 +7,17 @@ use super::{
     method_type::MethodType,
 };
 use crate::{
-    constructive::calldata::element_type::CallElementType,
+    constructive::calldata::{
+        element_type::CallElementType,
+        limits::{MAX_STRING_BYTE_LENGTH, MAX_VARARRAY_LENGTH},
+    },
     executive::{
         opcode::{
+            opcode::Opcode,
             opcodes::{
                 push::op_pushdata::OP_PUSHDATA,
                 reserved::{op_reserved_1::OP_RESERVED_1, op_reserved_2::OP_RESERVED_2},
             },
-            opcode::Opcode,
         },
         stack::{
             stack_item::StackItem,
@@ -142,58 +145,9 @@ impl ProgramMethod {
                 None => return false,
             };
 
-            // Check if the arg matches the arg type by looking at the byte size.
-            match arg_type {
-                CallElementType::U8 => {
-                    if arg.len() > 1 {
-                        return false;
-                    }
-                }
-                CallElementType::U16 => {
-                    if arg.len() > 2 {
-                        return false;
-                    }
-                }
-                CallElementType::U32 => {
-                    if arg.len() > 4 {
-                        return false;
-                    }
-                }
-                CallElementType::U64 => {
-                    if arg.len() > 8 {
-                        return false;
-                    }
-                }
-                CallElementType::Bool => {
-                    if arg.len() > 1 {
-                        return false;
-                    }
-                }
-                CallElementType::Account => {
-                    if arg.len() != 32 {
-                        return false;
-                    }
-                }
-                CallElementType::Contract => {
-                    if arg.len() != 32 {
-                        return false;
-                    }
-                }
-                CallElementType::Bytes(index) => {
-                    if arg.len() != *index as u32 + 1 {
-                        return false;
-                    }
-                }
-                CallElementType::Varbytes => {
-                    if arg.len() > 4096 {
-                        return false;
-                    }
-                }
-                CallElementType::Payable => {
-                    if arg.len() > 4 {
-                        return false;
-                    }
-                }
+            // Check if the arg matches the arg type.
+            if !match_arg(arg_type, arg.bytes()) {
+                return false;
             }
         }
 
@@ -235,6 +189,11 @@ impl ProgramMethod {
 
     /// Validates the args.
     pub fn validate_arg_types(&self) -> bool {
+        // Composite arg types must be well-formed.
+        if !self.arg_types.iter().all(|arg| arg.is_valid()) {
+            return false;
+        }
+
         // More than ONE payable is not allowed.
         self.arg_types
             .iter()
@@ -282,3 +241,72 @@ impl ProgramMethod {
         Value::Object(obj)
     }
 }
+
+/// Matches an arg to its arg type by looking at the byte size.
+fn match_arg(arg_type: &CallElementType, arg: &[u8]) -> bool {
+    match arg_type {
+        CallElementType::U8 => arg.len() <= 1,
+        CallElementType::U16 => arg.len() <= 2,
+        CallElementType::U32 => arg.len() <= 4,
+        CallElementType::U64 => arg.len() <= 8,
+        CallElementType::Bool => arg.len() <= 1,
+        CallElementType::Account => arg.len() == 32,
+        CallElementType::Contract => arg.len() == 32,
+        CallElementType::Bytes(index) => arg.len() == *index as usize + 1,
+        CallElementType::Varbytes => arg.len() <= 4096,
+        CallElementType::Payable => arg.len() <= 4,
+        CallElementType::U128 => arg.len() <= 16,
+        CallElementType::U256 => arg.len() <= 32,
+        CallElementType::String => {
+            arg.len() <= MAX_STRING_BYTE_LENGTH && std::str::from_utf8(arg).is_ok()
+        }
+        CallElementType::Array(item_type, index) => match split_composite_arg(arg) {
+            Some(items) => {
+                items.len() == *index as usize + 1
+                    && items.iter().all(|item| match_arg(item_type, item))
+            }
+            None => false,
+        },
+        CallElementType::Vararray(item_type) => match split_composite_arg(arg) {
+            Some(items) => {
+                items.len() <= MAX_VARARRAY_LENGTH
+                    && items.iter().all(|item| match_arg(item_type, item))
+            }
+            None => false,
+        },
+        CallElementType::Tuple(item_types) => match split_composite_arg(arg) {
+            Some(items) => {
+                items.len() == item_types.len()
+                    && items
+                        .iter()
+                        .zip(item_types.iter())
+                        .all(|(item, item_type)| match_arg(item_type, item))
+            }
+            None => false,
+        },
+    }
+}
+
+/// Splits a composite arg into its items, each prefixed with its byte length as a little-endian u16.
+fn split_composite_arg(arg: &[u8]) -> Option<Vec<&[u8]>> {
+    let mut items = Vec::<&[u8]>::new();
+    let mut rest = arg;
+
+    while !rest.is_empty() {
+        // Read the item length.
+        if rest.len() < 2 {
+            return None;
+        }
+        let item_length = u16::from_le_bytes([rest[0], rest[1]]) as usize;
+        rest = &rest[2..];
+
+        // Read the item.
+        if rest.len() < item_length {
+            return None;
+        }
+        items.push(&rest[..item_length]);
+        rest = &rest[item_length..];
+    }
+
+    Some(items)
+}
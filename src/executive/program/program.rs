@@ -161,6 +161,20 @@ impl Program {
         contract_id
     }
 
+    /// Returns the 32-bytes contract ID of the program when deployed by another contract.
+    ///
+    /// The ID commits to the deployer contract ID, the salt and the program contract ID, so
+    /// the same deployer can re-deploy the same program only with a different salt.
+    pub fn factory_contract_id(&self, deployer_contract_id: [u8; 32], salt: [u8; 32]) -> [u8; 32] {
+        let mut preimage = Vec::<u8>::with_capacity(96);
+        preimage.extend(deployer_contract_id);
+        preimage.extend(salt);
+        preimage.extend(self.contract_id());
+
+        // Return the factory contract ID.
+        preimage.hash(Some(HashTag::FactoryContractID))
+    }
+
     /// Returns the program as a JSON object.
     pub fn json(&self) -> Value {
         // Convert the methods to JSON.
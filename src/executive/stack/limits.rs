@@ -16,5 +16,8 @@ pub const MIN_VALUE_LENGTH: u32 = 1;
 /// The maximum byte size of a contract memory.
 pub const MAX_CONTRACT_MEMORY_SIZE: u32 = 65_536;
 
+/// The maximum byte size of a contract transient storage.
+pub const MAX_CONTRACT_TRANSIENT_SIZE: u32 = 65_536;
+
 // Ops upper bound.
 pub const OPS_LIMIT: u32 = 100_000;
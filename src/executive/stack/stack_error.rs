@@ -1,4 +1,7 @@
-use crate::executive::exec::accountant::accountant_error::InsertPaymentError;
+use crate::executive::{
+    exec::accountant::accountant_error::InsertPaymentError,
+    program::compiler::compiler_error::ProgramDecompileError,
+};
 
 /// The call error.
 #[derive(Debug, Clone)]
@@ -33,6 +36,53 @@ pub enum SchnorrError {
     InvalidSchnorrSignatureBytes,
 }
 
+/// The ECDSA error.
+#[derive(Debug, Clone)]
+pub enum ECDSAError {
+    /// The ECDSA public key is invalid.
+    InvalidECDSAPublicKeyBytes,
+    /// The ECDSA message is invalid.
+    InvalidECDSAMessageBytes,
+    /// The ECDSA signature is invalid.
+    InvalidECDSASignatureBytes,
+    /// The ECDSA recovery id is invalid.
+    InvalidECDSARecoveryId,
+}
+
+/// The Merkle proof error.
+#[derive(Debug, Clone)]
+pub enum MerkleError {
+    /// The Merkle leaf is invalid.
+    InvalidMerkleLeafBytes,
+    /// The Merkle root is invalid.
+    InvalidMerkleRootBytes,
+    /// The Merkle proof length is not a multiple of 32 bytes.
+    InvalidMerkleProofLength,
+    /// The Merkle proof depth exceeds the limit.
+    MerkleProofDepthExceeded,
+    /// The Merkle leaf index is out of range for the proof depth.
+    MerkleIndexOutOfRange,
+    /// The Merkle hash mode is invalid.
+    InvalidMerkleHashMode,
+}
+
+/// The Bitcoin SPV error.
+#[derive(Debug, Clone)]
+pub enum SPVError {
+    /// The raw transaction or txid is invalid.
+    InvalidSPVTxBytes,
+    /// The Merkle branch length is not a multiple of 32 bytes.
+    InvalidSPVBranchLength,
+    /// The Merkle branch depth exceeds the limit.
+    SPVBranchDepthExceeded,
+    /// The transaction index is out of range for the branch depth.
+    SPVIndexOutOfRange,
+    /// The block at the given height is not confirmed deep enough.
+    SPVHeightNotConfirmed(u64),
+    /// The block header at the given height is not indexed.
+    SPVHeaderNotFound(u64),
+}
+
 /// The secp error.
 #[derive(Debug, Clone)]
 pub enum SecpError {
@@ -69,6 +119,43 @@ pub enum StorageError {
     InvalidStorageValueLength(u8),
 }
 
+/// The transient storage error.
+#[derive(Debug, Clone)]
+pub enum TransientError {
+    /// The invalid transient key length error.
+    InvalidTransientKeyLength(u8),
+    /// The invalid transient value length error.
+    InvalidTransientValueLength(u8),
+    /// The transient storage size limit exceeded error.
+    ContractTransientSizeLimitExceeded,
+}
+
+/// The contract deployment error.
+#[derive(Debug, Clone)]
+pub enum DeployError {
+    /// The deploy salt is not 32 bytes.
+    InvalidDeploySaltBytes,
+    /// The bytecode source is invalid.
+    InvalidDeploySource,
+    /// The bytecode is not found in the contract memory.
+    DeployBytecodeNotFoundInMemory,
+    /// The program bytecode could not be decompiled.
+    ProgramDecompileError(ProgramDecompileError),
+    /// The bytecode has trailing bytes after the program.
+    TrailingBytecode,
+    /// A contract is already deployed at the contract id.
+    ContractAlreadyDeployed([u8; 32]),
+}
+
+/// The randomness beacon error.
+#[derive(Debug, Clone)]
+pub enum BeaconError {
+    /// The beacon session is not finalized prior to the current session.
+    BeaconSessionNotFinalized(u64),
+    /// The beacon is not found for the session.
+    BeaconNotFound(u64),
+}
+
 /// The memory error.
 #[derive(Debug, Clone)]
 pub enum MemoryError {
@@ -119,6 +206,12 @@ pub enum StackError {
     MemoryError(MemoryError),
     /// The storage error.
     StorageError(StorageError),
+    /// The transient storage error.
+    TransientError(TransientError),
+    /// The contract deployment error.
+    DeployError(DeployError),
+    /// The randomness beacon error.
+    BeaconError(BeaconError),
     /// The ops budget error.
     OpsBudgetError(OpsBudgetError),
     /// The stack uint error.
@@ -143,6 +236,12 @@ pub enum StackError {
     BLSError(BLSError),
     // Schnorr error.
     SchnorrError(SchnorrError),
+    // ECDSA error.
+    ECDSAError(ECDSAError),
+    // Merkle error.
+    MerkleError(MerkleError),
+    // SPV error.
+    SPVError(SPVError),
     // Call error.
     CallError(CallError),
     // Key conversion error.
@@ -5,7 +5,7 @@ use super::{
     stack_error::{OpsBudgetError, StackError},
     stack_item::StackItem,
 };
-use crate::executive::exec::caller::Caller;
+use crate::executive::{exec::caller::Caller, opcode::ops::OpsSchedule};
 use std::collections::HashMap;
 
 /// The stack holder.
@@ -37,6 +37,8 @@ pub struct StackHolder {
     internal_ops_counter: u32,
     // External ops counter.
     external_ops_counter: u32,
+    // The ops schedule the opcodes read their costs from.
+    ops_schedule: &'static OpsSchedule,
     // List of flow encounters nested in each other.
     // Since OP_IF/OP_NOTIF/OP_ELSE/OP_ENDIF can be nested, we need to keep track of the flow encounters.
     flow_encounters: Vec<FlowEncounter>,
@@ -53,6 +55,7 @@ impl<'a> StackHolder {
         ops_price: u32,
         internal_ops_counter: u32,
         external_ops_counter: u32,
+        ops_schedule: &'static OpsSchedule,
     ) -> Result<Self, StackError> {
         // Check if the internal ops counter exceeds the ops budget.
         if internal_ops_counter > ops_budget {
@@ -83,6 +86,7 @@ impl<'a> StackHolder {
             ops_price,
             internal_ops_counter,
             external_ops_counter,
+            ops_schedule,
             flow_encounters: Vec::<FlowEncounter>::new(),
         };
 
@@ -100,6 +104,7 @@ impl<'a> StackHolder {
         ops_price: u32,
         internal_ops_counter: u32,
         external_ops_counter: u32,
+        ops_schedule: &'static OpsSchedule,
         initial_stack_items: Vec<StackItem>,
     ) -> Result<StackHolder, StackError> {
         // Create a new stack holder.
@@ -112,6 +117,7 @@ impl<'a> StackHolder {
             ops_price,
             internal_ops_counter,
             external_ops_counter,
+            ops_schedule,
         )?;
 
         // Push the items to the stack.
@@ -179,6 +185,11 @@ impl<'a> StackHolder {
         self.ops_price
     }
 
+    /// Returns the ops schedule.
+    pub fn ops_schedule(&self) -> &'static OpsSchedule {
+        self.ops_schedule
+    }
+
     /// Returns the internal ops counter.
     pub fn internal_ops_counter(&self) -> u32 {
         self.internal_ops_counter
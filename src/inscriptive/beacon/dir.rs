@@ -0,0 +1,107 @@
+use crate::{operative::Chain, transmutative::noist::beacon::RandomnessBeacon};
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::Mutex;
+
+/// Guarded randomness beacon directory.
+#[allow(non_camel_case_types)]
+pub type BEACON_DIRECTORY = Arc<Mutex<BeaconDirectory>>;
+
+/// Directory for the per-session randomness beacons.
+pub struct BeaconDirectory {
+    // In-memory list.
+    beacons: HashMap<u64, RandomnessBeacon>,
+    // In-storage db.
+    db: sled::Db,
+}
+
+impl BeaconDirectory {
+    pub fn new(chain: Chain) -> Option<BEACON_DIRECTORY> {
+        let path = format!("{}/{}/{}", "db", chain.to_string(), "dir/beacon");
+        let db = sled::open(path).ok()?;
+
+        let mut beacons = HashMap::<u64, RandomnessBeacon>::new();
+
+        for lookup in db.iter() {
+            if let Ok((_, val)) = lookup {
+                let beacon = RandomnessBeacon::from_slice(&val)?;
+                beacons.insert(beacon.session_height(), beacon);
+            }
+        }
+
+        let beacon_dir = BeaconDirectory { beacons, db };
+
+        Some(Arc::new(Mutex::new(beacon_dir)))
+    }
+
+    /// Creates an empty beacon directory backed by a temporary in-memory db.
+    ///
+    /// NOTE: Used for testing contracts in-process.
+    pub fn new_in_memory() -> Option<BEACON_DIRECTORY> {
+        let db = sled::Config::new().temporary(true).open().ok()?;
+
+        let beacon_dir = BeaconDirectory {
+            beacons: HashMap::<u64, RandomnessBeacon>::new(),
+            db,
+        };
+
+        Some(Arc::new(Mutex::new(beacon_dir)))
+    }
+
+    /// Returns the beacon of the given session height.
+    pub fn beacon(&self, session_height: u64) -> Option<RandomnessBeacon> {
+        self.beacons.get(&session_height).cloned()
+    }
+
+    /// Returns the session height of the next beacon to insert.
+    pub fn next_session_height(&self) -> u64 {
+        match self.beacons.keys().max() {
+            Some(session_height) => session_height + 1,
+            None => 0,
+        }
+    }
+
+    /// Returns the randomness the beacon of the given session height chains to: the randomness of
+    /// the previous session's beacon, or all zeros if there is none.
+    pub fn prev_randomness(&self, session_height: u64) -> [u8; 32] {
+        session_height
+            .checked_sub(1)
+            .and_then(|prev_height| self.beacon(prev_height))
+            .map(|beacon| beacon.randomness())
+            .unwrap_or([0x00; 32])
+    }
+
+    /// Returns the message the operator quorum signs for the beacon of the given session height.
+    pub fn beacon_message(&self, session_height: u64) -> [u8; 32] {
+        RandomnessBeacon::signing_message(session_height, self.prev_randomness(session_height))
+    }
+
+    /// Inserts the beacon of a finalized session, signed by the given operator quorum key.
+    /// Beacons are never overwritten.
+    pub fn insert_beacon(&mut self, beacon: &RandomnessBeacon, quorum_key: [u8; 32]) -> bool {
+        // Beacons must chain to the previous session's beacon.
+        if beacon.prev() != self.prev_randomness(beacon.session_height()) {
+            return false;
+        }
+
+        // Beacons must be signed by the operator quorum.
+        if !beacon.verify(quorum_key) {
+            return false;
+        }
+
+        // Beacons are never overwritten.
+        if self.beacons.contains_key(&beacon.session_height()) {
+            return false;
+        }
+
+        // Update in-memory.
+        self.beacons
+            .insert(beacon.session_height(), beacon.to_owned());
+
+        // Update in-db.
+        let _ = self
+            .db
+            .insert(beacon.session_height().to_be_bytes(), beacon.serialize());
+
+        true
+    }
+}
@@ -1,4 +1,11 @@
-use crate::{constructive::entity::account::account::Account, operative::Chain};
+use crate::{
+    constructive::entity::account::account::Account,
+    operative::Chain,
+    transmutative::codec::canonical::{
+        canonical::{Canonical, CANONICAL_CODEC_VERSION},
+        migrate::decode_persisted,
+    },
+};
 use std::{collections::HashMap, sync::Arc};
 use tokio::sync::Mutex;
 
@@ -27,21 +34,53 @@ impl BlacklistDirectory {
         let db = sled::open(path).ok()?;
 
         let mut list = HashMap::<Account, (BlameCounter, BlacklistedUntil)>::new();
+        let mut legacy_keys = Vec::<sled::IVec>::new();
 
         for lookup in db.iter() {
             if let Ok((key, val)) = lookup {
-                let account: Account = serde_json::from_slice(&key).ok()?;
-                let (blame_counter, blacklisted_until) = serde_json::from_slice(&val).ok()?;
+                let (account, legacy_key) = decode_persisted::<Account>(&key)?;
+                let (record, legacy_val) = decode_record(&val)?;
 
-                list.insert(account, (blame_counter, blacklisted_until));
+                if legacy_key || legacy_val {
+                    legacy_keys.push(key);
+                }
+
+                list.insert(account, record);
             }
         }
 
+        // Migrate the legacy JSON records to the canonical encoding.
+        for key in legacy_keys {
+            let (account, _) = decode_persisted::<Account>(&key)?;
+            let (blame_counter, blacklisted_until) = list.get(&account)?.to_owned();
+
+            db.remove(key).ok()?;
+            db.insert(
+                account.encode_canonical(),
+                encode_record(blame_counter, blacklisted_until),
+            )
+            .ok()?;
+        }
+
         let blaming_dir = BlacklistDirectory { list, db };
 
         Some(Arc::new(Mutex::new(blaming_dir)))
     }
 
+    /// Creates an empty blacklist directory backed by a temporary in-memory db.
+    ///
+    /// NOTE: Used for testing in-process.
+    pub fn new_in_memory() -> Option<BLIST_DIRECTORY> {
+        let db = sled::Config::new().temporary(true).open().ok()?;
+
+        let blaming_dir = BlacklistDirectory {
+            list: HashMap::<Account, (BlameCounter, BlacklistedUntil)>::new(),
+            db,
+        };
+
+        Some(Arc::new(Mutex::new(blaming_dir)))
+    }
+
     /// Inserts or updates blaming records for a given accounts.
     fn update(
         &mut self,
@@ -57,10 +96,7 @@ impl BlacklistDirectory {
         {
             let key_serialized = account.serialize();
 
-            let value_serialized = match serde_json::to_vec(&(blame_counter, blacklisted_until)) {
-                Ok(bytes) => bytes,
-                Err(_) => vec![],
-            };
+            let value_serialized = encode_record(blame_counter, blacklisted_until);
 
             let _ = self.db.insert(key_serialized, value_serialized);
         }
@@ -132,3 +168,25 @@ fn current_unix_timestamp() -> u64 {
         .expect("Time went backwards")
         .as_secs()
 }
+
+/// Encodes a blacklist record as the version byte, the blame counter and the blacklisted until timestamp.
+fn encode_record(blame_counter: BlameCounter, blacklisted_until: BlacklistedUntil) -> Vec<u8> {
+    let mut bytes = vec![CANONICAL_CODEC_VERSION];
+    bytes.extend(blame_counter.to_le_bytes());
+    bytes.extend(blacklisted_until.to_le_bytes());
+    bytes
+}
+
+/// Decodes a blacklist record, falling back to its legacy JSON encoding.
+/// Returns the record, and whether it was found in the legacy encoding.
+fn decode_record(bytes: &[u8]) -> Option<((BlameCounter, BlacklistedUntil), bool)> {
+    if bytes.len() == 11 && bytes[0] == CANONICAL_CODEC_VERSION {
+        let blame_counter = BlameCounter::from_le_bytes(bytes[1..3].try_into().ok()?);
+        let blacklisted_until = BlacklistedUntil::from_le_bytes(bytes[3..11].try_into().ok()?);
+        return Some(((blame_counter, blacklisted_until), false));
+    }
+
+    serde_json::from_slice(bytes)
+        .ok()
+        .map(|record| (record, true))
+}
@@ -1,7 +1,12 @@
 use super::epoch::Epoch;
 use crate::{
-    constructive::entity::account::account::Account, inscriptive::baked::INITIAL_OPERATOR_SET,
-    operative::Chain, transmutative::secp::into::IntoPoint,
+    constructive::entity::account::account::Account,
+    inscriptive::baked::INITIAL_OPERATOR_SET,
+    operative::Chain,
+    transmutative::{
+        codec::canonical::{canonical::Canonical, migrate::migrate_db},
+        secp::into::IntoPoint,
+    },
 };
 use secp::Point;
 use std::{collections::HashMap, sync::Arc};
@@ -24,6 +29,11 @@ impl EpochDirectory {
         let path = format!("{}/{}/{}", "db", chain.to_string(), "dir/epoch");
         let db = sled::open(path).ok()?;
 
+        // Migrate the legacy JSON records to the canonical encoding.
+        if !migrate_db::<Epoch>(&db) {
+            return None;
+        }
+
         let mut epochs = HashMap::<u64, Epoch>::new();
 
         // Remove later
@@ -40,7 +50,7 @@ impl EpochDirectory {
         for lookup in db.iter() {
             if let Ok((key, val)) = lookup {
                 let height: u64 = u64::from_be_bytes(key.as_ref().try_into().ok()?);
-                let epoch: Epoch = serde_json::from_slice(&val).ok()?;
+                let epoch = Epoch::decode_canonical(&val).ok()?;
 
                 epochs.insert(height, epoch);
             }
@@ -51,6 +61,20 @@ impl EpochDirectory {
         Some(Arc::new(Mutex::new(epoch_dir)))
     }
 
+    /// Creates an empty epoch directory backed by a temporary in-memory db.
+    ///
+    /// NOTE: Used for testing in-process.
+    pub fn new_in_memory() -> Option<EPOCH_DIRECTORY> {
+        let db = sled::Config::new().temporary(true).open().ok()?;
+
+        let epoch_dir = EpochDirectory {
+            epochs: HashMap::<u64, Epoch>::new(),
+            db,
+        };
+
+        Some(Arc::new(Mutex::new(epoch_dir)))
+    }
+
     pub fn insert_epoch(&mut self, epoch: &Epoch) -> bool {
         let height = epoch.height();
 
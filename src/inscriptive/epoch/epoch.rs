@@ -1,4 +1,8 @@
 use crate::constructive::entity::account::account::Account;
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    writer::CanonicalWriter,
+};
 use secp::Point;
 use serde::{Deserialize, Serialize};
 
@@ -44,9 +48,26 @@ impl Epoch {
     }
 
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
+    }
+}
+
+impl Canonical for Epoch {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.height.to_le_bytes());
+        bytes.write_bool(self.active);
+        bytes.write_point(&self.group_key);
+        bytes.write_list(&self.operators, |bytes, operator| {
+            operator.encode_fields(bytes)
+        });
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Epoch, CanonicalDecodeError> {
+        Ok(Epoch {
+            height: reader.read_u64()?,
+            active: reader.read_bool()?,
+            group_key: reader.read_point()?,
+            operators: reader.read_list(Account::decode_fields)?,
+        })
     }
 }
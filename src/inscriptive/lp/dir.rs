@@ -1,7 +1,11 @@
 use super::lp::LP;
 use crate::{
-    constructive::entity::account::account::Account, operative::Chain,
-    transmutative::secp::into::IntoPointVec,
+    constructive::entity::account::account::Account,
+    operative::Chain,
+    transmutative::{
+        codec::canonical::{canonical::Canonical, migrate::migrate_db},
+        secp::into::IntoPointVec,
+    },
 };
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -23,11 +27,16 @@ impl LPDirectory {
         let path = format!("{}/{}/{}", "db", chain.to_string(), "dir/lp");
         let db = sled::open(path).ok()?;
 
+        // Migrate the legacy JSON records to the canonical encoding.
+        if !migrate_db::<LP>(&db) {
+            return None;
+        }
+
         let mut lps = Vec::<LP>::new();
 
         for lookup in db.iter() {
             if let Ok((_, val)) = lookup {
-                let lp: LP = serde_json::from_slice(&val).ok()?;
+                let lp = LP::decode_canonical(&val).ok()?;
 
                 lps.push(lp);
             }
@@ -1,4 +1,7 @@
 use crate::constructive::entity::account::account::Account;
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
 use serde::{Deserialize, Serialize};
 
 /// Liquidity provider
@@ -31,10 +34,7 @@ impl LP {
     }
 
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
     }
 
     pub fn update_add(&mut self, liquidity_add: u64, lp_add: u64) -> bool {
@@ -75,3 +75,19 @@ impl LP {
         self.lp == 0
     }
 }
+
+impl Canonical for LP {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.account.encode_fields(bytes);
+        bytes.extend(self.liquidity.to_le_bytes());
+        bytes.extend(self.lp.to_le_bytes());
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<LP, CanonicalDecodeError> {
+        Ok(LP {
+            account: Account::decode_fields(reader)?,
+            liquidity: reader.read_u64()?,
+            lp: reader.read_u64()?,
+        })
+    }
+}
@@ -1,7 +1,9 @@
 pub mod baked;
+pub mod beacon;
 pub mod blacklist;
 pub mod epoch;
 pub mod lp;
+pub mod price;
 pub mod registery;
 pub mod repo;
 pub mod rollup;
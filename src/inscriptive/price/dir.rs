@@ -0,0 +1,111 @@
+use super::market::{next_base_ops_price, INITIAL_BASE_OPS_PRICE};
+use crate::operative::Chain;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::Mutex;
+
+/// Guarded base ops price directory.
+#[allow(non_camel_case_types)]
+pub type PRICE_DIRECTORY = Arc<Mutex<PriceDirectory>>;
+
+/// The type of the base ops price.
+type BaseOpsPrice = u32;
+
+/// The type of the ops spent.
+type OpsSpent = u64;
+
+/// Directory for the per-session base ops price history.
+pub struct PriceDirectory {
+    // In-memory list.
+    history: HashMap<u64, (BaseOpsPrice, OpsSpent)>,
+    // In-storage db.
+    db: sled::Db,
+}
+
+impl PriceDirectory {
+    pub fn new(chain: Chain) -> Option<PRICE_DIRECTORY> {
+        let path = format!("{}/{}/{}", "db", chain.to_string(), "dir/price");
+        let db = sled::open(path).ok()?;
+
+        let mut history = HashMap::<u64, (BaseOpsPrice, OpsSpent)>::new();
+
+        for (key, val) in db.iter().flatten() {
+            let session_height = u64::from_be_bytes(key.as_ref().try_into().ok()?);
+
+            if val.len() != 12 {
+                return None;
+            }
+
+            let base_ops_price = u32::from_le_bytes(val[0..4].try_into().ok()?);
+            let ops_spent = u64::from_le_bytes(val[4..12].try_into().ok()?);
+
+            history.insert(session_height, (base_ops_price, ops_spent));
+        }
+
+        let price_dir = PriceDirectory { history, db };
+
+        Some(Arc::new(Mutex::new(price_dir)))
+    }
+
+    /// Returns the base ops price and the ops spent of the given session height.
+    pub fn session(&self, session_height: u64) -> Option<(BaseOpsPrice, OpsSpent)> {
+        self.history.get(&session_height).cloned()
+    }
+
+    /// Returns the base ops price of the given session height.
+    pub fn base_ops_price(&self, session_height: u64) -> Option<BaseOpsPrice> {
+        self.session(session_height)
+            .map(|(base_ops_price, _)| base_ops_price)
+    }
+
+    /// Returns the last recorded session height, if any.
+    pub fn last_session_height(&self) -> Option<u64> {
+        self.history.keys().max().cloned()
+    }
+
+    /// Returns the height of the next session to be recorded.
+    pub fn next_session_height(&self) -> u64 {
+        match self.last_session_height() {
+            Some(height) => height + 1,
+            None => 0,
+        }
+    }
+
+    /// Returns the base ops price of the next session to be recorded.
+    pub fn next_base_ops_price(&self) -> BaseOpsPrice {
+        match self
+            .last_session_height()
+            .and_then(|height| self.session(height))
+        {
+            Some((base_ops_price, ops_spent)) => next_base_ops_price(base_ops_price, ops_spent),
+            None => INITIAL_BASE_OPS_PRICE,
+        }
+    }
+
+    /// Records a finalized session with the number of ops it spent.
+    ///
+    /// Sessions are recorded in order, and each is priced at the base ops price derived
+    /// from the previous one.
+    pub fn insert_session(&mut self, session_height: u64, ops_spent: OpsSpent) -> bool {
+        // Sessions must be recorded in order.
+        if session_height != self.next_session_height() {
+            return false;
+        }
+
+        let base_ops_price = self.next_base_ops_price();
+
+        // Update in-db.
+        let mut val = Vec::<u8>::with_capacity(12);
+        val.extend(base_ops_price.to_le_bytes());
+        val.extend(ops_spent.to_le_bytes());
+
+        if self.db.insert(session_height.to_be_bytes(), val).is_err() {
+            return false;
+        }
+
+        // Update in-memory.
+        self.history
+            .insert(session_height, (base_ops_price, ops_spent));
+
+        true
+    }
+}
@@ -0,0 +1,43 @@
+/// The base ops price of the very first session.
+pub const INITIAL_BASE_OPS_PRICE: u32 = 10;
+
+/// The lowest the base ops price can go.
+pub const MIN_BASE_OPS_PRICE: u32 = 1;
+
+/// The number of ops a session is targeted to spend.
+pub const SESSION_OPS_TARGET: u64 = 10_000_000;
+
+/// Bounds the base ops price change between two sessions to 1/8 (12.5%), apart from the
+/// one-unit minimum increase.
+pub const BASE_OPS_PRICE_CHANGE_DENOMINATOR: u64 = 8;
+
+/// Returns the base ops price of the next session, given the base ops price of the
+/// previous session and the number of ops it spent.
+///
+/// The price goes up when the previous session spent more than `SESSION_OPS_TARGET`,
+/// goes down when it spent less, and stays the same when it hit the target exactly.
+/// Ops spent beyond twice the target are not counted, so that the price moves by at
+/// most 1/8 either way.
+pub fn next_base_ops_price(base_ops_price: u32, ops_spent: u64) -> u32 {
+    let base_ops_price = base_ops_price.max(MIN_BASE_OPS_PRICE) as u128;
+    let target = SESSION_OPS_TARGET as u128;
+    let ops_spent = (ops_spent as u128).min(target * 2);
+    let denominator = BASE_OPS_PRICE_CHANGE_DENOMINATOR as u128;
+
+    let next = match ops_spent.cmp(&target) {
+        // Spent exactly the target; the price stays the same.
+        std::cmp::Ordering::Equal => base_ops_price,
+        // Spent more than the target; the price goes up by at least one.
+        std::cmp::Ordering::Greater => {
+            let delta = (base_ops_price * (ops_spent - target) / target / denominator).max(1);
+            base_ops_price + delta
+        }
+        // Spent less than the target; the price goes down.
+        std::cmp::Ordering::Less => {
+            let delta = base_ops_price * (target - ops_spent) / target / denominator;
+            base_ops_price.saturating_sub(delta)
+        }
+    };
+
+    next.clamp(MIN_BASE_OPS_PRICE as u128, u32::MAX as u128) as u32
+}
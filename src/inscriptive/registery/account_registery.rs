@@ -1,4 +1,8 @@
-use crate::{constructive::entity::account::account::Account, operative::Chain};
+use crate::{
+    constructive::entity::account::account::Account,
+    operative::Chain,
+    transmutative::codec::canonical::{canonical::Canonical, migrate::migrate_db},
+};
 use secp::Point;
 use std::{collections::HashMap, sync::Arc};
 use tokio::sync::Mutex;
@@ -15,6 +19,10 @@ type REGISTERY_INDEX = u32;
 #[allow(non_camel_case_types)]
 type CALL_COUNTER = u64;
 
+/// Next nonce of an account; each entry of the account consumes one.
+#[allow(non_camel_case_types)]
+type NONCE = u64;
+
 /// Rank integer representing the rank position of a contract (from 1 to U32::MAX).
 #[allow(non_camel_case_types)]
 type RANK = u32;
@@ -30,6 +38,10 @@ pub struct AccountRegistery {
     call_counters: HashMap<REGISTERY_INDEX, CALL_COUNTER>,
     // In-storage db for storing the call counters.
     call_counters_db: sled::Db,
+    // In-memory list of account keys mapping to their next nonce.
+    nonces: HashMap<[u8; 32], NONCE>,
+    // In-storage db for storing the nonces.
+    nonces_db: sled::Db,
 }
 
 impl AccountRegistery {
@@ -40,6 +52,11 @@ impl AccountRegistery {
             sled::open(path).ok()?
         };
 
+        // Migrate the legacy JSON records to the canonical encoding.
+        if !migrate_db::<Account>(&accounts_db) {
+            return None;
+        }
+
         // Initialize the in-memory list of accounts.
         let mut accounts = HashMap::<REGISTERY_INDEX, Account>::new();
 
@@ -50,7 +67,7 @@ impl AccountRegistery {
                 // Value is the account serialized in bytes.
 
                 // Deserialize the account from value.
-                let account: Account = serde_json::from_slice(&val).ok()?;
+                let account = Account::decode_canonical(&val).ok()?;
 
                 // Insert into the in-memory accounts list.
                 // Set rank to index for now.
@@ -92,12 +109,46 @@ impl AccountRegistery {
             }
         }
 
+        // Open the nonces db.
+        let nonces_db = {
+            let path = format!(
+                "{}/{}/{}",
+                "db",
+                chain.to_string(),
+                "registery/account/nonce"
+            );
+
+            sled::open(path).ok()?
+        };
+
+        // Initialize the in-memory list of nonces.
+        let mut nonces = HashMap::<[u8; 32], NONCE>::new();
+
+        // Collect the in-memory list of nonces.
+        for lookup in nonces_db.iter() {
+            if let Ok((key, val)) = lookup {
+                // Key is the 32-byte account key.
+                // Value is the 8-byte next nonce.
+
+                // Deserialize the account key from key.
+                let account_key: [u8; 32] = key.as_ref().try_into().ok()?;
+
+                // Deserialize the nonce from value.
+                let nonce: NONCE = u64::from_le_bytes(val.as_ref().try_into().ok()?);
+
+                // Insert into the in-memory nonces list.
+                nonces.insert(account_key, nonce);
+            }
+        }
+
         // Construct the account registery.
         let mut registery = AccountRegistery {
             accounts,
             accounts_db,
             call_counters,
             call_counters_db,
+            nonces,
+            nonces_db,
         };
 
         // Update the accounts ranks which were initially set to 0.
@@ -109,6 +160,24 @@ impl AccountRegistery {
         Some(Arc::new(Mutex::new(registery)))
     }
 
+    /// Creates an empty account registery backed by temporary storage.
+    pub fn new_in_memory() -> Option<ACCOUNT_REGISTERY> {
+        let accounts_db = sled::Config::new().temporary(true).open().ok()?;
+        let call_counters_db = sled::Config::new().temporary(true).open().ok()?;
+        let nonces_db = sled::Config::new().temporary(true).open().ok()?;
+
+        let registery = AccountRegistery {
+            accounts: HashMap::new(),
+            accounts_db,
+            call_counters: HashMap::new(),
+            call_counters_db,
+            nonces: HashMap::new(),
+            nonces_db,
+        };
+
+        Some(Arc::new(Mutex::new(registery)))
+    }
+
     /// Sorts the call counters.
     fn sort_call_counters(
         call_counters: &HashMap<REGISTERY_INDEX, CALL_COUNTER>,
@@ -236,6 +305,16 @@ impl AccountRegistery {
             .map(|(rank, _)| rank.to_owned())
     }
 
+    /// Returns the next nonce of the account by the given account key.
+    ///
+    /// Accounts that have not yet entered a session start from zero.
+    pub fn nonce_by_key(&self, key: Point) -> NONCE {
+        self.nonces
+            .get(&key.serialize_xonly())
+            .copied()
+            .unwrap_or(0)
+    }
+
     /// Returns the current registery index height.
     pub fn registery_index_height(&self) -> u32 {
         self.accounts.keys().max().unwrap_or(&0).to_owned()
@@ -300,6 +379,39 @@ impl AccountRegistery {
         true
     }
 
+    /// Consumes the given account nonces, so that entries signed for them cannot be replayed.
+    /// Each nonce must be the next nonce of its account.
+    pub fn consume_nonces(&mut self, nonces: HashMap<Point, NONCE>) -> bool {
+        // Check if all the nonces are the next nonces.
+        for (account_key, nonce) in nonces.iter() {
+            if self.nonce_by_key(account_key.to_owned()) != *nonce {
+                return false;
+            }
+        }
+
+        // Advance the nonces.
+        for (account_key, nonce) in nonces {
+            let account_key = account_key.serialize_xonly();
+            let next_nonce = match nonce.checked_add(1) {
+                Some(next_nonce) => next_nonce,
+                None => return false,
+            };
+
+            // Insert into the in-memory nonces list.
+            self.nonces.insert(account_key, next_nonce);
+
+            // Insert into the in-storage nonces db.
+            if let Err(_) = self
+                .nonces_db
+                .insert(&account_key, next_nonce.to_le_bytes().to_vec())
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
     /// Updates the registery in a single batch operation.
     /// This is the only public operation that can be used to write/update the contract registery.
     pub fn batch_update(
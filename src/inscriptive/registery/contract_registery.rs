@@ -1,4 +1,8 @@
-use crate::{constructive::entity::contract::contract::Contract, operative::Chain};
+use crate::{
+    constructive::entity::contract::contract::Contract,
+    operative::Chain,
+    transmutative::codec::canonical::{canonical::Canonical, migrate::migrate_db},
+};
 use std::{collections::HashMap, sync::Arc};
 use tokio::sync::Mutex;
 
@@ -39,6 +43,11 @@ impl ContractRegistery {
             sled::open(path).ok()?
         };
 
+        // Migrate the legacy JSON records to the canonical encoding.
+        if !migrate_db::<Contract>(&contracts_db) {
+            return None;
+        }
+
         // Initialize the in-memory list of contracts.
         let mut contracts = HashMap::<REGISTERY_INDEX, Contract>::new();
 
@@ -49,7 +58,7 @@ impl ContractRegistery {
                 // Value is the contract serialized in bytes.
 
                 // Deserialize the contract from value.
-                let contract: Contract = serde_json::from_slice(&val).ok()?;
+                let contract = Contract::decode_canonical(&val).ok()?;
 
                 // Insert into the in-memory contracts list.
                 // Set rank to index for now.
@@ -108,6 +117,21 @@ impl ContractRegistery {
         Some(Arc::new(Mutex::new(registery)))
     }
 
+    /// Creates an empty contract registery backed by temporary storage.
+    pub fn new_in_memory() -> Option<CONTRACT_REGISTERY> {
+        let contracts_db = sled::Config::new().temporary(true).open().ok()?;
+        let call_counters_db = sled::Config::new().temporary(true).open().ok()?;
+
+        let registery = ContractRegistery {
+            contracts: HashMap::new(),
+            contracts_db,
+            call_counters: HashMap::new(),
+            call_counters_db,
+        };
+
+        Some(Arc::new(Mutex::new(registery)))
+    }
+
     /// Sorts the call counters.
     fn sort_call_counters(
         call_counters: &HashMap<REGISTERY_INDEX, CALL_COUNTER>,
@@ -221,21 +245,22 @@ impl ContractRegistery {
 
     //////// WRITE-UPDATE OPERATIONS ////////
 
-    /// Inserts the given contract into the registery.
-    fn insert_contract(&mut self, contract_id: [u8; 32], registery_index: u32) -> bool {
+    /// Inserts the given contract into the registery in-memory, and into the batches.
+    fn insert_contract(
+        &mut self,
+        contract_id: [u8; 32],
+        registery_index: u32,
+        contracts_batch: &mut sled::Batch,
+        call_counters_batch: &mut sled::Batch,
+    ) {
         // Construct the contract.
         let contract = Contract::new(contract_id, registery_index, None);
 
         // Insert into the in-memory contracts list.
         self.contracts.insert(registery_index, contract);
 
-        // Insert into the in-storage contracts db.
-        if let Err(_) = self
-            .contracts_db
-            .insert(&contract.contract_id(), contract.serialize())
-        {
-            return false;
-        }
+        // Insert into the contracts batch.
+        contracts_batch.insert(contract.contract_id().to_vec(), contract.serialize());
 
         // Initial call counter value is set to zero.
         let initial_call_counter_value: u64 = 0;
@@ -244,19 +269,20 @@ impl ContractRegistery {
         self.call_counters
             .insert(registery_index, initial_call_counter_value);
 
-        // Insert into the in-storage call counters db.
-        if let Err(_) = self.call_counters_db.insert(
-            &registery_index.to_le_bytes(),
+        // Insert into the call counters batch.
+        call_counters_batch.insert(
+            registery_index.to_le_bytes().to_vec(),
             initial_call_counter_value.to_le_bytes().to_vec(),
-        ) {
-            return false;
-        }
-
-        true
+        );
     }
 
-    // Increments the call counter for the given contract.
-    fn increment_call_counter(&mut self, registery_index: u32, increment_by: u64) -> bool {
+    // Increments the call counter for the given contract in-memory, and in the batch.
+    fn increment_call_counter(
+        &mut self,
+        registery_index: u32,
+        increment_by: u64,
+        call_counters_batch: &mut sled::Batch,
+    ) {
         // Update the call counter in-memory, and return the new call counter.
         let new_call_counter = self
             .call_counters
@@ -264,11 +290,35 @@ impl ContractRegistery {
             .and_modify(|counter| *counter += increment_by)
             .or_insert(increment_by);
 
-        // Update the call counter in-storage.
-        if let Err(_) = self.call_counters_db.insert(
-            &registery_index.to_le_bytes(),
+        // Update the call counter in the batch.
+        call_counters_batch.insert(
+            registery_index.to_le_bytes().to_vec(),
             new_call_counter.to_le_bytes().to_vec(),
-        ) {
+        );
+    }
+
+    /// Applies the contracts and the call counters batches in-storage.
+    /// The newly registered contracts are removed again if the call counters cannot be written.
+    fn apply_batches(
+        &self,
+        registered_contract_ids: &[[u8; 32]],
+        contracts_batch: sled::Batch,
+        call_counters_batch: sled::Batch,
+    ) -> bool {
+        if self.contracts_db.apply_batch(contracts_batch).is_err() {
+            return false;
+        }
+
+        if self
+            .call_counters_db
+            .apply_batch(call_counters_batch)
+            .is_err()
+        {
+            let mut undo_batch = sled::Batch::default();
+            for contract_id in registered_contract_ids {
+                undo_batch.remove(contract_id.to_vec());
+            }
+            let _ = self.contracts_db.apply_batch(undo_batch);
             return false;
         }
 
@@ -277,6 +327,7 @@ impl ContractRegistery {
 
     /// Updates the registery in a single batch operation.
     /// This is the only public operation that can be used to write/update the contract registery.
+    /// Nothing is updated if any of the writes fails.
     pub fn batch_update(
         &mut self,
         // List of new contracts IDs to register.
@@ -298,26 +349,41 @@ impl ContractRegistery {
             }
         }
 
+        // Keep the in-memory lists to restore them on failure.
+        let contracts = self.contracts.clone();
+        let call_counters = self.call_counters.clone();
+
         // Get the current registery index height.
         let mut registery_index_height = self.registery_index_height();
 
         // Register the new contracts.
-        for contract_id in contracts_to_register {
+        let mut contracts_batch = sled::Batch::default();
+        let mut call_counters_batch = sled::Batch::default();
+        for contract_id in contracts_to_register.iter() {
             registery_index_height += 1;
-            if !self.insert_contract(contract_id, registery_index_height) {
-                return false;
-            }
+            self.insert_contract(
+                contract_id.to_owned(),
+                registery_index_height,
+                &mut contracts_batch,
+                &mut call_counters_batch,
+            );
         }
 
         // Increment the call counter for the given contracts.
         for (contract, num_times_called) in contracts_called {
-            if !self.increment_call_counter(contract.registery_index(), num_times_called) {
-                return false;
-            }
+            self.increment_call_counter(
+                contract.registery_index(),
+                num_times_called,
+                &mut call_counters_batch,
+            );
         }
 
-        // Update the contracts ranks.
-        if !self.rank_contracts() {
+        // Update the contracts ranks, then write the contracts and their call counters in-storage.
+        if !self.rank_contracts()
+            || !self.apply_batches(&contracts_to_register, contracts_batch, call_counters_batch)
+        {
+            self.contracts = contracts;
+            self.call_counters = call_counters;
             return false;
         }
 
@@ -28,6 +28,17 @@ impl Registery {
         Some(Arc::new(Mutex::new(registery)))
     }
 
+    pub fn new_in_memory() -> Option<REGISTERY> {
+        let account_registery = AccountRegistery::new_in_memory()?;
+        let contract_registery = ContractRegistery::new_in_memory()?;
+        let registery = Registery {
+            account_registery,
+            contract_registery,
+        };
+
+        Some(Arc::new(Mutex::new(registery)))
+    }
+
     pub fn account_registery(&self) -> ACCOUNT_REGISTERY {
         Arc::clone(&self.account_registery)
     }
@@ -1,4 +1,4 @@
-use super::repo_error::{RepoConstructionError, RepoInsertError};
+use super::repo_error::{RepoConstructionError, RepoInsertError, RepoRemoveError};
 use crate::{
     executive::program::{compiler::compiler::ProgramCompiler, program::Program},
     operative::Chain,
@@ -19,6 +19,10 @@ type CONTRACT_ID = [u8; 32];
 pub struct ProgramsRepo {
     programs: HashMap<CONTRACT_ID, Program>,
     programs_db: sled::Db,
+    // Programs deployed during the session, in deployment order.
+    ephemeral_programs: Vec<(CONTRACT_ID, Program)>,
+    // Stack of ephemeral programs savepoints, the last one being the innermost.
+    savepoints: Vec<usize>,
 }
 
 impl ProgramsRepo {
@@ -59,6 +63,30 @@ impl ProgramsRepo {
         let repo = ProgramsRepo {
             programs,
             programs_db,
+            ephemeral_programs: Vec::<(CONTRACT_ID, Program)>::new(),
+            savepoints: Vec::<usize>::new(),
+        };
+
+        // Return the guarded repo.
+        Ok(Arc::new(Mutex::new(repo)))
+    }
+
+    /// Creates an empty repo backed by a temporary in-memory db.
+    ///
+    /// NOTE: Used for testing contracts in-process.
+    pub fn new_in_memory() -> Result<PROGRAMS_REPO, RepoConstructionError> {
+        // Open a temporary db, removed once dropped.
+        let programs_db = sled::Config::new()
+            .temporary(true)
+            .open()
+            .map_err(RepoConstructionError::DBOpenError)?;
+
+        // Construct the repo.
+        let repo = ProgramsRepo {
+            programs: HashMap::<CONTRACT_ID, Program>::new(),
+            programs_db,
+            ephemeral_programs: Vec::<(CONTRACT_ID, Program)>::new(),
+            savepoints: Vec::<usize>::new(),
         };
 
         // Return the guarded repo.
@@ -66,58 +94,170 @@ impl ProgramsRepo {
     }
 
     /// Inserts multiple programs into the repo.
+    ///
+    /// Nothing is inserted if any of the contract ids already exists.
     pub fn insert_multi(
         &mut self,
         programs: &HashMap<CONTRACT_ID, Program>,
     ) -> Result<(), RepoInsertError> {
-        // Insert in-memory.
-        for (contract_id, program) in programs {
-            // Insert in-memory.
-            if let Some(_) = self
-                .programs
-                .insert(contract_id.to_owned(), program.to_owned())
-            {
-                // Return error if the contract id already exists.
+        // Return error if any of the contract ids already exists.
+        for contract_id in programs.keys() {
+            if self.programs.contains_key(contract_id) {
                 return Err(RepoInsertError::ContractIdAlreadyExists(
                     contract_id.to_owned(),
                 ));
             }
         }
 
-        // Insert in-storage.
+        // Compile the programs into a single batch, so that they are inserted in-storage at once.
+        let mut batch = sled::Batch::default();
         for (contract_id, program) in programs {
-            // Serialize the program.
-            let program_bytes = program
-                .compile()
-                .map_err(|e| RepoInsertError::ProgramCompileError(contract_id.to_owned(), e))?;
-
-            // Insert the program into the db.
-            if let Some(_) = self
+            // Return error if the contract id already exists in-storage.
+            if self
                 .programs_db
-                .insert(contract_id, program_bytes)
+                .contains_key(contract_id)
                 .map_err(|e| RepoInsertError::DBInsertError(contract_id.to_owned(), e))?
             {
-                // Return error if the contract id already exists.
                 return Err(RepoInsertError::ContractIdAlreadyExists(
                     contract_id.to_owned(),
                 ));
             }
+
+            // Serialize the program.
+            let program_bytes = program
+                .compile()
+                .map_err(|e| RepoInsertError::ProgramCompileError(contract_id.to_owned(), e))?;
+
+            batch.insert(contract_id.to_vec(), program_bytes);
+        }
+
+        // Insert in-storage.
+        self.programs_db
+            .apply_batch(batch)
+            .map_err(RepoInsertError::DBBatchApplyError)?;
+
+        // Insert in-memory.
+        for (contract_id, program) in programs {
+            self.programs
+                .insert(contract_id.to_owned(), program.to_owned());
+        }
+
+        // Return success.
+        Ok(())
+    }
+
+    /// Removes multiple programs from the repo, undoing their insertion.
+    pub fn remove_multi(&mut self, contract_ids: &[CONTRACT_ID]) -> Result<(), RepoRemoveError> {
+        // Remove in-storage.
+        let mut batch = sled::Batch::default();
+        for contract_id in contract_ids {
+            batch.remove(contract_id.to_vec());
+        }
+        self.programs_db
+            .apply_batch(batch)
+            .map_err(RepoRemoveError::DBBatchApplyError)?;
+
+        // Remove in-memory.
+        for contract_id in contract_ids {
+            self.programs.remove(contract_id);
+        }
+
+        Ok(())
+    }
+
+    /// Inserts a program deployed during the session ephemerally.
+    pub fn insert_ephemeral(
+        &mut self,
+        contract_id: &CONTRACT_ID,
+        program: &Program,
+    ) -> Result<(), RepoInsertError> {
+        // Return error if the contract id already exists.
+        if self.is_deployed(contract_id) {
+            return Err(RepoInsertError::ContractIdAlreadyExists(
+                contract_id.to_owned(),
+            ));
+        }
+
+        // Insert in-memory.
+        self.ephemeral_programs
+            .push((contract_id.to_owned(), program.to_owned()));
+
+        Ok(())
+    }
+
+    /// Pushes a savepoint at the current ephemeral programs length.
+    pub fn push_savepoint(&mut self) {
+        self.savepoints.push(self.ephemeral_programs.len());
+    }
+
+    /// Commits the innermost savepoint, keeping the programs deployed since it was pushed.
+    pub fn commit_savepoint(&mut self) {
+        self.savepoints.pop();
+    }
+
+    /// Reverts the programs deployed since the innermost savepoint was pushed, and pops it.
+    pub fn revert_to_savepoint(&mut self) {
+        if let Some(ephemeral_programs_len) = self.savepoints.pop() {
+            self.ephemeral_programs.truncate(ephemeral_programs_len);
         }
+    }
+
+    /// Reverts all programs deployed during the session.
+    pub fn rollback_all(&mut self) {
+        self.ephemeral_programs.clear();
+        self.savepoints.clear();
+    }
+
+    /// Returns the contract ids of the programs deployed during the session, in deployment order.
+    pub fn pending_deployments(&self) -> Vec<CONTRACT_ID> {
+        self.ephemeral_programs
+            .iter()
+            .map(|(contract_id, _)| contract_id.to_owned())
+            .collect()
+    }
+
+    /// Saves the programs deployed during the session.
+    ///
+    /// The ephemeral programs are only cleared once they are inserted, so a failed save can be retried.
+    pub fn save_all_deployments(&mut self) -> Result<(), RepoInsertError> {
+        // Collect the deployed programs.
+        let programs = self
+            .ephemeral_programs
+            .iter()
+            .cloned()
+            .collect::<HashMap<CONTRACT_ID, Program>>();
+
+        // Insert in-memory and in-storage.
+        self.insert_multi(&programs)?;
+
+        // Clear the ephemeral programs and the savepoints.
+        self.rollback_all();
 
         // Return success.
         Ok(())
     }
 
-    /// Returns the program by the contract id.
+    /// Returns whether a program is deployed at the contract id, including ephemeral deployments.
+    pub fn is_deployed(&self, contract_id: &CONTRACT_ID) -> bool {
+        self.program_by_contract_id(contract_id).is_some()
+    }
+
+    /// Returns the program by the contract id, including ephemeral deployments.
     pub fn program_by_contract_id(&self, contract_id: &CONTRACT_ID) -> Option<Program> {
-        self.programs.get(contract_id).cloned()
+        match self.programs.get(contract_id) {
+            Some(program) => Some(program.to_owned()),
+            None => self
+                .ephemeral_programs
+                .iter()
+                .find(|(id, _)| id == contract_id)
+                .map(|(_, program)| program.to_owned()),
+        }
     }
 
     /// Returns the number of methods in the program by the contract id.
     pub fn methods_len_by_contract_id(&self, contract_id: &CONTRACT_ID) -> Option<u8> {
         let methods_len = self
-            .programs
-            .get(contract_id)
+            .program_by_contract_id(contract_id)
             .map(|program| program.methods_len())?;
 
         // Return none if the methods length is greater than the maximum value of u8.
@@ -15,5 +15,12 @@ pub enum RepoConstructionError {
 pub enum RepoInsertError {
     ProgramCompileError([u8; 32], ProgramCompileError),
     DBInsertError([u8; 32], sled::Error),
+    DBBatchApplyError(sled::Error),
     ContractIdAlreadyExists([u8; 32]),
 }
+
+/// The repo remove error.
+#[derive(Debug, Clone)]
+pub enum RepoRemoveError {
+    DBBatchApplyError(sled::Error),
+}
@@ -1,4 +1,6 @@
 use crate::operative::Chain;
+use bitcoin::block::Header;
+use bitcoin::consensus::{deserialize, serialize};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -15,6 +17,8 @@ pub struct RollupDirectory {
     rollup_sync_height: u64,
     // In-storage db.
     db: sled::Db,
+    // In-storage block headers db.
+    headers_db: sled::Db,
 }
 
 impl RollupDirectory {
@@ -36,11 +40,33 @@ impl RollupDirectory {
             .and_then(|val| val.as_ref().try_into().ok().map(u64::from_be_bytes))
             .unwrap_or(0);
 
+        let headers_path = format!("{}/{}/{}", "db", chain.to_string(), "dir/headers");
+        let headers_db = sled::open(headers_path).ok()?;
+
         let rollup_dir = RollupDirectory {
             synced: false,
             bitcoin_sync_height,
             rollup_sync_height,
             db,
+            headers_db,
+        };
+
+        Some(Arc::new(Mutex::new(rollup_dir)))
+    }
+
+    /// Creates an empty rollup directory backed by temporary in-memory dbs.
+    ///
+    /// NOTE: Used for testing contracts in-process.
+    pub fn new_in_memory() -> Option<ROLLUP_DIRECTORY> {
+        let db = sled::Config::new().temporary(true).open().ok()?;
+        let headers_db = sled::Config::new().temporary(true).open().ok()?;
+
+        let rollup_dir = RollupDirectory {
+            synced: false,
+            bitcoin_sync_height: 0,
+            rollup_sync_height: 0,
+            db,
+            headers_db,
         };
 
         Some(Arc::new(Mutex::new(rollup_dir)))
@@ -85,4 +111,25 @@ impl RollupDirectory {
             .db
             .insert(b"rollup_sync_height", height.to_be_bytes().to_vec());
     }
+
+    /// Returns the block header at the given height.
+    pub fn header(&self, height: u64) -> Option<Header> {
+        let header_bytes = self.headers_db.get(height.to_be_bytes()).ok().flatten()?;
+        deserialize::<Header>(&header_bytes).ok()
+    }
+
+    /// Returns whether the block header at the given height is indexed.
+    pub fn has_header(&self, height: u64) -> bool {
+        self.headers_db
+            .contains_key(height.to_be_bytes())
+            .unwrap_or(false)
+    }
+
+    /// Inserts the block header at the given height.
+    pub fn insert_header(&mut self, height: u64, header: &Header) {
+        // Update in-db.
+        let _ = self
+            .headers_db
+            .insert(height.to_be_bytes(), serialize(header));
+    }
 }
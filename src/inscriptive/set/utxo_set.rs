@@ -58,6 +58,20 @@ impl UTXOSet {
         Some(Arc::new(Mutex::new(utxoset)))
     }
 
+    /// Creates an empty UTXOSet backed by a temporary in-memory db.
+    ///
+    /// NOTE: Used for testing in-process.
+    pub fn new_in_memory() -> Option<UTXO_SET> {
+        let utxos_db = sled::Config::new().temporary(true).open().ok()?;
+
+        let utxoset = UTXOSet {
+            utxos: HashMap::<OutPoint, TxOut>::new(),
+            utxos_db,
+        };
+
+        Some(Arc::new(Mutex::new(utxoset)))
+    }
+
     /// Returns the number of utxos in the set.
     pub fn num_utxos(&self) -> usize {
         self.utxos.len()
@@ -1,7 +1,12 @@
 use crate::{
     constructive::{entity::account::account::Account, txn::ext::OutpointExt, txo::vtxo::VTXO},
     operative::Chain,
+    transmutative::{
+        codec::canonical::{canonical::Canonical, migrate::migrate_db},
+        hash::{Hash, HashTag},
+    },
 };
+use bitcoin::{hashes::Hash as _, OutPoint, TxIn, Txid};
 use secp::Point;
 use std::{collections::HashMap, sync::Arc};
 use tokio::sync::Mutex;
@@ -19,6 +24,10 @@ pub struct VTXOSet {
     vtxos: HashMap<AccountKey, Vec<VTXO>>,
     // In-storage VTXO set.
     vtxos_db: sled::Db,
+    // In-memory record of the expired VTXOs swept by the operator.
+    swept_vtxos: HashMap<AccountKey, Vec<VTXO>>,
+    // In-storage record of the expired VTXOs swept by the operator.
+    swept_vtxos_db: sled::Db,
 }
 
 impl VTXOSet {
@@ -27,14 +36,39 @@ impl VTXOSet {
         // Collect VTXOs from db.
         let vtxos_path = format!("{}/{}/{}", "db", chain.to_string(), "set/vtxo");
         let vtxos_db = sled::open(vtxos_path).ok()?;
+        let global_vtxo_set = Self::load(&vtxos_db)?;
+
+        // Collect swept VTXOs from db.
+        let swept_vtxos_path = format!("{}/{}/{}", "db", chain.to_string(), "set/vtxo_swept");
+        let swept_vtxos_db = sled::open(swept_vtxos_path).ok()?;
+        let swept_vtxos = Self::load(&swept_vtxos_db)?;
+
+        // Construct VTXOSet instance.
+        let vtxoset = VTXOSet {
+            vtxos: global_vtxo_set,
+            vtxos_db,
+            swept_vtxos,
+            swept_vtxos_db,
+        };
+
+        // Return the VTXOSet instance.
+        Some(Arc::new(Mutex::new(vtxoset)))
+    }
+
+    /// Loads VTXOs from db, grouped by account key.
+    fn load(db: &sled::Db) -> Option<HashMap<AccountKey, Vec<VTXO>>> {
+        // Migrate the legacy JSON records to the canonical encoding.
+        if !migrate_db::<VTXO>(db) {
+            return None;
+        }
 
         let mut global_vtxo_set = HashMap::<AccountKey, Vec<VTXO>>::new();
 
         // Load VTXOs from db.
-        for lookup in vtxos_db.iter() {
+        for lookup in db.iter() {
             if let Ok((_, val)) = lookup {
                 // Deserialize VTXO.
-                let vtxo = serde_json::from_slice::<VTXO>(&val).ok()?;
+                let vtxo = VTXO::decode_canonical(&val).ok()?;
 
                 // Get account key.
                 let account_key = vtxo.account_key();
@@ -55,13 +89,24 @@ impl VTXOSet {
             }
         }
 
-        // Construct VTXOSet instance.
+        Some(global_vtxo_set)
+    }
+
+    /// Creates an empty VTXOSet backed by a temporary in-memory db.
+    ///
+    /// NOTE: Used for testing in-process.
+    pub fn new_in_memory() -> Option<VTXO_SET> {
+        let vtxos_db = sled::Config::new().temporary(true).open().ok()?;
+
+        let swept_vtxos_db = sled::Config::new().temporary(true).open().ok()?;
+
         let vtxoset = VTXOSet {
-            vtxos: global_vtxo_set,
+            vtxos: HashMap::<AccountKey, Vec<VTXO>>::new(),
             vtxos_db,
+            swept_vtxos: HashMap::<AccountKey, Vec<VTXO>>::new(),
+            swept_vtxos_db,
         };
 
-        // Return the VTXOSet instance.
         Some(Arc::new(Mutex::new(vtxoset)))
     }
 
@@ -73,6 +118,14 @@ impl VTXOSet {
             .unwrap_or(Vec::<VTXO>::new())
     }
 
+    /// Returns the VTXO set of a given account key, sorted by outpoint.
+    /// The order is canonical across nodes, unlike the insertion order.
+    pub fn sorted_vtxo_set_by_account_key(&self, account_key: &Point) -> Vec<VTXO> {
+        let mut vtxos = self.vtxo_set_by_account_key(account_key);
+        vtxos.sort_by_key(|vtxo| vtxo.outpoint());
+        vtxos
+    }
+
     /// Inserts a VTXO to the VTXO set.
     pub fn insert_vtxo(&mut self, vtxo: &VTXO) -> bool {
         // Get VTXO's account key.
@@ -152,6 +205,16 @@ impl VTXOSet {
         }
     }
 
+    /// Returns the sats balance of a given x-only account key.
+    pub fn balance_by_account_key(&self, account_key: [u8; 32]) -> u64 {
+        self.vtxos
+            .iter()
+            .filter(|(key, _)| key.serialize_xonly() == account_key)
+            .flat_map(|(_, vtxos)| vtxos.iter())
+            .map(|vtxo| vtxo.value().unwrap_or(0))
+            .sum()
+    }
+
     /// Returns the VTXOs to recharge.
     pub fn vtxos_to_recharge(&self, account_key: &Point, current_bitcoin_height: u32) -> Vec<VTXO> {
         // Retrieve the account's VTXO set.
@@ -170,4 +233,206 @@ impl VTXOSet {
         // Return the rechargeable VTXOs.
         rechargeable_vtxos
     }
+
+    /// Moves the VTXO swept by the given transaction input, if any, into the sweep records.
+    /// Returns the swept VTXO.
+    pub fn sweep_vtxo(&mut self, txn_input: &TxIn) -> Option<VTXO> {
+        // Find the live VTXO the input sweeps.
+        let swept_vtxo = self
+            .vtxos
+            .values()
+            .flatten()
+            .find(|vtxo| vtxo.is_swept_by(txn_input))
+            .cloned()?;
+
+        // Move it from the live set to the sweep records.
+        match self.remove_vtxo(&swept_vtxo) && self.insert_swept_vtxo(&swept_vtxo) {
+            true => Some(swept_vtxo),
+            false => None,
+        }
+    }
+
+    /// Inserts a VTXO to the sweep records.
+    pub fn insert_swept_vtxo(&mut self, vtxo: &VTXO) -> bool {
+        // Get VTXO's outpoint.
+        let vtxo_outpoint = match vtxo.outpoint() {
+            Some(outpoint) => outpoint,
+            None => return false,
+        };
+
+        // Insert VTXO to the in-memory records.
+        let account_swept_vtxos = self.swept_vtxos.entry(vtxo.account_key()).or_default();
+        if account_swept_vtxos
+            .iter()
+            .any(|swept| swept.outpoint() == Some(vtxo_outpoint))
+        {
+            return false;
+        }
+        account_swept_vtxos.push(vtxo.to_owned());
+
+        // Insert VTXO to the in-storage records.
+        self.swept_vtxos_db
+            .insert(vtxo_outpoint.bytes_36(), vtxo.serialize())
+            .is_ok()
+    }
+
+    /// Removes a VTXO from the sweep records, once it is revived.
+    pub fn remove_swept_vtxo(&mut self, vtxo: &VTXO) -> bool {
+        // Get VTXO's outpoint.
+        let vtxo_outpoint = match vtxo.outpoint() {
+            Some(outpoint) => outpoint,
+            None => return false,
+        };
+
+        // Remove VTXO from the in-memory records.
+        let account_swept_vtxos = match self.swept_vtxos.get_mut(&vtxo.account_key()) {
+            Some(records) => records,
+            None => return false,
+        };
+        account_swept_vtxos.retain(|swept| swept.outpoint() != Some(vtxo_outpoint));
+
+        // Remove VTXO from the in-storage records.
+        self.swept_vtxos_db.remove(vtxo_outpoint.bytes_36()).is_ok()
+    }
+
+    /// Returns the swept VTXOs of a given account key, sorted by outpoint.
+    pub fn swept_vtxos_by_account_key(&self, account_key: &Point) -> Vec<VTXO> {
+        let mut vtxos = self
+            .swept_vtxos
+            .get(account_key)
+            .cloned()
+            .unwrap_or_default();
+        vtxos.sort_by_key(|vtxo| vtxo.outpoint());
+        vtxos
+    }
+
+    /// Returns the VTXOs to revive.
+    /// These are the account's expired VTXOs swept by the operator, and no longer in the live set.
+    pub fn vtxos_to_revive(&self, account_key: &Point, current_bitcoin_height: u32) -> Vec<VTXO> {
+        let live_vtxos = self.vtxo_set_by_account_key(account_key);
+
+        self.swept_vtxos_by_account_key(account_key)
+            .into_iter()
+            .filter(|vtxo| vtxo.is_expired(current_bitcoin_height).unwrap_or(false))
+            .filter(|vtxo| {
+                !live_vtxos
+                    .iter()
+                    .any(|live| live.outpoint() == vtxo.outpoint())
+            })
+            .collect()
+    }
+
+    /// Returns the VTXOs spent and created to settle the balance changes of a session, at the given
+    /// session and its anchored Bitcoin height, without applying them.
+    ///
+    /// Each account is debited the given debits and credited the given credits. An account whose
+    /// balance decreases spends its VTXOs in outpoint order until the debit is covered, and an
+    /// account whose balance changes ends up with a single new VTXO for its change or its credit.
+    /// Returns `None` if an account cannot cover its debit.
+    pub fn settlement(
+        &self,
+        debits: &HashMap<[u8; 32], u64>,
+        credits: &HashMap<[u8; 32], u64>,
+        operator_key: Point,
+        at_rollup_height: u32,
+        at_bitcoin_height: u32,
+    ) -> Option<(Vec<VTXO>, Vec<VTXO>)> {
+        // Net the balance changes of each account.
+        let mut balance_changes = HashMap::<[u8; 32], i128>::new();
+        for (key, amount) in debits.iter() {
+            *balance_changes.entry(*key).or_insert(0) -= *amount as i128;
+        }
+        for (key, amount) in credits.iter() {
+            *balance_changes.entry(*key).or_insert(0) += *amount as i128;
+        }
+
+        // Settle the accounts in key order, so that the settlement is canonical across nodes.
+        let mut balance_changes = balance_changes
+            .into_iter()
+            .filter(|(_, change)| *change != 0)
+            .collect::<Vec<([u8; 32], i128)>>();
+        balance_changes.sort_by_key(|(key, _)| *key);
+
+        let mut spent_vtxos = Vec::<VTXO>::new();
+        let mut created_vtxos = Vec::<VTXO>::new();
+
+        for (key, change) in balance_changes {
+            // Account keys are even.
+            let account_key = Point::lift_x(&key).ok()?;
+
+            // Spend the VTXOs covering the debit, if any.
+            let mut value = change;
+            if change < 0 {
+                for vtxo in self.sorted_vtxo_set_by_account_key(&account_key) {
+                    if value >= 0 {
+                        break;
+                    }
+                    value += vtxo.value()? as i128;
+                    spent_vtxos.push(vtxo);
+                }
+
+                // The account cannot cover its debit.
+                if value < 0 {
+                    return None;
+                }
+            }
+
+            // Create the VTXO for the change or the credit, if any.
+            if value > 0 {
+                let mut preimage = Vec::<u8>::new();
+                preimage.extend(at_rollup_height.to_be_bytes());
+                preimage.extend(key);
+
+                let txid = Txid::from_byte_array(preimage.hash(Some(HashTag::SettledVTXO)));
+
+                created_vtxos.push(VTXO::new(
+                    account_key,
+                    operator_key,
+                    Some(OutPoint::new(txid, 0)),
+                    Some(u64::try_from(value).ok()?),
+                    Some(at_rollup_height),
+                    Some(at_bitcoin_height),
+                ));
+            }
+        }
+
+        Some((spent_vtxos, created_vtxos))
+    }
+
+    /// Applies a settlement by removing the spent VTXOs and inserting the created ones, at once.
+    /// Reverting a settlement is applying it with the spent and created VTXOs swapped.
+    pub fn apply_settlement(&mut self, spent_vtxos: &[VTXO], created_vtxos: &[VTXO]) -> bool {
+        // Write in-storage in a single batch.
+        let mut batch = sled::Batch::default();
+        for vtxo in spent_vtxos {
+            match vtxo.outpoint() {
+                Some(outpoint) => batch.remove(outpoint.bytes_36().to_vec()),
+                None => return false,
+            }
+        }
+        for vtxo in created_vtxos {
+            match vtxo.outpoint() {
+                Some(outpoint) => batch.insert(outpoint.bytes_36().to_vec(), vtxo.serialize()),
+                None => return false,
+            }
+        }
+        if self.vtxos_db.apply_batch(batch).is_err() {
+            return false;
+        }
+
+        // Write in-memory.
+        for vtxo in spent_vtxos {
+            if let Some(account_vtxo_set) = self.vtxos.get_mut(&vtxo.account_key()) {
+                account_vtxo_set.retain(|account_vtxo| account_vtxo.outpoint() != vtxo.outpoint());
+            }
+        }
+        for vtxo in created_vtxos {
+            self.vtxos
+                .entry(vtxo.account_key())
+                .or_default()
+                .push(vtxo.to_owned());
+        }
+
+        true
+    }
 }
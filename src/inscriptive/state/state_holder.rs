@@ -24,8 +24,8 @@ pub struct StateHolder {
     states_db: sled::Db,
     /// In-memory cache of ephemeral states.
     ephemeral_states: HashMap<CONTRACT_ID, HashMap<STATE_KEY, STATE_VALUE>>,
-    /// In-memory cache of ephemeral states backup.
-    ephemeral_states_backup: HashMap<CONTRACT_ID, HashMap<STATE_KEY, STATE_VALUE>>,
+    /// Stack of ephemeral states savepoints, the last one being the innermost.
+    savepoints: Vec<HashMap<CONTRACT_ID, HashMap<STATE_KEY, STATE_VALUE>>>,
 }
 
 /// Guarded state holder.
@@ -71,29 +71,57 @@ impl StateHolder {
             states,
             states_db,
             ephemeral_states: HashMap::<CONTRACT_ID, HashMap<STATE_KEY, STATE_VALUE>>::new(),
-            ephemeral_states_backup: HashMap::<CONTRACT_ID, HashMap<STATE_KEY, STATE_VALUE>>::new(),
+            savepoints: Vec::<HashMap<CONTRACT_ID, HashMap<STATE_KEY, STATE_VALUE>>>::new(),
         };
 
         // Return the guarded state holder.
         Ok(Arc::new(Mutex::new(state_holder)))
     }
 
-    /// Clones ephemeral states into the backup.
-    fn backup_ephemeral_states(&mut self) {
-        self.ephemeral_states_backup = self.ephemeral_states.clone();
-    }
+    /// Initialize an empty state backed by a temporary in-memory db.
+    ///
+    /// NOTE: Used for testing contracts in-process.
+    pub fn new_in_memory() -> Result<STATE_HOLDER, StateHolderConstructionError> {
+        // Open a temporary db, removed once dropped.
+        let states_db = sled::Config::new()
+            .temporary(true)
+            .open()
+            .map_err(StateHolderConstructionError::MainDBOpenError)?;
 
-    /// Restores ephemeral states from the backup.
-    fn restore_ephemeral_states(&mut self) {
-        self.ephemeral_states = self.ephemeral_states_backup.clone();
+        // Create the state holder.
+        let state_holder = StateHolder {
+            states: HashMap::<CONTRACT_ID, HashMap<STATE_KEY, STATE_VALUE>>::new(),
+            states_db,
+            ephemeral_states: HashMap::<CONTRACT_ID, HashMap<STATE_KEY, STATE_VALUE>>::new(),
+            savepoints: Vec::<HashMap<CONTRACT_ID, HashMap<STATE_KEY, STATE_VALUE>>>::new(),
+        };
+
+        // Return the guarded state holder.
+        Ok(Arc::new(Mutex::new(state_holder)))
     }
 
-    /// Prepares the state holder prior to each execution.
+    /// Pushes a savepoint of the ephemeral states.
     ///
-    /// NOTE: Used by the Engine coordinator.
-    pub fn pre_execution(&mut self) {
-        // Backup the ephemeral states.
-        self.backup_ephemeral_states();
+    /// NOTE: Used prior to each execution and each nested call frame.
+    pub fn push_savepoint(&mut self) {
+        self.savepoints.push(self.ephemeral_states.clone());
+    }
+
+    /// Commits the innermost savepoint, keeping the state update(s) made since it was pushed.
+    pub fn commit_savepoint(&mut self) {
+        self.savepoints.pop();
+    }
+
+    /// Reverts the state update(s) made since the innermost savepoint was pushed, and pops it.
+    pub fn revert_to_savepoint(&mut self) {
+        if let Some(savepoint) = self.savepoints.pop() {
+            self.ephemeral_states = savepoint;
+        }
+    }
+
+    /// Returns the number of open savepoints.
+    pub fn savepoints_len(&self) -> usize {
+        self.savepoints.len()
     }
 
     /// Get the value by key and contract ID.
@@ -135,14 +163,6 @@ impl StateHolder {
         ephemeral_contract_states.insert(key.clone(), value.clone());
     }
 
-    /// Reverts the state update(s) associated with the last execution.
-    ///
-    /// NOTE: Used by the Engine coordinator.
-    pub fn rollback_last(&mut self) {
-        // Restore the ephemeral states from the backup.
-        self.restore_ephemeral_states();
-    }
-
     /// Reverts all state updates associated with all executions.
     ///
     /// NOTE: Used by the Engine coordinator.
@@ -150,66 +170,128 @@ impl StateHolder {
         // Clear the ephemeral states.
         self.ephemeral_states.clear();
 
-        // Clear the ephemeral states backup.
-        self.ephemeral_states_backup.clear();
+        // Clear the savepoints.
+        self.savepoints.clear();
+    }
+
+    /// Returns the saved values of the keys updated during the session, none for the keys not
+    /// saved yet, so that a save can be undone with `restore_values`.
+    pub fn saved_values(&self) -> HashMap<CONTRACT_ID, HashMap<STATE_KEY, Option<STATE_VALUE>>> {
+        self.ephemeral_states
+            .iter()
+            .map(|(contract_id, ephemeral_contract_states)| {
+                let saved_contract_states = ephemeral_contract_states
+                    .keys()
+                    .map(|key| {
+                        let saved_value = self
+                            .states
+                            .get(contract_id)
+                            .and_then(|states| states.get(key).cloned());
+                        (key.clone(), saved_value)
+                    })
+                    .collect::<HashMap<STATE_KEY, Option<STATE_VALUE>>>();
+
+                (*contract_id, saved_contract_states)
+            })
+            .collect()
+    }
+
+    /// Restores the saved values returned by `saved_values`, removing the keys that had none.
+    pub fn restore_values(
+        &mut self,
+        values: &HashMap<CONTRACT_ID, HashMap<STATE_KEY, Option<STATE_VALUE>>>,
+    ) -> Result<(), StateHolderSaveError> {
+        for (contract_id, contract_values) in values.iter() {
+            for (key, value) in contract_values.iter() {
+                self.save_value(contract_id, key, value.as_ref())?;
+            }
+        }
+
+        Ok(())
     }
 
     /// Saves the states updated associated with all executions (on-disk and in-memory).
     ///
+    /// The saved values are restored if any of the updates cannot be saved, and the ephemeral
+    /// states are only cleared once all of them are.
+    ///
     /// TODO Performance Optimization: Open the tree *once per contract ID* and then insert all key-values at once.
     pub fn save_all_executions(&mut self) -> Result<(), StateHolderSaveError> {
-        // Iterate over all ephemeral states.
-        for (contract_id, ephemeral_contract_states) in self.ephemeral_states.iter() {
-            // Iterate over all items in the contract state.
-            for (ephemeral_state_key, ephemeral_state_value) in ephemeral_contract_states.iter() {
-                // In-memory insertion.
-                {
-                    // Get mutable states.
-                    let states = match self.states.get_mut(contract_id) {
-                        Some(states) => states,
-                        None => {
-                            // Create it if it doesn't exist.
-                            let contract_states = HashMap::<STATE_KEY, STATE_VALUE>::new();
-
-                            // Insert it.
-                            self.states.insert(*contract_id, contract_states);
-
-                            // Get it again.
-                            self.states.get_mut(contract_id).unwrap() // Safe because we just inserted it.
-                        }
-                    };
-
-                    // Insert the value into the in-memory contract states.
-                    states.insert(ephemeral_state_key.clone(), ephemeral_state_value.clone());
-                }
-
-                // On-disk insertion.
-                {
-                    // Open the contract tree.
-                    let tree = self
-                        .states_db
-                        .open_tree(contract_id)
-                        .map_err(|e| StateHolderSaveError::OpenTreeError(*contract_id, e))?;
-
-                    // Insert the value into the on-disk contract tree.
-                    tree.insert(ephemeral_state_key, ephemeral_state_value.clone())
-                        .map_err(|e| {
-                            StateHolderSaveError::TreeValueInsertError(
-                                *contract_id,
-                                ephemeral_state_key.clone(),
-                                ephemeral_state_value.clone(),
-                                e,
-                            )
-                        })?;
-                }
+        // Collect the saved values to restore on failure.
+        let saved_values = self.saved_values();
+
+        // Collect the updates to save.
+        let updates = self
+            .ephemeral_states
+            .iter()
+            .flat_map(|(contract_id, ephemeral_contract_states)| {
+                ephemeral_contract_states
+                    .iter()
+                    .map(move |(key, value)| (*contract_id, key.clone(), value.clone()))
+            })
+            .collect::<Vec<(CONTRACT_ID, STATE_KEY, STATE_VALUE)>>();
+
+        // Save the updates.
+        for (contract_id, key, value) in updates.iter() {
+            if let Err(err) = self.save_value(contract_id, key, Some(value)) {
+                // Best-effort restore of the values saved so far.
+                let _ = self.restore_values(&saved_values);
+                return Err(err);
             }
         }
 
         // Clear the ephemeral states.
         self.ephemeral_states.clear();
 
-        // Clear the ephemeral states backup.
-        self.ephemeral_states_backup.clear();
+        // Clear the savepoints.
+        self.savepoints.clear();
+
+        Ok(())
+    }
+
+    /// Saves a value by key and contract ID (on-disk and in-memory), or removes it if none.
+    fn save_value(
+        &mut self,
+        contract_id: &CONTRACT_ID,
+        key: &STATE_KEY,
+        value: Option<&STATE_VALUE>,
+    ) -> Result<(), StateHolderSaveError> {
+        // Open the contract tree.
+        let tree = self
+            .states_db
+            .open_tree(contract_id)
+            .map_err(|e| StateHolderSaveError::OpenTreeError(*contract_id, e))?;
+
+        match value {
+            Some(value) => {
+                // On-disk insertion.
+                tree.insert(key, value.clone()).map_err(|e| {
+                    StateHolderSaveError::TreeValueInsertError(
+                        *contract_id,
+                        key.clone(),
+                        value.clone(),
+                        e,
+                    )
+                })?;
+
+                // In-memory insertion.
+                self.states
+                    .entry(*contract_id)
+                    .or_default()
+                    .insert(key.clone(), value.clone());
+            }
+            None => {
+                // On-disk removal.
+                tree.remove(key).map_err(|e| {
+                    StateHolderSaveError::TreeValueRemoveError(*contract_id, key.clone(), e)
+                })?;
+
+                // In-memory removal.
+                if let Some(states) = self.states.get_mut(contract_id) {
+                    states.remove(key);
+                }
+            }
+        }
 
         Ok(())
     }
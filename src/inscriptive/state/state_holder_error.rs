@@ -24,4 +24,5 @@ pub enum StateHolderConstructionError {
 pub enum StateHolderSaveError {
     OpenTreeError(CONTRACT_ID, sled::Error),
     TreeValueInsertError(CONTRACT_ID, STATE_KEY, STATE_VALUE, sled::Error),
+    TreeValueRemoveError(CONTRACT_ID, STATE_KEY, sled::Error),
 }
@@ -1,6 +1,7 @@
 use crate::{
     constructive::{txn::ext::OutpointExt, txo::lift::Lift},
     operative::Chain,
+    transmutative::codec::canonical::{canonical::Canonical, migrate::migrate_db},
 };
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -24,11 +25,16 @@ impl LiftWallet {
         let lifts_path = format!("{}/{}/{}", "db", chain.to_string(), "wallet/lift");
         let lifts_db = sled::open(lifts_path).ok()?;
 
+        // Migrate the legacy JSON records to the canonical encoding.
+        if !migrate_db::<Lift>(&lifts_db) {
+            return None;
+        }
+
         let mut lift_set = Vec::<Lift>::new();
 
         for lookup in lifts_db.iter() {
             if let Ok((_, val)) = lookup {
-                if let Ok(lift) = serde_json::from_slice::<Lift>(&val) {
+                if let Ok(lift) = Lift::decode_canonical(&val) {
                     lift_set.push(lift);
                 }
             }
@@ -1,6 +1,7 @@
 use crate::{
     constructive::{txn::ext::OutpointExt, txo::vtxo::VTXO},
     operative::Chain,
+    transmutative::codec::canonical::{canonical::Canonical, migrate::migrate_db},
 };
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -24,11 +25,16 @@ impl VTXOWallet {
         let vtxos_path = format!("{}/{}/{}", "db", chain.to_string(), "wallet/vtxo");
         let vtxos_db = sled::open(vtxos_path).ok()?;
 
+        // Migrate the legacy JSON records to the canonical encoding.
+        if !migrate_db::<VTXO>(&vtxos_db) {
+            return None;
+        }
+
         let mut vtxo_set = Vec::<VTXO>::new();
 
         for lookup in vtxos_db.iter() {
             if let Ok((_, val)) = lookup {
-                let vtxo: VTXO = serde_json::from_slice(&val).ok()?;
+                let vtxo = VTXO::decode_canonical(&val).ok()?;
                 vtxo_set.push(vtxo);
             }
         }
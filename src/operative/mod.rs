@@ -2,8 +2,13 @@ pub mod mode;
 pub mod session;
 pub mod sync;
 
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
+use serde::{Deserialize, Serialize};
+
 /// Chain type.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Chain {
     Signet,
     Mainnet,
@@ -18,6 +23,24 @@ impl ToString for Chain {
     }
 }
 
+/// The canonical encoding for `Chain`: 0x00 for signet, 0x01 for mainnet.
+impl Canonical for Chain {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Chain::Signet => bytes.push(0x00),
+            Chain::Mainnet => bytes.push(0x01),
+        }
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Chain, CanonicalDecodeError> {
+        match reader.read_u8()? {
+            0x00 => Ok(Chain::Signet),
+            0x01 => Ok(Chain::Mainnet),
+            tag => Err(CanonicalDecodeError::InvalidTag(tag)),
+        }
+    }
+}
+
 /// Operating mode type.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum OperatingMode {
@@ -9,18 +9,26 @@ use crate::communicative::rpc::bitcoin::rpcholder::RPCHolder;
 use crate::communicative::tcp;
 use crate::communicative::tcp::tcp::open_port;
 use crate::communicative::tcp::tcp::port_number;
+use crate::inscriptive::beacon::dir::BeaconDirectory;
+use crate::inscriptive::beacon::dir::BEACON_DIRECTORY;
 use crate::inscriptive::blacklist::BlacklistDirectory;
 use crate::inscriptive::blacklist::BLIST_DIRECTORY;
 use crate::inscriptive::epoch::dir::EpochDirectory;
 use crate::inscriptive::epoch::dir::EPOCH_DIRECTORY;
 use crate::inscriptive::lp::dir::LPDirectory;
 use crate::inscriptive::lp::dir::LP_DIRECTORY;
+use crate::inscriptive::price::dir::PriceDirectory;
+use crate::inscriptive::price::dir::PRICE_DIRECTORY;
 use crate::inscriptive::registery::registery::Registery;
 use crate::inscriptive::registery::registery::REGISTERY;
+use crate::inscriptive::repo::repo::ProgramsRepo;
+use crate::inscriptive::repo::repo::PROGRAMS_REPO;
 use crate::inscriptive::rollup::dir::RollupDirectory;
 use crate::inscriptive::rollup::dir::ROLLUP_DIRECTORY;
 use crate::inscriptive::set::set::CoinSet;
 use crate::inscriptive::set::set::COIN_SET;
+use crate::inscriptive::state::state_holder::StateHolder;
+use crate::inscriptive::state::state_holder::STATE_HOLDER;
 use crate::operative::mode::ccli;
 use crate::operative::mode::coordinator::dkgops::DKGOps;
 use crate::operative::session::ccontext::CContextRunner;
@@ -114,6 +122,8 @@ pub async fn run(key_holder: KeyHolder, chain: Chain, rpc_holder: RPCHolder) {
                     &lp_dir,
                     &registery,
                     None,
+                    None,
+                    None,
                     &coin_set,
                 )
                 .await;
@@ -185,11 +195,79 @@ pub async fn run(key_holder: KeyHolder, chain: Chain, rpc_holder: RPCHolder) {
         }
     };
 
-    // #18 Construct CSession.
-    let csession_ctx: CSESSION_CTX =
-        CSessionCtx::construct(&dkg_manager, &peer_manager, &blacklist_dir, &registery);
+    // #18 Construct price directory.
+    let price_dir: PRICE_DIRECTORY = match PriceDirectory::new(chain) {
+        Some(price_dir) => price_dir,
+        None => {
+            eprintln!(
+                "{}",
+                "Unexpected error: Failed to create price directory.".red()
+            );
+            return;
+        }
+    };
+
+    // #19 Construct state holder.
+    let state_holder: STATE_HOLDER = match StateHolder::new(chain) {
+        Ok(state_holder) => state_holder,
+        Err(err) => {
+            eprintln!(
+                "{}",
+                format!("Unexpected error: Failed to create state holder: {:?}", err).red()
+            );
+            return;
+        }
+    };
+
+    // #20 Construct programs repo.
+    let programs_repo: PROGRAMS_REPO = match ProgramsRepo::new(chain) {
+        Ok(programs_repo) => programs_repo,
+        Err(err) => {
+            eprintln!(
+                "{}",
+                format!(
+                    "Unexpected error: Failed to create programs repo: {:?}",
+                    err
+                )
+                .red()
+            );
+            return;
+        }
+    };
+
+    // #21 Construct beacon directory.
+    let beacon_dir: BEACON_DIRECTORY = match BeaconDirectory::new(chain) {
+        Some(beacon_dir) => beacon_dir,
+        None => {
+            eprintln!(
+                "{}",
+                "Unexpected error: Failed to create beacon directory.".red()
+            );
+            return;
+        }
+    };
+
+    // #22 Construct CSession.
+    let (vtxo_set, utxo_set) = {
+        let _coin_set = coin_set.lock().await;
+        (_coin_set.vtxo_set(), _coin_set.utxo_set())
+    };
+    let csession_ctx: CSESSION_CTX = CSessionCtx::construct(
+        chain,
+        &dkg_manager,
+        &peer_manager,
+        &blacklist_dir,
+        &registery,
+        &price_dir,
+        &vtxo_set,
+        &utxo_set,
+        &rollup_dir,
+        &state_holder,
+        &programs_repo,
+        &beacon_dir,
+    );
 
-    // #19 Run CSession.
+    // #23 Run CSession.
     {
         let csession_ctx = Arc::clone(&csession_ctx);
         let _ = tokio::spawn(async move {
@@ -197,7 +275,7 @@ pub async fn run(key_holder: KeyHolder, chain: Chain, rpc_holder: RPCHolder) {
         });
     }
 
-    // #20 Run TCP server.
+    // #24 Run TCP server.
     {
         let nns_client = nns_client.clone();
         let dkg_manager = Arc::clone(&dkg_manager);
@@ -216,7 +294,7 @@ pub async fn run(key_holder: KeyHolder, chain: Chain, rpc_holder: RPCHolder) {
         });
     }
 
-    // #21 Initialize CLI.
+    // #25 Initialize CLI.
     cli(&mut peer_manager, &mut dkg_manager, &mut blacklist_dir).await;
 }
 
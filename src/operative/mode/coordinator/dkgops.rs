@@ -6,10 +6,14 @@ use crate::{
         },
         tcp::client::TCPClient,
     },
-    inscriptive::{baked::INITIAL_OPERATOR_SET, lp::dir::LP_DIRECTORY},
+    constructive::payload::payload::SessionPayload,
+    inscriptive::{
+        baked::INITIAL_OPERATOR_SET, beacon::dir::BEACON_DIRECTORY, lp::dir::LP_DIRECTORY,
+    },
     transmutative::{
         musig::session::MusigSessionCtx,
         noist::{
+            beacon::RandomnessBeacon,
             dkg::{
                 directory::DKG_DIRECTORY,
                 session::{DKGSession, DKG_SESSION},
@@ -50,6 +54,22 @@ pub enum DKGSignError {
     PartialSignTimeout,
 }
 
+#[derive(Clone, Debug)]
+pub enum DKGBeaconError {
+    DirectoryNotFound,
+    GroupKeyNotFound,
+    SignErr(DKGSignError),
+    BeaconInsertionErr,
+}
+
+#[derive(Clone, Debug)]
+pub enum DKGPayloadError {
+    DirectoryNotFound,
+    GroupKeyNotFound,
+    SignErr(DKGSignError),
+    PayloadVerifyErr,
+}
+
 #[async_trait]
 pub trait DKGOps {
     async fn new_setup(&self, peer_manager: &mut PEER_MANAGER) -> Result<u64, DKGSetupError>;
@@ -60,6 +80,17 @@ pub trait DKGOps {
         dir_height: u64,
         messages: Vec<(Option<u64>, [u8; 32], Option<MusigSessionCtx>)>,
     ) -> Result<Vec<[u8; 64]>, DKGSignError>;
+    async fn sign_beacon(
+        &self,
+        peer_manager: &mut PEER_MANAGER,
+        beacon_dir: &BEACON_DIRECTORY,
+        session_height: u64,
+    ) -> Result<RandomnessBeacon, DKGBeaconError>;
+    async fn sign_payload(
+        &self,
+        peer_manager: &mut PEER_MANAGER,
+        payload: &SessionPayload,
+    ) -> Result<SessionPayload, DKGPayloadError>;
 }
 
 #[async_trait]
@@ -393,6 +424,116 @@ impl DKGOps for DKG_MANAGER {
         // # 12 Return full signatures.
         Ok(full_signatures)
     }
+
+    async fn sign_beacon(
+        &self,
+        peer_manager: &mut PEER_MANAGER,
+        beacon_dir: &BEACON_DIRECTORY,
+        session_height: u64,
+    ) -> Result<RandomnessBeacon, DKGBeaconError> {
+        // #1 Initialize the active DKG directory.
+        let dkg_directory: DKG_DIRECTORY = {
+            let _dkg_manager = self.lock().await;
+            match _dkg_manager.active_directory() {
+                Some(directory) => directory,
+                None => return Err(DKGBeaconError::DirectoryNotFound),
+            }
+        };
+
+        // #2 Return the directory height and the quorum key.
+        let (dir_height, quorum_key) = {
+            let _dkg_directory = dkg_directory.lock().await;
+            match _dkg_directory.group_key() {
+                Some(group_key) => (_dkg_directory.dir_height(), group_key.serialize_xonly()),
+                None => return Err(DKGBeaconError::GroupKeyNotFound),
+            }
+        };
+
+        // #3 Return the randomness the beacon chains to and the message to sign.
+        let (prev, message) = {
+            let _beacon_dir = beacon_dir.lock().await;
+            (
+                _beacon_dir.prev_randomness(session_height),
+                _beacon_dir.beacon_message(session_height),
+            )
+        };
+
+        // #4 Sign the message with the operator quorum.
+        let signature = match self
+            .sign(peer_manager, dir_height, vec![(None, message, None)])
+            .await
+            .map_err(DKGBeaconError::SignErr)?
+            .first()
+        {
+            Some(signature) => signature.to_owned(),
+            None => return Err(DKGBeaconError::SignErr(DKGSignError::AggSigErr)),
+        };
+
+        // #5 Construct the beacon.
+        let beacon = RandomnessBeacon::new(session_height, quorum_key, prev, signature);
+
+        // #6 Insert the beacon into the beacon directory.
+        {
+            let mut _beacon_dir = beacon_dir.lock().await;
+            if !_beacon_dir.insert_beacon(&beacon, quorum_key) {
+                return Err(DKGBeaconError::BeaconInsertionErr);
+            }
+        }
+
+        // #7 Return the beacon.
+        Ok(beacon)
+    }
+
+    async fn sign_payload(
+        &self,
+        peer_manager: &mut PEER_MANAGER,
+        payload: &SessionPayload,
+    ) -> Result<SessionPayload, DKGPayloadError> {
+        // #1 Initialize the active DKG directory.
+        let dkg_directory: DKG_DIRECTORY = {
+            let _dkg_manager = self.lock().await;
+            match _dkg_manager.active_directory() {
+                Some(directory) => directory,
+                None => return Err(DKGPayloadError::DirectoryNotFound),
+            }
+        };
+
+        // #2 Return the directory height and the quorum key.
+        let (dir_height, quorum_key) = {
+            let _dkg_directory = dkg_directory.lock().await;
+            match _dkg_directory.group_key() {
+                Some(group_key) => (_dkg_directory.dir_height(), group_key.serialize_xonly()),
+                None => return Err(DKGPayloadError::GroupKeyNotFound),
+            }
+        };
+
+        // #3 Sign the payload message with the operator quorum.
+        let signature = match self
+            .sign(
+                peer_manager,
+                dir_height,
+                vec![(None, payload.signing_message(), None)],
+            )
+            .await
+            .map_err(DKGPayloadError::SignErr)?
+            .first()
+        {
+            Some(signature) => signature.to_owned(),
+            None => return Err(DKGPayloadError::SignErr(DKGSignError::AggSigErr)),
+        };
+
+        // #4 Set the signature.
+        let mut payload = payload.to_owned();
+        payload.set_operator_sig(signature);
+
+        // #5 Nodes only sync payloads signed by the quorum key.
+        if !payload.verify(quorum_key) {
+            return Err(DKGPayloadError::PayloadVerifyErr);
+        }
+
+        // #6 Return the signed payload.
+        Ok(payload)
+    }
 }
 
 pub async fn preprocess(peer_manager: &mut PEER_MANAGER, dkg_directory: &DKG_DIRECTORY) {
@@ -5,4 +5,5 @@ pub mod decomp;
 pub mod lift;
 pub mod r#move;
 pub mod npub;
+pub mod opsprice;
 pub mod ping;
@@ -0,0 +1,12 @@
+use crate::{communicative::peer::peer::PEER, communicative::tcp::client::TCPClient};
+
+// opsprice
+pub async fn opsprice_command(coordinator: &PEER) {
+    match coordinator.request_base_ops_price().await {
+        Ok((session_height, base_ops_price)) => println!(
+            "Session #{} base ops price: {}",
+            session_height, base_ops_price
+        ),
+        Err(_) => println!("Error requesting base ops price."),
+    }
+}
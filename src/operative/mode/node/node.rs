@@ -6,6 +6,10 @@ use crate::communicative::peer::peer::PEER;
 use crate::communicative::rpc::bitcoin::rpc::validate_rpc;
 use crate::communicative::rpc::bitcoin::rpcholder::RPCHolder;
 use crate::constructive::entity::account::account::Account;
+use crate::inscriptive::beacon::dir::BeaconDirectory;
+use crate::inscriptive::beacon::dir::BEACON_DIRECTORY;
+use crate::inscriptive::blacklist::BlacklistDirectory;
+use crate::inscriptive::blacklist::BLIST_DIRECTORY;
 use crate::inscriptive::epoch::dir::EpochDirectory;
 use crate::inscriptive::epoch::dir::EPOCH_DIRECTORY;
 use crate::inscriptive::lp::dir::LPDirectory;
@@ -13,6 +17,8 @@ use crate::inscriptive::lp::dir::LP_DIRECTORY;
 use crate::inscriptive::registery::account_registery::ACCOUNT_REGISTERY;
 use crate::inscriptive::registery::registery::Registery;
 use crate::inscriptive::registery::registery::REGISTERY;
+use crate::inscriptive::repo::repo::ProgramsRepo;
+use crate::inscriptive::repo::repo::PROGRAMS_REPO;
 use crate::inscriptive::rollup::dir::RollupDirectory;
 use crate::inscriptive::rollup::dir::ROLLUP_DIRECTORY;
 use crate::inscriptive::set::set::CoinSet;
@@ -20,6 +26,7 @@ use crate::inscriptive::set::set::COIN_SET;
 use crate::inscriptive::wallet::wallet::Wallet;
 use crate::inscriptive::wallet::wallet::WALLET;
 use crate::operative::mode::ncli;
+use crate::operative::sync::beacon::BeaconSync;
 use crate::operative::sync::rollup::RollupSync;
 use crate::operative::Chain;
 use crate::operative::OperatingMode;
@@ -95,7 +102,34 @@ pub async fn run(key_holder: KeyHolder, chain: Chain, rpc_holder: RPCHolder) {
         }
     };
 
-    // #8 Spawn syncer
+    // #8 Initialize beacon directory.
+    let beacon_dir: BEACON_DIRECTORY = match BeaconDirectory::new(chain) {
+        Some(dir) => dir,
+        None => {
+            println!("{}", "Error initializing beacon directory.".red());
+            return;
+        }
+    };
+
+    // #9 Initialize programs repo.
+    let programs_repo: PROGRAMS_REPO = match ProgramsRepo::new(chain) {
+        Ok(programs_repo) => programs_repo,
+        Err(_) => {
+            println!("{}", "Error initializing programs repo.".red());
+            return;
+        }
+    };
+
+    // #10 Initialize blacklist directory.
+    let blacklist_dir: BLIST_DIRECTORY = match BlacklistDirectory::new(chain) {
+        Some(dir) => dir,
+        None => {
+            println!("{}", "Error initializing blacklist directory.".red());
+            return;
+        }
+    };
+
+    // #11 Spawn syncer
     {
         let chain = chain.clone();
         let key_holder = key_holder.clone();
@@ -104,6 +138,8 @@ pub async fn run(key_holder: KeyHolder, chain: Chain, rpc_holder: RPCHolder) {
         let lp_dir = Arc::clone(&lp_dir);
         let registery = Arc::clone(&registery);
         let wallet = Arc::clone(&wallet);
+        let programs_repo = Arc::clone(&programs_repo);
+        let blacklist_dir = Arc::clone(&blacklist_dir);
         let rollup_dir = Arc::clone(&rollup_dir);
         let coin_set = Arc::clone(&coin_set);
 
@@ -117,6 +153,8 @@ pub async fn run(key_holder: KeyHolder, chain: Chain, rpc_holder: RPCHolder) {
                     &lp_dir,
                     &registery,
                     Some(&wallet),
+                    Some(&programs_repo),
+                    Some(&blacklist_dir),
                     &coin_set,
                 )
                 .await;
@@ -125,12 +163,12 @@ pub async fn run(key_holder: KeyHolder, chain: Chain, rpc_holder: RPCHolder) {
 
     println!("{}", "Syncing rollup.");
 
-    // #9 Wait until rollup to be synced to the latest Bitcoin chain tip.
+    // #12 Wait until rollup to be synced to the latest Bitcoin chain tip.
     rollup_dir.await_sync().await;
 
     println!("{}", "Syncing complete.");
 
-    // #10 Construct account.
+    // #13 Construct account.
     let account = {
         let account_registery: ACCOUNT_REGISTERY = {
             let _registery = registery.lock().await;
@@ -148,10 +186,10 @@ pub async fn run(key_holder: KeyHolder, chain: Chain, rpc_holder: RPCHolder) {
         }
     };
 
-    // #11 Initialize NNS client.
+    // #14 Initialize NNS client.
     let nns_client = NNSClient::new(&key_holder).await;
 
-    // #12 Connect to the coordinator.
+    // #15 Connect to the coordinator.
     let coordinator: PEER = {
         let coordinator_key = coordinator_key(chain);
 
@@ -167,7 +205,18 @@ pub async fn run(key_holder: KeyHolder, chain: Chain, rpc_holder: RPCHolder) {
         }
     };
 
-    // #13 CLI.
+    // #16 Spawn beacon syncer.
+    {
+        let coordinator = Arc::clone(&coordinator);
+        let epoch_dir = Arc::clone(&epoch_dir);
+        let beacon_dir = Arc::clone(&beacon_dir);
+
+        tokio::spawn(async move {
+            beacon_dir.sync(&coordinator, &epoch_dir).await;
+        });
+    }
+
+    // #17 CLI.
     cli(
         chain,
         &coordinator,
@@ -216,6 +265,7 @@ pub async fn cli(
             "clear" => ncli::clear::clear_command(),
             "conn" => ncli::conn::conn_command(coordinator_conn).await,
             "ping" => ncli::ping::ping_command(coordinator_conn).await,
+            "opsprice" => ncli::opsprice::opsprice_command(coordinator_conn).await,
             "npub" => ncli::npub::npub_command(key_holder).await,
             "addr" => ncli::addr::addr_command(chain, epoch_dir, key_holder).await,
             "lift" => ncli::lift::lift_command(wallet, epoch_dir, chain, key_holder, parts).await,
@@ -110,6 +110,8 @@ pub async fn run(key_holder: KeyHolder, chain: Chain, rpc_holder: RPCHolder) {
                     &lp_dir,
                     &registery,
                     None,
+                    None,
+                    None,
                     &coin_set,
                 )
                 .await;
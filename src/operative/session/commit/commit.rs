@@ -1,6 +1,10 @@
 use crate::{
     constructive::{entity::account::account::Account, entry::entry::Entry, txo::lift::Lift},
+    operative::Chain,
     transmutative::{
+        codec::canonical::{
+            canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+        },
         hash::{Hash, HashTag},
         secp::authenticable::AuthSighash,
     },
@@ -13,6 +17,8 @@ use std::collections::HashMap;
 /// It is sent by the msg.senders to the coordinator, who then responds with `CSessionCommitAck`.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct NSessionCommit {
+    // Chain the entry is signed for
+    chain: Chain,
     // Entry
     entry: Entry,
     // Payload auth nonces (hiding & binding)
@@ -31,6 +37,7 @@ pub struct NSessionCommit {
 
 impl NSessionCommit {
     pub fn new(
+        chain: Chain,
         entry: Entry,
         // Payload auth nonces
         payload_auth_hiding_nonce: Point,
@@ -49,6 +56,7 @@ impl NSessionCommit {
         connector_txo_nonces: &Vec<(Point, Point)>,
     ) -> NSessionCommit {
         NSessionCommit {
+            chain,
             entry,
             payload_auth_nonces: (payload_auth_hiding_nonce, payload_auth_binding_nonce),
             vtxo_projector_nonces: (vtxo_projector_hiding_nonce, vtxo_projector_binding_nonce),
@@ -62,6 +70,10 @@ impl NSessionCommit {
         }
     }
 
+    pub fn chain(&self) -> Chain {
+        self.chain
+    }
+
     pub fn account(&self) -> Account {
         self.entry.account()
     }
@@ -100,7 +112,7 @@ impl AuthSighash for NSessionCommit {
         let mut preimage: Vec<u8> = Vec::<u8>::new();
 
         // Entry
-        preimage.extend(self.entry.auth_sighash());
+        preimage.extend(self.entry.sighash(self.chain));
 
         // Payload auth nonces
         preimage.extend(self.payload_auth_nonces.0.serialize());
@@ -134,3 +146,32 @@ impl AuthSighash for NSessionCommit {
         preimage.hash(Some(HashTag::Sighash))
     }
 }
+
+/// The canonical encoding for `NSessionCommit`, sent to the coordinator to commit to a session.
+impl Canonical for NSessionCommit {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.chain.encode_fields(bytes);
+        self.entry.encode_fields(bytes);
+        self.payload_auth_nonces.encode_fields(bytes);
+        self.vtxo_projector_nonces.encode_fields(bytes);
+        self.connector_projector_nonces.encode_fields(bytes);
+        self.zkp_contingent_nonces.encode_fields(bytes);
+        self.lift_prevtxo_nonces.encode_fields(bytes);
+        self.connector_txo_nonces.encode_fields(bytes);
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<NSessionCommit, CanonicalDecodeError> {
+        Ok(NSessionCommit {
+            chain: Chain::decode_fields(reader)?,
+            entry: Entry::decode_fields(reader)?,
+            payload_auth_nonces: Canonical::decode_fields(reader)?,
+            vtxo_projector_nonces: Canonical::decode_fields(reader)?,
+            connector_projector_nonces: Canonical::decode_fields(reader)?,
+            zkp_contingent_nonces: Canonical::decode_fields(reader)?,
+            lift_prevtxo_nonces: Canonical::decode_fields(reader)?,
+            connector_txo_nonces: Canonical::decode_fields(reader)?,
+        })
+    }
+}
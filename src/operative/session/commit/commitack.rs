@@ -1,10 +1,15 @@
 use crate::{
-    constructive::{entity::account::account::Account, entry::entry::Entry, txo::lift::Lift},
-    transmutative::{
-        hash::{Hash, HashTag},
-        musig::session::MusigSessionCtx,
-        secp::authenticable::AuthSighash,
+    constructive::{
+        entity::account::account::Account,
+        entry::entry::Entry,
+        payload::{anchor::SessionAnchor, payload::SessionPayload},
+        txo::lift::Lift,
     },
+    operative::Chain,
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    },
+    transmutative::musig::session::MusigSessionCtx,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -17,7 +22,12 @@ use std::collections::HashMap;
 pub struct CSessionCommitAck {
     // Msg sender
     account: Account,
+    chain: Chain,
     session_id: [u8; 32],
+    // Base ops price the session is priced at
+    base_ops_price: u32,
+    // Bitcoin block the session is anchored to
+    anchor: SessionAnchor,
     // Entries
     entries: Vec<Entry>,
     // Payload auth
@@ -37,7 +47,10 @@ pub struct CSessionCommitAck {
 impl CSessionCommitAck {
     pub fn new(
         account: Account,
+        chain: Chain,
         session_id: [u8; 32],
+        base_ops_price: u32,
+        anchor: SessionAnchor,
         entries: Vec<Entry>,
         payload_auth_musig_ctx: MusigSessionCtx,
         vtxo_projector_musig_ctx: Option<MusigSessionCtx>,
@@ -48,7 +61,10 @@ impl CSessionCommitAck {
     ) -> CSessionCommitAck {
         CSessionCommitAck {
             account,
+            chain,
             session_id,
+            base_ops_price,
+            anchor,
             entries,
             payload_auth_musig_ctx,
             vtxo_projector_musig_ctx,
@@ -63,10 +79,22 @@ impl CSessionCommitAck {
         self.account.clone()
     }
 
+    pub fn chain(&self) -> Chain {
+        self.chain
+    }
+
     pub fn session_id(&self) -> [u8; 32] {
         self.session_id
     }
 
+    pub fn base_ops_price(&self) -> u32 {
+        self.base_ops_price
+    }
+
+    pub fn anchor(&self) -> SessionAnchor {
+        self.anchor
+    }
+
     pub fn entries(&self) -> Vec<Entry> {
         self.entries.clone()
     }
@@ -96,22 +124,53 @@ impl CSessionCommitAck {
     }
 
     fn payload_auth_msg(&self) -> [u8; 32] {
-        let mut preimage = Vec::<u8>::new();
-
-        // Session ID
-        preimage.extend(self.session_id);
-
-        // Entries
-        for (index, entry) in self.entries.iter().enumerate() {
-            let entry_sighash = entry.auth_sighash();
-            preimage.extend((index as u32).to_le_bytes());
-            preimage.extend(entry_sighash);
-        }
-
-        preimage.hash(Some(HashTag::PayloadAuth))
+        SessionPayload::auth_msg(
+            self.session_id,
+            self.chain,
+            self.base_ops_price,
+            self.anchor,
+            &self.entries,
+        )
     }
 
     pub fn validate_payload_auth_msg(&self) -> bool {
         self.payload_auth_musig_ctx.message() == self.payload_auth_msg()
     }
 }
+
+/// The canonical encoding for `CSessionCommitAck`, returned to the msg.senders.
+impl Canonical for CSessionCommitAck {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.account.encode_fields(bytes);
+        self.chain.encode_fields(bytes);
+        self.session_id.encode_fields(bytes);
+        self.base_ops_price.encode_fields(bytes);
+        self.anchor.encode_fields(bytes);
+        self.entries.encode_fields(bytes);
+        self.payload_auth_musig_ctx.encode_fields(bytes);
+        self.vtxo_projector_musig_ctx.encode_fields(bytes);
+        self.connector_projector_musig_ctx.encode_fields(bytes);
+        self.zkp_contingent_musig_ctx.encode_fields(bytes);
+        self.lift_prevtxo_musig_ctxes.encode_fields(bytes);
+        self.connector_txo_musig_ctxes.encode_fields(bytes);
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<CSessionCommitAck, CanonicalDecodeError> {
+        Ok(CSessionCommitAck {
+            account: Account::decode_fields(reader)?,
+            chain: Chain::decode_fields(reader)?,
+            session_id: <[u8; 32]>::decode_fields(reader)?,
+            base_ops_price: u32::decode_fields(reader)?,
+            anchor: SessionAnchor::decode_fields(reader)?,
+            entries: Canonical::decode_fields(reader)?,
+            payload_auth_musig_ctx: MusigSessionCtx::decode_fields(reader)?,
+            vtxo_projector_musig_ctx: Canonical::decode_fields(reader)?,
+            connector_projector_musig_ctx: Canonical::decode_fields(reader)?,
+            zkp_contingent_musig_ctx: Canonical::decode_fields(reader)?,
+            lift_prevtxo_musig_ctxes: Canonical::decode_fields(reader)?,
+            connector_txo_musig_ctxes: Canonical::decode_fields(reader)?,
+        })
+    }
+}
@@ -1,3 +1,7 @@
+use crate::constructive::entry::validation::validation_error::EntryValidationError;
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
 use serde::{Deserialize, Serialize};
 
 /// `CSessionCommitNack` is returned by the coordinator to the msg.senders
@@ -6,14 +10,11 @@ use serde::{Deserialize, Serialize};
 pub enum CSessionCommitNack {
     // Immediate errors upon insertion.
     SessionLocked,
-    BlacklistedUntil(u64),
     AuthErr,
+    ChainMismatch,
     Overlap,
-    Allowance,
-    InvalidLiftAccountKey,
-    InvalidAccountRegisteryIndex,
+    InvalidEntry(EntryValidationError),
     InvalidLiftOperatorKey,
-    MissingLiftOutpoint,
     InvalidLiftOutpoint,
     InsufficientConnectors,
     // Post commit-pool errors
@@ -22,3 +23,48 @@ pub enum CSessionCommitNack {
     AccountMismatch,
     PayloadAuthCtxErr,
 }
+
+/// The canonical encoding for `CSessionCommitNack`: the variant tag, followed by its value.
+impl Canonical for CSessionCommitNack {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        match self {
+            CSessionCommitNack::SessionLocked => bytes.push(0x00),
+            CSessionCommitNack::AuthErr => bytes.push(0x01),
+            CSessionCommitNack::ChainMismatch => bytes.push(0x02),
+            CSessionCommitNack::Overlap => bytes.push(0x03),
+            CSessionCommitNack::InvalidEntry(value) => {
+                bytes.push(0x04);
+                value.encode_fields(bytes);
+            }
+            CSessionCommitNack::InvalidLiftOperatorKey => bytes.push(0x05),
+            CSessionCommitNack::InvalidLiftOutpoint => bytes.push(0x06),
+            CSessionCommitNack::InsufficientConnectors => bytes.push(0x07),
+            CSessionCommitNack::CommitPruned => bytes.push(0x08),
+            CSessionCommitNack::SessionNotLocked => bytes.push(0x09),
+            CSessionCommitNack::AccountMismatch => bytes.push(0x0a),
+            CSessionCommitNack::PayloadAuthCtxErr => bytes.push(0x0b),
+        }
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<CSessionCommitNack, CanonicalDecodeError> {
+        match reader.read_u8()? {
+            0x00 => Ok(CSessionCommitNack::SessionLocked),
+            0x01 => Ok(CSessionCommitNack::AuthErr),
+            0x02 => Ok(CSessionCommitNack::ChainMismatch),
+            0x03 => Ok(CSessionCommitNack::Overlap),
+            0x04 => Ok(CSessionCommitNack::InvalidEntry(
+                EntryValidationError::decode_fields(reader)?,
+            )),
+            0x05 => Ok(CSessionCommitNack::InvalidLiftOperatorKey),
+            0x06 => Ok(CSessionCommitNack::InvalidLiftOutpoint),
+            0x07 => Ok(CSessionCommitNack::InsufficientConnectors),
+            0x08 => Ok(CSessionCommitNack::CommitPruned),
+            0x09 => Ok(CSessionCommitNack::SessionNotLocked),
+            0x0a => Ok(CSessionCommitNack::AccountMismatch),
+            0x0b => Ok(CSessionCommitNack::PayloadAuthCtxErr),
+            tag => Err(CanonicalDecodeError::InvalidTag(tag)),
+        }
+    }
+}
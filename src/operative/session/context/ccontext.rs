@@ -9,28 +9,47 @@ use crate::{
     },
     constructive::{
         entity::account::account::Account,
-        entry::entry::Entry,
+        entry::{
+            combinator::combinator::Combinator, entry::Entry,
+            validation::validation_ctx::EntryValidationCtx,
+        },
+        payload::{
+            anchor::SessionAnchor, envelope_error::SessionPayloadEnvelopeError,
+            payload::SessionPayload, payload_error::SessionPayloadConstructionError,
+        },
         txo::{
             connector::Connector,
             lift::Lift,
             projector::{Projector, ProjectorTag},
         },
     },
+    executive::exec::{exec_ctx::ExecCtx, exec_error::ExecCtxSaveError},
     inscriptive::{
+        beacon::dir::BEACON_DIRECTORY,
         blacklist::BLIST_DIRECTORY,
-        registery::{account_registery::ACCOUNT_REGISTERY, registery::REGISTERY},
+        price::dir::PRICE_DIRECTORY,
+        registery::{
+            account_registery::ACCOUNT_REGISTERY, contract_registery::CONTRACT_REGISTERY,
+            registery::REGISTERY,
+        },
+        repo::repo::PROGRAMS_REPO,
+        rollup::dir::ROLLUP_DIRECTORY,
+        set::{utxo_set::UTXO_SET, vtxo_set::VTXO_SET},
+        state::state_holder::STATE_HOLDER,
     },
-    operative::session::{
-        allowance::allowance, commit::NSessionCommit, commitack::CSessionCommitAck,
+    operative::{
+        mode::coordinator::dkgops::DKGOps,
+        session::{commit::NSessionCommit, commitack::CSessionCommitAck},
+        Chain,
     },
     transmutative::{
-        hash::{Hash, HashTag},
         musig::{keyagg::MusigKeyAggCtx, session::MusigSessionCtx},
         noist::{dkg::directory::DKG_DIRECTORY, manager::DKG_MANAGER, session::NOISTSessionCtx},
-        secp::authenticable::{AuthSighash, Authenticable},
+        secp::authenticable::Authenticable,
     },
 };
 use async_trait::async_trait;
+use bitcoin::ScriptBuf;
 use colored::Colorize;
 use secp::{Point, Scalar};
 use std::{collections::HashMap, sync::Arc, time::Duration};
@@ -66,9 +85,21 @@ pub struct CSessionCtx {
     peer_manager: PEER_MANAGER,
     blacklist_dir: BLIST_DIRECTORY,
     registery: REGISTERY,
+    price_dir: PRICE_DIRECTORY,
+    vtxo_set: VTXO_SET,
+    utxo_set: UTXO_SET,
+    rollup_dir: ROLLUP_DIRECTORY,
+    state_holder: STATE_HOLDER,
+    programs_repo: PROGRAMS_REPO,
+    beacon_dir: BEACON_DIRECTORY,
     //
+    chain: Chain,
     session_id: [u8; 32],
     stage: CSessionStage,
+    // Base ops price the session is priced at.
+    base_ops_price: u32,
+    // Bitcoin block the session is anchored to.
+    anchor: SessionAnchor,
     // Commit pool.
     commit_pool: Vec<NSessionCommit>,
     // Post-commit-pool pruned (failed) commits
@@ -77,6 +108,8 @@ pub struct CSessionCtx {
     passed_commits: Vec<NSessionCommit>,
     // Entries
     entries: Vec<Entry>,
+    // Finalized session payload and its envelope
+    payload: Option<(SessionPayload, ScriptBuf)>,
     // Payload Auth:
     payload_auth_nonces: HashMap<Account, (Point, Point)>,
     payload_auth_ctxes: Option<(
@@ -138,24 +171,43 @@ pub struct CSessionCtx {
 
 impl CSessionCtx {
     pub fn construct(
+        chain: Chain,
         dkg_manager: &DKG_MANAGER,
         peer_manager: &PEER_MANAGER,
         blacklist_dir: &BLIST_DIRECTORY,
         registery: &REGISTERY,
+        price_dir: &PRICE_DIRECTORY,
+        vtxo_set: &VTXO_SET,
+        utxo_set: &UTXO_SET,
+        rollup_dir: &ROLLUP_DIRECTORY,
+        state_holder: &STATE_HOLDER,
+        programs_repo: &PROGRAMS_REPO,
+        beacon_dir: &BEACON_DIRECTORY,
     ) -> CSESSION_CTX {
         let session = CSessionCtx {
             dkg_manager: Arc::clone(dkg_manager),
             peer_manager: Arc::clone(peer_manager),
             blacklist_dir: Arc::clone(blacklist_dir),
             registery: Arc::clone(registery),
+            price_dir: Arc::clone(price_dir),
+            vtxo_set: Arc::clone(vtxo_set),
+            utxo_set: Arc::clone(utxo_set),
+            rollup_dir: Arc::clone(rollup_dir),
+            state_holder: Arc::clone(state_holder),
+            programs_repo: Arc::clone(programs_repo),
+            beacon_dir: Arc::clone(beacon_dir),
+            chain,
             session_id: [0xffu8; 32],
             stage: CSessionStage::Off,
+            base_ops_price: 0,
+            anchor: SessionAnchor::new(0, 0),
             commit_pool: Vec::<NSessionCommit>::new(),
             pruned_commits: Vec::<NSessionCommit>::new(),
             // Post-commit-pool valid commits
             passed_commits: Vec::<NSessionCommit>::new(),
             // Entries
             entries: Vec::<Entry>::new(),
+            payload: None,
             payload_auth_nonces: HashMap::<Account, (Point, Point)>::new(),
             payload_auth_ctxes: None,
             vtxo_projector_nonces: HashMap::<Account, (Point, Point)>::new(),
@@ -199,10 +251,54 @@ impl CSessionCtx {
         Arc::clone(&self.peer_manager)
     }
 
-    pub fn init(&mut self, session_id: [u8; 32]) {
+    pub fn price_dir(&self) -> PRICE_DIRECTORY {
+        Arc::clone(&self.price_dir)
+    }
+
+    pub fn beacon_dir(&self) -> BEACON_DIRECTORY {
+        Arc::clone(&self.beacon_dir)
+    }
+
+    /// Initializes the session, priced and anchored to the current Bitcoin sync height before any
+    /// `NSessionCommit`s are accepted. Returns false if the session cannot be anchored.
+    pub async fn init(&mut self, session_id: [u8; 32]) -> bool {
         self.session_id = session_id;
         self.reset();
+
+        self.base_ops_price = {
+            let _price_dir = self.price_dir.lock().await;
+            _price_dir.next_base_ops_price()
+        };
+
+        if !self.set_anchor().await {
+            return false;
+        }
+
         self.on();
+        true
+    }
+
+    /// Anchors the session to the Bitcoin sync height and the timestamp of its block header.
+    async fn set_anchor(&mut self) -> bool {
+        let _rollup_dir = self.rollup_dir.lock().await;
+
+        let bitcoin_height = match u32::try_from(_rollup_dir.bitcoin_sync_height()) {
+            Ok(height) => height,
+            Err(_) => return false,
+        };
+
+        let timestamp = match _rollup_dir.header(bitcoin_height as u64) {
+            Some(header) => header.time,
+            None => return false,
+        };
+
+        self.anchor = SessionAnchor::new(bitcoin_height, timestamp);
+        true
+    }
+
+    /// Returns the Bitcoin block the session is anchored to.
+    pub fn anchor(&self) -> SessionAnchor {
+        self.anchor
     }
 
     pub fn session_id(&self) -> [u8; 32] {
@@ -257,64 +353,62 @@ impl CSessionCtx {
         if auth_commit.key() != account.key().serialize_xonly() {
             return Err(CSessionCommitNack::AuthErr);
         }
-        // #1 Blacklist check.
-        {
-            let _blacklist_dir = self.blacklist_dir.lock().await;
-            if let Some(until) = _blacklist_dir.check_blacklist(account) {
-                return Err(CSessionCommitNack::BlacklistedUntil(until));
-            }
-        }
-
-        // #2 Entry-account validation
-        if !commit.entry().validate_account() {
-            return Err(CSessionCommitNack::AuthErr);
-        }
-
-        // #3 Overlap check
+        // #1 Overlap check
         if self.commit_pool_overlap(account) {
             return Err(CSessionCommitNack::Overlap);
         }
 
-        // #4 Allowance check
-        if allowance(account) {
-            return Err(CSessionCommitNack::Allowance);
-        }
-
-        // #5 Lift prevtxouts validation
-        for (lift, _) in commit.lift_prevtxo_nonces().iter() {
-            // #1 Operator key validation
-            {
-                let lift_operator_key = lift.operator_key();
-                let lift_account_key = lift.account_key();
+        // #2 Entry validation
+        // The spends of the pooled entries are pending against the entry.
+        // Entries are validated at the anchored height, which nodes read from the session payload.
+        {
+            let mut validation_ctx = EntryValidationCtx::new(
+                self.chain,
+                &self.registery,
+                &self.vtxo_set,
+                &self.utxo_set,
+                &self.blacklist_dir,
+                self.anchor.bitcoin_height(),
+            );
+
+            for pooled in self.commit_pool.iter() {
+                validation_ctx.add_pending_spends(&pooled.entry().max_spends());
+            }
 
-                if lift_operator_key == lift_account_key {
-                    return Err(CSessionCommitNack::InvalidLiftOperatorKey);
-                }
+            commit
+                .entry()
+                .validate(&validation_ctx)
+                .await
+                .map_err(CSessionCommitNack::InvalidEntry)?;
+        }
 
-                if lift_account_key != account.key() {
-                    return Err(CSessionCommitNack::InvalidLiftAccountKey);
-                }
+        // #3 Lift prevtxouts validation
+        let lifts = match commit.entry().liftup() {
+            Some(liftup) => liftup.lifts(),
+            None => vec![],
+        };
 
-                {
-                    let dkg_manager_ = dkg_manager.lock().await;
-                    if let None = dkg_manager_.directory_by_key(lift_operator_key).await {
-                        return Err(CSessionCommitNack::InvalidLiftOperatorKey);
-                    }
-                }
+        for (lift, _) in commit.lift_prevtxo_nonces().iter() {
+            // #1 Lift validation
+            // Lift nonces must be for the lifts of the entry.
+            if !lifts.contains(lift) {
+                return Err(CSessionCommitNack::InvalidLiftOutpoint);
             }
 
-            // #2 Outpoint validation
+            // #2 Operator key validation
             {
-                match lift.outpoint() {
-                    Some(_outpoint) => {
-                        // TODO: check if this is a valid outpoint.
-                    }
-                    None => return Err(CSessionCommitNack::MissingLiftOutpoint),
-                };
+                let dkg_manager_ = dkg_manager.lock().await;
+                if dkg_manager_
+                    .directory_by_key(lift.operator_key())
+                    .await
+                    .is_none()
+                {
+                    return Err(CSessionCommitNack::InvalidLiftOperatorKey);
+                }
             }
         }
 
-        // #6 TODO: Check for num of connectors:
+        // #4 TODO: Check for num of connectors:
         let _connector_count = self.connector_projector_nonces.len();
 
         Ok(())
@@ -337,36 +431,11 @@ impl CSessionCtx {
         self.validate_commit(&auth_commit).await?;
 
         let commit = auth_commit.object();
-        let mut account = commit.account();
-
-        // #3 Registery index validation.
-        let given_registery_index = account.registery_index();
-        let local_registery_index = {
-            let account_registery: ACCOUNT_REGISTERY = {
-                let _registery = self.registery.lock().await;
-                _registery.account_registery()
-            };
 
-            let _account_registery = account_registery.lock().await;
-            match _account_registery.account_by_key(account.key()) {
-                Some(account) => account.registery_index(),
-                None => None,
-            }
-        };
-
-        match given_registery_index {
-            Some(given_registery_index) => {
-                if let Some(local_registery_index) = local_registery_index {
-                    if local_registery_index != given_registery_index {
-                        return Err(CSessionCommitNack::InvalidAccountRegisteryIndex);
-                    }
-                }
-            }
-            None => {
-                if let Some(local_registery_index) = local_registery_index {
-                    account.set_registery_index(local_registery_index);
-                }
-            }
+        // #3 Chain check
+        // The entry must be signed for the chain this coordinator runs on.
+        if commit.chain() != self.chain {
+            return Err(CSessionCommitNack::ChainMismatch);
         }
 
         // #4 Insert into commit pool.
@@ -436,19 +505,13 @@ impl CSessionCtx {
     }
 
     fn payload_auth_msg(&self) -> [u8; 32] {
-        let mut preimage = Vec::<u8>::new();
-
-        // Session ID
-        preimage.extend(self.session_id);
-
-        // Entries
-        for (index, entry) in self.entries.iter().enumerate() {
-            let entry_sighash = entry.auth_sighash();
-            preimage.extend((index as u32).to_le_bytes());
-            preimage.extend(entry_sighash);
-        }
-
-        preimage.hash(Some(HashTag::PayloadAuth))
+        SessionPayload::auth_msg(
+            self.session_id,
+            self.chain,
+            self.base_ops_price,
+            self.anchor,
+            &self.entries,
+        )
     }
 
     /// Sets the NOIST and MuSig contexes upon collecting `NSessionCommit`s, triggered by `lock`.
@@ -1044,7 +1107,10 @@ impl CSessionCtx {
 
         let commitack = CSessionCommitAck::new(
             account,
+            self.chain,
             session_id,
+            self.base_ops_price,
+            self.anchor,
             entries,
             payload_auth_musig_ctx,
             vtxo_projector_musig_ctx,
@@ -1591,6 +1657,200 @@ impl CSessionCtx {
         Ok(uphold_ack)
     }
 
+    /// Executes the calls and bundles of the entries upheld in this session, in entry order.
+    ///
+    /// Failed calls and bundles are reverted and charged by the execution context, so they do not
+    /// halt the session.
+    pub async fn execute_entries(&self) -> ExecCtx {
+        // The session is priced and anchored to its Bitcoin block on init.
+        let session_height = {
+            let _price_dir = self.price_dir.lock().await;
+            _price_dir.next_session_height()
+        };
+
+        let mut exec_ctx = ExecCtx::new(
+            &self.state_holder,
+            &self.programs_repo,
+            &self.rollup_dir,
+            self.base_ops_price,
+            self.anchor.timestamp() as u64,
+            self.anchor.bitcoin_height() as u64,
+            &self.beacon_dir,
+            session_height,
+        );
+
+        for entry in self.entries.iter() {
+            let result = match entry.main_combinator() {
+                Some(Combinator::Call(call)) => exec_ctx.exec_insert_call(call).await,
+                Some(Combinator::Bundle(bundle)) => exec_ctx.exec_insert_bundle(bundle).await,
+                _ => continue,
+            };
+
+            if let Err(err) = result {
+                eprintln!("{}", format!("Entry execution failed: {}", err).yellow());
+            }
+        }
+
+        exec_ctx
+    }
+
+    /// Saves the programs deployed and the states updated by the executed entries, settles their
+    /// pay list against the VTXO set, and registers the deployed contracts, all together.
+    ///
+    /// Fees are settled against their payers before anything is saved. The executions are
+    /// discarded if they cannot be settled or saved.
+    pub async fn save_executions(&self, exec_ctx: &mut ExecCtx) -> Result<(), ExecCtxSaveError> {
+        let contract_registery: CONTRACT_REGISTERY = {
+            let _registery = self.registery.lock().await;
+            _registery.contract_registery()
+        };
+
+        // The settled VTXOs are held under the active operator group key.
+        let operator_key = match self.operator_key().await {
+            Some(operator_key) => operator_key,
+            None => {
+                exec_ctx.flush_all().await;
+                return Err(ExecCtxSaveError::VTXOSettlementError);
+            }
+        };
+
+        // Settle and save, or discard the executions if they cannot be.
+        if let Err(err) = exec_ctx
+            .save_all(&contract_registery, &self.vtxo_set, operator_key)
+            .await
+        {
+            exec_ctx.flush_all().await;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the active operator group key.
+    async fn operator_key(&self) -> Option<Point> {
+        let dkg_directory = {
+            let _dkg_manager = self.dkg_manager.lock().await;
+            _dkg_manager.active_directory()?
+        };
+
+        let _dkg_directory = dkg_directory.lock().await;
+        _dkg_directory.group_key()
+    }
+
+    /// Records the ops spent in this session in the price directory,
+    /// from which the base ops price of the next session is derived.
+    pub async fn insert_session_price(&self, exec_ctx: &ExecCtx) -> bool {
+        let mut _price_dir = self.price_dir.lock().await;
+        _price_dir.insert_session(exec_ctx.session_height(), exec_ctx.ops_spent())
+    }
+
+    /// Revives the VTXOs of the revive entries upheld in this session. The revived VTXOs leave the
+    /// operator sweep records, so that they cannot be revived again, and their value is brought
+    /// back into a new VTXO held by the account under the active operator group key.
+    pub async fn revive_vtxos(&self, exec_ctx: &ExecCtx) -> bool {
+        // Return the active operator group key.
+        let operator_key = match self.operator_key().await {
+            Some(operator_key) => operator_key,
+            None => return false,
+        };
+
+        // The revived VTXOs are created at this session and its anchored Bitcoin height.
+        let (at_rollup_height, at_bitcoin_height) = match (
+            u32::try_from(exec_ctx.session_height()),
+            u32::try_from(exec_ctx.bitcoin_height()),
+        ) {
+            (Ok(rollup_height), Ok(bitcoin_height)) => (rollup_height, bitcoin_height),
+            _ => return false,
+        };
+
+        let mut _vtxo_set = self.vtxo_set.lock().await;
+
+        for entry in self.entries.iter() {
+            if let Some(Combinator::Revive(revive)) = entry.main_combinator() {
+                let revived_vtxo =
+                    match revive.revived_vtxo(operator_key, at_rollup_height, at_bitcoin_height) {
+                        Some(vtxo) => vtxo,
+                        None => return false,
+                    };
+
+                for vtxo in revive.vtxos().iter() {
+                    if !_vtxo_set.remove_swept_vtxo(vtxo) {
+                        return false;
+                    }
+                }
+
+                if !_vtxo_set.insert_vtxo(&revived_vtxo) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Consumes the nonces of the entries upheld in this session, so that they cannot be replayed
+    /// in a later session. Runs as soon as the session is upheld, before any fallible step.
+    pub async fn consume_nonces(&self) -> bool {
+        let nonces: HashMap<Point, u64> = self
+            .entries
+            .iter()
+            .map(|entry| (entry.account().key(), entry.nonce()))
+            .collect();
+
+        let account_registery: ACCOUNT_REGISTERY = {
+            let _registery = self.registery.lock().await;
+            _registery.account_registery()
+        };
+
+        let mut _account_registery = account_registery.lock().await;
+        _account_registery.consume_nonces(nonces)
+    }
+
+    /// Returns the session payload carrying the entries upheld in this session and their session
+    /// signature, yet to be signed by the operator quorum.
+    pub async fn unsigned_payload(
+        &self,
+    ) -> Result<SessionPayload, SessionPayloadConstructionError> {
+        let session_sig = match &self.payload_auth_ctxes {
+            Some((_, _, _, musig_ctx)) => musig_ctx
+                .full_agg_sig()
+                .ok_or(SessionPayloadConstructionError::SessionSigMissing)?,
+            None => return Err(SessionPayloadConstructionError::SessionSigMissing),
+        };
+
+        let mut payload = SessionPayload::from_entries(
+            self.session_id,
+            &self.entries,
+            &self.registery,
+            &self.programs_repo,
+            &self.vtxo_set,
+            self.base_ops_price,
+            self.anchor,
+        )
+        .await?;
+
+        payload.set_session_sig(session_sig);
+
+        Ok(payload)
+    }
+
+    /// Finalizes the session with the payload signed by the operator quorum, and returns the
+    /// envelope that carries it to the Bitcoin data-availability layer.
+    pub fn finalize_payload(
+        &mut self,
+        payload: SessionPayload,
+    ) -> Result<ScriptBuf, SessionPayloadEnvelopeError> {
+        let envelope = payload.envelope()?;
+        self.payload = Some((payload, envelope.clone()));
+        self.finalized();
+        Ok(envelope)
+    }
+
+    /// Returns the finalized session payload and its envelope.
+    pub fn payload(&self) -> Option<(SessionPayload, ScriptBuf)> {
+        self.payload.clone()
+    }
+
     pub fn upheld(&mut self) {
         self.stage = CSessionStage::Upheld;
     }
@@ -1609,6 +1869,7 @@ impl CSessionCtx {
         self.pruned_commits = Vec::<NSessionCommit>::new();
         self.passed_commits = Vec::<NSessionCommit>::new();
         self.entries = Vec::<Entry>::new();
+        self.payload = None;
         self.payload_auth_nonces = HashMap::<Account, (Point, Point)>::new();
         self.payload_auth_ctxes = None;
         self.vtxo_projector_nonces = HashMap::<Account, (Point, Point)>::new();
@@ -1655,6 +1916,8 @@ pub trait CContextRunner {
     async fn opcov_peer_list(&self) -> Option<Vec<PEER>>;
     /// Requests `CSessionOpCov`s and retrieves & inserts `OSessionOpCovAck`s.
     async fn opcov_task(&self) -> bool;
+    /// Signs the session payload with the operator quorum and finalizes the session with it.
+    async fn finalize(&self) -> bool;
 }
 
 #[async_trait]
@@ -1664,12 +1927,22 @@ impl CContextRunner for CSESSION_CTX {
 
         loop {
             // Initialize session
-            {
+            let initialized = {
                 // TODO:
                 let session_id = [0x00u8; 32];
 
                 let mut _session_ctx = self.lock().await;
-                _session_ctx.init(session_id);
+                _session_ctx.init(session_id).await
+            };
+
+            // Re-start the session if it cannot be anchored.
+            if !initialized {
+                eprintln!(
+                    "{}",
+                    "Unexpected error: Failed to anchor the session.".red()
+                );
+                tokio::time::sleep(ON_STAGE_WAIT_TIME_REGULAR).await;
+                continue;
             }
 
             // Wait for other commits.
@@ -1723,6 +1996,77 @@ impl CContextRunner for CSESSION_CTX {
 
             // Post-uphold logic..
 
+            // Consume the entry nonces. The session is upheld, so its entries can no longer be
+            // replayed whatever the outcome of the steps below.
+            {
+                let _session_ctx = self.lock().await;
+                if !_session_ctx.consume_nonces().await {
+                    eprintln!("{}", "Unexpected error: Failed to consume nonces.".red());
+                }
+            }
+
+            // Execute the entries and save their effects. A failed save discards the executions,
+            // but the session still goes on with the steps below.
+            let exec_ctx = {
+                let _session_ctx = self.lock().await;
+                let mut exec_ctx = _session_ctx.execute_entries().await;
+                if let Err(err) = _session_ctx.save_executions(&mut exec_ctx).await {
+                    eprintln!(
+                        "{}",
+                        format!("Unexpected error: Failed to save the executions: {}", err).red()
+                    );
+                }
+                exec_ctx
+            };
+
+            // Sign the session beacon.
+            {
+                let (dkg_manager, mut peer_manager, beacon_dir) = {
+                    let _session_ctx = self.lock().await;
+                    (
+                        _session_ctx.dkg_manager(),
+                        _session_ctx.peer_manager(),
+                        _session_ctx.beacon_dir(),
+                    )
+                };
+
+                if let Err(err) = dkg_manager
+                    .sign_beacon(&mut peer_manager, &beacon_dir, exec_ctx.session_height())
+                    .await
+                {
+                    eprintln!(
+                        "{}",
+                        format!(
+                            "Unexpected error: Failed to sign the session beacon: {:?}",
+                            err
+                        )
+                        .red()
+                    );
+                }
+            }
+
+            // Finalize the session payload, signed by the operator quorum.
+            self.finalize().await;
+
+            // Record the session price.
+            {
+                let _session_ctx = self.lock().await;
+                if !_session_ctx.insert_session_price(&exec_ctx).await {
+                    eprintln!(
+                        "{}",
+                        "Unexpected error: Failed to record the session price.".red()
+                    );
+                }
+            }
+
+            // Revive the swept VTXOs into new VTXOs.
+            {
+                let _session_ctx = self.lock().await;
+                if !_session_ctx.revive_vtxos(&exec_ctx).await {
+                    eprintln!("{}", "Unexpected error: Failed to revive VTXOs.".red());
+                }
+            }
+
             // End of successful session.
             waiting_window = ON_STAGE_WAIT_TIME_REGULAR;
         }
@@ -1737,6 +2081,15 @@ impl CContextRunner for CSESSION_CTX {
                 let mut _session_ctx = self.lock().await;
 
                 if _session_ctx.is_upheld_ready() {
+                    // The operator aggregate signatures complete the MuSig contexts.
+                    if !_session_ctx.set_operator_agg_sigs() {
+                        eprintln!(
+                            "{}",
+                            "Unexpected error: Failed to set the operator signatures.".red()
+                        );
+                        return false;
+                    }
+
                     _session_ctx.upheld();
                     return true;
                 }
@@ -1841,4 +2194,61 @@ impl CContextRunner for CSESSION_CTX {
 
         true
     }
+
+    async fn finalize(&self) -> bool {
+        // #1 Return the unsigned payload.
+        let payload = {
+            let _session_ctx = self.lock().await;
+            match _session_ctx.unsigned_payload().await {
+                Ok(payload) => payload,
+                Err(err) => {
+                    eprintln!(
+                        "{}",
+                        format!(
+                            "Unexpected error: Failed to construct the payload: {:?}",
+                            err
+                        )
+                        .red()
+                    );
+                    return false;
+                }
+            }
+        };
+
+        // #2 Sign the payload with the operator quorum.
+        let payload = {
+            let (dkg_manager, mut peer_manager) = {
+                let _session_ctx = self.lock().await;
+                (_session_ctx.dkg_manager(), _session_ctx.peer_manager())
+            };
+
+            match dkg_manager.sign_payload(&mut peer_manager, &payload).await {
+                Ok(payload) => payload,
+                Err(err) => {
+                    eprintln!(
+                        "{}",
+                        format!("Unexpected error: Failed to sign the payload: {:?}", err).red()
+                    );
+                    return false;
+                }
+            }
+        };
+
+        // #3 Finalize the session with the signed payload.
+        let mut _session_ctx = self.lock().await;
+        match _session_ctx.finalize_payload(payload) {
+            Ok(_) => true,
+            Err(err) => {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "Unexpected error: Failed to envelope the payload: {:?}",
+                        err
+                    )
+                    .red()
+                );
+                false
+            }
+        }
+    }
 }
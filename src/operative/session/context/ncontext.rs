@@ -1,9 +1,14 @@
 use super::{
-    commit::NSessionCommit, commitack::CSessionCommitAck, uphold::NSessionUphold,
-    upholdack::CSessionUpholdAck, upholderr::NSessionUpholdError,
+    commit::NSessionCommit, commitack::CSessionCommitAck, ncontexterr::NSessionCtxError,
+    uphold::NSessionUphold, upholdack::CSessionUpholdAck, upholderr::NSessionUpholdError,
 };
 use crate::{
-    constructive::{entity::account::account::Account, entry::entry::Entry, txo::lift::Lift},
+    constructive::{
+        entity::account::account::Account,
+        entry::{entry::Entry, validation::validation_ctx::EntryValidationCtx},
+        txo::lift::Lift,
+    },
+    operative::Chain,
     transmutative::{
         key::KeyHolder,
         secp::{authenticable::Authenticable, into::IntoScalar, schnorr::generate_secret},
@@ -20,6 +25,7 @@ pub const CONNECTORS_EXTRA_IN: u8 = 10;
 #[derive(Clone, Serialize, Deserialize)]
 pub struct NSessionCtx {
     secret_key: Scalar,
+    chain: Chain,
     entry: Entry,
     // Payload auth nonces
     payload_auth_secret_nonces: (Scalar, Scalar),
@@ -42,18 +48,33 @@ pub struct NSessionCtx {
 }
 
 impl NSessionCtx {
-    pub fn new(key_holder: KeyHolder, entry: Entry) -> Option<NSessionCtx> {
+    /// Creates a new node session context for the entry to be committed.
+    ///
+    /// The entry is validated against the local stores before any nonces are generated,
+    /// by the same rules the coordinator applies to the commit.
+    pub async fn new(
+        key_holder: KeyHolder,
+        chain: Chain,
+        entry: Entry,
+        validation_ctx: &EntryValidationCtx,
+    ) -> Result<NSessionCtx, NSessionCtxError> {
         let secret_key = key_holder.secret_key();
         let public_key = secret_key.base_point_mul();
 
         if entry.account().key() != public_key {
-            return None;
+            return Err(NSessionCtxError::AccountKeyMismatch);
         }
 
-        let nonces = gen_nonce(&entry)?;
+        entry
+            .validate(validation_ctx)
+            .await
+            .map_err(NSessionCtxError::InvalidEntry)?;
+
+        let nonces = gen_nonce(&entry).ok_or(NSessionCtxError::NonceGenerationErr)?;
 
         let ctx = NSessionCtx {
             secret_key,
+            chain,
             entry,
             //
             // Payload auth nonces
@@ -76,7 +97,7 @@ impl NSessionCtx {
             connector_txo_public_nonces: nonces.11,
         };
 
-        Some(ctx)
+        Ok(ctx)
     }
 
     pub fn account(&self) -> Account {
@@ -90,6 +111,7 @@ impl NSessionCtx {
     // Returns the commitment
     pub fn commit(&self) -> Option<Authenticable<NSessionCommit>> {
         let commit = NSessionCommit::new(
+            self.chain,
             self.entry(),
             self.payload_auth_public_nonces.0,
             self.payload_auth_public_nonces.1,
@@ -114,6 +136,11 @@ impl NSessionCtx {
             return false;
         }
 
+        // Validate chain
+        if commitack.chain() != self.chain {
+            return false;
+        }
+
         // Validate payload auth msg
         if !commitack.validate_payload_auth_msg() {
             return false;
@@ -0,0 +1,10 @@
+use crate::constructive::entry::validation::validation_error::EntryValidationError;
+
+/// `NSessionCtxError` is returned if the msg.sender is unable to construct
+/// an `NSessionCtx` for the entry it is about to commit.
+#[derive(Clone, Debug)]
+pub enum NSessionCtxError {
+    AccountKeyMismatch,
+    InvalidEntry(EntryValidationError),
+    NonceGenerationErr,
+}
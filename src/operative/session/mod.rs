@@ -3,6 +3,8 @@
 pub mod ccontext;
 #[path = "context/ncontext.rs"]
 pub mod ncontext;
+#[path = "context/ncontexterr.rs"]
+pub mod ncontexterr;
 
 // Commit
 #[path = "commit/commit.rs"]
@@ -27,5 +29,3 @@ pub mod upholdack;
 pub mod upholderr;
 #[path = "uphold/upholdnack.rs"]
 pub mod upholdnack;
-
-pub mod allowance;
@@ -1,6 +1,9 @@
 use super::opcovack::OSessionOpCovAck;
 use crate::{
     constructive::{entity::account::account::Account, entry::entry::Entry, txo::lift::Lift},
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    },
     transmutative::{key::KeyHolder, musig::session::MusigSessionCtx, noist::manager::DKG_MANAGER},
 };
 use secp::Scalar;
@@ -268,3 +271,30 @@ impl CSessionOpCov {
         self.connector_txo_musig_ctxes.clone()
     }
 }
+
+/// The canonical encoding for `CSessionOpCov`, sent to the operators.
+impl Canonical for CSessionOpCov {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.entries.encode_fields(bytes);
+        self.payload_auth_musig_ctx.encode_fields(bytes);
+        self.vtxo_projector_musig_ctx.encode_fields(bytes);
+        self.connector_projector_musig_ctx.encode_fields(bytes);
+        self.zkp_contingent_musig_ctx.encode_fields(bytes);
+        self.lift_prevtxo_musig_ctxes.encode_fields(bytes);
+        self.connector_txo_musig_ctxes.encode_fields(bytes);
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<CSessionOpCov, CanonicalDecodeError> {
+        Ok(CSessionOpCov {
+            entries: Canonical::decode_fields(reader)?,
+            payload_auth_musig_ctx: Canonical::decode_fields(reader)?,
+            vtxo_projector_musig_ctx: Canonical::decode_fields(reader)?,
+            connector_projector_musig_ctx: Canonical::decode_fields(reader)?,
+            zkp_contingent_musig_ctx: Canonical::decode_fields(reader)?,
+            lift_prevtxo_musig_ctxes: Canonical::decode_fields(reader)?,
+            connector_txo_musig_ctxes: Canonical::decode_fields(reader)?,
+        })
+    }
+}
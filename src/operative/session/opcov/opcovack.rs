@@ -1,4 +1,7 @@
 use crate::constructive::{entity::account::account::Account, txo::lift::Lift};
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
 use secp::{Point, Scalar};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -73,3 +76,30 @@ impl OSessionOpCovAck {
         self.connector_txo_partial_sigs.clone()
     }
 }
+
+/// The canonical encoding for `OSessionOpCovAck`, returned to the coordinator.
+impl Canonical for OSessionOpCovAck {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.signatory.encode_fields(bytes);
+        self.payload_auth_partial_sig.encode_fields(bytes);
+        self.vtxo_projector_partial_sig.encode_fields(bytes);
+        self.connector_projector_partial_sig.encode_fields(bytes);
+        self.zkp_contingent_partial_sig.encode_fields(bytes);
+        self.lift_prevtxo_partial_sigs.encode_fields(bytes);
+        self.connector_txo_partial_sigs.encode_fields(bytes);
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<OSessionOpCovAck, CanonicalDecodeError> {
+        Ok(OSessionOpCovAck {
+            signatory: Point::decode_fields(reader)?,
+            payload_auth_partial_sig: Canonical::decode_fields(reader)?,
+            vtxo_projector_partial_sig: Canonical::decode_fields(reader)?,
+            connector_projector_partial_sig: Canonical::decode_fields(reader)?,
+            zkp_contingent_partial_sig: Canonical::decode_fields(reader)?,
+            lift_prevtxo_partial_sigs: Canonical::decode_fields(reader)?,
+            connector_txo_partial_sigs: Canonical::decode_fields(reader)?,
+        })
+    }
+}
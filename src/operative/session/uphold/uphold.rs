@@ -1,6 +1,9 @@
 use crate::{
     constructive::{entity::account::account::Account, txo::lift::Lift},
     transmutative::{
+        codec::canonical::{
+            canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+        },
         hash::{Hash, HashTag},
         secp::authenticable::AuthSighash,
     },
@@ -127,3 +130,30 @@ impl AuthSighash for NSessionUphold {
         preimage.hash(Some(HashTag::Sighash))
     }
 }
+
+/// The canonical encoding for `NSessionUphold`, sent to the coordinator to uphold a session.
+impl Canonical for NSessionUphold {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.msg_sender.encode_fields(bytes);
+        self.payload_auth_partial_sig.encode_fields(bytes);
+        self.vtxo_projector_partial_sig.encode_fields(bytes);
+        self.connector_projector_partial_sig.encode_fields(bytes);
+        self.zkp_contingent_partial_sig.encode_fields(bytes);
+        self.lift_prevtxo_partial_sigs.encode_fields(bytes);
+        self.connector_txo_partial_sigs.encode_fields(bytes);
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<NSessionUphold, CanonicalDecodeError> {
+        Ok(NSessionUphold {
+            msg_sender: Account::decode_fields(reader)?,
+            payload_auth_partial_sig: Scalar::decode_fields(reader)?,
+            vtxo_projector_partial_sig: Canonical::decode_fields(reader)?,
+            connector_projector_partial_sig: Canonical::decode_fields(reader)?,
+            zkp_contingent_partial_sig: Canonical::decode_fields(reader)?,
+            lift_prevtxo_partial_sigs: Canonical::decode_fields(reader)?,
+            connector_txo_partial_sigs: Canonical::decode_fields(reader)?,
+        })
+    }
+}
@@ -1,4 +1,7 @@
 use crate::constructive::{entity::account::account::Account, txo::lift::Lift};
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
 use secp::Scalar;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -74,3 +77,30 @@ impl CSessionUpholdAck {
         self.connector_txo_agg_sigs.clone()
     }
 }
+
+/// The canonical encoding for `CSessionUpholdAck`, returned to the msg.senders.
+impl Canonical for CSessionUpholdAck {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.msg_sender.encode_fields(bytes);
+        self.payload_auth_agg_sig.encode_fields(bytes);
+        self.vtxo_projector_agg_sig.encode_fields(bytes);
+        self.connector_projector_agg_sig.encode_fields(bytes);
+        self.zkp_contingent_agg_sig.encode_fields(bytes);
+        self.lift_prevtxo_agg_sigs.encode_fields(bytes);
+        self.connector_txo_agg_sigs.encode_fields(bytes);
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<CSessionUpholdAck, CanonicalDecodeError> {
+        Ok(CSessionUpholdAck {
+            msg_sender: Account::decode_fields(reader)?,
+            payload_auth_agg_sig: Scalar::decode_fields(reader)?,
+            vtxo_projector_agg_sig: Canonical::decode_fields(reader)?,
+            connector_projector_agg_sig: Canonical::decode_fields(reader)?,
+            zkp_contingent_agg_sig: Canonical::decode_fields(reader)?,
+            lift_prevtxo_agg_sigs: Canonical::decode_fields(reader)?,
+            connector_txo_agg_sigs: Canonical::decode_fields(reader)?,
+        })
+    }
+}
@@ -1,4 +1,7 @@
 use crate::constructive::entity::account::account::Account;
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
 use serde::{Deserialize, Serialize};
 
 /// `CSessionUpholdINack` (Inner Nack)is returned by the coordinator to the msg.senders
@@ -29,3 +32,66 @@ pub enum CSessionUpholdNack {
     LiftSigErr,
     ConnectorSigErr,
 }
+
+/// The canonical encoding for `CSessionUpholdNack`: the variant tag, followed by its value.
+impl Canonical for CSessionUpholdNack {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        match self {
+            CSessionUpholdNack::SessionNotLocked => bytes.push(0x00),
+            CSessionUpholdNack::AuthErr => bytes.push(0x01),
+            CSessionUpholdNack::InvalidPayloadAuthSig => bytes.push(0x02),
+            CSessionUpholdNack::MissingVTXOProjectorSig => bytes.push(0x03),
+            CSessionUpholdNack::InvalidVTXOProjectorSig => bytes.push(0x04),
+            CSessionUpholdNack::MissingConnectorProjectorSig => bytes.push(0x05),
+            CSessionUpholdNack::InvalidConnectorProjectorSig => bytes.push(0x06),
+            CSessionUpholdNack::MissingZKPContigentSig => bytes.push(0x07),
+            CSessionUpholdNack::InvalidZKPContigentSig => bytes.push(0x08),
+            CSessionUpholdNack::MissingLiftSig => bytes.push(0x09),
+            CSessionUpholdNack::InvalidLiftSig => bytes.push(0x0a),
+            CSessionUpholdNack::MissingConnectorSig => bytes.push(0x0b),
+            CSessionUpholdNack::InvalidConnectorSig => bytes.push(0x0c),
+            CSessionUpholdNack::BlameMsgSenders(value) => {
+                bytes.push(0x0d);
+                value.encode_fields(bytes);
+            }
+            CSessionUpholdNack::BlameOperator => bytes.push(0x0e),
+            CSessionUpholdNack::PayloadAuthSigErr => bytes.push(0x0f),
+            CSessionUpholdNack::VtxoProjectorSigErr => bytes.push(0x10),
+            CSessionUpholdNack::ConnectorProjectorSigErr => bytes.push(0x11),
+            CSessionUpholdNack::ZkpContigentSigErr => bytes.push(0x12),
+            CSessionUpholdNack::LiftSigErr => bytes.push(0x13),
+            CSessionUpholdNack::ConnectorSigErr => bytes.push(0x14),
+        }
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<CSessionUpholdNack, CanonicalDecodeError> {
+        match reader.read_u8()? {
+            0x00 => Ok(CSessionUpholdNack::SessionNotLocked),
+            0x01 => Ok(CSessionUpholdNack::AuthErr),
+            0x02 => Ok(CSessionUpholdNack::InvalidPayloadAuthSig),
+            0x03 => Ok(CSessionUpholdNack::MissingVTXOProjectorSig),
+            0x04 => Ok(CSessionUpholdNack::InvalidVTXOProjectorSig),
+            0x05 => Ok(CSessionUpholdNack::MissingConnectorProjectorSig),
+            0x06 => Ok(CSessionUpholdNack::InvalidConnectorProjectorSig),
+            0x07 => Ok(CSessionUpholdNack::MissingZKPContigentSig),
+            0x08 => Ok(CSessionUpholdNack::InvalidZKPContigentSig),
+            0x09 => Ok(CSessionUpholdNack::MissingLiftSig),
+            0x0a => Ok(CSessionUpholdNack::InvalidLiftSig),
+            0x0b => Ok(CSessionUpholdNack::MissingConnectorSig),
+            0x0c => Ok(CSessionUpholdNack::InvalidConnectorSig),
+            0x0d => Ok(CSessionUpholdNack::BlameMsgSenders(
+                Canonical::decode_fields(reader)?,
+            )),
+            0x0e => Ok(CSessionUpholdNack::BlameOperator),
+            0x0f => Ok(CSessionUpholdNack::PayloadAuthSigErr),
+            0x10 => Ok(CSessionUpholdNack::VtxoProjectorSigErr),
+            0x11 => Ok(CSessionUpholdNack::ConnectorProjectorSigErr),
+            0x12 => Ok(CSessionUpholdNack::ZkpContigentSigErr),
+            0x13 => Ok(CSessionUpholdNack::LiftSigErr),
+            0x14 => Ok(CSessionUpholdNack::ConnectorSigErr),
+            tag => Err(CanonicalDecodeError::InvalidTag(tag)),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{
+    communicative::{peer::peer::PEER, tcp::client::TCPClient},
+    inscriptive::{beacon::dir::BEACON_DIRECTORY, epoch::dir::EPOCH_DIRECTORY},
+};
+use async_trait::async_trait;
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// The time to wait before asking for a beacon that is not yet finalized.
+const BEACON_SYNC_INTERVAL: Duration = Duration::from_secs(10);
+
+#[async_trait]
+pub trait BeaconSync {
+    /// Continuously syncs the randomness beacons from the coordinator.
+    ///
+    /// Beacons are inserted in session order, and only if signed by one of the
+    /// active operator quorum keys.
+    async fn sync(&self, coordinator: &PEER, epoch_dir: &EPOCH_DIRECTORY);
+}
+
+#[async_trait]
+impl BeaconSync for BEACON_DIRECTORY {
+    async fn sync(&self, coordinator: &PEER, epoch_dir: &EPOCH_DIRECTORY) {
+        loop {
+            // #1 Return the session height of the next beacon.
+            let session_height = {
+                let _self = self.lock().await;
+                _self.next_session_height()
+            };
+
+            // #2 Request the beacon from the coordinator.
+            let beacon = match coordinator.request_beacon(session_height).await {
+                Ok(beacon) => beacon,
+                Err(_) => {
+                    sleep(BEACON_SYNC_INTERVAL).await;
+                    continue;
+                }
+            };
+
+            // #3 Return the active operator quorum keys.
+            let quorum_keys = {
+                let _epoch_dir = epoch_dir.lock().await;
+                _epoch_dir.active_group_keys()
+            };
+
+            // #4 Insert the beacon if signed by one of the quorum keys.
+            let inserted = {
+                let mut _self = self.lock().await;
+                quorum_keys
+                    .iter()
+                    .any(|quorum_key| _self.insert_beacon(&beacon, quorum_key.serialize_xonly()))
+            };
+
+            if !inserted {
+                sleep(BEACON_SYNC_INTERVAL).await;
+            }
+        }
+    }
+}
@@ -1 +1,2 @@
+pub mod beacon;
 pub mod rollup;
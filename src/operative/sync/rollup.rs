@@ -1,20 +1,33 @@
 use crate::{
     communicative::rpc::bitcoin::{
-        rpc::{get_block, get_chain_height},
+        rpc::{get_block, get_block_header, get_chain_height},
         rpcholder::RPCHolder,
     },
-    constructive::{taproot::P2TR, txo::lift::Lift},
+    constructive::{
+        entry::{entry::Entry, validation::validation_ctx::EntryValidationCtx},
+        payload::payload::SessionPayload,
+        taproot::P2TR,
+        txn::txholder::TxHolder,
+        txo::lift::Lift,
+    },
     inscriptive::{
-        baked, epoch::dir::EPOCH_DIRECTORY, lp::dir::LP_DIRECTORY, registery::registery::REGISTERY,
-        rollup::dir::ROLLUP_DIRECTORY, set::set::COIN_SET, wallet::wallet::WALLET,
+        baked,
+        blacklist::BLIST_DIRECTORY,
+        epoch::dir::EPOCH_DIRECTORY,
+        lp::dir::LP_DIRECTORY,
+        registery::{account_registery::ACCOUNT_REGISTERY, registery::REGISTERY},
+        repo::repo::PROGRAMS_REPO,
+        rollup::dir::ROLLUP_DIRECTORY,
+        set::set::COIN_SET,
+        wallet::wallet::WALLET,
     },
     operative::Chain,
     transmutative::key::KeyHolder,
 };
 use async_trait::async_trait;
-use bitcoin::OutPoint;
+use bitcoin::{OutPoint, Transaction};
 use secp::Point;
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 use tokio::time::sleep;
 
 type LiftSPK = Vec<u8>;
@@ -44,9 +57,101 @@ pub async fn lifts_spks_to_scan(
     Some(spks)
 }
 
+/// Syncs the session payloads enveloped in a transaction. Returns the synced entries, in order.
+///
+/// Payloads not signed by one of the active operator quorum keys, or anchored past the block of the
+/// given validation context, are skipped before anything is decoded. Entries are validated at the
+/// anchored height once the session signature is verified, with the spends of the entries before
+/// them pending; payloads carrying an invalid entry are skipped.
+/// The nonces of the synced entries are consumed, so that they cannot be replayed.
+pub async fn sync_session_payloads(
+    transaction: &Transaction,
+    epoch_dir: &EPOCH_DIRECTORY,
+    programs_repo: &PROGRAMS_REPO,
+    validation_ctx: &mut EntryValidationCtx,
+) -> Vec<Entry> {
+    let mut entries = Vec::<Entry>::new();
+
+    let quorum_keys = {
+        let _epoch_dir = epoch_dir.lock().await;
+        _epoch_dir.active_group_keys()
+    };
+
+    // The height of the block carrying the transaction.
+    let block_height = validation_ctx.current_bitcoin_height();
+
+    for payload in SessionPayload::from_tx(transaction).iter() {
+        // The payload must be signed by one of the quorum keys.
+        let quorum_key = match quorum_keys
+            .iter()
+            .find(|quorum_key| payload.verify(quorum_key.serialize_xonly()))
+        {
+            Some(quorum_key) => quorum_key.to_owned(),
+            None => {
+                println!("Skipping session payload not signed by the operator quorum.");
+                continue;
+            }
+        };
+
+        // The payload cannot be anchored past the block carrying it.
+        if payload.anchor().bitcoin_height() > block_height {
+            println!("Skipping session payload anchored past its block.");
+            continue;
+        }
+
+        let mut txholder = match TxHolder::new(&validation_ctx.utxo_set(), transaction.clone(), 0) {
+            Some(txholder) => txholder,
+            None => continue,
+        };
+
+        let payload_entries = match payload
+            .decode_entries(
+                &mut txholder,
+                epoch_dir,
+                programs_repo,
+                validation_ctx,
+                quorum_key,
+            )
+            .await
+        {
+            Ok(payload_entries) => payload_entries,
+            Err(err) => {
+                println!("Skipping invalid session payload: {:?}", err);
+                continue;
+            }
+        };
+
+        // Consume the nonces of the entries.
+        {
+            let nonces: HashMap<Point, u64> = payload_entries
+                .iter()
+                .map(|entry| (entry.account().key(), entry.nonce()))
+                .collect();
+
+            let account_registery: ACCOUNT_REGISTERY = {
+                let registery = validation_ctx.registery();
+                let _registery = registery.lock().await;
+                _registery.account_registery()
+            };
+
+            let mut _account_registery = account_registery.lock().await;
+            if !_account_registery.consume_nonces(nonces) {
+                println!("Skipping session payload with stale nonces.");
+                continue;
+            }
+        }
+
+        entries.extend(payload_entries);
+    }
+
+    entries
+}
+
 #[async_trait]
 pub trait RollupSync {
     /// Continuously syncs the rollup.
+    ///
+    /// Session payloads are synced if a programs repo and a blacklist directory are given.
     async fn sync(
         &self,
         chain: Chain,
@@ -56,6 +161,8 @@ pub trait RollupSync {
         _lp_dir: &LP_DIRECTORY,
         _registery: &REGISTERY,
         wallet: Option<&WALLET>,
+        programs_repo: Option<&PROGRAMS_REPO>,
+        blacklist_dir: Option<&BLIST_DIRECTORY>,
         coin_set: &COIN_SET,
     );
 
@@ -86,8 +193,10 @@ impl RollupSync for ROLLUP_DIRECTORY {
         key_holder: &KeyHolder,
         epoch_dir: &EPOCH_DIRECTORY,
         _lp_dir: &LP_DIRECTORY,
-        _registery: &REGISTERY,
+        registery: &REGISTERY,
         wallet: Option<&WALLET>,
+        programs_repo: Option<&PROGRAMS_REPO>,
+        blacklist_dir: Option<&BLIST_DIRECTORY>,
         coin_set: &COIN_SET,
     ) {
         let mut synced: bool = false;
@@ -99,6 +208,39 @@ impl RollupSync for ROLLUP_DIRECTORY {
             Chain::Mainnet => baked::MAINNET_SYNC_START_HEIGHT,
         };
 
+        // Backfill the headers of the blocks synced before headers were indexed, so that
+        // every node resolves the same headers for `OP_CHECKBTCINCLUSION`.
+        {
+            let rollup_bitcoin_sync_height = {
+                let _rollup_dir = rollup_dir.lock().await;
+                _rollup_dir.bitcoin_sync_height()
+            };
+
+            let mut height_to_backfill = sync_start_height;
+
+            while height_to_backfill <= rollup_bitcoin_sync_height {
+                let is_indexed = {
+                    let _rollup_dir = rollup_dir.lock().await;
+                    _rollup_dir.has_header(height_to_backfill)
+                };
+
+                if !is_indexed {
+                    let header = match get_block_header(rpc_holder, height_to_backfill) {
+                        Ok(header) => header,
+                        Err(_) => {
+                            sleep(Duration::from_secs(5)).await;
+                            continue;
+                        }
+                    };
+
+                    let mut _rollup_dir = rollup_dir.lock().await;
+                    _rollup_dir.insert_header(height_to_backfill, &header);
+                }
+
+                height_to_backfill += 1;
+            }
+        }
+
         loop {
             let rollup_bitcoin_sync_height = {
                 let _rollup_dir = rollup_dir.lock().await;
@@ -173,6 +315,37 @@ impl RollupSync for ROLLUP_DIRECTORY {
                         let outputs = transaction.output.clone();
                         let txid = transaction.compute_txid();
 
+                        // Sync the session payloads, before the lifts they spend are removed.
+                        if let (Some(programs_repo), Some(blacklist_dir)) =
+                            (programs_repo, blacklist_dir)
+                        {
+                            let (utxo_set, vtxo_set) = {
+                                let _coin_set = coin_set.lock().await;
+                                (_coin_set.utxo_set(), _coin_set.vtxo_set())
+                            };
+
+                            let mut validation_ctx = EntryValidationCtx::new(
+                                chain,
+                                registery,
+                                &vtxo_set,
+                                &utxo_set,
+                                blacklist_dir,
+                                height_to_sync as u32,
+                            );
+
+                            let entries = sync_session_payloads(
+                                transaction,
+                                epoch_dir,
+                                programs_repo,
+                                &mut validation_ctx,
+                            )
+                            .await;
+
+                            if !entries.is_empty() {
+                                println!("Synced {} entries.", entries.len());
+                            }
+                        }
+
                         // Iterate over inputs.
                         for txn_input in inputs.iter() {
                             let txn_input_outpoint = txn_input.previous_output;
@@ -198,6 +371,17 @@ impl RollupSync for ROLLUP_DIRECTORY {
                                 }
                             }
 
+                            // Record the VTXO swept by the operator, if any.
+                            {
+                                let vtxo_set = {
+                                    let _coin_set = coin_set.lock().await;
+                                    _coin_set.vtxo_set()
+                                };
+
+                                let mut _vtxo_set = vtxo_set.lock().await;
+                                _vtxo_set.sweep_vtxo(txn_input);
+                            }
+
                             // Remove spent utxos from utxoset.
                             {
                                 let utxo_set = {
@@ -257,9 +441,10 @@ impl RollupSync for ROLLUP_DIRECTORY {
                         }
                     }
 
-                    // Set the new rollup bitcoin sync height.
+                    // Index the block header and set the new rollup bitcoin sync height.
                     {
                         let mut _rollup_dir = rollup_dir.lock().await;
+                        _rollup_dir.insert_header(height_to_sync, &block.header);
                         _rollup_dir.set_bitcoin_sync_height(height_to_sync);
                     }
 
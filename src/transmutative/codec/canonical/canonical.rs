@@ -0,0 +1,49 @@
+use super::{canonical_error::CanonicalDecodeError, reader::CanonicalReader};
+
+/// The version byte every canonical encoding begins with.
+pub const CANONICAL_CODEC_VERSION: u8 = 0x01;
+
+/// Compact, versioned and canonical binary codec for stored and signed structures.
+///
+/// An encoding is the version byte, followed by the fields in declaration order:
+/// - Integers are fixed-width and little-endian, and booleans are a single 0x00 or 0x01 byte.
+/// - Points are 33-byte compressed keys, and account keys are 32-byte x-only keys.
+/// - Outpoints are the 32-byte txid followed by the vout.
+/// - Optionals are a 0x00 or 0x01 flag, followed by the value if present.
+/// - Lists are a u32 length, followed by the items.
+/// - Maps are a u32 length, followed by the entries in ascending order of their encoded keys.
+///
+/// Every value has exactly one encoding, and decoding rejects any other.
+pub trait Canonical: Sized {
+    /// Encodes the fields, without the version byte.
+    fn encode_fields(&self, bytes: &mut Vec<u8>);
+
+    /// Decodes the fields, without the version byte.
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Self, CanonicalDecodeError>;
+
+    /// Encodes the value with the version byte.
+    fn encode_canonical(&self) -> Vec<u8> {
+        let mut bytes = vec![CANONICAL_CODEC_VERSION];
+        self.encode_fields(&mut bytes);
+        bytes
+    }
+
+    /// Decodes a value with the version byte, rejecting any trailing bytes.
+    fn decode_canonical(bytes: &[u8]) -> Result<Self, CanonicalDecodeError> {
+        let mut reader = CanonicalReader::new(bytes);
+
+        // Check the version.
+        let version = reader.read_u8()?;
+        if version != CANONICAL_CODEC_VERSION {
+            return Err(CanonicalDecodeError::UnsupportedVersion(version));
+        }
+
+        // Decode the fields.
+        let value = Self::decode_fields(&mut reader)?;
+
+        // The encoding must be fully consumed.
+        reader.finish()?;
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,16 @@
+/// The error type for decoding a canonical encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CanonicalDecodeError {
+    UnsupportedVersion(u8),
+    UnexpectedEnd,
+    InvalidFlag(u8),
+    InvalidPoint,
+    InvalidAccountKey,
+    InvalidScalar,
+    InvalidTag(u8),
+    InvalidElementType,
+    InvalidString,
+    InvalidValue,
+    UnsortedMapKeys,
+    TrailingBytes(usize),
+}
@@ -0,0 +1,38 @@
+use super::canonical::Canonical;
+use serde::de::DeserializeOwned;
+
+/// Decodes a persisted value, falling back to its legacy JSON encoding.
+/// Returns the value, and whether it was found in the legacy encoding.
+pub fn decode_persisted<T: Canonical + DeserializeOwned>(bytes: &[u8]) -> Option<(T, bool)> {
+    match T::decode_canonical(bytes) {
+        Ok(value) => Some((value, false)),
+        Err(_) => serde_json::from_slice::<T>(bytes)
+            .ok()
+            .map(|value| (value, true)),
+    }
+}
+
+/// Rewrites the legacy JSON values of a db in the canonical encoding.
+///
+/// Values that decode in neither encoding are left as they are.
+/// Returns `false` if a migrated value could not be written back.
+pub fn migrate_db<T: Canonical + DeserializeOwned>(db: &sled::Db) -> bool {
+    // Collect the legacy values first, rather than writing while iterating.
+    let mut legacy_records = Vec::<(sled::IVec, T)>::new();
+    for lookup in db.iter() {
+        if let Ok((key, val)) = lookup {
+            if let Some((value, true)) = decode_persisted::<T>(&val) {
+                legacy_records.push((key, value));
+            }
+        }
+    }
+
+    // Rewrite them in the canonical encoding.
+    for (key, value) in legacy_records {
+        if db.insert(key, value.encode_canonical()).is_err() {
+            return false;
+        }
+    }
+
+    true
+}
@@ -0,0 +1,6 @@
+pub mod canonical;
+pub mod canonical_error;
+pub mod migrate;
+pub mod primitive;
+pub mod reader;
+pub mod writer;
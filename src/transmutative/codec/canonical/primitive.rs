@@ -0,0 +1,169 @@
+use super::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    writer::CanonicalWriter,
+};
+use bitcoin::OutPoint;
+use secp::{Point, Scalar};
+use std::{collections::HashMap, hash::Hash};
+
+/// The canonical encodings for primitives and the containers that nest them.
+///
+/// These let composite structures encode their fields with `encode_fields` and `decode_fields`,
+/// without spelling out the writer and reader calls for every nested container.
+impl Canonical for u8 {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.push(*self);
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<u8, CanonicalDecodeError> {
+        reader.read_u8()
+    }
+}
+
+impl Canonical for u32 {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.to_le_bytes());
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<u32, CanonicalDecodeError> {
+        reader.read_u32()
+    }
+}
+
+impl Canonical for u64 {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.to_le_bytes());
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<u64, CanonicalDecodeError> {
+        reader.read_u64()
+    }
+}
+
+impl Canonical for [u8; 32] {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self);
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<[u8; 32], CanonicalDecodeError> {
+        reader.read_array::<32>()
+    }
+}
+
+impl Canonical for Point {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.write_point(self);
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Point, CanonicalDecodeError> {
+        reader.read_point()
+    }
+}
+
+impl Canonical for Scalar {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.write_scalar(self);
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Scalar, CanonicalDecodeError> {
+        reader.read_scalar()
+    }
+}
+
+impl Canonical for OutPoint {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.write_outpoint(self);
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<OutPoint, CanonicalDecodeError> {
+        reader.read_outpoint()
+    }
+}
+
+impl<T: Canonical> Canonical for Vec<T> {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.write_list(self, |bytes, item| item.encode_fields(bytes));
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Vec<T>, CanonicalDecodeError> {
+        reader.read_list(T::decode_fields)
+    }
+}
+
+impl<T: Canonical> Canonical for Option<T> {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.write_option(self, |bytes, value| value.encode_fields(bytes));
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<Option<T>, CanonicalDecodeError> {
+        reader.read_option(T::decode_fields)
+    }
+}
+
+impl<A: Canonical, B: Canonical> Canonical for (A, B) {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.0.encode_fields(bytes);
+        self.1.encode_fields(bytes);
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<(A, B), CanonicalDecodeError> {
+        Ok((A::decode_fields(reader)?, B::decode_fields(reader)?))
+    }
+}
+
+impl<A: Canonical, B: Canonical, C: Canonical> Canonical for (A, B, C) {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.0.encode_fields(bytes);
+        self.1.encode_fields(bytes);
+        self.2.encode_fields(bytes);
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<(A, B, C), CanonicalDecodeError> {
+        Ok((
+            A::decode_fields(reader)?,
+            B::decode_fields(reader)?,
+            C::decode_fields(reader)?,
+        ))
+    }
+}
+
+/// A result is flagged by 0x00 for `Ok` or 0x01 for `Err`, followed by the value.
+impl<T: Canonical, E: Canonical> Canonical for Result<T, E> {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Ok(value) => {
+                bytes.write_bool(false);
+                value.encode_fields(bytes);
+            }
+            Err(error) => {
+                bytes.write_bool(true);
+                error.encode_fields(bytes);
+            }
+        }
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<Result<T, E>, CanonicalDecodeError> {
+        match reader.read_bool()? {
+            false => Ok(Ok(T::decode_fields(reader)?)),
+            true => Ok(Err(E::decode_fields(reader)?)),
+        }
+    }
+}
+
+impl<K: Canonical + Eq + Hash, V: Canonical> Canonical for HashMap<K, V> {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        bytes.write_map(
+            self,
+            |bytes, key| key.encode_fields(bytes),
+            |bytes, value| value.encode_fields(bytes),
+        );
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<HashMap<K, V>, CanonicalDecodeError> {
+        reader.read_map(K::decode_fields, V::decode_fields)
+    }
+}
@@ -0,0 +1,164 @@
+use super::canonical_error::CanonicalDecodeError;
+use crate::{constructive::txn::ext::OutpointExt, transmutative::secp::schnorr::Bytes32};
+use bitcoin::OutPoint;
+use secp::{Point, Scalar};
+use std::{collections::HashMap, hash::Hash};
+
+/// Reads canonically encoded fields from a byte slice.
+pub struct CanonicalReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> CanonicalReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> CanonicalReader<'a> {
+        CanonicalReader { bytes, position: 0 }
+    }
+
+    /// Reads the next `N` bytes.
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], CanonicalDecodeError> {
+        let end = self
+            .position
+            .checked_add(N)
+            .ok_or(CanonicalDecodeError::UnexpectedEnd)?;
+
+        let array: [u8; N] = self
+            .bytes
+            .get(self.position..end)
+            .ok_or(CanonicalDecodeError::UnexpectedEnd)?
+            .try_into()
+            .map_err(|_| CanonicalDecodeError::UnexpectedEnd)?;
+
+        self.position = end;
+
+        Ok(array)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, CanonicalDecodeError> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, CanonicalDecodeError> {
+        Ok(u16::from_le_bytes(self.read_array::<2>()?))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, CanonicalDecodeError> {
+        Ok(u32::from_le_bytes(self.read_array::<4>()?))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, CanonicalDecodeError> {
+        Ok(u64::from_le_bytes(self.read_array::<8>()?))
+    }
+
+    /// Reads a boolean, which must be 0x00 or 0x01.
+    pub fn read_bool(&mut self) -> Result<bool, CanonicalDecodeError> {
+        match self.read_u8()? {
+            0x00 => Ok(false),
+            0x01 => Ok(true),
+            flag => Err(CanonicalDecodeError::InvalidFlag(flag)),
+        }
+    }
+
+    /// Reads a 33-byte compressed point.
+    pub fn read_point(&mut self) -> Result<Point, CanonicalDecodeError> {
+        let bytes = self.read_array::<33>()?;
+        let point = Point::from_slice(&bytes).map_err(|_| CanonicalDecodeError::InvalidPoint)?;
+
+        // The parser may be lenient on the prefix byte, so the encoding is checked to be canonical.
+        if point.serialize() != bytes {
+            return Err(CanonicalDecodeError::InvalidPoint);
+        }
+
+        Ok(point)
+    }
+
+    /// Reads a 32-byte scalar.
+    pub fn read_scalar(&mut self) -> Result<Scalar, CanonicalDecodeError> {
+        let bytes = self.read_array::<32>()?;
+        Scalar::from_slice(&bytes).map_err(|_| CanonicalDecodeError::InvalidScalar)
+    }
+
+    /// Reads a 32-byte x-only account key, as an even point.
+    pub fn read_account_key(&mut self) -> Result<Point, CanonicalDecodeError> {
+        self.read_array::<32>()?
+            .to_even_point()
+            .ok_or(CanonicalDecodeError::InvalidAccountKey)
+    }
+
+    /// Reads a 36-byte outpoint.
+    pub fn read_outpoint(&mut self) -> Result<OutPoint, CanonicalDecodeError> {
+        let bytes = self.read_array::<36>()?;
+        OutPoint::from_bytes36(&bytes).ok_or(CanonicalDecodeError::UnexpectedEnd)
+    }
+
+    /// Reads a byte vector, prefixed by its u32 length.
+    pub fn read_bytes(&mut self) -> Result<Vec<u8>, CanonicalDecodeError> {
+        self.read_list(CanonicalReader::read_u8)
+    }
+
+    /// Reads an optional value, flagged by 0x00 or 0x01.
+    pub fn read_option<T>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<T, CanonicalDecodeError>,
+    ) -> Result<Option<T>, CanonicalDecodeError> {
+        match self.read_bool()? {
+            false => Ok(None),
+            true => Ok(Some(read(self)?)),
+        }
+    }
+
+    /// Reads a list, prefixed by its u32 length.
+    pub fn read_list<T>(
+        &mut self,
+        mut read: impl FnMut(&mut Self) -> Result<T, CanonicalDecodeError>,
+    ) -> Result<Vec<T>, CanonicalDecodeError> {
+        let len = self.read_u32()?;
+
+        // Items are read one by one, so a forged length cannot over-allocate.
+        let mut items = Vec::<T>::new();
+        for _ in 0..len {
+            items.push(read(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Reads a map, prefixed by its u32 length.
+    /// The entries must be in strictly ascending order of their encoded keys.
+    pub fn read_map<K: Eq + Hash, V>(
+        &mut self,
+        mut read_key: impl FnMut(&mut Self) -> Result<K, CanonicalDecodeError>,
+        mut read_value: impl FnMut(&mut Self) -> Result<V, CanonicalDecodeError>,
+    ) -> Result<HashMap<K, V>, CanonicalDecodeError> {
+        let len = self.read_u32()?;
+
+        let mut map = HashMap::<K, V>::new();
+        let mut previous_key_bytes: Option<&'a [u8]> = None;
+        for _ in 0..len {
+            let start = self.position;
+            let key = read_key(self)?;
+            let key_bytes: &'a [u8] = &self.bytes[start..self.position];
+
+            // Sorted and unique keys leave each map a single encoding.
+            if let Some(previous_key_bytes) = previous_key_bytes {
+                if key_bytes <= previous_key_bytes {
+                    return Err(CanonicalDecodeError::UnsortedMapKeys);
+                }
+            }
+            previous_key_bytes = Some(key_bytes);
+
+            let value = read_value(self)?;
+            map.insert(key, value);
+        }
+
+        Ok(map)
+    }
+
+    /// Checks that all bytes are read.
+    pub fn finish(&self) -> Result<(), CanonicalDecodeError> {
+        match self.bytes.len() - self.position {
+            0 => Ok(()),
+            trailing => Err(CanonicalDecodeError::TrailingBytes(trailing)),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::constructive::txn::ext::OutpointExt;
+use bitcoin::OutPoint;
+use secp::{Point, Scalar};
+use std::collections::HashMap;
+
+/// Writes canonically encoded fields to a byte vector.
+pub trait CanonicalWriter {
+    fn write_bool(&mut self, value: bool);
+    fn write_point(&mut self, point: &Point);
+    fn write_scalar(&mut self, scalar: &Scalar);
+    fn write_account_key(&mut self, key: &Point);
+    fn write_outpoint(&mut self, outpoint: &OutPoint);
+    fn write_bytes(&mut self, bytes: &[u8]);
+    fn write_option<T>(&mut self, value: &Option<T>, write: impl FnOnce(&mut Self, &T));
+    fn write_list<T>(&mut self, items: &[T], write: impl FnMut(&mut Self, &T));
+    fn write_map<K, V>(
+        &mut self,
+        map: &HashMap<K, V>,
+        write_key: impl FnMut(&mut Vec<u8>, &K),
+        write_value: impl FnMut(&mut Self, &V),
+    );
+}
+
+impl CanonicalWriter for Vec<u8> {
+    /// Writes a boolean as 0x00 or 0x01.
+    fn write_bool(&mut self, value: bool) {
+        self.push(value as u8);
+    }
+
+    /// Writes a 33-byte compressed point.
+    fn write_point(&mut self, point: &Point) {
+        self.extend(point.serialize());
+    }
+
+    /// Writes a 32-byte scalar.
+    fn write_scalar(&mut self, scalar: &Scalar) {
+        self.extend(scalar.serialize());
+    }
+
+    /// Writes a 32-byte x-only account key.
+    fn write_account_key(&mut self, key: &Point) {
+        self.extend(key.serialize_xonly());
+    }
+
+    /// Writes a 36-byte outpoint.
+    fn write_outpoint(&mut self, outpoint: &OutPoint) {
+        self.extend(outpoint.bytes_36());
+    }
+
+    /// Writes a byte vector, prefixed by its u32 length.
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_list(bytes, |vec, byte| vec.push(*byte));
+    }
+
+    /// Writes an optional value, flagged by 0x00 or 0x01.
+    fn write_option<T>(&mut self, value: &Option<T>, write: impl FnOnce(&mut Self, &T)) {
+        self.write_bool(value.is_some());
+        if let Some(value) = value {
+            write(self, value);
+        }
+    }
+
+    /// Writes a list, prefixed by its u32 length.
+    fn write_list<T>(&mut self, items: &[T], mut write: impl FnMut(&mut Self, &T)) {
+        self.extend((items.len() as u32).to_le_bytes());
+        for item in items.iter() {
+            write(self, item);
+        }
+    }
+
+    /// Writes a map, prefixed by its u32 length, with the entries sorted by their encoded keys.
+    fn write_map<K, V>(
+        &mut self,
+        map: &HashMap<K, V>,
+        mut write_key: impl FnMut(&mut Vec<u8>, &K),
+        mut write_value: impl FnMut(&mut Self, &V),
+    ) {
+        // Encode the keys first, so that the entries can be sorted by them.
+        let mut entries = map
+            .iter()
+            .map(|(key, value)| {
+                let mut key_bytes = Vec::<u8>::new();
+                write_key(&mut key_bytes, key);
+                (key_bytes, value)
+            })
+            .collect::<Vec<(Vec<u8>, &V)>>();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        self.extend((entries.len() as u32).to_le_bytes());
+        for (key_bytes, value) in entries.iter() {
+            self.extend(key_bytes);
+            write_value(self, value);
+        }
+    }
+}
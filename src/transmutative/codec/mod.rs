@@ -1,3 +1,4 @@
 pub mod address;
+pub mod canonical;
 pub mod csv;
 pub mod prefix;
@@ -1,12 +1,16 @@
-use crate::{constructive::entry::combinator::combinator_type::CombinatorType, inscriptive::baked};
+use crate::{
+    constructive::entry::combinator::combinator_type::CombinatorType, inscriptive::baked,
+    operative::Chain,
+};
 use sha2::{Digest, Sha256};
+use sha3::Keccak256;
 
 #[derive(Clone, PartialEq)]
 pub enum HashTag {
     VSEEncryptionAuth,
     Sighash,
-    SighashCombinator(CombinatorType),
-    SighashEntry,
+    SighashCombinator(Chain, CombinatorType),
+    SighashEntry(Chain),
     PayloadAuth,
     SignatureChallenge,
     BIP340Challenge,
@@ -30,6 +34,18 @@ pub enum HashTag {
     CustomBytes(Vec<u8>),
     // Method ID
     ContractID,
+    // Factory contract ID
+    FactoryContractID,
+    // Randomness beacon
+    RandomnessBeacon,
+    // Randomness beacon message
+    RandomnessBeaconMessage,
+    // Revived VTXO
+    RevivedVTXO,
+    // Settled VTXO
+    SettledVTXO,
+    // Session payload message
+    SessionPayloadMessage,
 }
 
 impl HashTag {
@@ -37,18 +53,24 @@ impl HashTag {
         match self {
             HashTag::VSEEncryptionAuth => format!("{}/{}", baked::PROJECT_TAG, "vseencryptionauth"),
             HashTag::Sighash => format!("{}/{}", baked::PROJECT_TAG, "sighash"),
-            // Combinators
-            HashTag::SighashCombinator(combinator_type) => {
+            // Combinators are bound to the chain they are signed for.
+            HashTag::SighashCombinator(chain, combinator_type) => {
                 format!(
                     "{}/{}/{}/{}",
                     baked::PROJECT_TAG,
-                    "sighash",
-                    "combinator",
+                    chain.to_string(),
+                    "sighash/combinator",
                     combinator_type.as_str()
                 )
             }
             //
-            HashTag::SighashEntry => format!("{}/{}", baked::PROJECT_TAG, "sighash/entry"),
+            // Entries are bound to the chain they are signed for.
+            HashTag::SighashEntry(chain) => format!(
+                "{}/{}/{}",
+                baked::PROJECT_TAG,
+                chain.to_string(),
+                "sighash/entry"
+            ),
             HashTag::SignatureChallenge => format!("{}/{}", baked::PROJECT_TAG, "challenge"),
             HashTag::BIP340Challenge => format!("{}/{}", "BIP0340", "challenge"),
             HashTag::GroupCommitment => format!("{}/{}", baked::PROJECT_TAG, "groupcommitment"),
@@ -68,6 +90,18 @@ impl HashTag {
             HashTag::CustomString(tag) => tag.clone(),
             HashTag::CustomBytes(tag) => tag.clone().into_iter().map(|b| b as char).collect(),
             HashTag::ContractID => format!("{}/{}", baked::PROJECT_TAG, "contractid"),
+            HashTag::FactoryContractID => {
+                format!("{}/{}", baked::PROJECT_TAG, "factorycontractid")
+            }
+            HashTag::RandomnessBeacon => format!("{}/{}", baked::PROJECT_TAG, "randomnessbeacon"),
+            HashTag::RandomnessBeaconMessage => {
+                format!("{}/{}", baked::PROJECT_TAG, "randomnessbeacon/message")
+            }
+            HashTag::RevivedVTXO => format!("{}/{}", baked::PROJECT_TAG, "revivedvtxo"),
+            HashTag::SettledVTXO => format!("{}/{}", baked::PROJECT_TAG, "settledvtxo"),
+            HashTag::SessionPayloadMessage => {
+                format!("{}/{}", baked::PROJECT_TAG, "sessionpayload/message")
+            }
         }
     }
 }
@@ -81,6 +115,15 @@ pub fn sha256(preimage: &[u8]) -> [u8; 32] {
     hash_array
 }
 
+pub fn keccak256(preimage: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(preimage);
+    let result = hasher.finalize();
+    let mut hash_array = [0u8; 32];
+    hash_array.copy_from_slice(&result);
+    hash_array
+}
+
 pub trait Hash {
     fn hash(&self, tag: Option<HashTag>) -> [u8; 32];
 }
@@ -1,3 +1,6 @@
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
 use crate::transmutative::hash::{Hash, HashTag};
 use crate::transmutative::secp::into::IntoScalar;
 use secp::{MaybePoint, Point, Scalar};
@@ -70,6 +73,32 @@ impl MusigKeyAggCtx {
     }
 }
 
+/// The canonical encoding for `MusigKeyAggCtx`: the sorted keys, followed by the optional tweak.
+///
+/// The key coefficients and the aggregate keys are derived again when decoding.
+impl Canonical for MusigKeyAggCtx {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.keys.encode_fields(bytes);
+        self.tweak.encode_fields(bytes);
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<MusigKeyAggCtx, CanonicalDecodeError> {
+        let keys = Vec::<Point>::decode_fields(reader)?;
+        let tweak = Option::<Scalar>::decode_fields(reader)?;
+
+        let ctx = MusigKeyAggCtx::new(&keys, tweak).ok_or(CanonicalDecodeError::InvalidValue)?;
+
+        // Keys must be in their sorted order.
+        if ctx.keys != keys {
+            return Err(CanonicalDecodeError::InvalidValue);
+        }
+
+        Ok(ctx)
+    }
+}
+
 fn sort_keys(keys: &Vec<Point>) -> Vec<Point> {
     let mut keys = keys.clone();
     keys.sort();
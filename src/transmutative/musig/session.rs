@@ -1,4 +1,7 @@
 use super::keyagg::MusigKeyAggCtx;
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
 use crate::transmutative::hash::{Hash, HashTag};
 use crate::transmutative::secp::into::IntoScalar;
 use crate::transmutative::secp::schnorr::{challenge, SchnorrSigningMode};
@@ -282,6 +285,46 @@ impl MusigSessionCtx {
     }
 }
 
+/// The canonical encoding for `MusigSessionCtx`: the key aggregation context, the message,
+/// the nonces and the partial signatures.
+///
+/// The nonce coefficient, the aggregate nonce and the challenge are derived again when decoding,
+/// and the partial signatures are verified as they are inserted back.
+impl Canonical for MusigSessionCtx {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.key_agg_ctx.encode_fields(bytes);
+        self.message.encode_fields(bytes);
+        self.nonces.encode_fields(bytes);
+        self.partial_sigs.encode_fields(bytes);
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<MusigSessionCtx, CanonicalDecodeError> {
+        let key_agg_ctx = MusigKeyAggCtx::decode_fields(reader)?;
+        let message = <[u8; 32]>::decode_fields(reader)?;
+        let nonces = HashMap::<Point, (Point, Point)>::decode_fields(reader)?;
+        let partial_sigs = HashMap::<Point, Scalar>::decode_fields(reader)?;
+
+        let mut ctx = MusigSessionCtx::new(&key_agg_ctx, message)
+            .ok_or(CanonicalDecodeError::InvalidValue)?;
+
+        for (key, (hiding_nonce, binding_nonce)) in nonces {
+            if !ctx.insert_nonce(key, hiding_nonce, binding_nonce) {
+                return Err(CanonicalDecodeError::InvalidValue);
+            }
+        }
+
+        for (key, partial_sig) in partial_sigs {
+            if !ctx.insert_partial_sig(key, partial_sig) {
+                return Err(CanonicalDecodeError::InvalidValue);
+            }
+        }
+
+        Ok(ctx)
+    }
+}
+
 fn compute_challenge(agg_nonce: Point, agg_key: Point, message: [u8; 32]) -> Option<Scalar> {
     let challenge = match challenge(agg_nonce, agg_key, message, SchnorrSigningMode::BIP340) {
         MaybeScalar::Valid(scalar) => scalar,
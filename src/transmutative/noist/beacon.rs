@@ -0,0 +1,147 @@
+use super::session::NOISTSessionCtx;
+use crate::transmutative::{
+    hash::{Hash, HashTag},
+    secp::schnorr::{self, SchnorrSigningMode},
+};
+
+/// A randomness beacon derived from the finalized aggregated signature of a NOIST session.
+///
+/// The quorum signs `H(session_height ‖ prev)`, where `prev` is the randomness of the previous
+/// session's beacon, so a beacon is bound to its height and chained to its predecessor. The group
+/// nonce is committed to by the quorum before the message is known, and the aggregated signature
+/// is unique for a given nonce, key and message. No single operator can therefore bias the beacon,
+/// and anyone holding the operator quorum key can verify it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RandomnessBeacon {
+    // The session height the beacon belongs to.
+    session_height: u64,
+    // The x-only group key of the operator quorum.
+    group_key: [u8; 32],
+    // The randomness of the previous session's beacon.
+    prev: [u8; 32],
+    // The full aggregated BIP-340 signature.
+    signature: [u8; 64],
+}
+
+impl RandomnessBeacon {
+    /// Creates a new beacon. The beacon is not verified until `verify` is called.
+    pub fn new(
+        session_height: u64,
+        group_key: [u8; 32],
+        prev: [u8; 32],
+        signature: [u8; 64],
+    ) -> RandomnessBeacon {
+        RandomnessBeacon {
+            session_height,
+            group_key,
+            prev,
+            signature,
+        }
+    }
+
+    /// Creates a new beacon from a NOIST signing session which met the threshold.
+    ///
+    /// The session must have signed the beacon message of the given height and previous randomness.
+    pub fn from_session(
+        session_height: u64,
+        prev: [u8; 32],
+        session: &NOISTSessionCtx,
+    ) -> Option<RandomnessBeacon> {
+        if session.message() != RandomnessBeacon::signing_message(session_height, prev) {
+            return None;
+        }
+
+        let group_key = session.group_key().serialize_xonly();
+        let signature = session.full_aggregated_sig_bytes()?;
+
+        Some(RandomnessBeacon::new(
+            session_height,
+            group_key,
+            prev,
+            signature,
+        ))
+    }
+
+    /// Returns the message the quorum signs for the given session height and previous randomness.
+    pub fn signing_message(session_height: u64, prev: [u8; 32]) -> [u8; 32] {
+        let mut preimage = Vec::<u8>::with_capacity(40);
+        preimage.extend(session_height.to_le_bytes());
+        preimage.extend(prev);
+
+        preimage.hash(Some(HashTag::RandomnessBeaconMessage))
+    }
+
+    /// Returns the session height.
+    pub fn session_height(&self) -> u64 {
+        self.session_height
+    }
+
+    /// Returns the x-only group key.
+    pub fn group_key(&self) -> [u8; 32] {
+        self.group_key
+    }
+
+    /// Returns the randomness of the previous session's beacon.
+    pub fn prev(&self) -> [u8; 32] {
+        self.prev
+    }
+
+    /// Returns the signed message.
+    pub fn message(&self) -> [u8; 32] {
+        RandomnessBeacon::signing_message(self.session_height, self.prev)
+    }
+
+    /// Returns the full aggregated signature.
+    pub fn signature(&self) -> [u8; 64] {
+        self.signature
+    }
+
+    /// Verifies that the beacon is signed by the given operator quorum key.
+    pub fn verify(&self, quorum_key: [u8; 32]) -> bool {
+        if self.group_key != quorum_key {
+            return false;
+        }
+
+        schnorr::verify_xonly(
+            self.group_key,
+            self.message(),
+            self.signature,
+            SchnorrSigningMode::BIP340,
+        )
+    }
+
+    /// Returns the 32-byte randomness output of the beacon.
+    pub fn randomness(&self) -> [u8; 32] {
+        self.serialize().hash(Some(HashTag::RandomnessBeacon))
+    }
+
+    /// Serializes the beacon as session height (8 bytes LE), group key, previous randomness and
+    /// signature.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut bytes = Vec::<u8>::with_capacity(136);
+        bytes.extend(self.session_height.to_le_bytes());
+        bytes.extend(self.group_key);
+        bytes.extend(self.prev);
+        bytes.extend(self.signature);
+        bytes
+    }
+
+    /// Deserializes a beacon. The beacon is not verified.
+    pub fn from_slice(bytes: &[u8]) -> Option<RandomnessBeacon> {
+        if bytes.len() != 136 {
+            return None;
+        }
+
+        let session_height = u64::from_le_bytes(bytes[0..8].try_into().ok()?);
+        let group_key: [u8; 32] = bytes[8..40].try_into().ok()?;
+        let prev: [u8; 32] = bytes[40..72].try_into().ok()?;
+        let signature: [u8; 64] = bytes[72..136].try_into().ok()?;
+
+        Some(RandomnessBeacon::new(
+            session_height,
+            group_key,
+            prev,
+            signature,
+        ))
+    }
+}
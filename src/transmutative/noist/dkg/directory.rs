@@ -1,5 +1,6 @@
 use super::session::DKGSession;
 use crate::{
+    transmutative::codec::canonical::{canonical::Canonical, migrate::migrate_db},
     transmutative::musig::session::MusigSessionCtx,
     transmutative::noist::{session::NOISTSessionCtx, setup::setup::VSESetup},
 };
@@ -39,12 +40,17 @@ impl DKGDirectory {
         let sessions_path = format!("{}/{}/{}", "db/noist/dkgdir", setup_height, "dkgses");
         let sessions_db = sled::open(sessions_path).ok()?;
 
+        // Migrate the legacy JSON records to the canonical encoding.
+        if !migrate_db::<DKGSession>(&sessions_db) {
+            return None;
+        }
+
         let mut sessions = HashMap::<u64, DKGSession>::new();
 
         // Insert DKG sessions to the memory.
         for lookup in sessions_db.iter() {
             if let Ok((_, session)) = lookup {
-                let session: DKGSession = match serde_json::from_slice(&session) {
+                let session: DKGSession = match DKGSession::decode_canonical(&session) {
                     Ok(session) => session,
                     Err(_) => return None,
                 };
@@ -1,4 +1,7 @@
 use crate::{
+    transmutative::codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    },
     transmutative::hash::{Hash, HashTag},
     transmutative::noist::setup::setup::VSESetup,
     transmutative::secp::authenticable::AuthSighash,
@@ -32,17 +35,11 @@ impl DKGPackage {
     }
 
     pub fn from_slice(bytes: &[u8]) -> Option<Self> {
-        match serde_json::from_slice(bytes) {
-            Ok(keymap) => Some(keymap),
-            Err(_) => None,
-        }
+        Self::decode_canonical(bytes).ok()
     }
 
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
     }
 
     pub fn signatory(&self) -> Point {
@@ -115,3 +112,19 @@ impl AuthSighash for DKGPackage {
         preimage.hash(Some(HashTag::Sighash))
     }
 }
+
+impl Canonical for DKGPackage {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.signatory.encode_fields(bytes);
+        self.hiding.encode_fields(bytes);
+        self.binding.encode_fields(bytes);
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<DKGPackage, CanonicalDecodeError> {
+        Ok(DKGPackage {
+            signatory: Point::decode_fields(reader)?,
+            hiding: DKGShareMap::decode_fields(reader)?,
+            binding: DKGShareMap::decode_fields(reader)?,
+        })
+    }
+}
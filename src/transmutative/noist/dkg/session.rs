@@ -1,5 +1,8 @@
 use super::package::DKGPackage;
 use crate::transmutative::{
+    codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    },
     hash::{Hash, HashTag},
     noist::{core::vse, setup::setup::VSESetup},
     secp::{
@@ -40,17 +43,11 @@ impl DKGSession {
     }
 
     pub fn from_slice(bytes: &[u8]) -> Option<Self> {
-        match serde_json::from_slice(bytes) {
-            Ok(keymap) => Some(keymap),
-            Err(_) => None,
-        }
+        Self::decode_canonical(bytes).ok()
     }
 
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
     }
 
     pub fn index(&self) -> u64 {
@@ -456,3 +453,19 @@ impl AuthSighash for DKGSession {
         preimage.hash(Some(HashTag::Sighash))
     }
 }
+
+impl Canonical for DKGSession {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.index.encode_fields(bytes);
+        self.signatories.encode_fields(bytes);
+        self.packages.encode_fields(bytes);
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<DKGSession, CanonicalDecodeError> {
+        Ok(DKGSession {
+            index: u64::decode_fields(reader)?,
+            signatories: Vec::<Point>::decode_fields(reader)?,
+            packages: HashMap::<Point, Authenticable<DKGPackage>>::decode_fields(reader)?,
+        })
+    }
+}
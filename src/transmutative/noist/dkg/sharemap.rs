@@ -1,4 +1,7 @@
 use crate::transmutative::{
+    codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    },
     hash::{Hash, HashTag},
     noist::{
         core::{share::gen_polynomial, vse, vss},
@@ -81,17 +84,11 @@ impl DKGShareMap {
     }
 
     pub fn from_slice(bytes: &[u8]) -> Option<Self> {
-        match serde_json::from_slice(bytes) {
-            Ok(keymap) => Some(keymap),
-            Err(_) => None,
-        }
+        Self::decode_canonical(bytes).ok()
     }
 
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
     }
 
     pub fn signatory(&self) -> Point {
@@ -233,3 +230,21 @@ impl AuthSighash for DKGShareMap {
         preimage.hash(Some(HashTag::Sighash))
     }
 }
+
+impl Canonical for DKGShareMap {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.signatory.encode_fields(bytes);
+        self.vss_commitments.encode_fields(bytes);
+        self.shares.encode_fields(bytes);
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<DKGShareMap, CanonicalDecodeError> {
+        Ok(DKGShareMap {
+            signatory: Point::decode_fields(reader)?,
+            vss_commitments: Vec::<Point>::decode_fields(reader)?,
+            shares: HashMap::<Point, (Point, Scalar)>::decode_fields(reader)?,
+        })
+    }
+}
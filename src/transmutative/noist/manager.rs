@@ -4,7 +4,9 @@ use super::{
     setup::setup::VSESetup,
 };
 use crate::{
-    inscriptive::lp::dir::LP_DIRECTORY, transmutative::musig::session::MusigSessionCtx,
+    inscriptive::lp::dir::LP_DIRECTORY,
+    transmutative::codec::canonical::{canonical::Canonical, migrate::migrate_db},
+    transmutative::musig::session::MusigSessionCtx,
     transmutative::secp::into::IntoPointByteVec,
 };
 use secp::Point;
@@ -32,11 +34,16 @@ impl DKGManager {
     pub fn new(lp_dir: &LP_DIRECTORY) -> Option<DKG_MANAGER> {
         let setup_db = sled::open("db/noist/setup").ok()?;
 
+        // Migrate the legacy JSON records to the canonical encoding.
+        if !migrate_db::<VSESetup>(&setup_db) {
+            return None;
+        }
+
         let mut directories = HashMap::<u64, DKG_DIRECTORY>::new();
 
         for lookup in setup_db.iter() {
             if let Ok((_, setup_)) = lookup {
-                let setup: VSESetup = serde_json::from_slice(&setup_).ok()?;
+                let setup: VSESetup = VSESetup::decode_canonical(&setup_).ok()?;
                 let setup_height = setup.height();
                 let dkg_directory: DKGDirectory = DKGDirectory::new(&setup)?;
                 directories.insert(setup_height, Arc::new(Mutex::new(dkg_directory)));
@@ -1,3 +1,4 @@
+pub mod beacon;
 pub mod core;
 pub mod dkg;
 pub mod manager;
@@ -106,6 +106,10 @@ impl NOISTSessionCtx {
         self.group_nonce
     }
 
+    pub fn message(&self) -> [u8; 32] {
+        self.message
+    }
+
     pub fn nonce_height(&self) -> u64 {
         self.group_nonce_session.index()
     }
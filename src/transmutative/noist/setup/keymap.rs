@@ -1,4 +1,7 @@
 use crate::transmutative::{
+    codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    },
     hash::{Hash, HashTag},
     noist::core::vse::encrypting_key_public,
     secp::{
@@ -54,17 +57,11 @@ impl VSEKeyMap {
     }
 
     pub fn from_slice(bytes: &[u8]) -> Option<Self> {
-        match serde_json::from_slice(bytes) {
-            Ok(keymap) => Some(keymap),
-            Err(_) => None,
-        }
+        Self::decode_canonical(bytes).ok()
     }
 
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
     }
 
     pub fn signatory(&self) -> Point {
@@ -181,3 +178,17 @@ impl AuthSighash for VSEKeyMap {
         preimage.hash(Some(HashTag::Sighash))
     }
 }
+
+impl Canonical for VSEKeyMap {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.signatory.encode_fields(bytes);
+        self.map.encode_fields(bytes);
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<VSEKeyMap, CanonicalDecodeError> {
+        Ok(VSEKeyMap {
+            signatory: Point::decode_fields(reader)?,
+            map: HashMap::<Point, (Point, SigTuple, Proof)>::decode_fields(reader)?,
+        })
+    }
+}
@@ -1,5 +1,10 @@
 use super::keymap::VSEKeyMap;
-use crate::transmutative::secp::into::IntoPoint;
+use crate::transmutative::{
+    codec::canonical::{
+        canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+    },
+    secp::into::IntoPoint,
+};
 use secp::Point;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -26,17 +31,11 @@ impl VSESetup {
     }
 
     pub fn from_slice(bytes: &[u8]) -> Option<Self> {
-        match serde_json::from_slice(bytes) {
-            Ok(keymap) => Some(keymap),
-            Err(_) => None,
-        }
+        Self::decode_canonical(bytes).ok()
     }
 
     pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
+        self.encode_canonical()
     }
 
     pub fn height(&self) -> u64 {
@@ -195,3 +194,19 @@ impl VSESetup {
         }
     }
 }
+
+impl Canonical for VSESetup {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.height.encode_fields(bytes);
+        self.signatories.encode_fields(bytes);
+        self.map.encode_fields(bytes);
+    }
+
+    fn decode_fields(reader: &mut CanonicalReader<'_>) -> Result<VSESetup, CanonicalDecodeError> {
+        Ok(VSESetup {
+            height: u64::decode_fields(reader)?,
+            signatories: Vec::<Point>::decode_fields(reader)?,
+            map: HashMap::<Point, VSEKeyMap>::decode_fields(reader)?,
+        })
+    }
+}
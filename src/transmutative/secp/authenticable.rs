@@ -1,3 +1,6 @@
+use crate::transmutative::codec::canonical::{
+    canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+};
 use crate::transmutative::secp::into::{IntoPoint, IntoScalar};
 use crate::transmutative::secp::schnorr::{sign, verify_xonly, SchnorrSigningMode};
 use secp::{Point, Scalar};
@@ -36,14 +39,6 @@ where
         })
     }
 
-    /// Serialize the authenticable object.
-    pub fn serialize(&self) -> Vec<u8> {
-        match serde_json::to_vec(self) {
-            Ok(bytes) => bytes,
-            Err(_) => vec![],
-        }
-    }
-
     /// Get the object.
     pub fn object(&self) -> T {
         self.object.clone()
@@ -81,6 +76,40 @@ where
     }
 }
 
+impl<T> Authenticable<T>
+where
+    T: Canonical,
+{
+    /// Serialize the authenticable object.
+    pub fn serialize(&self) -> Vec<u8> {
+        self.encode_canonical()
+    }
+
+    /// Deserialize an authenticable object.
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        Self::decode_canonical(bytes).ok()
+    }
+}
+
+/// The canonical encoding for `Authenticable`: the object, followed by the signature and the key.
+impl<T: Canonical> Canonical for Authenticable<T> {
+    fn encode_fields(&self, bytes: &mut Vec<u8>) {
+        self.object.encode_fields(bytes);
+        self.sig.encode_fields(bytes);
+        self.key.encode_fields(bytes);
+    }
+
+    fn decode_fields(
+        reader: &mut CanonicalReader<'_>,
+    ) -> Result<Authenticable<T>, CanonicalDecodeError> {
+        Ok(Authenticable {
+            object: T::decode_fields(reader)?,
+            sig: <(Point, Scalar)>::decode_fields(reader)?,
+            key: Point::decode_fields(reader)?,
+        })
+    }
+}
+
 pub trait AuthSighash {
     fn auth_sighash(&self) -> [u8; 32];
 }
@@ -0,0 +1,71 @@
+use k256::ecdsa::{
+    signature::hazmat::PrehashVerifier, RecoveryId, Signature, SigningKey, VerifyingKey,
+};
+
+/// Signs an ECDSA message and returns a 65-byte recoverable signature (r || s || v).
+pub fn sign_recoverable(secret_key: [u8; 32], message: [u8; 32]) -> Option<[u8; 65]> {
+    let signing_key = SigningKey::from_bytes(&secret_key.into()).ok()?;
+
+    let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&message).ok()?;
+
+    let mut signature_bytes = Vec::<u8>::with_capacity(65);
+    signature_bytes.extend(signature.to_bytes());
+    signature_bytes.push(recovery_id.to_byte());
+
+    signature_bytes.try_into().ok()
+}
+
+/// Verifies an ECDSA message against a compressed (33-byte) or uncompressed (65-byte) public key.
+pub fn verify(public_key: &[u8], message: [u8; 32], signature: [u8; 64]) -> bool {
+    let verifying_key = match VerifyingKey::from_sec1_bytes(public_key) {
+        Ok(verifying_key) => verifying_key,
+        Err(_) => return false,
+    };
+
+    let signature = match Signature::from_slice(&signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+
+    // High-S signatures are malleable and therefore rejected.
+    if signature.normalize_s().is_some() {
+        return false;
+    }
+
+    verifying_key.verify_prehash(&message, &signature).is_ok()
+}
+
+/// Recovers the uncompressed (65-byte) public key from a 65-byte recoverable signature (r || s || v).
+/// The recovery id `v` can be either 0/1 or the Ethereum-style 27/28.
+///
+/// Like Ethereum's `ecrecover`, high-S signatures are accepted: they recover the same key as their
+/// low-S counterpart.
+pub fn recover(message: [u8; 32], signature: [u8; 65]) -> Option<[u8; 65]> {
+    let recovery_byte = match signature[64] {
+        0 | 1 => signature[64],
+        27 | 28 => signature[64] - 27,
+        _ => return None,
+    };
+
+    let recovery_id = RecoveryId::from_byte(recovery_byte)?;
+
+    let signature = Signature::from_slice(&signature[..64]).ok()?;
+
+    // Normalize a high-S signature, which negates the nonce point and so flips its y-parity.
+    let (signature, recovery_id) = match signature.normalize_s() {
+        Some(normalized) => (
+            normalized,
+            RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced()),
+        ),
+        None => (signature, recovery_id),
+    };
+
+    let verifying_key =
+        VerifyingKey::recover_from_prehash(&message, &signature, recovery_id).ok()?;
+
+    verifying_key
+        .to_encoded_point(false)
+        .as_bytes()
+        .try_into()
+        .ok()
+}
@@ -1,4 +1,5 @@
 pub mod authenticable;
+pub mod ecdsa;
 pub mod error;
 pub mod into;
 pub mod schnorr;
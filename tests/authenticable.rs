@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod authenticable_tests {
     use cube::transmutative::{
+        codec::canonical::{
+            canonical::Canonical, canonical_error::CanonicalDecodeError, reader::CanonicalReader,
+            writer::CanonicalWriter,
+        },
         hash::{Hash, HashTag},
         secp::authenticable::{AuthSighash, Authenticable},
     };
@@ -23,6 +27,23 @@ mod authenticable_tests {
         }
     }
 
+    impl Canonical for DemoStruct {
+        fn encode_fields(&self, bytes: &mut Vec<u8>) {
+            bytes.write_bytes(self.field1.as_bytes());
+            self.field2.encode_fields(bytes);
+        }
+
+        fn decode_fields(
+            reader: &mut CanonicalReader<'_>,
+        ) -> Result<DemoStruct, CanonicalDecodeError> {
+            Ok(DemoStruct {
+                field1: String::from_utf8(reader.read_bytes()?)
+                    .map_err(|_| CanonicalDecodeError::InvalidString)?,
+                field2: u32::decode_fields(reader)?,
+            })
+        }
+    }
+
     #[test]
     fn authenticable_test() -> Result<(), String> {
         let my_struct = DemoStruct {
@@ -46,7 +67,7 @@ mod authenticable_tests {
         let authenticable_bytes = authenticable.serialize();
 
         let authenticable: Authenticable<DemoStruct> =
-            serde_json::from_slice(&authenticable_bytes).unwrap();
+            Authenticable::from_slice(&authenticable_bytes).unwrap();
 
         if !authenticable.authenticate() {
             return Err("Authentication failed.".into());
@@ -0,0 +1,535 @@
+#[cfg(test)]
+mod canonical_tests {
+    use bitcoin::{hashes::Hash, OutPoint, Txid};
+    use cube::{
+        constructive::{
+            calldata::{element::element::CallElement, element_type::CallElementType},
+            entity::{account::account::Account, contract::contract::Contract},
+            entry::{
+                combinator::{
+                    combinator::Combinator,
+                    combinators::{
+                        add::add::Add,
+                        batch_move::batch_move::BatchMove,
+                        bundle::{bundle::Bundle, bundle_item::BundleItem},
+                        call::call::Call,
+                        claim::claim::Claim,
+                        deploy::Deploy,
+                        liftup::liftup::Liftup,
+                        r#move::r#move::Move,
+                        recharge::recharge::Recharge,
+                        reserved::Reserved,
+                        revive::revive::Revive,
+                        sub::sub::Sub,
+                        swapout::swapout::Swapout,
+                    },
+                },
+                entry::Entry,
+            },
+            txo::{lift::Lift, vtxo::VTXO},
+            valtype::val::{long_val::long_val::LongVal, short_val::short_val::ShortVal},
+        },
+        inscriptive::{epoch::epoch::Epoch, lp::lp::LP},
+        operative::Chain,
+        transmutative::{
+            codec::canonical::{
+                canonical::{Canonical, CANONICAL_CODEC_VERSION},
+                canonical_error::CanonicalDecodeError,
+                migrate::{decode_persisted, migrate_db},
+            },
+            musig::{keyagg::MusigKeyAggCtx, session::MusigSessionCtx},
+            noist::{
+                dkg::{package::DKGPackage, session::DKGSession},
+                setup::{keymap::VSEKeyMap, setup::VSESetup},
+            },
+            secp::{authenticable::Authenticable, schnorr::Bytes32},
+        },
+    };
+    use secp::{Point, Scalar};
+    use std::collections::HashMap;
+
+    fn key(secret: u8) -> Point {
+        [secret; 32]
+            .secret_to_public()
+            .unwrap()
+            .to_even_point()
+            .unwrap()
+    }
+
+    fn secret(byte: u8) -> Scalar {
+        Scalar::from_slice(&[byte; 32]).unwrap()
+    }
+
+    fn outpoint(vout: u32) -> OutPoint {
+        OutPoint {
+            txid: Txid::from_byte_array([0xab; 32]),
+            vout,
+        }
+    }
+
+    #[test]
+    fn canonical_roundtrip_test() -> Result<(), String> {
+        let alice = Account::new(key(1), Some(7), Some(2)).unwrap();
+        let bob = Account::new(key(2), None, None).unwrap();
+        // Operator keys may be odd.
+        let operator = -key(3);
+
+        // Account
+        let decoded =
+            Account::decode_canonical(&alice.serialize()).map_err(|e| format!("{:?}", e))?;
+        assert_eq!(decoded.key(), alice.key());
+        assert_eq!(decoded.registery_index(), Some(7));
+        assert_eq!(decoded.rank(), Some(2));
+        assert_eq!(alice.serialize().len(), 1 + 32 + 5 + 5);
+
+        // Contract
+        let contract = Contract::new([0x11; 32], 3, None);
+        let decoded =
+            Contract::decode_canonical(&contract.serialize()).map_err(|e| format!("{:?}", e))?;
+        assert_eq!(decoded, contract);
+        assert_eq!(decoded.registery_index(), 3);
+        assert_eq!(decoded.rank(), None);
+
+        // VTXO
+        let vtxo = VTXO::new(
+            alice.key(),
+            operator,
+            Some(outpoint(1)),
+            Some(50_000),
+            None,
+            Some(840_000),
+        );
+        let decoded = VTXO::decode_canonical(&vtxo.serialize()).map_err(|e| format!("{:?}", e))?;
+        assert!(decoded == vtxo);
+        assert_eq!(decoded.operator_key(), operator);
+
+        // The canonical encoding is more compact than JSON.
+        assert!(vtxo.serialize().len() < serde_json::to_vec(&vtxo).unwrap().len() / 2);
+
+        // Lift
+        let lift = Lift::new(alice.key(), operator, Some(outpoint(2)), None);
+        let decoded = Lift::decode_canonical(&lift.serialize()).map_err(|e| format!("{:?}", e))?;
+        assert!(decoded == lift);
+
+        // Epoch
+        let epoch = Epoch::new(5, true, operator, vec![alice, bob]);
+        let decoded =
+            Epoch::decode_canonical(&epoch.serialize()).map_err(|e| format!("{:?}", e))?;
+        assert_eq!(decoded.height(), 5);
+        assert!(decoded.active());
+        assert_eq!(decoded.group_key(), operator);
+        assert_eq!(decoded.operators(), vec![alice, bob]);
+        assert_eq!(decoded.serialize(), epoch.serialize());
+
+        // LP
+        let lp = LP::new(bob, 1_000_000, 999);
+        let decoded = LP::decode_canonical(&lp.serialize()).map_err(|e| format!("{:?}", e))?;
+        assert_eq!(decoded.account(), bob);
+        assert_eq!(decoded.liquidity(), 1_000_000);
+        assert_eq!(decoded.lp(), 999);
+
+        // Add, Sub and Move
+        let add = Add::new(alice, 100);
+        assert!(Add::decode_canonical(&add.serialize()) == Ok(add));
+        let sub = Sub::new(alice, 200);
+        assert!(Sub::decode_canonical(&sub.serialize()) == Ok(sub));
+        let r#move = Move::new(alice, bob, 300);
+        assert!(Move::decode_canonical(&r#move.serialize()) == Ok(r#move));
+
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_rejection_test() -> Result<(), String> {
+        let account = Account::new(key(1), None, Some(4)).unwrap();
+        let bytes = account.serialize();
+        assert_eq!(bytes[0], CANONICAL_CODEC_VERSION);
+
+        // Unknown versions are rejected.
+        let mut unsupported = bytes.clone();
+        unsupported[0] = 0x02;
+        assert_eq!(
+            Account::decode_canonical(&unsupported).map(|_| ()),
+            Err(CanonicalDecodeError::UnsupportedVersion(0x02))
+        );
+
+        // Each value has a single encoding; trailing bytes and other flags are rejected.
+        let mut trailing = bytes.clone();
+        trailing.push(0x00);
+        assert_eq!(
+            Account::decode_canonical(&trailing).map(|_| ()),
+            Err(CanonicalDecodeError::TrailingBytes(1))
+        );
+
+        let mut flag = bytes.clone();
+        flag[33] = 0x02;
+        assert_eq!(
+            Account::decode_canonical(&flag).map(|_| ()),
+            Err(CanonicalDecodeError::InvalidFlag(0x02))
+        );
+
+        // Truncated encodings are rejected.
+        assert_eq!(
+            Account::decode_canonical(&bytes[..bytes.len() - 1]).map(|_| ()),
+            Err(CanonicalDecodeError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Account::decode_canonical(&[]).map(|_| ()),
+            Err(CanonicalDecodeError::UnexpectedEnd)
+        );
+
+        // Account keys are x-only, and must lift to a point.
+        let mut invalid_key = bytes.clone();
+        invalid_key[1..33].copy_from_slice(&[0xff; 32]);
+        assert_eq!(
+            Account::decode_canonical(&invalid_key).map(|_| ()),
+            Err(CanonicalDecodeError::InvalidAccountKey)
+        );
+
+        // Points must be valid.
+        let vtxo = VTXO::new(key(1), key(2), None, None, None, None);
+        let mut invalid_point = vtxo.serialize();
+        invalid_point[1] = 0x05;
+        assert_eq!(
+            VTXO::decode_canonical(&invalid_point).map(|_| ()),
+            Err(CanonicalDecodeError::InvalidPoint)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_entry_test() -> Result<(), String> {
+        let alice = Account::new(key(1), Some(7), None).unwrap();
+        let bob = Account::new(key(2), None, None).unwrap();
+        let operator = -key(3);
+        let vtxo = |vout: u32| {
+            VTXO::new(
+                alice.key(),
+                operator,
+                Some(outpoint(vout)),
+                Some(10_000),
+                Some(3),
+                Some(840_000),
+            )
+        };
+
+        // Calls carry their arguments and the fee payer co-signature.
+        let mut call = Call::new(
+            alice.key().serialize_xonly(),
+            [0x11; 32],
+            2,
+            vec![
+                CallElement::U8(1),
+                CallElement::U16(2),
+                CallElement::U32(ShortVal::new(3)),
+                CallElement::U64(LongVal::new(4)),
+                CallElement::Bool(true),
+                CallElement::Account(bob),
+                CallElement::Contract(Contract::new([0x22; 32], 1, Some(1))),
+                CallElement::Bytes(vec![0xaa; 3]),
+                CallElement::Varbytes(vec![]),
+                CallElement::Payable(ShortVal::new(500)),
+                CallElement::U128(u128::MAX),
+                CallElement::U256([0x33; 32]),
+                CallElement::String("cube".to_string()),
+                CallElement::Array(
+                    CallElementType::U8,
+                    vec![CallElement::U8(5), CallElement::U8(6)],
+                ),
+                CallElement::Vararray(CallElementType::Bytes(1), vec![]),
+                CallElement::Tuple(vec![CallElement::U8(7), CallElement::Bool(false)]),
+            ],
+            1_000,
+            10,
+            Some(2),
+            Some(key(4).serialize_xonly()),
+        );
+        assert!(call.sign_as_fee_payer([4; 32], Chain::Signet, 1));
+        let decoded =
+            Call::decode_canonical(&call.encode_canonical()).map_err(|e| format!("{:?}", e))?;
+        assert_eq!(decoded, call);
+        assert!(decoded.authenticate_fee_payer(Chain::Signet, 1));
+
+        // Every combinator round-trips.
+        let combinators = vec![
+            Combinator::new_liftup(
+                Liftup::new(vec![Lift::new(
+                    alice.key(),
+                    operator,
+                    Some(outpoint(0)),
+                    None,
+                )])
+                .unwrap(),
+            ),
+            Combinator::new_recharge(Recharge::new(vec![vtxo(1)]).unwrap()),
+            Combinator::new_move(Move::new(alice, bob, 300)),
+            Combinator::new_batch_move(BatchMove::new(alice, vec![(bob, 1), (bob, 2)]).unwrap()),
+            Combinator::new_call(call.clone()),
+            Combinator::new_bundle(
+                Bundle::new(vec![
+                    BundleItem::Move(Move::new(alice, bob, 1)),
+                    BundleItem::Call(call.clone()),
+                ])
+                .unwrap(),
+            ),
+            Combinator::new_add(Add::new(alice, 100)),
+            Combinator::new_sub(Sub::new(alice, 200)),
+            Combinator::new_deploy(Deploy::new()),
+            Combinator::new_swapout(Swapout::new_p2wpkh(alice, 400, [0x44; 20])),
+            Combinator::new_swapout(Swapout::new_p2tr(alice, 400, [0x55; 32])),
+            Combinator::new_revive(Revive::new(alice, vec![vtxo(2), vtxo(3)]).unwrap()),
+            Combinator::new_claim(Claim::new(alice, vec![vtxo(4)]).unwrap()),
+            Combinator::new_reserved(Reserved {}),
+        ];
+        for combinator in combinators.iter() {
+            let bytes = combinator.serialize();
+            assert_eq!(bytes[0], CANONICAL_CODEC_VERSION);
+            assert!(Combinator::decode_canonical(&bytes) == Ok(combinator.clone()));
+        }
+
+        // Entries round-trip with their nonce and both branches.
+        let mut entry = Entry::new_move(
+            alice,
+            Move::new(alice, bob, 300),
+            Liftup::new(vec![Lift::new(
+                alice.key(),
+                operator,
+                Some(outpoint(0)),
+                None,
+            )]),
+            Recharge::new(vec![vtxo(1)]),
+        );
+        entry.set_nonce(9);
+        let decoded =
+            Entry::decode_canonical(&entry.serialize()).map_err(|e| format!("{:?}", e))?;
+        assert!(decoded == entry);
+        assert_eq!(decoded.nonce(), 9);
+        assert!(decoded.sighash(Chain::Signet) == entry.sighash(Chain::Signet));
+
+        let entry = Entry::new_call(alice, call, None, None);
+        assert!(Entry::decode_canonical(&entry.serialize()) == Ok(entry));
+
+        let entry = Entry::new_nop(alice, None, Recharge::new(vec![vtxo(1)]));
+        assert!(Entry::decode_canonical(&entry.serialize()) == Ok(entry));
+
+        // Liftup and recharge cannot be the main combinator.
+        let mut bytes = Entry::new_nop(alice, None, None).serialize();
+        bytes.pop();
+        bytes.push(0x01);
+        bytes.extend(&combinators[0].serialize()[1..]);
+        assert!(
+            Entry::decode_canonical(&bytes).map(|_| ()) == Err(CanonicalDecodeError::InvalidValue)
+        );
+
+        // Unknown combinator tags are rejected.
+        assert!(
+            Combinator::decode_canonical(&[CANONICAL_CODEC_VERSION, 0x0d]).map(|_| ())
+                == Err(CanonicalDecodeError::InvalidTag(0x0d))
+        );
+
+        // Decoded combinators are checked like constructed ones.
+        let mut empty_claim = vec![CANONICAL_CODEC_VERSION];
+        empty_claim.extend(&alice.serialize()[1..]);
+        empty_claim.extend(0u32.to_le_bytes());
+        assert!(
+            Claim::decode_canonical(&empty_claim).map(|_| ())
+                == Err(CanonicalDecodeError::InvalidValue)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_migration_test() -> Result<(), String> {
+        let db = sled::Config::new()
+            .temporary(true)
+            .open()
+            .map_err(|e| e.to_string())?;
+
+        let legacy_vtxo = VTXO::new(
+            key(1),
+            key(2),
+            Some(outpoint(0)),
+            Some(1_000),
+            Some(1),
+            None,
+        );
+        let vtxo = VTXO::new(key(3), key(2), Some(outpoint(1)), Some(2_000), None, None);
+
+        // A legacy JSON record next to a canonical one.
+        let legacy_json = serde_json::to_vec(&legacy_vtxo).unwrap();
+        db.insert(b"legacy", legacy_json.clone())
+            .map_err(|e| e.to_string())?;
+        db.insert(b"canonical", vtxo.serialize())
+            .map_err(|e| e.to_string())?;
+
+        match decode_persisted::<VTXO>(&legacy_json) {
+            Some((decoded, true)) => assert!(decoded == legacy_vtxo),
+            _ => return Err("Failed to decode the legacy record.".to_string()),
+        }
+
+        assert!(migrate_db::<VTXO>(&db));
+
+        // Both records are now in the canonical encoding.
+        let legacy = db.get(b"legacy").map_err(|e| e.to_string())?.unwrap();
+        assert_eq!(legacy.to_vec(), legacy_vtxo.serialize());
+        let canonical = db.get(b"canonical").map_err(|e| e.to_string())?.unwrap();
+        assert_eq!(canonical.to_vec(), vtxo.serialize());
+
+        // Migrating again changes nothing.
+        assert!(migrate_db::<VTXO>(&db));
+        assert_eq!(db.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_map_test() -> Result<(), String> {
+        let map: HashMap<Point, u64> = (1..=3)
+            .map(|byte| (secret(byte).base_point_mul(), byte as u64))
+            .collect();
+
+        // Entries are a 33-byte key followed by an 8-byte value, in ascending order of their keys.
+        let bytes = map.encode_canonical();
+        let mut keys: Vec<Point> = map.keys().cloned().collect();
+        keys.sort_by_key(|key| key.serialize());
+        for (index, key) in keys.iter().enumerate() {
+            let offset = 5 + index * 41;
+            assert_eq!(bytes[offset..offset + 33], key.serialize());
+        }
+
+        match HashMap::<Point, u64>::decode_canonical(&bytes) {
+            Ok(decoded) => assert!(decoded == map),
+            Err(_) => return Err("Failed to decode the map.".to_string()),
+        }
+
+        // Entries out of order are rejected.
+        let mut unsorted = bytes.clone();
+        unsorted[5..46].copy_from_slice(&bytes[46..87]);
+        unsorted[46..87].copy_from_slice(&bytes[5..46]);
+        assert_eq!(
+            HashMap::<Point, u64>::decode_canonical(&unsorted).map(|_| ()),
+            Err(CanonicalDecodeError::UnsortedMapKeys)
+        );
+
+        // Duplicate keys are rejected.
+        let mut duplicate = bytes.clone();
+        duplicate[46..87].copy_from_slice(&bytes[5..46]);
+        assert_eq!(
+            HashMap::<Point, u64>::decode_canonical(&duplicate).map(|_| ()),
+            Err(CanonicalDecodeError::UnsortedMapKeys)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_musig_test() -> Result<(), String> {
+        let secret_keys = [secret(1), secret(2)];
+        let keys: Vec<Point> = secret_keys.iter().map(|key| key.base_point_mul()).collect();
+        let nonces = [(secret(3), secret(4)), (secret(5), secret(6))];
+
+        let key_agg_ctx = MusigKeyAggCtx::new(&keys, None).unwrap();
+        let mut ctx = MusigSessionCtx::new(&key_agg_ctx, [0xaa; 32]).unwrap();
+        for (key, (hiding_nonce, binding_nonce)) in keys.iter().zip(nonces.iter()) {
+            assert!(ctx.insert_nonce(
+                *key,
+                hiding_nonce.base_point_mul(),
+                binding_nonce.base_point_mul()
+            ));
+        }
+
+        // The first signer has signed, the second has not.
+        let partial_sig = ctx
+            .partial_sign(secret_keys[0], nonces[0].0, nonces[0].1)
+            .unwrap();
+        assert!(ctx.insert_partial_sig(keys[0], partial_sig));
+
+        // The derived values are computed again, and the partial signature is kept.
+        let bytes = ctx.encode_canonical();
+        let decoded = match MusigSessionCtx::decode_canonical(&bytes) {
+            Ok(decoded) => decoded,
+            Err(_) => return Err("Failed to decode the session context.".to_string()),
+        };
+        assert_eq!(decoded.agg_nonce(), ctx.agg_nonce());
+        assert_eq!(decoded.nonce_coef(), ctx.nonce_coef());
+        assert_eq!(decoded.challenge(), ctx.challenge());
+        assert_eq!(decoded.blame_list(), vec![keys[1]]);
+        assert_eq!(decoded.encode_canonical(), bytes);
+
+        // A forged partial signature is rejected, as it does not verify.
+        let mut forged = bytes.clone();
+        let last = forged.len() - 1;
+        forged[last] ^= 0x01;
+        assert_eq!(
+            MusigSessionCtx::decode_canonical(&forged).map(|_| ()),
+            Err(CanonicalDecodeError::InvalidValue)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_noist_test() -> Result<(), String> {
+        // Signatories are well-known by their x-only keys, so their keys are even.
+        let secret_keys: Vec<Scalar> = (1..=3)
+            .map(|byte| secret(byte).negate_if(secret(byte).base_point_mul().parity()))
+            .collect();
+        let mut signatories: Vec<Point> =
+            secret_keys.iter().map(|key| key.base_point_mul()).collect();
+        signatories.sort();
+
+        // A VSE setup with every keymap.
+        let mut setup = VSESetup::new(&signatories, 0).unwrap();
+        for secret_key in secret_keys.iter() {
+            let keymap = VSEKeyMap::new(*secret_key, &signatories).unwrap();
+            assert!(setup.insert_keymap(keymap));
+        }
+        assert!(setup.verify());
+
+        // A DKG session with every package.
+        let mut session = DKGSession::new(0, &signatories).unwrap();
+        for secret_key in secret_keys.iter() {
+            let package = DKGPackage::new(*secret_key, &signatories).unwrap();
+            let auth_package = Authenticable::new(package, secret_key.serialize()).unwrap();
+            assert!(session.insert(&auth_package, &setup));
+        }
+
+        // Both round-trip, and the decoded session still verifies against the setup.
+        assert!(VSESetup::from_slice(&setup.serialize()) == Some(setup.clone()));
+        let decoded_session = match DKGSession::from_slice(&session.serialize()) {
+            Some(decoded_session) => decoded_session,
+            None => return Err("Failed to decode the DKG session.".to_string()),
+        };
+        assert_eq!(decoded_session.serialize(), session.serialize());
+        assert!(decoded_session.verify(&setup));
+
+        // Legacy JSON records are migrated to the canonical encoding.
+        let setup_db = sled::Config::new()
+            .temporary(true)
+            .open()
+            .map_err(|e| e.to_string())?;
+        let sessions_db = sled::Config::new()
+            .temporary(true)
+            .open()
+            .map_err(|e| e.to_string())?;
+
+        setup_db
+            .insert(0u64.to_be_bytes(), serde_json::to_vec(&setup).unwrap())
+            .map_err(|e| e.to_string())?;
+        sessions_db
+            .insert(0u64.to_be_bytes(), serde_json::to_vec(&session).unwrap())
+            .map_err(|e| e.to_string())?;
+
+        assert!(migrate_db::<VSESetup>(&setup_db));
+        assert!(migrate_db::<DKGSession>(&sessions_db));
+
+        let migrated_setup = setup_db.get(0u64.to_be_bytes()).unwrap().unwrap();
+        assert_eq!(migrated_setup.to_vec(), setup.serialize());
+        let migrated_session = sessions_db.get(0u64.to_be_bytes()).unwrap().unwrap();
+        assert_eq!(migrated_session.to_vec(), session.serialize());
+
+        Ok(())
+    }
+}
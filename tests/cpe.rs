@@ -1,9 +1,67 @@
 #[cfg(test)]
 mod cpe_tests {
     use bit_vec::BitVec;
+    use bitcoin::{
+        absolute::LockTime, hashes::Hash, transaction::Version, Amount, OutPoint, ScriptBuf,
+        Transaction, TxIn, TxOut, Txid,
+    };
     use cube::{
         constructive::{
+            calldata::{
+                element::{
+                    cpe::{
+                        decode::decode_error::{
+                            CallArgCPEDecodingError, StringArgCPEDecodingError,
+                            U128ArgCPEDecodingError,
+                        },
+                        encode::encode_error::CallArgCPEEncodeError,
+                    },
+                    element::CallElement,
+                },
+                element_type::CallElementType,
+            },
             entity::{account::account::Account, contract::contract::Contract},
+            entry::{
+                branches::{
+                    uppermost_left_branch::uppermost_left_branch::UppermostLeftBranch,
+                    uppermost_right_branch::uppermost_right_branch::UppermostRightBranch,
+                },
+                codec::cpe::{
+                    decode::decode_error::EntryCPEDecodingError,
+                    encode::encode_error::EntryCPEEncodeError,
+                },
+                combinator::combinators::{
+                    add::add::Add,
+                    batch_move::{
+                        batch_move::BatchMove,
+                        codec::cpe::encode::encode_error::BatchMoveCPEEncodeError,
+                    },
+                    bundle::{bundle::Bundle, bundle_item::BundleItem},
+                    call::{
+                        call::Call,
+                        codec::cpe::{
+                            decode::decode_error::CallCPEDecodingError,
+                            encode::encode_error::CallCPEEncodeError,
+                        },
+                    },
+                    claim::claim::Claim,
+                    deploy::Deploy,
+                    liftup::liftup::Liftup,
+                    r#move::r#move::Move,
+                    recharge::{
+                        codec::cpe::decode::decode_error::RechargeCPEDecodingError,
+                        recharge::Recharge,
+                    },
+                    reserved::Reserved,
+                    revive::revive::Revive,
+                    sub::sub::Sub,
+                    swapout::swapout::Swapout,
+                },
+                entry::Entry,
+            },
+            taproot::P2TR,
+            txn::txholder::TxHolder,
+            txo::{lift::Lift, vtxo::VTXO},
             valtype::{
                 maybe_common::{
                     common::{
@@ -14,14 +72,37 @@ mod cpe_tests {
                 },
                 val::{
                     atomic_val::atomic_val::AtomicVal,
-                    long_val::long_val::{LongVal, LongValTier},
+                    long_val::{
+                        cpe::decode::decode_error::LongValCPEDecodingError,
+                        long_val::{LongVal, LongValTier},
+                    },
                     short_val::short_val::{ShortVal, ShortValTier},
                 },
             },
         },
-        inscriptive::registery::registery::Registery,
+        executive::{
+            opcode::{
+                opcode::Opcode,
+                opcodes::{
+                    flow::{op_nop::OP_NOP, op_returnall::OP_RETURNALL},
+                    push::op_true::OP_TRUE,
+                },
+            },
+            program::{
+                method::{method::ProgramMethod, method_type::MethodType},
+                program::Program,
+            },
+        },
+        inscriptive::{
+            epoch::{dir::EpochDirectory, epoch::Epoch},
+            registery::{account_registery::AccountRegistery, registery::Registery},
+            repo::repo::ProgramsRepo,
+            set::{utxo_set::UTXOSet, vtxo_set::VTXOSet},
+        },
         operative::Chain,
+        transmutative::secp::schnorr::Bytes32,
     };
+    use rand::{rngs::StdRng, Rng, SeedableRng};
     use secp::Point;
     use std::collections::HashMap;
 
@@ -1063,4 +1144,780 @@ mod cpe_tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn composite_calldata_cpe_test() -> Result<(), String> {
+        let registery = Registery::new_in_memory().unwrap();
+
+        let mut u256 = [0u8; 32];
+        u256[0] = 0x01;
+        u256[31] = 0xff;
+
+        let elements = vec![
+            CallElement::U128(0),
+            CallElement::U128(1_000_000),
+            CallElement::U128(u128::MAX),
+            CallElement::U256([0u8; 32]),
+            CallElement::U256(u256),
+            CallElement::String(String::new()),
+            CallElement::String("héllo, wörld".to_string()),
+            CallElement::Array(
+                CallElementType::U16,
+                vec![
+                    CallElement::U16(1),
+                    CallElement::U16(2),
+                    CallElement::U16(3),
+                ],
+            ),
+            CallElement::Array(
+                CallElementType::String,
+                vec![CallElement::String("a".to_string())],
+            ),
+            CallElement::Vararray(CallElementType::Bool, vec![]),
+            CallElement::Vararray(
+                CallElementType::U128,
+                vec![CallElement::U128(5), CallElement::U128(u128::MAX)],
+            ),
+            CallElement::Tuple(vec![
+                CallElement::U8(7),
+                CallElement::Bool(true),
+                CallElement::Varbytes(b"hi".to_vec()),
+                CallElement::U256(u256),
+            ]),
+        ];
+
+        // Elements are decoded back from a single bit stream, by their element types.
+        let mut bits = BitVec::new();
+        for element in elements.iter() {
+            bits.extend(element.encode_cpe().map_err(|e| format!("{:?}", e))?);
+        }
+
+        let mut bit_stream = bits.iter();
+        for element in elements.iter() {
+            let decoded =
+                CallElement::decode_cpe(&mut bit_stream, element.element_type(), &registery)
+                    .await
+                    .map_err(|e| format!("{:?}", e))?;
+            assert_eq!(&decoded, element);
+        }
+        assert_eq!(bit_stream.len(), 0);
+
+        // Small integers are compact.
+        assert_eq!(CallElement::U128(0).encode_cpe().unwrap().len(), 5);
+        assert_eq!(CallElement::U128(255).encode_cpe().unwrap().len(), 5 + 8);
+        assert_eq!(CallElement::U256(u256).encode_cpe().unwrap().len(), 6 + 256);
+
+        // Integers have a single encoding.
+        let mut bits = BitVec::new();
+        bits.extend([true, false, false, false, false]);
+        bits.extend(BitVec::from_bytes(&[0x00]));
+        assert_eq!(
+            CallElement::decode_cpe(&mut bits.iter(), CallElementType::U128, &registery).await,
+            Err(CallArgCPEDecodingError::U128(
+                U128ArgCPEDecodingError::NonMinimalError
+            ))
+        );
+
+        // Strings must be valid UTF-8.
+        let mut bits = BitVec::new();
+        bits.extend([true; 1]);
+        bits.extend([false; 11]);
+        bits.extend(BitVec::from_bytes(&[0xff]));
+        assert_eq!(
+            CallElement::decode_cpe(&mut bits.iter(), CallElementType::String, &registery).await,
+            Err(CallArgCPEDecodingError::String(
+                StringArgCPEDecodingError::InvalidUTF8Error
+            ))
+        );
+
+        // Composite items must match the item type, and cannot be composites or payables.
+        assert_eq!(
+            CallElement::Array(CallElementType::U8, vec![CallElement::U16(1)]).encode_cpe(),
+            Err(CallArgCPEEncodeError::InvalidItemType(0))
+        );
+        assert_eq!(
+            CallElement::Array(CallElementType::U8, vec![]).encode_cpe(),
+            Err(CallArgCPEEncodeError::InvalidArrayLength(0))
+        );
+        assert_eq!(
+            CallElement::Tuple(vec![
+                CallElement::U8(1),
+                CallElement::Payable(ShortVal::new(1))
+            ])
+            .encode_cpe(),
+            Err(CallArgCPEEncodeError::InvalidItemType(1))
+        );
+        assert_eq!(
+            CallElement::Tuple(vec![CallElement::U8(1)]).encode_cpe(),
+            Err(CallArgCPEEncodeError::InvalidTupleLength(1))
+        );
+        assert_eq!(
+            CallElement::Vararray(CallElementType::U8, vec![CallElement::U8(0); 256]).encode_cpe(),
+            Err(CallArgCPEEncodeError::VararrayLengthGreaterThan255Error(
+                256
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn call_cpe_test() -> Result<(), String> {
+        let method = |name: &str, arg_types: Vec<CallElementType>| {
+            ProgramMethod::new(
+                name.to_string(),
+                MethodType::Callable,
+                arg_types,
+                vec![
+                    Opcode::OP_NOP(OP_NOP),
+                    Opcode::OP_NOP(OP_NOP),
+                    Opcode::OP_TRUE(OP_TRUE),
+                    Opcode::OP_RETURNALL(OP_RETURNALL),
+                ],
+            )
+            .unwrap()
+        };
+
+        let program = Program::new(
+            "codec".to_string(),
+            [0x00; 32],
+            vec![
+                method("noop", vec![]),
+                method(
+                    "mixed",
+                    vec![
+                        CallElementType::U8,
+                        CallElementType::U32,
+                        CallElementType::Bool,
+                        CallElementType::Varbytes,
+                    ],
+                ),
+                method("flag", vec![CallElementType::Bool]),
+            ],
+        )
+        .unwrap();
+        let contract_id = program.contract_id();
+
+        // Deploy the program.
+        let repo = ProgramsRepo::new_in_memory().unwrap();
+        {
+            let mut programs = HashMap::new();
+            programs.insert(contract_id, program);
+            let mut _repo = repo.lock().await;
+            _repo.insert_multi(&programs).unwrap();
+        }
+
+        // Register the contract.
+        let registery = Registery::new_in_memory().unwrap();
+        let contract_registery = {
+            let _registery = registery.lock().await;
+            _registery.contract_registery()
+        };
+        {
+            let mut _contract_registery = contract_registery.lock().await;
+            assert!(_contract_registery.batch_update(vec![contract_id], HashMap::new()));
+        }
+
+        let account_key = [0xaau8; 32];
+        let ops_price_base = 10;
+
+        let sponsor_secret = [0xbbu8; 32];
+        let sponsor = sponsor_secret.secret_to_public().unwrap();
+
+        let mut calls = vec![
+            Call::new(
+                account_key,
+                contract_id,
+                0,
+                vec![],
+                1_000,
+                ops_price_base,
+                None,
+                None,
+            ),
+            Call::new(
+                account_key,
+                contract_id,
+                1,
+                vec![
+                    CallElement::U8(7),
+                    CallElement::U32(ShortVal::new(70_000)),
+                    CallElement::Bool(true),
+                    CallElement::Varbytes(b"hello".to_vec()),
+                ],
+                50_000,
+                ops_price_base,
+                Some(5),
+                Some(sponsor),
+            ),
+        ];
+
+        // A sponsored call is not encoded without its fee payer co-signature.
+        assert_eq!(
+            calls[1]
+                .encode_cpe(account_key, &contract_registery, &repo, ops_price_base)
+                .await,
+            Err(CallCPEEncodeError::FeePayerSigMissing(sponsor))
+        );
+        assert!(calls[1].sign_as_fee_payer(sponsor_secret, Chain::Signet, 0));
+
+        // Calls are decoded back from a single bit stream.
+        let mut bits = BitVec::new();
+        for call in calls.iter() {
+            bits.extend(
+                call.encode_cpe(account_key, &contract_registery, &repo, ops_price_base)
+                    .await
+                    .map_err(|e| format!("{:?}", e))?,
+            );
+        }
+
+        let mut bit_stream = bits.iter();
+        for call in calls.iter() {
+            let decoded = Call::decode_cpe(
+                &mut bit_stream,
+                account_key,
+                &registery,
+                &repo,
+                ops_price_base,
+            )
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+            assert_eq!(&decoded, call);
+            assert!(decoded.authenticate_fee_payer(Chain::Signet, 0));
+        }
+        assert_eq!(bit_stream.len(), 0);
+
+        // The method index is bound by the method count.
+        let encoded = calls[0]
+            .encode_cpe(account_key, &contract_registery, &repo, ops_price_base)
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+        let mut bits = BitVec::new();
+        bits.extend(encoded.iter().take(10));
+        bits.extend([true, true]);
+        let mut bit_stream = bits.iter();
+        assert_eq!(
+            Call::decode_cpe(
+                &mut bit_stream,
+                account_key,
+                &registery,
+                &repo,
+                ops_price_base
+            )
+            .await,
+            Err(CallCPEDecodingError::MethodNotFoundAtIndex(contract_id, 3))
+        );
+
+        // A truncated stream fails to decode.
+        let mut bits = encoded.clone();
+        bits.truncate(encoded.len() - 1);
+        let mut bit_stream = bits.iter();
+        assert_eq!(
+            Call::decode_cpe(
+                &mut bit_stream,
+                account_key,
+                &registery,
+                &repo,
+                ops_price_base
+            )
+            .await,
+            Err(CallCPEDecodingError::FeePayerPresenceBitCollectError)
+        );
+
+        // A sponsored call missing its co-signature bits fails to decode.
+        let mut bits = calls[1]
+            .encode_cpe(account_key, &contract_registery, &repo, ops_price_base)
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+        bits.truncate(bits.len() - 1);
+        let mut bit_stream = bits.iter();
+        assert_eq!(
+            Call::decode_cpe(
+                &mut bit_stream,
+                account_key,
+                &registery,
+                &repo,
+                ops_price_base
+            )
+            .await,
+            Err(CallCPEDecodingError::FeePayerSigBitsCollectError)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn entry_cpe_test() -> Result<(), String> {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let method = |name: &str, arg_types: Vec<CallElementType>| {
+            ProgramMethod::new(
+                name.to_string(),
+                MethodType::Callable,
+                arg_types,
+                vec![
+                    Opcode::OP_NOP(OP_NOP),
+                    Opcode::OP_NOP(OP_NOP),
+                    Opcode::OP_TRUE(OP_TRUE),
+                    Opcode::OP_RETURNALL(OP_RETURNALL),
+                ],
+            )
+            .unwrap()
+        };
+
+        let program = Program::new(
+            "codec".to_string(),
+            [0x00; 32],
+            vec![
+                method("noop", vec![]),
+                method("flag", vec![CallElementType::Bool]),
+            ],
+        )
+        .unwrap();
+        let contract_id = program.contract_id();
+
+        // Deploy the program.
+        let repo = ProgramsRepo::new_in_memory().unwrap();
+        {
+            let mut programs = HashMap::new();
+            programs.insert(contract_id, program);
+            let mut _repo = repo.lock().await;
+            _repo.insert_multi(&programs).unwrap();
+        }
+
+        // Register the contract.
+        let registery = Registery::new_in_memory().unwrap();
+        {
+            let contract_registery = {
+                let _registery = registery.lock().await;
+                _registery.contract_registery()
+            };
+            let mut _contract_registery = contract_registery.lock().await;
+            assert!(_contract_registery.batch_update(vec![contract_id], HashMap::new()));
+        }
+
+        // Set up the operator epoch.
+        let group_key = [0x01u8; 32]
+            .secret_to_public()
+            .unwrap()
+            .to_even_point()
+            .unwrap();
+        let epoch_dir = EpochDirectory::new_in_memory().unwrap();
+        {
+            let mut _epoch_dir = epoch_dir.lock().await;
+            assert!(_epoch_dir.insert_epoch(&Epoch::new(1, true, group_key, vec![])));
+        }
+
+        let utxo_set = UTXOSet::new_in_memory().unwrap();
+        let vtxo_set = VTXOSet::new_in_memory().unwrap();
+        let current_bitcoin_height = 100_000;
+        let ops_price_base = 10;
+
+        // Every account holds a few rechargeable VTXOs, and a few swept ones to revive.
+        let mut accounts = Vec::<(Account, Recharge, Revive)>::new();
+        for _ in 0..4 {
+            let key = rng
+                .gen::<[u8; 32]>()
+                .secret_to_public()
+                .unwrap()
+                .to_even_point()
+                .unwrap();
+            let account = Account::new(key, None, None).unwrap();
+
+            let mut vtxos = Vec::<VTXO>::new();
+            for _ in 0..rng.gen_range(1..=3) {
+                let vtxo = VTXO::new(
+                    key,
+                    group_key,
+                    Some(random_outpoint(&mut rng)),
+                    Some(rng.gen_range(1_000..1_000_000)),
+                    Some(1),
+                    Some(rng.gen_range(0..90_000)),
+                );
+                let mut _vtxo_set = vtxo_set.lock().await;
+                assert!(_vtxo_set.insert_vtxo(&vtxo));
+                vtxos.push(vtxo);
+            }
+
+            let mut swept_vtxos = Vec::<VTXO>::new();
+            for _ in 0..rng.gen_range(1..=3) {
+                let vtxo = VTXO::new(
+                    key,
+                    group_key,
+                    Some(random_outpoint(&mut rng)),
+                    Some(rng.gen_range(1_000..1_000_000)),
+                    Some(1),
+                    Some(rng.gen_range(0..80_000)),
+                );
+                let mut _vtxo_set = vtxo_set.lock().await;
+                assert!(_vtxo_set.insert_swept_vtxo(&vtxo));
+                swept_vtxos.push(vtxo);
+            }
+            swept_vtxos.sort_by_key(|vtxo| vtxo.outpoint());
+
+            accounts.push((
+                account,
+                Recharge::new(vtxos).unwrap(),
+                Revive::new(account, swept_vtxos).unwrap(),
+            ));
+        }
+
+        for _ in 0..256 {
+            let (account, recharge, revive) = accounts[rng.gen_range(0..accounts.len())].clone();
+            let (counterparty, _, _) = accounts[rng.gen_range(0..accounts.len())].clone();
+            let account_key = account.key().serialize_xonly();
+
+            // Lifts are spent from the inputs following the default input.
+            let mut input = vec![TxIn::default()];
+            let liftup = match rng.gen_bool(0.5) {
+                true => {
+                    let mut lifts = Vec::<Lift>::new();
+                    for _ in 0..rng.gen_range(1..=3) {
+                        let outpoint = random_outpoint(&mut rng);
+                        let value = rng.gen_range(1_000..1_000_000);
+                        let lift = Lift::new(account.key(), group_key, Some(outpoint), Some(value));
+                        let txout = TxOut {
+                            value: Amount::from_sat(value),
+                            script_pubkey: ScriptBuf::from_bytes(
+                                lift.taproot().unwrap().spk().unwrap(),
+                            ),
+                        };
+                        utxo_set.lock().await.insert_txout(&outpoint, &txout);
+                        input.push(TxIn {
+                            previous_output: outpoint,
+                            ..TxIn::default()
+                        });
+                        lifts.push(lift);
+                    }
+                    Some(Liftup::new(lifts).unwrap())
+                }
+                false => None,
+            };
+            let recharge_vtxos = recharge.vtxos();
+            let recharge = match rng.gen_bool(0.5) {
+                true => Some(recharge),
+                false => None,
+            };
+            let uppermost_left_branch = match liftup.is_some() || recharge.is_some() {
+                true => Some(UppermostLeftBranch::new(liftup, recharge)),
+                false => None,
+            };
+
+            let random_call = |rng: &mut StdRng| {
+                let (method_index, args) = match rng.gen_bool(0.5) {
+                    true => (0, vec![]),
+                    false => (1, vec![CallElement::Bool(rng.gen())]),
+                };
+                let sponsor_secret = rng.gen::<[u8; 32]>();
+                let mut call = Call::new(
+                    account_key,
+                    contract_id,
+                    method_index,
+                    args,
+                    rng.gen_range(1..100_000),
+                    ops_price_base,
+                    rng.gen_bool(0.5).then(|| rng.gen_range(1..100)),
+                    rng.gen_bool(0.5)
+                        .then(|| sponsor_secret.secret_to_public().unwrap()),
+                );
+                if call.fee_payer().is_some() {
+                    assert!(call.sign_as_fee_payer(sponsor_secret, Chain::Signet, 0));
+                }
+                call
+            };
+
+            // The right branch is optional only when the left branch is present.
+            let uppermost_right_branch = match rng.gen_range(0..13) {
+                0 if uppermost_left_branch.is_some() => None,
+                0 | 1 => Some(UppermostRightBranch::new_move(Move::new(
+                    account,
+                    counterparty,
+                    rng.gen(),
+                ))),
+                2 => Some(UppermostRightBranch::new_call(random_call(&mut rng))),
+                3 => {
+                    let mut items = vec![BundleItem::Call(random_call(&mut rng))];
+                    for _ in 0..rng.gen_range(1..=3) {
+                        items.push(match rng.gen_bool(0.5) {
+                            true => BundleItem::Call(random_call(&mut rng)),
+                            false => BundleItem::Move(Move::new(account, counterparty, rng.gen())),
+                        });
+                    }
+                    Some(UppermostRightBranch::new_bundle(
+                        Bundle::new(items).unwrap(),
+                    ))
+                }
+                4 => Some(UppermostRightBranch::new_add(Add::new(account, rng.gen()))),
+                5 => Some(UppermostRightBranch::new_sub(Sub::new(account, rng.gen()))),
+                6 => Some(UppermostRightBranch::new_deploy(Deploy::new())),
+                7 => Some(UppermostRightBranch::new_swapout(Swapout::new_p2tr(
+                    account,
+                    rng.gen(),
+                    rng.gen(),
+                ))),
+                8 => Some(UppermostRightBranch::new_swapout(Swapout::new_p2wsh(
+                    account,
+                    rng.gen(),
+                    rng.gen(),
+                ))),
+                9 => Some(UppermostRightBranch::new_swapout(Swapout::new_p2wpkh(
+                    account,
+                    rng.gen(),
+                    rng.gen(),
+                ))),
+                10 => Some(UppermostRightBranch::new_revive(revive)),
+                11 => {
+                    let recipients = (0..rng.gen_range(2..=8))
+                        .map(|_| {
+                            let (to, _, _) = accounts[rng.gen_range(0..accounts.len())].clone();
+                            (to, rng.gen_range(0..1_000_000))
+                        })
+                        .collect();
+                    Some(UppermostRightBranch::new_batch_move(
+                        BatchMove::new(account, recipients).unwrap(),
+                    ))
+                }
+                _ => {
+                    // Claimed VTXOs decode in outpoint order.
+                    let mut vtxos = recharge_vtxos.clone();
+                    vtxos.sort_by_key(|vtxo| vtxo.outpoint());
+                    vtxos.retain(|_| rng.gen_bool(0.5));
+                    if vtxos.is_empty() {
+                        vtxos.push(recharge_vtxos[0].clone());
+                    }
+                    Some(UppermostRightBranch::new_claim(
+                        Claim::new(account, vtxos).unwrap(),
+                    ))
+                }
+            };
+
+            let entry = Entry::new(account, uppermost_left_branch, uppermost_right_branch);
+
+            let bits = entry
+                .encode_cpe(&registery, &repo, &vtxo_set, ops_price_base)
+                .await
+                .map_err(|e| format!("{:?}", e))?;
+
+            let tx = Transaction {
+                version: Version::TWO,
+                lock_time: LockTime::ZERO,
+                input,
+                output: vec![],
+            };
+            let input_len = tx.input.len() as u32;
+            let mut txholder = TxHolder::new(&utxo_set, tx, 0).unwrap();
+
+            let mut bit_stream = bits.iter();
+            let decoded = Entry::decode_cpe(
+                &mut bit_stream,
+                &mut txholder,
+                &epoch_dir,
+                &registery,
+                &repo,
+                &vtxo_set,
+                current_bitcoin_height,
+                ops_price_base,
+            )
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+
+            assert!(decoded == entry);
+            assert_eq!(bit_stream.len(), 0);
+            assert_eq!(txholder.input_iter_position(), input_len);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn entry_cpe_error_test() -> Result<(), String> {
+        let registery = Registery::new_in_memory().unwrap();
+        let repo = ProgramsRepo::new_in_memory().unwrap();
+        let epoch_dir = EpochDirectory::new_in_memory().unwrap();
+        let utxo_set = UTXOSet::new_in_memory().unwrap();
+        let vtxo_set = VTXOSet::new_in_memory().unwrap();
+
+        let key = [0x02u8; 32]
+            .secret_to_public()
+            .unwrap()
+            .to_even_point()
+            .unwrap();
+        let account = Account::new(key, None, None).unwrap();
+
+        // An entry must carry at least one combinator.
+        assert_eq!(
+            Entry::new(account, None, None)
+                .encode_cpe(&registery, &repo, &vtxo_set, 10)
+                .await,
+            Err(EntryCPEEncodeError::EmptyEntry)
+        );
+        assert_eq!(
+            Entry::new(account, Some(UppermostLeftBranch::new(None, None)), None)
+                .encode_cpe(&registery, &repo, &vtxo_set, 10)
+                .await,
+            Err(EntryCPEEncodeError::EmptyUppermostLeftBranch)
+        );
+
+        // The reserved branch cannot be encoded.
+        assert_eq!(
+            Entry::new_reserved(account, Reserved {}, None, None)
+                .encode_cpe(&registery, &repo, &vtxo_set, 10)
+                .await,
+            Err(EntryCPEEncodeError::ReservedBranch)
+        );
+
+        let decode = |bits: BitVec| {
+            let registery = registery.clone();
+            let repo = repo.clone();
+            let epoch_dir = epoch_dir.clone();
+            let utxo_set = utxo_set.clone();
+            let vtxo_set = vtxo_set.clone();
+            async move {
+                let tx = Transaction {
+                    version: Version::TWO,
+                    lock_time: LockTime::ZERO,
+                    input: vec![TxIn::default()],
+                    output: vec![],
+                };
+                let mut txholder = TxHolder::new(&utxo_set, tx, 0).unwrap();
+                let mut bit_stream = bits.iter();
+                Entry::decode_cpe(
+                    &mut bit_stream,
+                    &mut txholder,
+                    &epoch_dir,
+                    &registery,
+                    &repo,
+                    &vtxo_set,
+                    100_000,
+                    10,
+                )
+                .await
+                .map(|_| ())
+            }
+        };
+
+        // The nonce is missing.
+        assert_eq!(
+            decode(account.encode_cpe()).await,
+            Err(EntryCPEDecodingError::NonceCPEDecodeError(
+                LongValCPEDecodingError::TierBitsCollectError
+            ))
+        );
+
+        // Left branch off, right branch implied on, reserved branch selected.
+        let mut bits = account.encode_cpe();
+        bits.extend(LongVal::new(0).encode_cpe());
+        bits.extend([false, true, true, true, true]);
+        assert_eq!(
+            decode(bits).await,
+            Err(EntryCPEDecodingError::ReservedBranch)
+        );
+
+        // A selector bit is missing.
+        let mut bits = account.encode_cpe();
+        bits.extend(LongVal::new(0).encode_cpe());
+        bits.extend([false, true]);
+        assert_eq!(
+            decode(bits).await,
+            Err(EntryCPEDecodingError::SelectorBitCollectError)
+        );
+
+        // Recharge is implied on without a liftup, but there is nothing to recharge.
+        let mut bits = account.encode_cpe();
+        bits.extend(LongVal::new(0).encode_cpe());
+        bits.extend([true, false]);
+        assert_eq!(
+            decode(bits).await,
+            Err(EntryCPEDecodingError::RechargeCPEDecodeError(
+                RechargeCPEDecodingError::NoRechargeableVTXOsFound
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batch_move_cpe_test() -> Result<(), String> {
+        let account_registery =
+            AccountRegistery::new_in_memory().ok_or("Failed to construct account registery.")?;
+
+        let keys: Vec<Point> = (1..=4u8)
+            .map(|byte| {
+                [byte; 32]
+                    .secret_to_public()
+                    .unwrap()
+                    .to_even_point()
+                    .unwrap()
+            })
+            .collect();
+
+        // Register the first two recipients.
+        {
+            let mut _account_registery = account_registery.lock().await;
+            assert!(_account_registery.batch_update(vec![keys[1], keys[2]], HashMap::new()));
+        }
+
+        let (registered_1, registered_2) = {
+            let _account_registery = account_registery.lock().await;
+            (
+                _account_registery.account_by_key(keys[1]).unwrap(),
+                _account_registery.account_by_key(keys[2]).unwrap(),
+            )
+        };
+        let sender = Account::new(keys[0], None, None).unwrap();
+        let unregistered = Account::new(keys[3], None, None).unwrap();
+
+        let batch_move = BatchMove::new(
+            sender,
+            vec![
+                (registered_1, 1_000),
+                (registered_2, 250_000),
+                (unregistered, 7),
+            ],
+        )
+        .unwrap();
+
+        let bits = batch_move
+            .encode_cpe(sender.key().serialize_xonly())
+            .map_err(|e| format!("{:?}", e))?;
+
+        // Registered recipients are encoded by their rank, rather than their key.
+        let unranked = BatchMove::new(
+            sender,
+            vec![
+                (Account::new(keys[1], None, None).unwrap(), 1_000),
+                (Account::new(keys[2], None, None).unwrap(), 250_000),
+                (unregistered, 7),
+            ],
+        )
+        .unwrap()
+        .encode_cpe(sender.key().serialize_xonly())
+        .map_err(|e| format!("{:?}", e))?;
+        assert!(bits.len() + 2 * 256 <= unranked.len());
+
+        let mut bit_stream = bits.iter();
+        let decoded = BatchMove::decode_cpe(&mut bit_stream, sender, &account_registery)
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+        assert!(decoded == batch_move);
+        assert!(bit_stream.next().is_none());
+
+        // The sender is implied by the entry.
+        assert_eq!(
+            batch_move.encode_cpe(unregistered.key().serialize_xonly()),
+            Err(BatchMoveCPEEncodeError::AccountKeyMismatch(
+                unregistered.key().serialize_xonly(),
+                sender.key().serialize_xonly()
+            ))
+        );
+
+        Ok(())
+    }
+
+    fn random_outpoint(rng: &mut StdRng) -> OutPoint {
+        OutPoint {
+            txid: Txid::from_byte_array(rng.gen()),
+            vout: rng.gen_range(0..8),
+        }
+    }
 }
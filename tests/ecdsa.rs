@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod ecdsa_tests {
+    use cube::transmutative::{hash::keccak256, secp::ecdsa};
+    use hex;
+    use secp::Scalar;
+
+    #[test]
+    fn keccak256_test() -> Result<(), String> {
+        // Empty preimage.
+        assert_eq!(
+            hex::encode(keccak256(&[])),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+
+        // "abc" preimage.
+        assert_eq!(
+            hex::encode(keccak256(b"abc")),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sign_verify_recover() -> Result<(), String> {
+        let message: [u8; 32] =
+            hex::decode("1dd8312636f6a0bf3d21fa2855e63072507453e93a5ced4301b364e91c9d87d6")
+                .map_err(|_| format!("Failed to parse message hex."))?
+                .try_into()
+                .map_err(|_| "Failed to convert message hex.".to_string())?;
+
+        // Well-known Ethereum test key (EIP-155 example).
+        let secret_key: [u8; 32] = [0x46; 32];
+
+        let signature = ecdsa::sign_recoverable(secret_key, message)
+            .ok_or("Failed to sign message.".to_string())?;
+
+        // Recover the public key.
+        let public_key = ecdsa::recover(message, signature)
+            .ok_or("Failed to recover public key.".to_string())?;
+
+        // Ethereum address is the last 20 bytes of the Keccak-256 hash of the key.
+        let address = &keccak256(&public_key[1..])[12..];
+        assert_eq!(
+            hex::encode(address),
+            "9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f"
+        );
+
+        // Ethereum-style recovery ids are also accepted.
+        let mut eth_signature = signature;
+        eth_signature[64] += 27;
+        assert_eq!(ecdsa::recover(message, eth_signature), Some(public_key));
+
+        // The high-S twin (r, n - s) with the flipped recovery id recovers the same key, as with
+        // Ethereum's ecrecover.
+        let high_s = -Scalar::from_slice(&signature[32..64]).unwrap();
+        let mut high_s_signature = signature;
+        high_s_signature[32..64].copy_from_slice(&high_s.serialize());
+        high_s_signature[64] ^= 1;
+        assert_eq!(ecdsa::recover(message, high_s_signature), Some(public_key));
+
+        // But it does not verify, since high-S signatures are malleable.
+        let high_s_compact_signature: [u8; 64] = high_s_signature[..64].try_into().unwrap();
+        assert!(!ecdsa::verify(
+            &public_key,
+            message,
+            high_s_compact_signature
+        ));
+
+        // Verify the signature against the uncompressed key.
+        let compact_signature: [u8; 64] = signature[..64].try_into().unwrap();
+        assert!(ecdsa::verify(&public_key, message, compact_signature));
+
+        // Verify the signature against the compressed key.
+        let mut compressed_key = vec![0x02 | (public_key[64] & 0x01)];
+        compressed_key.extend(&public_key[1..33]);
+        assert!(ecdsa::verify(&compressed_key, message, compact_signature));
+
+        // A different message must not verify.
+        assert!(!ecdsa::verify(&public_key, [0xff; 32], compact_signature));
+
+        // Invalid recovery id.
+        let mut invalid_signature = signature;
+        invalid_signature[64] = 4;
+        assert_eq!(ecdsa::recover(message, invalid_signature), None);
+
+        Ok(())
+    }
+}
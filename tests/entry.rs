@@ -0,0 +1,830 @@
+#[cfg(test)]
+mod entry_tests {
+    use bitcoin::{
+        hashes::Hash, Amount, OutPoint, ScriptBuf, Sequence, TxIn, TxOut, Txid, Witness,
+    };
+    use cube::{
+        constructive::{
+            entity::account::account::Account,
+            entry::{
+                combinator::{
+                    combinator::Combinator,
+                    combinator_type::CombinatorType,
+                    combinators::{
+                        batch_move::batch_move::BatchMove,
+                        bundle::{bundle::Bundle, bundle_item::BundleItem},
+                        call::call::Call,
+                        claim::claim::Claim,
+                        liftup::liftup::Liftup,
+                        r#move::r#move::Move,
+                        recharge::recharge::Recharge,
+                        revive::revive::Revive,
+                    },
+                },
+                entry::Entry,
+                validation::{
+                    validation_ctx::EntryValidationCtx, validation_error::EntryValidationError,
+                },
+            },
+            taproot::P2TR,
+            txo::{lift::Lift, vtxo::VTXO},
+        },
+        inscriptive::{
+            blacklist::BlacklistDirectory,
+            registery::{account_registery::AccountRegistery, registery::Registery},
+            set::{utxo_set::UTXOSet, vtxo_set::VTXOSet},
+        },
+        operative::Chain,
+        transmutative::{hash::HashTag, secp::schnorr::Bytes32},
+    };
+    use secp::Point;
+    use std::collections::HashMap;
+
+    fn account(point: &str) -> Account {
+        Account::new(Point::from_hex(point).unwrap(), None, None).unwrap()
+    }
+
+    // Validates the entry against empty local stores.
+    async fn validate_in_memory(entry: &Entry) -> Result<(), EntryValidationError> {
+        let registery = Registery::new_in_memory().unwrap();
+        let vtxo_set = VTXOSet::new_in_memory().unwrap();
+        let utxo_set = UTXOSet::new_in_memory().unwrap();
+        let blacklist_dir = BlacklistDirectory::new_in_memory().unwrap();
+
+        let ctx = EntryValidationCtx::new(
+            Chain::Signet,
+            &registery,
+            &vtxo_set,
+            &utxo_set,
+            &blacklist_dir,
+            10,
+        );
+        entry.validate(&ctx).await
+    }
+
+    #[tokio::test]
+    async fn bundle_test() -> Result<(), String> {
+        let alice = account("021123864025e2c24bd82e6e19729eaa93cf02c57149bbfc84d239a0369f471316");
+        let bob = account("02fbdf138aaa8e1e0446b3641cdc7f2d92b4442a80bbb191b8d96b653ccd270e44");
+        let alice_key = alice.key().serialize_xonly();
+
+        let approve = Call::new(alice_key, [0x01; 32], 0, vec![], 1_000, 10, None, None);
+        let swap = Call::new(alice_key, [0x02; 32], 1, vec![], 1_000, 10, None, None);
+        let tip = Move::new(alice, bob, 100);
+
+        // A bundle needs at least two items, and at least one call.
+        assert!(Bundle::new(vec![BundleItem::Call(approve.clone())]).is_none());
+        assert!(Bundle::new(vec![
+            BundleItem::Move(tip.clone()),
+            BundleItem::Move(tip.clone())
+        ])
+        .is_none());
+        assert!(Bundle::new(vec![BundleItem::Call(approve.clone()); 17]).is_none());
+
+        let bundle = Bundle::new(vec![
+            BundleItem::Call(approve.clone()),
+            BundleItem::Call(swap.clone()),
+            BundleItem::Move(tip.clone()),
+        ])
+        .ok_or("Failed to construct bundle.")?;
+        assert_eq!(bundle.items_len(), 3);
+        assert_eq!(bundle.calls(), vec![approve.clone(), swap.clone()]);
+
+        // The combined sighash commits to the order of the items.
+        let reordered = Bundle::new(vec![
+            BundleItem::Call(swap.clone()),
+            BundleItem::Call(approve.clone()),
+            BundleItem::Move(tip.clone()),
+        ])
+        .unwrap();
+        assert_ne!(
+            bundle.auth_sighash(Chain::Signet, 0),
+            reordered.auth_sighash(Chain::Signet, 0)
+        );
+
+        // The bundle is carried as the main combinator of an entry.
+        let entry = Entry::new_bundle(alice, bundle.clone(), None, None);
+        assert!(matches!(
+            entry.main_combinator(),
+            Some(Combinator::Bundle(main)) if main == bundle
+        ));
+        // Account checks pass, and validation goes on to the called contracts.
+        assert_eq!(
+            validate_in_memory(&entry).await,
+            Err(EntryValidationError::UnregisteredContract([0x01; 32]))
+        );
+
+        // Every item must be made from the entry account.
+        let entry = Entry::new_bundle(bob, bundle, None, None);
+        assert_eq!(
+            validate_in_memory(&entry).await,
+            Err(EntryValidationError::CombinatorAccountMismatch)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sponsored_call_test() -> Result<(), String> {
+        let alice = account("021123864025e2c24bd82e6e19729eaa93cf02c57149bbfc84d239a0369f471316");
+        let alice_key = alice.key().serialize_xonly();
+
+        let sponsor_secret = [0x11u8; 32];
+        let sponsor_key = sponsor_secret
+            .secret_to_public()
+            .ok_or("Failed to derive sponsor key.")?;
+
+        let unsponsored = Call::new(alice_key, [0x01; 32], 0, vec![], 1_000, 10, None, None);
+        let mut call = Call::new(
+            alice_key,
+            [0x01; 32],
+            0,
+            vec![],
+            1_000,
+            10,
+            None,
+            Some(sponsor_key),
+        );
+
+        // The sighash commits to the fee payer.
+        assert_ne!(
+            call.auth_sighash(Chain::Signet, 0),
+            unsponsored.auth_sighash(Chain::Signet, 0)
+        );
+
+        // Fees are charged to the fee payer.
+        assert_eq!(unsponsored.payer_key(), alice_key);
+        assert_eq!(call.payer_key(), sponsor_key);
+
+        // A sponsored call is not valid until co-signed by the fee payer.
+        assert!(!call.authenticate_fee_payer(Chain::Signet, 0));
+        assert_eq!(
+            validate_in_memory(&Entry::new_call(alice.clone(), call.clone(), None, None)).await,
+            Err(EntryValidationError::InvalidFeePayerSignature)
+        );
+
+        // Only the fee payer can co-sign.
+        assert!(!call.sign_as_fee_payer([0x22u8; 32], Chain::Signet, 0));
+        assert!(call.sign_as_fee_payer(sponsor_secret, Chain::Signet, 0));
+        assert!(call.authenticate_fee_payer(Chain::Signet, 0));
+        assert_eq!(
+            validate_in_memory(&Entry::new_call(alice.clone(), call.clone(), None, None)).await,
+            Err(EntryValidationError::UnregisteredContract([0x01; 32]))
+        );
+
+        // The co-signature does not carry over to a tampered call.
+        let mut tampered = call.clone();
+        tampered.ops_budget = 2_000;
+        assert!(!tampered.authenticate_fee_payer(Chain::Signet, 0));
+
+        // The co-signature is bound to the chain and the entry nonce.
+        assert_ne!(
+            call.auth_sighash(Chain::Signet, 0),
+            call.auth_sighash(Chain::Mainnet, 0)
+        );
+        assert_ne!(
+            call.auth_sighash(Chain::Signet, 0),
+            call.auth_sighash(Chain::Signet, 1)
+        );
+        assert!(!call.authenticate_fee_payer(Chain::Mainnet, 0));
+        assert!(!call.authenticate_fee_payer(Chain::Signet, 1));
+
+        // The maximum fees are counted against the fee payer.
+        let entry = Entry::new_call(alice.clone(), call.clone(), None, None);
+        assert_eq!(entry.max_spends(), HashMap::from([(sponsor_key, 10_000)]));
+
+        // An account cannot sponsor its own call.
+        let self_sponsored = Call::new(
+            alice_key,
+            [0x01; 32],
+            0,
+            vec![],
+            1_000,
+            10,
+            None,
+            Some(alice_key),
+        );
+        assert_eq!(
+            validate_in_memory(&Entry::new_call(alice, self_sponsored, None, None)).await,
+            Err(EntryValidationError::SelfSponsoredCall)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn claim_test() -> Result<(), String> {
+        let alice = account("021123864025e2c24bd82e6e19729eaa93cf02c57149bbfc84d239a0369f471316");
+        let bob = account("02fbdf138aaa8e1e0446b3641cdc7f2d92b4442a80bbb191b8d96b653ccd270e44");
+
+        let vtxo = |owner: Account, vout: u32| {
+            VTXO::new(
+                owner.key(),
+                bob.key(),
+                Some(OutPoint {
+                    txid: Txid::from_byte_array([0x01; 32]),
+                    vout,
+                }),
+                Some(10_000),
+                Some(1),
+                Some(100),
+            )
+        };
+
+        let vtxo_set = VTXOSet::new_in_memory().ok_or("Failed to construct VTXO set.")?;
+        {
+            let mut _vtxo_set = vtxo_set.lock().await;
+            assert!(_vtxo_set.insert_vtxo(&vtxo(alice, 0)));
+            assert!(_vtxo_set.insert_vtxo(&vtxo(alice, 1)));
+            assert!(_vtxo_set.insert_vtxo(&vtxo(bob, 2)));
+        }
+
+        // There must be something to claim.
+        assert!(Claim::new(alice, vec![]).is_none());
+
+        let claim = Claim::new(alice, vec![vtxo(alice, 0), vtxo(alice, 1)])
+            .ok_or("Failed to construct claim.")?;
+        let partial = Claim::new(alice, vec![vtxo(alice, 0)]).unwrap();
+
+        // The sighash commits to the claimed VTXOs.
+        assert_ne!(claim.auth_sighash(Chain::Signet), [0xffu8; 32]);
+        assert_ne!(
+            claim.auth_sighash(Chain::Signet),
+            partial.auth_sighash(Chain::Signet)
+        );
+
+        // Claimed VTXOs must belong to, and be held by, the account.
+        {
+            let _vtxo_set = vtxo_set.lock().await;
+            assert!(claim.validate_ownership(alice, &_vtxo_set));
+            assert!(!claim.validate_ownership(bob, &_vtxo_set));
+
+            let foreign = Claim::new(alice, vec![vtxo(bob, 2)]).unwrap();
+            assert!(!foreign.validate_account(alice));
+            assert!(!foreign.validate_ownership(alice, &_vtxo_set));
+
+            let unheld = Claim::new(alice, vec![vtxo(alice, 3)]).unwrap();
+            assert!(unheld.validate_account(alice));
+            assert!(!unheld.validate_ownership(alice, &_vtxo_set));
+        }
+
+        let registery = Registery::new_in_memory().ok_or("Failed to create registery.")?;
+        let utxo_set = UTXOSet::new_in_memory().ok_or("Failed to construct UTXO set.")?;
+        let blacklist_dir =
+            BlacklistDirectory::new_in_memory().ok_or("Failed to create blacklist directory.")?;
+        let ctx = EntryValidationCtx::new(
+            Chain::Signet,
+            &registery,
+            &vtxo_set,
+            &utxo_set,
+            &blacklist_dir,
+            10,
+        );
+
+        let entry = Entry::new_claim(alice, claim.clone(), None, None);
+        assert_eq!(entry.validate(&ctx).await, Ok(()));
+        assert_eq!(
+            Entry::new_claim(bob, claim.clone(), None, None)
+                .validate(&ctx)
+                .await,
+            Err(EntryValidationError::CombinatorAccountMismatch)
+        );
+
+        // JSON lists the claimed outpoints.
+        let json = claim.json();
+        assert_eq!(
+            json["account_key"],
+            hex::encode(alice.key().serialize_xonly())
+        );
+        assert_eq!(json["vtxos"].as_array().map(|vtxos| vtxos.len()), Some(2));
+        assert_eq!(
+            json["vtxos"][1]["outpoint"],
+            format!("{}:1", Txid::from_byte_array([0x01; 32]))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn revive_test() -> Result<(), String> {
+        let alice = account("021123864025e2c24bd82e6e19729eaa93cf02c57149bbfc84d239a0369f471316");
+        let bob = account("02fbdf138aaa8e1e0446b3641cdc7f2d92b4442a80bbb191b8d96b653ccd270e44");
+
+        // VTXOs confirmed at height 100 expire at height 100 + 144 * 90.
+        let expiry_height = 100 + 144 * 90;
+
+        let vtxo = |owner: Account, vout: u32| {
+            VTXO::new(
+                owner.key(),
+                bob.key(),
+                Some(OutPoint {
+                    txid: Txid::from_byte_array([0x02; 32]),
+                    vout,
+                }),
+                Some(10_000),
+                Some(1),
+                Some(100),
+            )
+        };
+
+        // Spends the VTXO through its sweep path.
+        let sweep = |vtxo: &VTXO| {
+            let taproot = vtxo.taproot().unwrap();
+            let sweep_path = taproot.tree().unwrap().leaves()[0].tap_script();
+
+            TxIn {
+                previous_output: vtxo.outpoint().unwrap(),
+                script_sig: ScriptBuf::new(),
+                sequence: Sequence::MAX,
+                witness: Witness::from_slice(&[
+                    vec![0x00; 64],
+                    sweep_path,
+                    taproot.control_block(0).unwrap().to_vec(),
+                ]),
+            }
+        };
+
+        // Spends the VTXO through its key path.
+        let key_path_spend = |vtxo: &VTXO| TxIn {
+            previous_output: vtxo.outpoint().unwrap(),
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::MAX,
+            witness: Witness::from_slice(&[vec![0x00; 64]]),
+        };
+
+        assert_eq!(vtxo(alice, 0).is_expired(expiry_height - 1), Some(false));
+        assert_eq!(vtxo(alice, 0).is_expired(expiry_height), Some(true));
+
+        let vtxo_set = VTXOSet::new_in_memory().ok_or("Failed to construct VTXO set.")?;
+        {
+            let mut _vtxo_set = vtxo_set.lock().await;
+            assert!(_vtxo_set.insert_vtxo(&vtxo(alice, 0)));
+            assert!(_vtxo_set.insert_vtxo(&vtxo(alice, 1)));
+            assert!(_vtxo_set.insert_vtxo(&vtxo(bob, 2)));
+
+            // Only a spend through the sweep path is a sweep.
+            assert!(_vtxo_set
+                .sweep_vtxo(&key_path_spend(&vtxo(alice, 0)))
+                .is_none());
+            assert!(_vtxo_set.sweep_vtxo(&sweep(&vtxo(alice, 3))).is_none());
+            assert_eq!(_vtxo_set.vtxo_set_by_account_key(&alice.key()).len(), 2);
+
+            for swept in [vtxo(alice, 0), vtxo(alice, 1), vtxo(bob, 2)] {
+                assert!(_vtxo_set.sweep_vtxo(&sweep(&swept)) == Some(swept));
+            }
+            assert!(_vtxo_set.vtxo_set_by_account_key(&alice.key()).is_empty());
+
+            // Swept VTXOs are revivable once expired.
+            assert!(_vtxo_set
+                .vtxos_to_revive(&alice.key(), expiry_height - 1)
+                .is_empty());
+            assert!(
+                _vtxo_set.vtxos_to_revive(&alice.key(), expiry_height)
+                    == vec![vtxo(alice, 0), vtxo(alice, 1)]
+            );
+        }
+
+        // There must be something to revive.
+        assert!(Revive::new(alice, vec![]).is_none());
+
+        let revive = Revive::new(alice, vec![vtxo(alice, 0), vtxo(alice, 1)])
+            .ok_or("Failed to construct revive.")?;
+        assert_eq!(revive.value(), 20_000);
+
+        // The sighash commits to the revived VTXOs.
+        let partial = Revive::new(alice, vec![vtxo(alice, 0)]).unwrap();
+        assert_ne!(revive.auth_sighash(Chain::Signet), [0xffu8; 32]);
+        assert_ne!(
+            revive.auth_sighash(Chain::Signet),
+            partial.auth_sighash(Chain::Signet)
+        );
+
+        // Revived VTXOs must belong to the account, and have been swept.
+        {
+            let _vtxo_set = vtxo_set.lock().await;
+            assert!(revive.validate_revival(alice, &_vtxo_set, expiry_height));
+            assert!(!revive.validate_revival(bob, &_vtxo_set, expiry_height));
+
+            let foreign = Revive::new(alice, vec![vtxo(bob, 2)]).unwrap();
+            assert!(!foreign.validate_revival(alice, &_vtxo_set, expiry_height));
+
+            let unswept = Revive::new(alice, vec![vtxo(alice, 3)]).unwrap();
+            assert!(!unswept.validate_revival(alice, &_vtxo_set, expiry_height));
+        }
+
+        let registery = Registery::new_in_memory().ok_or("Failed to create registery.")?;
+        let utxo_set = UTXOSet::new_in_memory().ok_or("Failed to construct UTXO set.")?;
+        let blacklist_dir =
+            BlacklistDirectory::new_in_memory().ok_or("Failed to create blacklist directory.")?;
+        let ctx = EntryValidationCtx::new(
+            Chain::Signet,
+            &registery,
+            &vtxo_set,
+            &utxo_set,
+            &blacklist_dir,
+            expiry_height,
+        );
+
+        let entry = Entry::new_revive(alice, revive.clone(), None, None);
+        assert_eq!(entry.validate(&ctx).await, Ok(()));
+        assert_eq!(
+            Entry::new_revive(bob, revive.clone(), None, None)
+                .validate(&ctx)
+                .await,
+            Err(EntryValidationError::CombinatorAccountMismatch)
+        );
+
+        // The value is revived into a new VTXO, whose outpoint is derived from the revived ones.
+        let revived_vtxo = revive
+            .revived_vtxo(bob.key(), 2, 200)
+            .ok_or("Failed to derive the revived VTXO.")?;
+        assert_eq!(revived_vtxo.account_key(), alice.key());
+        assert_eq!(revived_vtxo.operator_key(), bob.key());
+        assert_eq!(revived_vtxo.value(), Some(20_000));
+        assert_eq!(revived_vtxo.at_rollup_height(), Some(2));
+        assert_eq!(revived_vtxo.at_bitcoin_height(), Some(200));
+        assert!(revive.revived_vtxo(bob.key(), 2, 200) == Some(revived_vtxo.clone()));
+        assert_ne!(
+            partial
+                .revived_vtxo(bob.key(), 2, 200)
+                .and_then(|vtxo| vtxo.outpoint()),
+            revived_vtxo.outpoint()
+        );
+
+        // Revived VTXOs leave the sweep records and cannot be revived twice.
+        {
+            let mut _vtxo_set = vtxo_set.lock().await;
+            assert!(_vtxo_set.insert_vtxo(&revived_vtxo));
+            assert_eq!(
+                _vtxo_set.balance_by_account_key(alice.key().serialize_xonly()),
+                20_000
+            );
+            assert!(_vtxo_set.remove_swept_vtxo(&vtxo(alice, 0)));
+            assert!(!revive.validate_revival(alice, &_vtxo_set, expiry_height));
+            assert!(!partial.validate_revival(alice, &_vtxo_set, expiry_height));
+            assert!(_vtxo_set.vtxos_to_revive(&alice.key(), expiry_height) == vec![vtxo(alice, 1)]);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batch_move_test() -> Result<(), String> {
+        let alice = account("021123864025e2c24bd82e6e19729eaa93cf02c57149bbfc84d239a0369f471316");
+        let bob = account("02fbdf138aaa8e1e0446b3641cdc7f2d92b4442a80bbb191b8d96b653ccd270e44");
+        let carol = account("02f2a9b1353ad81072c30a72c8efb125ca9fdc498a9b6cbe9d05e7937ac540c3ce");
+
+        // A batch move pays 2 to 64 recipients.
+        assert!(BatchMove::new(alice, vec![(bob, 100)]).is_none());
+        assert!(BatchMove::new(alice, vec![(bob, 1); 65]).is_none());
+        assert!(BatchMove::new(alice, vec![(bob, 1); 64]).is_some());
+
+        // The total amount must fit in a u32.
+        assert!(BatchMove::new(alice, vec![(bob, u32::MAX), (carol, 1)]).is_none());
+        assert!(BatchMove::new(alice, vec![(bob, u32::MAX - 1), (carol, 1)]).is_some());
+
+        let payroll = BatchMove::new(alice, vec![(bob, 6_000), (carol, 4_000)])
+            .ok_or("Failed to construct batch move.")?;
+        assert_eq!(payroll.total_amount(), Some(10_000));
+
+        // A single sighash covers every recipient and amount, in order.
+        let sighash = payroll.auth_sighash(Chain::Signet);
+        assert_ne!(
+            sighash,
+            BatchMove::new(alice, vec![(bob, 6_000), (carol, 4_001)])
+                .unwrap()
+                .auth_sighash(Chain::Signet)
+        );
+        assert_ne!(
+            sighash,
+            BatchMove::new(alice, vec![(carol, 4_000), (bob, 6_000)])
+                .unwrap()
+                .auth_sighash(Chain::Signet)
+        );
+        assert_ne!(
+            sighash,
+            BatchMove::new(alice, vec![(bob, 6_000), (carol, 4_000), (carol, 0)])
+                .unwrap()
+                .auth_sighash(Chain::Signet)
+        );
+
+        let entry = Entry::new_batch_move(alice, payroll.clone(), None, None);
+        assert!(matches!(
+            entry.main_combinator(),
+            Some(Combinator::BatchMove(_))
+        ));
+
+        // The sender's spend is the total amount moved.
+        assert_eq!(
+            entry.max_spends(),
+            HashMap::from([(alice.key().serialize_xonly(), 10_000)])
+        );
+
+        // The sender's balance must cover the total amount.
+        let registery = Registery::new_in_memory().ok_or("Failed to create registery.")?;
+        let vtxo_set = VTXOSet::new_in_memory().ok_or("Failed to construct VTXO set.")?;
+        let utxo_set = UTXOSet::new_in_memory().ok_or("Failed to construct UTXO set.")?;
+        let blacklist_dir =
+            BlacklistDirectory::new_in_memory().ok_or("Failed to create blacklist directory.")?;
+
+        let ctx = EntryValidationCtx::new(
+            Chain::Signet,
+            &registery,
+            &vtxo_set,
+            &utxo_set,
+            &blacklist_dir,
+            10,
+        );
+
+        assert_eq!(
+            entry.validate(&ctx).await,
+            Err(EntryValidationError::InsufficientBalance(
+                alice.key().serialize_xonly()
+            ))
+        );
+
+        // Only the sender can carry the batch move.
+        assert_eq!(
+            Entry::new_batch_move(bob, payroll.clone(), None, None)
+                .validate(&ctx)
+                .await,
+            Err(EntryValidationError::CombinatorAccountMismatch)
+        );
+
+        {
+            let mut _vtxo_set = vtxo_set.lock().await;
+            assert!(_vtxo_set.insert_vtxo(&VTXO::new(
+                alice.key(),
+                bob.key(),
+                Some(OutPoint {
+                    txid: Txid::from_byte_array([0x03; 32]),
+                    vout: 0,
+                }),
+                Some(10_000),
+                Some(1),
+                Some(100),
+            )));
+        }
+        assert_eq!(entry.validate(&ctx).await, Ok(()));
+
+        let overdraft = BatchMove::new(alice, vec![(bob, 6_000), (carol, 4_001)]).unwrap();
+        assert_eq!(
+            Entry::new_batch_move(alice, overdraft, None, None)
+                .validate(&ctx)
+                .await,
+            Err(EntryValidationError::InsufficientBalance(
+                alice.key().serialize_xonly()
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replay_protection_test() -> Result<(), String> {
+        let alice = account("021123864025e2c24bd82e6e19729eaa93cf02c57149bbfc84d239a0369f471316");
+        let bob = account("02fbdf138aaa8e1e0446b3641cdc7f2d92b4442a80bbb191b8d96b653ccd270e44");
+
+        // Entry sighashes are domain separated by chain.
+        assert_eq!(
+            HashTag::SighashEntry(Chain::Signet).as_str(),
+            "Cube/signet/sighash/entry"
+        );
+        assert_eq!(
+            HashTag::SighashEntry(Chain::Mainnet).as_str(),
+            "Cube/mainnet/sighash/entry"
+        );
+
+        // So are combinator sighashes.
+        assert_eq!(
+            HashTag::SighashCombinator(Chain::Signet, CombinatorType::Move).as_str(),
+            "Cube/signet/sighash/combinator/move"
+        );
+        let r#move = Move::new(alice, bob, 100);
+        assert_ne!(
+            r#move.auth_sighash(Chain::Signet),
+            r#move.auth_sighash(Chain::Mainnet)
+        );
+
+        // The same entry has a different sighash on each chain, and at each nonce.
+        let mut entry = Entry::new_move(alice, Move::new(alice, bob, 100), None, None);
+        assert_eq!(entry.nonce(), 0);
+        let signet_sighash = entry.sighash(Chain::Signet);
+        assert_ne!(signet_sighash, entry.sighash(Chain::Mainnet));
+
+        entry.set_nonce(1);
+        assert_ne!(signet_sighash, entry.sighash(Chain::Signet));
+        entry.set_nonce(0);
+        assert_eq!(signet_sighash, entry.sighash(Chain::Signet));
+
+        // The registery tracks the next nonce of each account.
+        let account_registery =
+            AccountRegistery::new_in_memory().ok_or("Failed to create account registery.")?;
+        let mut _account_registery = account_registery.lock().await;
+        assert_eq!(_account_registery.nonce_by_key(alice.key()), 0);
+        assert_eq!(_account_registery.nonce_by_key(bob.key()), 0);
+
+        // Consuming a nonce advances it.
+        let nonces = HashMap::from([(alice.key(), 0)]);
+        assert!(_account_registery.consume_nonces(nonces.clone()));
+        assert_eq!(_account_registery.nonce_by_key(alice.key()), 1);
+        assert_eq!(_account_registery.nonce_by_key(bob.key()), 0);
+
+        // A consumed nonce cannot be consumed again, nor can a future one be skipped to.
+        assert!(!_account_registery.consume_nonces(nonces));
+        assert!(!_account_registery.consume_nonces(HashMap::from([(alice.key(), 2)])));
+
+        // A failing batch consumes no nonces.
+        assert!(
+            !_account_registery.consume_nonces(HashMap::from([(alice.key(), 1), (bob.key(), 1)]))
+        );
+        assert_eq!(_account_registery.nonce_by_key(alice.key()), 1);
+        assert_eq!(_account_registery.nonce_by_key(bob.key()), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn entry_validation_test() -> Result<(), String> {
+        let alice = account("021123864025e2c24bd82e6e19729eaa93cf02c57149bbfc84d239a0369f471316");
+        let bob = account("02fbdf138aaa8e1e0446b3641cdc7f2d92b4442a80bbb191b8d96b653ccd270e44");
+        let alice_key = alice.key().serialize_xonly();
+
+        let registery = Registery::new_in_memory().ok_or("Failed to create registery.")?;
+        let vtxo_set = VTXOSet::new_in_memory().ok_or("Failed to construct VTXO set.")?;
+        let utxo_set = UTXOSet::new_in_memory().ok_or("Failed to construct UTXO set.")?;
+        let blacklist_dir =
+            BlacklistDirectory::new_in_memory().ok_or("Failed to create blacklist directory.")?;
+
+        // Alice holds a VTXO of 10,000 sats.
+        let vtxo = VTXO::new(
+            alice.key(),
+            bob.key(),
+            Some(OutPoint {
+                txid: Txid::from_byte_array([0x03; 32]),
+                vout: 0,
+            }),
+            Some(10_000),
+            Some(1),
+            Some(100),
+        );
+        {
+            let mut _vtxo_set = vtxo_set.lock().await;
+            assert!(_vtxo_set.insert_vtxo(&vtxo));
+        }
+
+        let mut ctx = EntryValidationCtx::new(
+            Chain::Signet,
+            &registery,
+            &vtxo_set,
+            &utxo_set,
+            &blacklist_dir,
+            10_000,
+        );
+
+        let r#move =
+            |amount: u32| Entry::new_move(alice, Move::new(alice, bob, amount), None, None);
+
+        // A move within the balance is valid.
+        assert_eq!(r#move(5_000).validate(&ctx).await, Ok(()));
+
+        // An empty entry is not.
+        assert_eq!(
+            Entry::new(alice, None, None).validate(&ctx).await,
+            Err(EntryValidationError::EmptyEntry)
+        );
+
+        // Amounts must be non-zero, and covered by the balance.
+        assert_eq!(
+            r#move(0).validate(&ctx).await,
+            Err(EntryValidationError::ZeroAmount)
+        );
+        assert_eq!(
+            r#move(10_001).validate(&ctx).await,
+            Err(EntryValidationError::InsufficientBalance(alice_key))
+        );
+
+        // Moves must be from the account.
+        assert_eq!(
+            Entry::new_move(alice, Move::new(bob, alice, 100), None, None)
+                .validate(&ctx)
+                .await,
+            Err(EntryValidationError::CombinatorAccountMismatch)
+        );
+
+        // The entry must be at the account's next nonce.
+        let mut replayed = r#move(5_000);
+        replayed.set_nonce(1);
+        assert_eq!(
+            replayed.validate(&ctx).await,
+            Err(EntryValidationError::InvalidNonce(0))
+        );
+
+        // An unregistered account cannot claim a registery index.
+        let mut indexed = alice;
+        indexed.set_registery_index(0);
+        assert_eq!(
+            Entry::new_move(indexed, Move::new(indexed, bob, 100), None, None)
+                .validate(&ctx)
+                .await,
+            Err(EntryValidationError::InvalidAccountRegisteryIndex)
+        );
+
+        // Calls must be to registered contracts.
+        let call = Call::new(alice_key, [0x01; 32], 0, vec![], 100, 10, None, None);
+        assert_eq!(
+            Entry::new_call(alice, call, None, None)
+                .validate(&ctx)
+                .await,
+            Err(EntryValidationError::UnregisteredContract([0x01; 32]))
+        );
+
+        // Recharged VTXOs must be held by the account.
+        let unheld = VTXO::new(
+            alice.key(),
+            bob.key(),
+            Some(OutPoint {
+                txid: Txid::from_byte_array([0x03; 32]),
+                vout: 1,
+            }),
+            Some(10_000),
+            Some(1),
+            Some(100),
+        );
+        let recharge = Recharge::new(vec![vtxo.clone()]).ok_or("Failed to construct recharge.")?;
+        assert_eq!(
+            Entry::new_nop(alice, None, Some(recharge))
+                .validate(&ctx)
+                .await,
+            Ok(())
+        );
+        let recharge =
+            Recharge::new(vec![unheld.clone()]).ok_or("Failed to construct recharge.")?;
+        assert_eq!(
+            Entry::new_nop(alice, None, Some(recharge))
+                .validate(&ctx)
+                .await,
+            Err(EntryValidationError::UnrechargeableVTXO(
+                unheld.outpoint().ok_or("Missing outpoint.")?
+            ))
+        );
+
+        // Lift outpoints must be unspent in the UTXO set.
+        let lift_outpoint = OutPoint {
+            txid: Txid::from_byte_array([0x04; 32]),
+            vout: 0,
+        };
+        let lift = Lift::new(alice.key(), bob.key(), Some(lift_outpoint), Some(10_000));
+        let liftup = Liftup::new(vec![lift.clone()]).ok_or("Failed to construct liftup.")?;
+        let lifted_move = Entry::new_move(alice, Move::new(alice, bob, 15_000), Some(liftup), None);
+        assert_eq!(
+            lifted_move.validate(&ctx).await,
+            Err(EntryValidationError::UnknownLiftOutpoint(lift_outpoint))
+        );
+
+        // Once unspent, the lifted value is spendable by the account.
+        {
+            let mut _utxo_set = utxo_set.lock().await;
+            _utxo_set.insert_txout(
+                &lift_outpoint,
+                &TxOut {
+                    value: Amount::from_sat(10_000),
+                    script_pubkey: ScriptBuf::from_bytes(
+                        lift.spk().ok_or("Failed to construct lift spk.")?,
+                    ),
+                },
+            );
+        }
+        assert_eq!(lifted_move.validate(&ctx).await, Ok(()));
+
+        // The lift value must match the outpoint value.
+        let overvalued = Lift::new(alice.key(), bob.key(), Some(lift_outpoint), Some(20_000));
+        let liftup = Liftup::new(vec![overvalued]).ok_or("Failed to construct liftup.")?;
+        assert_eq!(
+            Entry::new_nop(alice, Some(liftup), None)
+                .validate(&ctx)
+                .await,
+            Err(EntryValidationError::LiftValueMismatch(lift_outpoint))
+        );
+
+        // Spends of other entries of the batch are pending against the balance.
+        ctx.add_pending_spends(&r#move(6_000).max_spends());
+        assert_eq!(r#move(4_000).validate(&ctx).await, Ok(()));
+        assert_eq!(
+            r#move(5_000).validate(&ctx).await,
+            Err(EntryValidationError::InsufficientBalance(alice_key))
+        );
+
+        // Blacklisted accounts are rejected.
+        {
+            let mut _blacklist_dir = blacklist_dir.lock().await;
+            _blacklist_dir.manual_blacklist(alice, u64::MAX);
+        }
+        assert_eq!(
+            r#move(100).validate(&ctx).await,
+            Err(EntryValidationError::BlacklistedUntil(u64::MAX))
+        );
+
+        Ok(())
+    }
+}
@@ -1,8 +1,1083 @@
 #[cfg(test)]
 mod exec_tests {
+    use bitcoin::{hashes::Hash, OutPoint, Txid};
+    use cube::{
+        constructive::{
+            calldata::{element::element::CallElement, element_type::CallElementType},
+            entity::account::account::Account,
+            entry::combinator::combinators::{
+                bundle::{bundle::Bundle, bundle_item::BundleItem},
+                call::call::Call,
+                r#move::r#move::Move,
+            },
+            txo::vtxo::VTXO,
+        },
+        executive::{
+            exec::{
+                accountant::{
+                    accountant::Accountant,
+                    accountant_error::{InsertFeeError, PayListError},
+                    accountant_record::AccountantRecord,
+                },
+                caller::Caller,
+                exec::execute,
+                exec_ctx::ExecCtx,
+                exec_error::{ExecCtxSaveError, ExecutionError},
+                receipt::CallReceipt,
+                trace::ExecTrace,
+                transient::transient::TransientStorage,
+            },
+            opcode::{
+                opcode::Opcode,
+                opcodes::{
+                    arithmetic::{op_booland::OP_BOOLAND, op_not::OP_NOT},
+                    call::op_call::OP_CALL,
+                    flow::{op_returnall::OP_RETURNALL, op_returnerr::OP_RETURNERR},
+                    push::{
+                        op_2::OP_2, op_3::OP_3, op_false::OP_FALSE, op_pushdata::OP_PUSHDATA,
+                        op_true::OP_TRUE,
+                    },
+                    storage::op_swrite::OP_SWRITE,
+                },
+                ops::OpsSchedule,
+            },
+            program::{
+                method::{method::ProgramMethod, method_type::MethodType},
+                program::Program,
+            },
+            stack::stack_item::StackItem,
+        },
+        inscriptive::{
+            beacon::dir::BeaconDirectory,
+            registery::contract_registery::ContractRegistery,
+            repo::{repo::ProgramsRepo, repo_error::RepoInsertError},
+            rollup::dir::RollupDirectory,
+            set::vtxo_set::VTXOSet,
+            state::state_holder::StateHolder,
+        },
+    };
+    use secp::{Point, Scalar};
+    use std::collections::HashMap;
 
     #[test]
     fn exec_test() -> Result<(), String> {
         Ok(())
     }
+
+    #[test]
+    fn accountant_nested_savepoints_test() -> Result<(), String> {
+        let alice = [0x01u8; 32];
+        let bob = [0x02u8; 32];
+        let carol = [0x03u8; 32];
+
+        let mut accountant = Accountant::new();
+        accountant.insert_alloc(alice, 100).unwrap();
+
+        // Outer frame: alice pays bob 10.
+        accountant.push_savepoint();
+        accountant
+            .insert_record(AccountantRecord::new(alice, bob, 10))
+            .unwrap();
+
+        // Inner frame: alice pays carol 20, then the frame is reverted.
+        accountant.push_savepoint();
+        accountant
+            .insert_record(AccountantRecord::new(alice, carol, 20))
+            .unwrap();
+        assert_eq!(accountant.savepoints_len(), 2);
+        accountant.revert_to_savepoint();
+
+        // Only the inner payment is discarded.
+        let pay_list = accountant.pay_list().unwrap();
+        assert_eq!(pay_list.get(&bob), Some(&10));
+        assert_eq!(pay_list.get(&carol), None);
+        assert_eq!(pay_list.get(&alice), Some(&90));
+
+        // Inner frame: alice pays carol 30, then the frame is committed.
+        accountant.push_savepoint();
+        accountant
+            .insert_record(AccountantRecord::new(alice, carol, 30))
+            .unwrap();
+        accountant.commit_savepoint();
+
+        // Reverting the outer frame discards the committed inner frame too.
+        assert_eq!(accountant.savepoints_len(), 1);
+        accountant.revert_to_savepoint();
+
+        let pay_list = accountant.pay_list().unwrap();
+        assert_eq!(pay_list.get(&bob), None);
+        assert_eq!(pay_list.get(&carol), None);
+        assert_eq!(pay_list.get(&alice), Some(&100));
+        assert_eq!(accountant.savepoints_len(), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn state_holder_nested_savepoints_test() -> Result<(), String> {
+        let contract_id = [0xccu8; 32];
+        let key = b"key".to_vec();
+
+        let state_holder = StateHolder::new_in_memory().unwrap();
+        let mut _state_holder = state_holder.lock().await;
+
+        // Outer frame: write "outer".
+        _state_holder.push_savepoint();
+        _state_holder.insert_value(&contract_id, &key, &b"outer".to_vec());
+
+        // Inner frame: write "inner", then the frame is reverted.
+        _state_holder.push_savepoint();
+        _state_holder.insert_value(&contract_id, &key, &b"inner".to_vec());
+        assert_eq!(_state_holder.savepoints_len(), 2);
+        _state_holder.revert_to_savepoint();
+
+        // Only the inner write is discarded.
+        assert_eq!(
+            _state_holder.get_value(&key, &contract_id),
+            Some(b"outer".to_vec())
+        );
+
+        // Inner frame: write "committed", then the frame is committed.
+        _state_holder.push_savepoint();
+        _state_holder.insert_value(&contract_id, &key, &b"committed".to_vec());
+        _state_holder.commit_savepoint();
+        assert_eq!(
+            _state_holder.get_value(&key, &contract_id),
+            Some(b"committed".to_vec())
+        );
+
+        // Reverting the outer frame discards the committed inner frame too.
+        assert_eq!(_state_holder.savepoints_len(), 1);
+        _state_holder.revert_to_savepoint();
+        assert_eq!(_state_holder.get_value(&key, &contract_id), None);
+        assert_eq!(_state_holder.savepoints_len(), 0);
+
+        // Reverting with no savepoint left is a no-op.
+        _state_holder.insert_value(&contract_id, &key, &b"unsaved".to_vec());
+        _state_holder.revert_to_savepoint();
+        assert_eq!(
+            _state_holder.get_value(&key, &contract_id),
+            Some(b"unsaved".to_vec())
+        );
+
+        // Rolling back discards everything.
+        _state_holder.push_savepoint();
+        _state_holder.rollback_all();
+        assert_eq!(_state_holder.get_value(&key, &contract_id), None);
+        assert_eq!(_state_holder.savepoints_len(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn accountant_fees_test() -> Result<(), String> {
+        let alice = [0x01u8; 32];
+        let sponsor = [0x02u8; 32];
+
+        let mut accountant = Accountant::new();
+        accountant.insert_alloc(alice, 100).unwrap();
+
+        // Fees are deducted from the reservation, and the rest is owed back to the payer.
+        accountant.reserve_fees(sponsor, 50).unwrap();
+        accountant.insert_fee(sponsor, 30).unwrap();
+        let pay_list = accountant.pay_list().unwrap();
+        assert_eq!(pay_list.get(&sponsor), Some(&20));
+        assert_eq!(pay_list.get(&alice), Some(&100));
+
+        // Fees are not subject to savepoints.
+        accountant.push_savepoint();
+        accountant.insert_fee(sponsor, 20).unwrap();
+        accountant.revert_to_savepoint();
+        assert_eq!(accountant.pay_list().unwrap().get(&sponsor), None);
+
+        // A payer cannot be charged more than it reserved.
+        accountant.insert_fee(sponsor, 1).unwrap();
+        assert!(matches!(
+            accountant.pay_list(),
+            Err(PayListError::FeeReserveExceededError)
+        ));
+
+        // Fees never overflow.
+        assert!(matches!(
+            accountant.insert_fee(sponsor, u64::MAX),
+            Err(InsertFeeError::FeeOverflowError)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn call_receipt_test() -> Result<(), String> {
+        let passed = CallReceipt::new_passed(120, 1200);
+        assert!(passed.is_passed());
+        assert_eq!(passed.ops_spent(), 120);
+        assert_eq!(passed.fees_spent(), 1200);
+        assert!(passed.failure_reason().is_none());
+
+        // Failed calls are still charged for the ops consumed, and carry the failure reason.
+        let failed = CallReceipt::new_failed(80, 800, ExecutionError::InvalidStackEndingError);
+        assert!(!failed.is_passed());
+        assert_eq!(failed.ops_spent(), 80);
+        assert_eq!(failed.fees_spent(), 800);
+        assert!(matches!(
+            failed.failure_reason(),
+            Some(ExecutionError::InvalidStackEndingError)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn exec_trace_test() -> Result<(), String> {
+        let caller_contract = [0xaau8; 32];
+        let callee_contract = [0xbbu8; 32];
+
+        let mut trace = ExecTrace::new();
+
+        // The caller contract calls into the callee contract at pc 2.
+        trace.enter(caller_contract, 0);
+        trace.step(0, &Opcode::OP_TRUE(OP_TRUE), 1, 0, 0);
+        trace.step(2, &Opcode::OP_TRUE(OP_TRUE), 1, 3, 3);
+
+        // The callee contract reverts at pc 5.
+        trace.enter(callee_contract, 1);
+        trace.step(5, &Opcode::OP_RETURNERR(OP_RETURNERR), 4, 12, 12);
+
+        assert_eq!(trace.ops_reached(), 12);
+
+        // A failure is charged for the opcode it failed at too, up to the ops budget.
+        assert_eq!(trace.ops_charged(100), 16);
+        assert_eq!(trace.ops_charged(14), 14);
+
+        let error = ExecutionError::ReturnErrorFromStackError(StackItem::new(
+            b"insufficient balance".to_vec(),
+        ));
+
+        let located_error = trace.locate(error);
+
+        match &located_error {
+            ExecutionError::RevertError(context, _) => {
+                assert_eq!(context.contract_id, callee_contract);
+                assert_eq!(context.method_index, 1);
+                assert_eq!(context.pc, Some(5));
+                assert_eq!(context.opcode, Some("OP_RETURNERR".to_string()));
+                assert_eq!(
+                    context.call_path,
+                    vec![(caller_contract, 0), (callee_contract, 1)]
+                );
+            }
+            _ => return Err("Expected a revert error.".to_string()),
+        }
+
+        // UTF-8 payloads are decoded into revert reasons.
+        assert_eq!(
+            located_error.revert_reason(),
+            Some("insufficient balance".to_string())
+        );
+        assert!(located_error
+            .to_string()
+            .starts_with("Return error from stack: insufficient balance at contract"));
+
+        // Non UTF-8 payloads are not.
+        let error = ExecutionError::ReturnErrorFromStackError(StackItem::new(vec![0xff, 0xfe]));
+        assert_eq!(error.revert_reason(), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exec_bundle_test() -> Result<(), String> {
+        // Writes the given value under "key".
+        let store = ProgramMethod::new(
+            "store".to_string(),
+            MethodType::Callable,
+            vec![CallElementType::Varbytes],
+            vec![
+                Opcode::OP_PUSHDATA(OP_PUSHDATA(b"key".to_vec())),
+                Opcode::OP_SWRITE(OP_SWRITE),
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_RETURNALL(OP_RETURNALL),
+            ],
+        )
+        .unwrap();
+
+        // Always fails.
+        let fail = ProgramMethod::new(
+            "fail".to_string(),
+            MethodType::Callable,
+            vec![],
+            vec![
+                Opcode::OP_PUSHDATA(OP_PUSHDATA(b"nope".to_vec())),
+                Opcode::OP_RETURNERR(OP_RETURNERR),
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_RETURNALL(OP_RETURNALL),
+            ],
+        )
+        .unwrap();
+
+        let program = Program::new("bundle".to_string(), [0x00; 32], vec![store, fail]).unwrap();
+        let contract_id = program.contract_id();
+
+        let state_holder = StateHolder::new_in_memory().unwrap();
+        let programs_repo = ProgramsRepo::new_in_memory().unwrap();
+        let rollup_dir = RollupDirectory::new_in_memory().unwrap();
+        let beacon_dir = BeaconDirectory::new_in_memory().unwrap();
+
+        {
+            let mut programs = HashMap::new();
+            programs.insert(contract_id, program);
+            let mut _programs_repo = programs_repo.lock().await;
+            _programs_repo.insert_multi(&programs).unwrap();
+        }
+
+        let base_ops_price = 10;
+        let mut exec_ctx = ExecCtx::new(
+            &state_holder,
+            &programs_repo,
+            &rollup_dir,
+            base_ops_price,
+            0,
+            0,
+            &beacon_dir,
+            0,
+        );
+
+        let alice = [0xaau8; 32];
+        let store_call = |value: &[u8]| {
+            Call::new(
+                alice,
+                contract_id,
+                0,
+                vec![CallElement::Varbytes(value.to_vec())],
+                100_000,
+                base_ops_price,
+                None,
+                None,
+            )
+        };
+        let fail_call = Call::new(
+            alice,
+            contract_id,
+            1,
+            vec![],
+            100_000,
+            base_ops_price,
+            None,
+            None,
+        );
+
+        // A passing bundle keeps the changes of all its calls.
+        let bundle = Bundle::new(vec![
+            BundleItem::Call(store_call(b"first")),
+            BundleItem::Call(store_call(b"second")),
+        ])
+        .unwrap();
+        exec_ctx
+            .exec_insert_bundle(bundle)
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!(exec_ctx.passed_calls_len(), 2);
+
+        let value = {
+            let _state_holder = state_holder.lock().await;
+            _state_holder.get_value(&b"key".to_vec(), &contract_id)
+        };
+        assert_eq!(value, Some(b"second".to_vec()));
+
+        // A failing call reverts the calls that passed before it in the bundle.
+        let bundle = Bundle::new(vec![
+            BundleItem::Call(store_call(b"third")),
+            BundleItem::Call(fail_call),
+        ])
+        .unwrap();
+        let error = exec_ctx
+            .exec_insert_bundle(bundle)
+            .await
+            .err()
+            .ok_or("Expected the bundle to fail.")?;
+        assert!(matches!(error, ExecutionError::BundleItemError(1, _)));
+        assert_eq!(error.revert_reason(), Some("nope".to_string()));
+
+        let value = {
+            let _state_holder = state_holder.lock().await;
+            _state_holder.get_value(&b"key".to_vec(), &contract_id)
+        };
+        assert_eq!(value, Some(b"second".to_vec()));
+
+        // Both calls of the failed bundle are still charged.
+        let receipts = exec_ctx.receipts();
+        assert_eq!(receipts.len(), 4);
+        assert_eq!(exec_ctx.passed_calls_len(), 2);
+        assert!(matches!(
+            receipts[2].1.failure_reason(),
+            Some(ExecutionError::BundleRevertedError(1))
+        ));
+        assert!(receipts[2].1.fees_spent() > 0);
+        assert!(matches!(
+            receipts[3].1.failure_reason(),
+            Some(ExecutionError::RevertError(_, _))
+        ));
+
+        // The failing call is charged for the opcode it failed at.
+        let ops_schedule = OpsSchedule::at_height(0);
+        assert_eq!(
+            receipts[3].1.ops_spent(),
+            ops_schedule.op_pushdata_base
+                + ops_schedule.op_pushdata_multiplier * 4
+                + ops_schedule.op_returnerr
+        );
+
+        // A sponsored call is charged to its fee payer.
+        let sponsor = [0xbbu8; 32];
+        let mut sponsored_call = store_call(b"fourth");
+        sponsored_call.fee_payer = Some(sponsor);
+        exec_ctx
+            .exec_insert_call(sponsored_call)
+            .await
+            .map_err(|e| e.to_string())?;
+        let fees_spent = exec_ctx.receipts()[4].1.fees_spent();
+        assert!(fees_spent > 0);
+        assert_eq!(exec_ctx.fee_list().get(&sponsor), Some(&fees_spent));
+
+        // The sponsor is owed back what it reserved but was not charged.
+        let sponsored_max_fees = store_call(b"fourth").max_fees();
+        assert_eq!(
+            exec_ctx.pay_list().unwrap().get(&sponsor),
+            Some(&(sponsored_max_fees - fees_spent))
+        );
+
+        // Fees are charged at the total ops price.
+        let mut tipped_call = store_call(b"fifth");
+        tipped_call.ops_price_extra_in = Some(5);
+        exec_ctx
+            .exec_insert_call(tipped_call)
+            .await
+            .map_err(|e| e.to_string())?;
+        let receipt = exec_ctx.receipts()[5].1.clone();
+        assert_eq!(
+            receipt.fees_spent(),
+            receipt.ops_spent() as u64 * (base_ops_price as u64 + 5)
+        );
+
+        // A total ops price beyond the stack range is rejected before execution.
+        let debit_list = exec_ctx.debit_list().unwrap();
+        let mut overpriced_call = store_call(b"sixth");
+        overpriced_call.ops_price_extra_in = Some(u32::MAX);
+        assert!(matches!(
+            exec_ctx.exec_insert_call(overpriced_call).await,
+            Err(ExecutionError::OpsPriceOverflowError)
+        ));
+        assert_eq!(exec_ctx.receipts().len(), 6);
+
+        // A base ops price mismatch is rejected before execution, alone or in a bundle.
+        let mut mispriced_call = store_call(b"seventh");
+        mispriced_call.ops_price_base = base_ops_price + 1;
+        assert!(matches!(
+            exec_ctx.exec_insert_call(mispriced_call.clone()).await,
+            Err(ExecutionError::BaseOpsPriceMismatchError)
+        ));
+        let bundle = Bundle::new(vec![
+            BundleItem::Call(store_call(b"eighth")),
+            BundleItem::Call(mispriced_call),
+        ])
+        .unwrap();
+        assert!(matches!(
+            exec_ctx.exec_insert_bundle(bundle).await,
+            Err(ExecutionError::BaseOpsPriceMismatchError)
+        ));
+        assert_eq!(exec_ctx.receipts().len(), 6);
+
+        // Rejected calls keep no fee reservation.
+        assert_eq!(exec_ctx.debit_list().unwrap(), debit_list);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exec_nested_call_test() -> Result<(), String> {
+        // Writes the given value under "key".
+        let write = |value: &[u8]| {
+            vec![
+                Opcode::OP_PUSHDATA(OP_PUSHDATA(value.to_vec())),
+                Opcode::OP_PUSHDATA(OP_PUSHDATA(b"key".to_vec())),
+                Opcode::OP_SWRITE(OP_SWRITE),
+            ]
+        };
+
+        // Writes "outer", then calls the failing method and expects it to fail.
+        let mut script = write(b"outer");
+        script.extend([
+            Opcode::OP_FALSE(OP_FALSE),
+            Opcode::OP_2(OP_2),
+            Opcode::OP_CALL(OP_CALL),
+            Opcode::OP_NOT(OP_NOT),
+            Opcode::OP_RETURNALL(OP_RETURNALL),
+        ]);
+        let catch =
+            ProgramMethod::new("catch".to_string(), MethodType::Callable, vec![], script).unwrap();
+
+        // Writes "failed", then fails.
+        let mut script = write(b"failed");
+        script.extend([
+            Opcode::OP_PUSHDATA(OP_PUSHDATA(b"nope".to_vec())),
+            Opcode::OP_RETURNERR(OP_RETURNERR),
+        ]);
+        let fail =
+            ProgramMethod::new("fail".to_string(), MethodType::Internal, vec![], script).unwrap();
+
+        // Writes "passed", then returns true.
+        let mut script = write(b"passed");
+        script.extend([Opcode::OP_TRUE(OP_TRUE), Opcode::OP_RETURNALL(OP_RETURNALL)]);
+        let pass =
+            ProgramMethod::new("pass".to_string(), MethodType::Internal, vec![], script).unwrap();
+
+        // Calls the passing method and expects both its return item and the success flag.
+        let call = ProgramMethod::new(
+            "call".to_string(),
+            MethodType::Callable,
+            vec![],
+            vec![
+                Opcode::OP_FALSE(OP_FALSE),
+                Opcode::OP_3(OP_3),
+                Opcode::OP_CALL(OP_CALL),
+                Opcode::OP_BOOLAND(OP_BOOLAND),
+                Opcode::OP_RETURNALL(OP_RETURNALL),
+            ],
+        )
+        .unwrap();
+
+        // Callable methods are ordered first: catch (0), call (1), fail (2), pass (3).
+        let program = Program::new(
+            "nested".to_string(),
+            [0x00; 32],
+            vec![catch, fail, pass, call],
+        )
+        .unwrap();
+        let contract_id = program.contract_id();
+
+        let state_holder = StateHolder::new_in_memory().unwrap();
+        let programs_repo = ProgramsRepo::new_in_memory().unwrap();
+        let rollup_dir = RollupDirectory::new_in_memory().unwrap();
+        let beacon_dir = BeaconDirectory::new_in_memory().unwrap();
+
+        {
+            let mut programs = HashMap::new();
+            programs.insert(contract_id, program);
+            let mut _programs_repo = programs_repo.lock().await;
+            _programs_repo.insert_multi(&programs).unwrap();
+        }
+
+        let base_ops_price = 10;
+        let mut exec_ctx = ExecCtx::new(
+            &state_holder,
+            &programs_repo,
+            &rollup_dir,
+            base_ops_price,
+            0,
+            0,
+            &beacon_dir,
+            0,
+        );
+
+        let alice = [0xaau8; 32];
+        let method_call = |method_index: u8| {
+            Call::new(
+                alice,
+                contract_id,
+                method_index,
+                vec![],
+                100_000,
+                base_ops_price,
+                None,
+                None,
+            )
+        };
+
+        let get_value = || async {
+            let _state_holder = state_holder.lock().await;
+            _state_holder.get_value(&b"key".to_vec(), &contract_id)
+        };
+
+        // A failed nested call is reverted, and the caller carries on.
+        exec_ctx
+            .exec_insert_call(method_call(0))
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!(exec_ctx.passed_calls_len(), 1);
+        assert_eq!(get_value().await, Some(b"outer".to_vec()));
+
+        // The caller is still charged for the ops the failed nested call consumed.
+        let ops_schedule = OpsSchedule::at_height(0);
+        let receipt = exec_ctx.receipts()[0].1.clone();
+        assert!(
+            receipt.ops_spent()
+                > 2 * ops_schedule.op_swrite_base
+                    + ops_schedule.op_call
+                    + ops_schedule.op_returnerr
+        );
+
+        // A passing nested call is kept, and its return items are handed to the caller.
+        exec_ctx
+            .exec_insert_call(method_call(1))
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!(exec_ctx.passed_calls_len(), 2);
+        assert_eq!(get_value().await, Some(b"passed".to_vec()));
+
+        // No savepoint is left open.
+        {
+            let _state_holder = state_holder.lock().await;
+            assert_eq!(_state_holder.savepoints_len(), 0);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exec_call_stack_contract_test() -> Result<(), String> {
+        // Pushes a marker, calls the pairing method, then the failing method.
+        let entry = ProgramMethod::new(
+            "entry".to_string(),
+            MethodType::Callable,
+            vec![],
+            vec![
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_FALSE(OP_FALSE),
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_CALL(OP_CALL),
+                Opcode::OP_FALSE(OP_FALSE),
+                Opcode::OP_2(OP_2),
+                Opcode::OP_CALL(OP_CALL),
+                Opcode::OP_RETURNALL(OP_RETURNALL),
+            ],
+        )
+        .unwrap();
+
+        // Returns three items.
+        let pair = ProgramMethod::new(
+            "pair".to_string(),
+            MethodType::Internal,
+            vec![],
+            vec![
+                Opcode::OP_2(OP_2),
+                Opcode::OP_3(OP_3),
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_RETURNALL(OP_RETURNALL),
+            ],
+        )
+        .unwrap();
+
+        // Pushes two items, then fails.
+        let fail = ProgramMethod::new(
+            "fail".to_string(),
+            MethodType::Internal,
+            vec![],
+            vec![
+                Opcode::OP_2(OP_2),
+                Opcode::OP_3(OP_3),
+                Opcode::OP_PUSHDATA(OP_PUSHDATA(b"nope".to_vec())),
+                Opcode::OP_RETURNERR(OP_RETURNERR),
+            ],
+        )
+        .unwrap();
+
+        // Callable methods are ordered first: entry (0), pair (1), fail (2).
+        let program =
+            Program::new("stack".to_string(), [0x00; 32], vec![entry, pair, fail]).unwrap();
+        let contract_id = program.contract_id();
+
+        let state_holder = StateHolder::new_in_memory().unwrap();
+        let programs_repo = ProgramsRepo::new_in_memory().unwrap();
+        let rollup_dir = RollupDirectory::new_in_memory().unwrap();
+        let beacon_dir = BeaconDirectory::new_in_memory().unwrap();
+
+        {
+            let mut programs = HashMap::new();
+            programs.insert(contract_id, program);
+            let mut _programs_repo = programs_repo.lock().await;
+            _programs_repo.insert_multi(&programs).unwrap();
+        }
+
+        let call = |caller: Caller, method_index: u8| {
+            let state_holder = &state_holder;
+            let programs_repo = &programs_repo;
+            let rollup_dir = &rollup_dir;
+            let beacon_dir = &beacon_dir;
+            async move {
+                execute(
+                    false,
+                    caller,
+                    contract_id,
+                    method_index,
+                    vec![],
+                    0,
+                    100_000,
+                    10,
+                    0,
+                    0,
+                    state_holder,
+                    programs_repo,
+                    rollup_dir,
+                    0,
+                    beacon_dir,
+                    0,
+                    &mut Accountant::new(),
+                    &mut TransientStorage::new(),
+                    &mut ExecTrace::new(),
+                )
+                .await
+                .map(|(return_items, _, _)| return_items)
+            }
+        };
+
+        // The caller resumes with the callee's return items in their order followed by a true
+        // flag, and with only a false flag after a failed call: the items the failed callee pushed
+        // are dropped. OP_RETURNALL returns the caller's stack top item first.
+        let expected = vec![
+            StackItem::false_item(),
+            StackItem::true_item(),
+            StackItem::new(vec![0x01]),
+            StackItem::new(vec![0x03]),
+            StackItem::new(vec![0x02]),
+            StackItem::new(vec![0x01]),
+        ];
+
+        // An account-initiated call reaches the internal methods through OP_CALL.
+        let alice = Caller::new_account([0xaau8; 32]);
+        match call(alice, 0).await {
+            Ok(return_items) => assert_eq!(return_items, expected),
+            Err(error) => return Err(error.to_string()),
+        }
+
+        // So does a contract-initiated call.
+        let contract = Caller::new_contract([0xbbu8; 32]);
+        match call(contract, 0).await {
+            Ok(return_items) => assert_eq!(return_items, expected),
+            Err(error) => return Err(error.to_string()),
+        }
+
+        // Internal methods are not callable directly, whoever the caller is.
+        for caller in [alice, contract] {
+            assert!(matches!(
+                call(caller, 1).await,
+                Err(ExecutionError::InvalidInternalCallError)
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn save_all_deployments_test() -> Result<(), String> {
+        let program = |name: &str| {
+            let method = ProgramMethod::new(
+                "noop".to_string(),
+                MethodType::Callable,
+                vec![],
+                vec![
+                    Opcode::OP_PUSHDATA(OP_PUSHDATA(name.as_bytes().to_vec())),
+                    Opcode::OP_TRUE(OP_TRUE),
+                    Opcode::OP_TRUE(OP_TRUE),
+                    Opcode::OP_RETURNALL(OP_RETURNALL),
+                ],
+            )
+            .unwrap();
+            Program::new(name.to_string(), [0x00; 32], vec![method]).unwrap()
+        };
+
+        let state_holder = StateHolder::new_in_memory().unwrap();
+        let programs_repo = ProgramsRepo::new_in_memory().unwrap();
+        let rollup_dir = RollupDirectory::new_in_memory().unwrap();
+        let beacon_dir = BeaconDirectory::new_in_memory().unwrap();
+        let contract_registery = ContractRegistery::new_in_memory().unwrap();
+        let vtxo_set = VTXOSet::new_in_memory().unwrap();
+        let operator_key = Point::generator();
+
+        // A failed save keeps the deployments pending.
+        let first = program("first");
+        {
+            let mut _programs_repo = programs_repo.lock().await;
+            _programs_repo
+                .insert_ephemeral(&first.contract_id(), &first)
+                .unwrap();
+
+            let mut programs = HashMap::new();
+            programs.insert(first.contract_id(), first.clone());
+            _programs_repo.insert_multi(&programs).unwrap();
+
+            assert!(matches!(
+                _programs_repo.save_all_deployments(),
+                Err(RepoInsertError::ContractIdAlreadyExists(_))
+            ));
+            assert_eq!(
+                _programs_repo.pending_deployments(),
+                vec![first.contract_id()]
+            );
+            _programs_repo.rollback_all();
+        }
+
+        // A saved deployment is registered and stored.
+        let second = program("second");
+        {
+            let mut _programs_repo = programs_repo.lock().await;
+            _programs_repo
+                .insert_ephemeral(&second.contract_id(), &second)
+                .unwrap();
+        }
+
+        let mut exec_ctx = ExecCtx::new(
+            &state_holder,
+            &programs_repo,
+            &rollup_dir,
+            10,
+            0,
+            0,
+            &beacon_dir,
+            0,
+        );
+        exec_ctx
+            .save_all(&contract_registery, &vtxo_set, operator_key)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        {
+            let _contract_registery = contract_registery.lock().await;
+            assert!(_contract_registery.is_registered(second.contract_id()));
+        }
+        {
+            let _programs_repo = programs_repo.lock().await;
+            assert!(_programs_repo.pending_deployments().is_empty());
+            assert!(_programs_repo.is_deployed(&second.contract_id()));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn save_all_atomic_test() -> Result<(), String> {
+        let method = ProgramMethod::new(
+            "noop".to_string(),
+            MethodType::Callable,
+            vec![],
+            vec![
+                Opcode::OP_PUSHDATA(OP_PUSHDATA(b"atomic".to_vec())),
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_RETURNALL(OP_RETURNALL),
+            ],
+        )
+        .unwrap();
+        let program = Program::new("atomic".to_string(), [0x00; 32], vec![method]).unwrap();
+        let contract_id = program.contract_id();
+
+        let state_holder = StateHolder::new_in_memory().unwrap();
+        let programs_repo = ProgramsRepo::new_in_memory().unwrap();
+        let rollup_dir = RollupDirectory::new_in_memory().unwrap();
+        let beacon_dir = BeaconDirectory::new_in_memory().unwrap();
+        let contract_registery = ContractRegistery::new_in_memory().unwrap();
+        let vtxo_set = VTXOSet::new_in_memory().unwrap();
+        let operator_key = Point::generator();
+
+        // The contract id is registered already, so that the registration fails last.
+        {
+            let mut _contract_registery = contract_registery.lock().await;
+            assert!(_contract_registery.batch_update(vec![contract_id], HashMap::new()));
+        }
+
+        // A saved state value to be overwritten, and a new one.
+        let (saved_key, new_key) = (b"saved".to_vec(), b"new".to_vec());
+        {
+            let mut _state_holder = state_holder.lock().await;
+            _state_holder.insert_value(&contract_id, &saved_key, &b"before".to_vec());
+            _state_holder.save_all_executions().unwrap();
+
+            _state_holder.insert_value(&contract_id, &saved_key, &b"after".to_vec());
+            _state_holder.insert_value(&contract_id, &new_key, &b"after".to_vec());
+        }
+        {
+            let mut _programs_repo = programs_repo.lock().await;
+            _programs_repo
+                .insert_ephemeral(&contract_id, &program)
+                .unwrap();
+        }
+
+        let mut exec_ctx = ExecCtx::new(
+            &state_holder,
+            &programs_repo,
+            &rollup_dir,
+            10,
+            0,
+            0,
+            &beacon_dir,
+            0,
+        );
+        assert!(matches!(
+            exec_ctx
+                .save_all(&contract_registery, &vtxo_set, operator_key)
+                .await,
+            Err(ExecCtxSaveError::ContractRegisteryUpdateError)
+        ));
+        exec_ctx.flush_all().await;
+
+        // Neither the program nor the states are saved.
+        {
+            let _programs_repo = programs_repo.lock().await;
+            assert!(!_programs_repo.is_deployed(&contract_id));
+        }
+        {
+            let _state_holder = state_holder.lock().await;
+            assert_eq!(
+                _state_holder.get_value(&saved_key, &contract_id),
+                Some(b"before".to_vec())
+            );
+            assert_eq!(_state_holder.get_value(&new_key, &contract_id), None);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn save_all_settlement_test() -> Result<(), String> {
+        let method = ProgramMethod::new(
+            "noop".to_string(),
+            MethodType::Callable,
+            vec![],
+            vec![
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_BOOLAND(OP_BOOLAND),
+                Opcode::OP_RETURNALL(OP_RETURNALL),
+            ],
+        )
+        .unwrap();
+        let program = Program::new("settle".to_string(), [0x00; 32], vec![method]).unwrap();
+        let contract_id = program.contract_id();
+
+        let state_holder = StateHolder::new_in_memory().unwrap();
+        let programs_repo = ProgramsRepo::new_in_memory().unwrap();
+        let rollup_dir = RollupDirectory::new_in_memory().unwrap();
+        let beacon_dir = BeaconDirectory::new_in_memory().unwrap();
+        let contract_registery = ContractRegistery::new_in_memory().unwrap();
+        let vtxo_set = VTXOSet::new_in_memory().unwrap();
+        let operator_key = Point::generator();
+
+        {
+            let mut programs = HashMap::new();
+            programs.insert(contract_id, program);
+            let mut _programs_repo = programs_repo.lock().await;
+            _programs_repo.insert_multi(&programs).unwrap();
+        }
+
+        // Alice holds a single VTXO.
+        let alice = Point::generator();
+        let alice_key = alice.serialize_xonly();
+        let balance = 2_000_000;
+        {
+            let vtxo = VTXO::new(
+                alice,
+                operator_key,
+                Some(OutPoint::new(Txid::from_byte_array([0x01; 32]), 0)),
+                Some(balance),
+                Some(0),
+                Some(0),
+            );
+            let mut _vtxo_set = vtxo_set.lock().await;
+            assert!(_vtxo_set.insert_vtxo(&vtxo));
+        }
+
+        let base_ops_price = 10;
+        let call = |account_key: [u8; 32]| {
+            Call::new(
+                account_key,
+                contract_id,
+                0,
+                vec![],
+                100_000,
+                base_ops_price,
+                None,
+                None,
+            )
+        };
+        let new_exec_ctx = || {
+            ExecCtx::new(
+                &state_holder,
+                &programs_repo,
+                &rollup_dir,
+                base_ops_price,
+                0,
+                0,
+                &beacon_dir,
+                1,
+            )
+        };
+
+        // The fees charged are debited from the payer, and the rest of its reservation is not.
+        let mut exec_ctx = new_exec_ctx();
+        exec_ctx
+            .exec_insert_call(call(alice_key))
+            .await
+            .map_err(|e| e.to_string())?;
+        let fees_spent = exec_ctx.fee_list().get(&alice_key).copied().unwrap();
+        assert!(fees_spent > 0);
+        exec_ctx
+            .save_all(&contract_registery, &vtxo_set, operator_key)
+            .await
+            .map_err(|e| e.to_string())?;
+        {
+            let _vtxo_set = vtxo_set.lock().await;
+            assert_eq!(
+                _vtxo_set.balance_by_account_key(alice_key),
+                balance - fees_spent
+            );
+            assert_eq!(_vtxo_set.vtxo_set_by_account_key(&alice).len(), 1);
+        }
+
+        // The moves of a passed bundle settle after all calls, from the sender to the recipient.
+        let bob = Scalar::two().base_point_mul();
+        let bob_key = bob.serialize_xonly();
+        let amount = 100_000;
+        let bundle = Bundle::new(vec![
+            BundleItem::Call(call(alice_key)),
+            BundleItem::Move(Move::new(
+                Account::new(alice, None, None).unwrap(),
+                Account::new(bob, None, None).unwrap(),
+                amount,
+            )),
+        ])
+        .unwrap();
+        let mut exec_ctx = new_exec_ctx();
+        exec_ctx
+            .exec_insert_bundle(bundle)
+            .await
+            .map_err(|e| e.to_string())?;
+        let bundle_fees_spent = exec_ctx.fee_list().get(&alice_key).copied().unwrap();
+        exec_ctx
+            .save_all(&contract_registery, &vtxo_set, operator_key)
+            .await
+            .map_err(|e| e.to_string())?;
+        {
+            let _vtxo_set = vtxo_set.lock().await;
+            assert_eq!(
+                _vtxo_set.balance_by_account_key(alice_key),
+                balance - fees_spent - bundle_fees_spent - amount as u64
+            );
+            assert_eq!(_vtxo_set.balance_by_account_key(bob_key), amount as u64);
+        }
+
+        // A payer that cannot cover its reservation fails the settlement, and nothing is saved.
+        let carol_key = [0xccu8; 32];
+        let mut exec_ctx = new_exec_ctx();
+        exec_ctx
+            .exec_insert_call(call(carol_key))
+            .await
+            .map_err(|e| e.to_string())?;
+        assert!(matches!(
+            exec_ctx
+                .save_all(&contract_registery, &vtxo_set, operator_key)
+                .await,
+            Err(ExecCtxSaveError::InsufficientBalanceError)
+        ));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,229 @@
+#[cfg(test)]
+mod harness_tests {
+    use cube::{
+        constructive::calldata::{element::element::CallElement, element_type::CallElementType},
+        executive::{
+            exec::{exec_error::ExecutionError, profiler::OpsCounterKind},
+            harness::harness::TestHarness,
+            opcode::{
+                opcode::Opcode,
+                opcodes::{
+                    arithmetic::op_not::OP_NOT,
+                    bitwise::op_equalverify::OP_EQUALVERIFY,
+                    callinfo::op_caller::OP_CALLER,
+                    flow::{
+                        op_nop::OP_NOP, op_returnall::OP_RETURNALL, op_returnerr::OP_RETURNERR,
+                    },
+                    push::{op_pushdata::OP_PUSHDATA, op_true::OP_TRUE},
+                    storage::op_swrite::OP_SWRITE,
+                },
+            },
+            program::{
+                method::{method::ProgramMethod, method_type::MethodType},
+                program::Program,
+            },
+        },
+    };
+
+    fn test_program(account_key: [u8; 32]) -> Program {
+        // Writes the given value under "key".
+        let store = ProgramMethod::new(
+            "store".to_string(),
+            MethodType::Callable,
+            vec![CallElementType::Varbytes],
+            vec![
+                Opcode::OP_PUSHDATA(OP_PUSHDATA(b"key".to_vec())),
+                Opcode::OP_SWRITE(OP_SWRITE),
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_RETURNALL(OP_RETURNALL),
+            ],
+        )
+        .unwrap();
+
+        // Writes "oops" under "key", then fails.
+        let fail = ProgramMethod::new(
+            "fail".to_string(),
+            MethodType::Callable,
+            vec![],
+            vec![
+                Opcode::OP_PUSHDATA(OP_PUSHDATA(b"oops".to_vec())),
+                Opcode::OP_PUSHDATA(OP_PUSHDATA(b"key".to_vec())),
+                Opcode::OP_SWRITE(OP_SWRITE),
+                Opcode::OP_PUSHDATA(OP_PUSHDATA(b"nope".to_vec())),
+                Opcode::OP_RETURNERR(OP_RETURNERR),
+            ],
+        )
+        .unwrap();
+
+        // Passes only if called by the given account.
+        let whoami = ProgramMethod::new(
+            "whoami".to_string(),
+            MethodType::Callable,
+            vec![],
+            vec![
+                Opcode::OP_CALLER(OP_CALLER),
+                Opcode::OP_PUSHDATA(OP_PUSHDATA(account_key.to_vec())),
+                Opcode::OP_EQUALVERIFY(OP_EQUALVERIFY),
+                Opcode::OP_NOT(OP_NOT),
+                Opcode::OP_RETURNALL(OP_RETURNALL),
+            ],
+        )
+        .unwrap();
+
+        // Returns the caller as is, which leaves two items on the stack.
+        let caller = ProgramMethod::new(
+            "caller".to_string(),
+            MethodType::Callable,
+            vec![],
+            vec![
+                Opcode::OP_CALLER(OP_CALLER),
+                Opcode::OP_NOP(OP_NOP),
+                Opcode::OP_NOP(OP_NOP),
+                Opcode::OP_RETURNALL(OP_RETURNALL),
+            ],
+        )
+        .unwrap();
+
+        Program::new(
+            "harness".to_string(),
+            [0x00; 32],
+            vec![store, fail, whoami, caller],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn harness_test() -> Result<(), String> {
+        let mut harness = TestHarness::new().ok_or("Failed to construct harness.")?;
+
+        let alice = [0xaau8; 32];
+        let contract_id = harness
+            .deploy(&test_program(alice))
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+
+        harness.impersonate(alice);
+
+        // Store a value.
+        let result = harness
+            .call(
+                contract_id,
+                0,
+                vec![CallElement::Varbytes(b"hello".to_vec())],
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        assert!(result.ops_spent > 0);
+        assert_eq!(result.fees_spent, result.ops_spent as u64);
+        assert_eq!(
+            harness.storage_value(contract_id, b"key").await,
+            Some(b"hello".to_vec())
+        );
+
+        // A failing call is located, and its writes are reverted.
+        let error = harness
+            .call(contract_id, 1, vec![])
+            .await
+            .err()
+            .ok_or("Expected the call to fail.")?;
+        assert_eq!(error.revert_reason(), Some("nope".to_string()));
+        match &error {
+            ExecutionError::RevertError(context, _) => {
+                assert_eq!(context.contract_id, contract_id);
+                assert_eq!(context.method_index, 1);
+                assert_eq!(context.pc, Some(4));
+            }
+            _ => return Err("Expected a revert error.".to_string()),
+        }
+        assert_eq!(
+            harness.storage_value(contract_id, b"key").await,
+            Some(b"hello".to_vec())
+        );
+
+        // Calls are made from the impersonated account.
+        harness
+            .call(contract_id, 2, vec![])
+            .await
+            .map_err(|e| e.to_string())?;
+        harness.impersonate([0xbbu8; 32]);
+        assert!(harness.call(contract_id, 2, vec![]).await.is_err());
+
+        // Calls must end with a single true item, as in a session.
+        let error = harness
+            .call(contract_id, 3, vec![])
+            .await
+            .err()
+            .ok_or("Expected the call to fail.")?;
+        assert!(matches!(
+            error,
+            ExecutionError::RevertError(_, ref inner)
+                if matches!(**inner, ExecutionError::InvalidStackEndingError)
+        ));
+
+        // Nothing is paid.
+        assert!(harness.allocs().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn harness_profiler_test() -> Result<(), String> {
+        let mut harness = TestHarness::new().ok_or("Failed to construct harness.")?;
+
+        let contract_id = harness
+            .deploy(&test_program([0xaau8; 32]))
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+
+        harness.enable_profiler();
+
+        // Profile two passing calls and a failing one.
+        let mut ops_spent = 0;
+        for value in [b"hello".to_vec(), b"world".to_vec()] {
+            let result = harness
+                .call(contract_id, 0, vec![CallElement::Varbytes(value)])
+                .await
+                .map_err(|e| e.to_string())?;
+            ops_spent += result.ops_spent as u64;
+        }
+        assert!(harness.call(contract_id, 1, vec![]).await.is_err());
+
+        let profiler = harness.profiler().ok_or("Expected a profiler.")?;
+        assert_eq!(profiler.calls(), 3);
+
+        // The ops of the passing calls add up, and the failing call adds the ops it reached.
+        let internal_ops = profiler.total_ops(OpsCounterKind::Internal);
+        assert!(internal_ops > ops_spent);
+        assert_eq!(internal_ops, profiler.total_ops(OpsCounterKind::External));
+
+        // Ops are aggregated by script offset.
+        let swrite = profiler
+            .entries()
+            .into_iter()
+            .find(|entry| entry.method_index == 0 && entry.pc == 1)
+            .ok_or("Expected an OP_SWRITE entry.")?;
+        assert_eq!(swrite.opcode, "OP_SWRITE");
+        assert_eq!(swrite.hits, 2);
+        assert!(swrite.internal_ops > 0);
+
+        // Push data is not part of the opcode kind.
+        assert!(profiler
+            .ops_by_opcode(OpsCounterKind::Internal)
+            .contains_key("OP_PUSHDATA"));
+
+        // The folded output has one call stack per line, followed by its ops.
+        let folded = profiler.folded(OpsCounterKind::Internal);
+        let swrite_line = format!(
+            "{}:0;OP_SWRITE@1 {}",
+            hex::encode(contract_id),
+            swrite.internal_ops
+        );
+        assert!(folded.lines().any(|line| line == swrite_line));
+
+        let json = profiler.json();
+        assert_eq!(json["calls"], 3);
+        assert_eq!(json["internal_ops"], internal_ops);
+
+        Ok(())
+    }
+}
@@ -2,12 +2,13 @@
 mod noist_tests {
     use cube::{
         constructive::txo::projector::{Projector, ProjectorTag},
-        inscriptive::lp::dir::LPDirectory,
+        inscriptive::{beacon::dir::BeaconDirectory, lp::dir::LPDirectory},
         operative::Chain,
         transmutative::{
             hash::Hash,
             musig::session::MusigSessionCtx,
             noist::{
+                beacon::RandomnessBeacon,
                 dkg::package::DKGPackage,
                 manager::DKGManager,
                 setup::{keymap::VSEKeyMap, setup::VSESetup},
@@ -416,4 +417,93 @@ mod noist_tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn randomness_beacon_test() -> Result<(), String> {
+        let secret_key = [0x11u8; 32];
+        let group_key = Scalar::from_slice(&secret_key)
+            .unwrap()
+            .base_point_mul()
+            .serialize_xonly();
+
+        let rogue_secret_key = [0x22u8; 32];
+        let rogue_key = Scalar::from_slice(&rogue_secret_key)
+            .unwrap()
+            .base_point_mul()
+            .serialize_xonly();
+
+        let beacon_dir = BeaconDirectory::new_in_memory().ok_or("Failed to create beacon dir.")?;
+        let mut _beacon_dir = beacon_dir.lock().await;
+        assert_eq!(_beacon_dir.next_session_height(), 0);
+
+        // The first beacon chains to all zeros.
+        let message = _beacon_dir.beacon_message(7);
+        assert_eq!(message, RandomnessBeacon::signing_message(7, [0x00; 32]));
+
+        let signature = schnorr::sign(secret_key, message, SchnorrSigningMode::BIP340).unwrap();
+        let beacon = RandomnessBeacon::new(7, group_key, [0x00; 32], signature);
+
+        // The beacon verifies against the quorum key only, and survives a round trip.
+        assert!(beacon.verify(group_key));
+        assert!(!beacon.verify(rogue_key));
+        assert_eq!(
+            RandomnessBeacon::from_slice(&beacon.serialize()),
+            Some(beacon)
+        );
+
+        // A beacon signed by another key is rejected, even if it carries that key.
+        let rogue_signature =
+            schnorr::sign(rogue_secret_key, message, SchnorrSigningMode::BIP340).unwrap();
+        let rogue_beacon = RandomnessBeacon::new(7, rogue_key, [0x00; 32], rogue_signature);
+        assert!(!_beacon_dir.insert_beacon(&rogue_beacon, group_key));
+
+        // A tampered signature is rejected.
+        let mut tampered_signature = signature;
+        tampered_signature[63] ^= 0x01;
+        let tampered_beacon = RandomnessBeacon::new(7, group_key, [0x00; 32], tampered_signature);
+        assert!(!_beacon_dir.insert_beacon(&tampered_beacon, group_key));
+
+        assert!(_beacon_dir.insert_beacon(&beacon, group_key));
+
+        // Beacons are never overwritten.
+        assert!(!_beacon_dir.insert_beacon(&beacon, group_key));
+
+        // A signature cannot be replayed at another height.
+        let replayed_beacon = RandomnessBeacon::new(8, group_key, [0x00; 32], signature);
+        assert!(!replayed_beacon.verify(group_key));
+        let replayed_beacon = RandomnessBeacon::new(8, group_key, beacon.randomness(), signature);
+        assert!(!_beacon_dir.insert_beacon(&replayed_beacon, group_key));
+
+        // The next beacon chains to the randomness of the previous one.
+        let next_message = _beacon_dir.beacon_message(8);
+        assert_eq!(
+            next_message,
+            RandomnessBeacon::signing_message(8, beacon.randomness())
+        );
+        let next_signature =
+            schnorr::sign(secret_key, next_message, SchnorrSigningMode::BIP340).unwrap();
+
+        let unchained_beacon = RandomnessBeacon::new(
+            8,
+            group_key,
+            [0x00; 32],
+            schnorr::sign(
+                secret_key,
+                RandomnessBeacon::signing_message(8, [0x00; 32]),
+                SchnorrSigningMode::BIP340,
+            )
+            .unwrap(),
+        );
+        assert!(!_beacon_dir.insert_beacon(&unchained_beacon, group_key));
+
+        let next_beacon = RandomnessBeacon::new(8, group_key, beacon.randomness(), next_signature);
+        assert!(_beacon_dir.insert_beacon(&next_beacon, group_key));
+        assert_ne!(beacon.randomness(), next_beacon.randomness());
+        assert_eq!(_beacon_dir.beacon(8), Some(next_beacon));
+
+        // Beacons are synced from the one after the latest.
+        assert_eq!(_beacon_dir.next_session_height(), 9);
+
+        Ok(())
+    }
 }
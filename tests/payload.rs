@@ -0,0 +1,617 @@
+#[cfg(test)]
+mod payload_tests {
+    use bitcoin::{
+        absolute::LockTime,
+        block::{Header, Version as BlockVersion},
+        hashes::Hash,
+        opcodes::{all::OP_IF, OP_FALSE},
+        script::Builder,
+        transaction::Version,
+        Block, BlockHash, CompactTarget, OutPoint, Transaction, TxIn, TxMerkleNode, Txid, Witness,
+    };
+    use cube::{
+        constructive::{
+            entity::account::account::Account,
+            entry::{
+                combinator::combinators::{
+                    add::add::Add, r#move::r#move::Move, sub::sub::Sub, swapout::swapout::Swapout,
+                },
+                entry::Entry,
+                validation::{
+                    validation_ctx::EntryValidationCtx, validation_error::EntryValidationError,
+                },
+            },
+            payload::{
+                anchor::SessionAnchor,
+                envelope::{MAX_ENVELOPE_CHUNK_SIZE, MAX_SESSION_PAYLOAD_SIZE},
+                envelope_error::SessionPayloadEnvelopeError,
+                payload::{SessionPayload, SESSION_PAYLOAD_HEADER_LEN},
+                payload_error::{SessionPayloadDeserializeError, SessionPayloadEntriesDecodeError},
+            },
+            txn::txholder::TxHolder,
+            txo::vtxo::VTXO,
+        },
+        inscriptive::{
+            blacklist::BlacklistDirectory,
+            epoch::{dir::EpochDirectory, epoch::Epoch},
+            registery::{account_registery::ACCOUNT_REGISTERY, registery::Registery},
+            repo::repo::ProgramsRepo,
+            set::{utxo_set::UTXOSet, vtxo_set::VTXOSet},
+        },
+        operative::{sync::rollup::sync_session_payloads, Chain},
+        transmutative::{
+            musig::{keyagg::MusigKeyAggCtx, session::MusigSessionCtx},
+            secp::schnorr::{self, Bytes32, SchnorrSigningMode},
+        },
+    };
+    use secp::{Point, Scalar};
+
+    fn account(secret: u8) -> Account {
+        let key = [secret; 32]
+            .secret_to_public()
+            .unwrap()
+            .to_even_point()
+            .unwrap();
+        Account::new(key, None, None).unwrap()
+    }
+
+    // Co-signs the session auth message of the payload entries with the given secrets.
+    fn session_sig(payload: &SessionPayload, entries: &[Entry], secrets: &[[u8; 32]]) -> [u8; 64] {
+        // Secrets are negated to their even keys, as account keys are.
+        let signers: Vec<(Scalar, Point)> = secrets
+            .iter()
+            .map(|secret| {
+                let secret_key = Scalar::from_slice(secret).unwrap();
+                let secret_key = secret_key.negate_if(secret_key.base_point_mul().parity());
+                (secret_key, secret_key.base_point_mul())
+            })
+            .collect();
+
+        let keys: Vec<Point> = signers.iter().map(|(_, key)| key.to_owned()).collect();
+        let key_agg_ctx = MusigKeyAggCtx::new(&keys, None).unwrap();
+
+        let message = SessionPayload::auth_msg(
+            payload.session_id(),
+            Chain::Signet,
+            payload.base_ops_price(),
+            payload.anchor(),
+            entries,
+        );
+        let mut musig_ctx = MusigSessionCtx::new(&key_agg_ctx, message).unwrap();
+
+        let nonces: Vec<(Scalar, Scalar)> = (0..signers.len())
+            .map(|index| {
+                (
+                    Scalar::from_slice(&[0x10 + index as u8; 32]).unwrap(),
+                    Scalar::from_slice(&[0x40 + index as u8; 32]).unwrap(),
+                )
+            })
+            .collect();
+
+        for ((_, key), (hiding, binding)) in signers.iter().zip(nonces.iter()) {
+            assert!(musig_ctx.insert_nonce(
+                key.to_owned(),
+                hiding.base_point_mul(),
+                binding.base_point_mul()
+            ));
+        }
+
+        for ((secret_key, key), (hiding, binding)) in signers.iter().zip(nonces.iter()) {
+            let partial_sig = musig_ctx
+                .partial_sign(secret_key.to_owned(), hiding.to_owned(), binding.to_owned())
+                .unwrap();
+            assert!(musig_ctx.insert_partial_sig(key.to_owned(), partial_sig));
+        }
+
+        musig_ctx.full_agg_sig().unwrap()
+    }
+
+    fn tx_with_witness(witness: Vec<Vec<u8>>) -> Transaction {
+        Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn {
+                witness: Witness::from_slice(&witness),
+                ..TxIn::default()
+            }],
+            output: vec![],
+        }
+    }
+
+    fn block(txdata: Vec<Transaction>) -> Block {
+        Block {
+            header: Header {
+                version: BlockVersion::ONE,
+                prev_blockhash: BlockHash::all_zeros(),
+                merkle_root: TxMerkleNode::all_zeros(),
+                time: 0,
+                bits: CompactTarget::from_consensus(0),
+                nonce: 0,
+            },
+            txdata,
+        }
+    }
+
+    #[tokio::test]
+    async fn session_payload_test() -> Result<(), String> {
+        let registery = Registery::new_in_memory().unwrap();
+        let repo = ProgramsRepo::new_in_memory().unwrap();
+        let epoch_dir = EpochDirectory::new_in_memory().unwrap();
+        let utxo_set = UTXOSet::new_in_memory().unwrap();
+        let vtxo_set = VTXOSet::new_in_memory().unwrap();
+        let blacklist_dir = BlacklistDirectory::new_in_memory().unwrap();
+
+        let anchor = SessionAnchor::new(100_000, 1_700_000_000);
+
+        let alice = account(0x01);
+        let bob = account(0x02);
+        let carol = account(0x03);
+        let dave = account(0x04);
+        let operator = account(0x05);
+
+        let vtxo = |owner: Account, vout: u32, value: u64| {
+            VTXO::new(
+                owner.key(),
+                operator.key(),
+                Some(OutPoint {
+                    txid: Txid::from_byte_array([0x04; 32]),
+                    vout,
+                }),
+                Some(value),
+                Some(1),
+                Some(100),
+            )
+        };
+
+        let entries = vec![
+            Entry::new_move(alice, Move::new(alice, bob, 1_000), None, None),
+            Entry::new_add(bob, Add::new(bob, 50_000), None, None),
+            Entry::new_sub(carol, Sub::new(carol, 7), None, None),
+            Entry::new_swapout(
+                dave,
+                Swapout::new_p2tr(dave, 25_000, [0xcc; 32]),
+                None,
+                None,
+            ),
+        ];
+
+        let mut payload = SessionPayload::from_entries(
+            [0xaa; 32], &entries, &registery, &repo, &vtxo_set, 10, anchor,
+        )
+        .await
+        .map_err(|e| format!("{:?}", e))?;
+        assert_eq!(payload.operator_sig(), [0x00; 64]);
+        assert_eq!(payload.session_sig(), [0x00; 64]);
+        let quorum_key = account(0x06).key();
+        payload.set_session_sig(session_sig(
+            &payload,
+            &entries,
+            &[[0x01; 32], [0x02; 32], [0x03; 32], [0x04; 32], [0x06; 32]],
+        ));
+        payload.set_operator_sig([0xbb; 64]);
+        assert_eq!(payload.session_id(), [0xaa; 32]);
+        assert_eq!(payload.operator_sig(), [0xbb; 64]);
+        assert_eq!(payload.base_ops_price(), 10);
+        assert_eq!(payload.anchor(), anchor);
+        assert_eq!(payload.entry_count(), 4);
+
+        // Byte serialization round-trips.
+        let bytes = payload.serialize();
+        assert_eq!(
+            bytes.len(),
+            SESSION_PAYLOAD_HEADER_LEN + payload.entries().len()
+        );
+        assert_eq!(SessionPayload::deserialize(&bytes), Ok(payload.clone()));
+
+        // The operator signature covers the anchor.
+        let mut reanchored = bytes.clone();
+        reanchored[168] ^= 0x01;
+        let reanchored =
+            SessionPayload::deserialize(&reanchored).map_err(|e| format!("{:?}", e))?;
+        assert_eq!(
+            reanchored.anchor().bitcoin_height(),
+            anchor.bitcoin_height() ^ 0x01
+        );
+        assert_ne!(reanchored.signing_message(), payload.signing_message());
+
+        // Entries are validated as they are decoded.
+        {
+            let mut _vtxo_set = vtxo_set.lock().await;
+            assert!(_vtxo_set.insert_vtxo(&vtxo(alice, 0, 1_000)));
+            assert!(_vtxo_set.insert_vtxo(&vtxo(bob, 1, 50_000)));
+            assert!(_vtxo_set.insert_vtxo(&vtxo(dave, 2, 24_999)));
+        }
+
+        let tx = tx_with_witness(vec![]);
+        let mut txholder = TxHolder::new(&utxo_set, tx.clone(), 0).unwrap();
+        let mut validation_ctx = EntryValidationCtx::new(
+            Chain::Signet,
+            &registery,
+            &vtxo_set,
+            &utxo_set,
+            &blacklist_dir,
+            100_000,
+        );
+        assert_eq!(
+            payload
+                .decode_entries(
+                    &mut txholder,
+                    &epoch_dir,
+                    &repo,
+                    &mut validation_ctx,
+                    quorum_key
+                )
+                .await
+                .map(|_| ()),
+            Err(SessionPayloadEntriesDecodeError::EntryValidationError(
+                3,
+                EntryValidationError::InsufficientBalance(dave.key().serialize_xonly())
+            ))
+        );
+
+        // Entries decode back in order.
+        {
+            let mut _vtxo_set = vtxo_set.lock().await;
+            assert!(_vtxo_set.insert_vtxo(&vtxo(dave, 3, 1)));
+        }
+
+        let mut txholder = TxHolder::new(&utxo_set, tx.clone(), 0).unwrap();
+        let mut validation_ctx = EntryValidationCtx::new(
+            Chain::Signet,
+            &registery,
+            &vtxo_set,
+            &utxo_set,
+            &blacklist_dir,
+            100_000,
+        );
+        let decoded = payload
+            .decode_entries(
+                &mut txholder,
+                &epoch_dir,
+                &repo,
+                &mut validation_ctx,
+                quorum_key,
+            )
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+        assert!(decoded == entries);
+
+        // Entries must be co-signed in the session by their accounts and the quorum key.
+        let reanchored = SessionPayload::new(
+            payload.session_id(),
+            payload.operator_sig(),
+            payload.session_sig(),
+            10,
+            SessionAnchor::new(99_999, anchor.timestamp()),
+            4,
+            payload.entries(),
+        );
+        for (payload, quorum_key) in [
+            (reanchored, quorum_key),
+            (payload.clone(), account(0x07).key()),
+        ] {
+            let mut txholder = TxHolder::new(&utxo_set, tx.clone(), 0).unwrap();
+            let mut validation_ctx = EntryValidationCtx::new(
+                Chain::Signet,
+                &registery,
+                &vtxo_set,
+                &utxo_set,
+                &blacklist_dir,
+                100_000,
+            );
+            assert_eq!(
+                payload
+                    .decode_entries(
+                        &mut txholder,
+                        &epoch_dir,
+                        &repo,
+                        &mut validation_ctx,
+                        quorum_key
+                    )
+                    .await
+                    .map(|_| ()),
+                Err(SessionPayloadEntriesDecodeError::InvalidSessionSignature)
+            );
+        }
+
+        // An account carries at most one entry per payload.
+        let duplicate = SessionPayload::from_entries(
+            [0xaa; 32],
+            &[entries[0].clone(), entries[0].clone()],
+            &registery,
+            &repo,
+            &vtxo_set,
+            10,
+            anchor,
+        )
+        .await
+        .map_err(|e| format!("{:?}", e))?;
+        let mut txholder = TxHolder::new(&utxo_set, tx.clone(), 0).unwrap();
+        let mut validation_ctx = EntryValidationCtx::new(
+            Chain::Signet,
+            &registery,
+            &vtxo_set,
+            &utxo_set,
+            &blacklist_dir,
+            100_000,
+        );
+        assert_eq!(
+            duplicate
+                .decode_entries(
+                    &mut txholder,
+                    &epoch_dir,
+                    &repo,
+                    &mut validation_ctx,
+                    quorum_key
+                )
+                .await
+                .map(|_| ()),
+            Err(SessionPayloadEntriesDecodeError::DuplicateAccount(1))
+        );
+
+        // Extra bytes past the declared entries are rejected.
+        let mut padded = payload.entries();
+        padded.push(0x80);
+        let padded = SessionPayload::new(
+            [0xaa; 32],
+            [0xbb; 64],
+            payload.session_sig(),
+            10,
+            anchor,
+            4,
+            padded,
+        );
+        let mut txholder = TxHolder::new(&utxo_set, tx, 0).unwrap();
+        let mut validation_ctx = EntryValidationCtx::new(
+            Chain::Signet,
+            &registery,
+            &vtxo_set,
+            &utxo_set,
+            &blacklist_dir,
+            100_000,
+        );
+        assert_eq!(
+            padded
+                .decode_entries(
+                    &mut txholder,
+                    &epoch_dir,
+                    &repo,
+                    &mut validation_ctx,
+                    quorum_key
+                )
+                .await
+                .map(|_| ()),
+            Err(SessionPayloadEntriesDecodeError::TrailingBits)
+        );
+
+        // Malformed headers.
+        assert_eq!(
+            SessionPayload::deserialize(&[]),
+            Err(SessionPayloadDeserializeError::EmptyPayload)
+        );
+        let mut unknown_version = bytes.clone();
+        unknown_version[0] = 0x02;
+        assert_eq!(
+            SessionPayload::deserialize(&unknown_version),
+            Err(SessionPayloadDeserializeError::UnsupportedVersion(0x02))
+        );
+        assert_eq!(
+            SessionPayload::deserialize(&bytes[..50]),
+            Err(SessionPayloadDeserializeError::HeaderLengthError(50))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn session_payload_sync_test() -> Result<(), String> {
+        let registery = Registery::new_in_memory().unwrap();
+        let repo = ProgramsRepo::new_in_memory().unwrap();
+        let epoch_dir = EpochDirectory::new_in_memory().unwrap();
+        let utxo_set = UTXOSet::new_in_memory().unwrap();
+        let vtxo_set = VTXOSet::new_in_memory().unwrap();
+        let blacklist_dir = BlacklistDirectory::new_in_memory().unwrap();
+
+        let anchor = SessionAnchor::new(100_000, 1_700_000_000);
+
+        let alice = account(0x01);
+        let bob = account(0x02);
+        let operator = account(0x05);
+
+        {
+            let mut _vtxo_set = vtxo_set.lock().await;
+            assert!(_vtxo_set.insert_vtxo(&VTXO::new(
+                alice.key(),
+                operator.key(),
+                Some(OutPoint {
+                    txid: Txid::from_byte_array([0x04; 32]),
+                    vout: 0,
+                }),
+                Some(10_000),
+                Some(1),
+                Some(100),
+            )));
+        }
+
+        let account_registery: ACCOUNT_REGISTERY = {
+            let _registery = registery.lock().await;
+            _registery.account_registery()
+        };
+
+        // The operator quorum key is active.
+        let quorum_secret = [0x06; 32];
+        {
+            let quorum_key = account(0x06).key();
+            let mut _epoch_dir = epoch_dir.lock().await;
+            assert!(_epoch_dir.insert_epoch(&Epoch::new(1, true, quorum_key, vec![])));
+        }
+
+        // Reveals a payload carrying the entry, signed with the given secret.
+        let reveal = |entry: Entry, secret: [u8; 32]| {
+            let registery = registery.clone();
+            let repo = repo.clone();
+            let vtxo_set = vtxo_set.clone();
+            async move {
+                let mut payload = SessionPayload::from_entries(
+                    [0xaa; 32],
+                    &[entry.clone()],
+                    &registery,
+                    &repo,
+                    &vtxo_set,
+                    10,
+                    anchor,
+                )
+                .await
+                .unwrap();
+                payload.set_session_sig(session_sig(
+                    &payload,
+                    &[entry],
+                    &[[0x01; 32], quorum_secret],
+                ));
+                let operator_sig = schnorr::sign(
+                    secret,
+                    payload.signing_message(),
+                    SchnorrSigningMode::BIP340,
+                )
+                .unwrap();
+                payload.set_operator_sig(operator_sig);
+                let envelope = payload.envelope().unwrap();
+                tx_with_witness(vec![vec![0x00; 64], envelope.to_bytes(), vec![0xc0; 33]])
+            }
+        };
+
+        let sync = |tx: Transaction, block_height: u32| {
+            let registery = registery.clone();
+            let repo = repo.clone();
+            let epoch_dir = epoch_dir.clone();
+            let utxo_set = utxo_set.clone();
+            let vtxo_set = vtxo_set.clone();
+            let blacklist_dir = blacklist_dir.clone();
+            async move {
+                let mut validation_ctx = EntryValidationCtx::new(
+                    Chain::Signet,
+                    &registery,
+                    &vtxo_set,
+                    &utxo_set,
+                    &blacklist_dir,
+                    block_height,
+                );
+                sync_session_payloads(&tx, &epoch_dir, &repo, &mut validation_ctx).await
+            }
+        };
+
+        // A payload not signed by the operator quorum is skipped, and its nonce is not consumed.
+        let entry = Entry::new_move(alice, Move::new(alice, bob, 1_000), None, None);
+        assert!(sync(reveal(entry.clone(), [0x07; 32]).await, 100_000)
+            .await
+            .is_empty());
+        assert_eq!(account_registery.lock().await.nonce_by_key(alice.key()), 0);
+
+        // A payload anchored past its block is skipped.
+        assert!(sync(reveal(entry.clone(), quorum_secret).await, 99_999)
+            .await
+            .is_empty());
+        assert_eq!(account_registery.lock().await.nonce_by_key(alice.key()), 0);
+
+        // The nonce is carried in the payload, and consumed once synced.
+        let tx = reveal(entry.clone(), quorum_secret).await;
+        assert!(sync(tx.clone(), 100_000).await == vec![entry.clone()]);
+        assert_eq!(account_registery.lock().await.nonce_by_key(alice.key()), 1);
+
+        // A replayed payload is skipped.
+        assert!(sync(tx, 100_000).await.is_empty());
+        assert_eq!(account_registery.lock().await.nonce_by_key(alice.key()), 1);
+
+        // The next entry is carried at the next nonce.
+        let mut next_entry = entry;
+        next_entry.set_nonce(1);
+        let synced = sync(reveal(next_entry.clone(), quorum_secret).await, 100_000).await;
+        assert!(synced == vec![next_entry]);
+        assert_eq!(synced[0].nonce(), 1);
+        assert_eq!(account_registery.lock().await.nonce_by_key(alice.key()), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn session_payload_envelope_test() -> Result<(), String> {
+        let entries = (0..3_000).map(|i| (i % 251) as u8).collect::<Vec<u8>>();
+        let payload = SessionPayload::new(
+            [0x01; 32],
+            [0x02; 64],
+            [0x03; 64],
+            10,
+            SessionAnchor::new(100_000, 1_700_000_000),
+            42,
+            entries,
+        );
+
+        // Chunks stay within the push size limit and reassemble to the payload.
+        let chunks = payload.envelope_chunks().map_err(|e| format!("{:?}", e))?;
+        assert_eq!(
+            chunks.len(),
+            payload.serialize().len().div_ceil(MAX_ENVELOPE_CHUNK_SIZE)
+        );
+        assert!(chunks
+            .iter()
+            .all(|chunk| chunk.len() <= MAX_ENVELOPE_CHUNK_SIZE));
+        assert_eq!(chunks.concat(), payload.serialize());
+
+        let envelope = payload.envelope().map_err(|e| format!("{:?}", e))?;
+        assert_eq!(
+            SessionPayload::from_tapscript(&envelope),
+            vec![payload.clone()]
+        );
+
+        // The envelope is found in a script-path spend: <sig> <tapscript> <control block>.
+        let reveal = tx_with_witness(vec![vec![0x00; 64], envelope.to_bytes(), vec![0xc0; 33]]);
+
+        // Non-envelope spends, other tags, and unterminated envelopes are skipped.
+        let other_tag = Builder::new()
+            .push_opcode(OP_FALSE)
+            .push_opcode(OP_IF)
+            .push_slice(b"ord!")
+            .push_slice([0x01; 8])
+            .into_script();
+        let unterminated = Builder::new()
+            .push_opcode(OP_FALSE)
+            .push_opcode(OP_IF)
+            .push_slice(b"cube")
+            .push_slice([0x01; 8])
+            .into_script();
+        let noise = vec![
+            tx_with_witness(vec![]),
+            tx_with_witness(vec![vec![0x00; 64]]),
+            tx_with_witness(vec![other_tag.to_bytes(), vec![0xc0; 33]]),
+            tx_with_witness(vec![unterminated.to_bytes(), vec![0xc0; 33]]),
+        ];
+
+        let mut txdata = noise.clone();
+        txdata.insert(2, reveal.clone());
+        txdata.push(reveal);
+        assert_eq!(
+            SessionPayload::from_block(&block(txdata)),
+            vec![payload.clone(), payload]
+        );
+        assert!(SessionPayload::from_block(&block(noise)).is_empty());
+
+        // Oversized payloads are rejected.
+        let oversized = SessionPayload::new(
+            [0x01; 32],
+            [0x02; 64],
+            [0x03; 64],
+            10,
+            SessionAnchor::new(100_000, 1_700_000_000),
+            0,
+            vec![0x00; MAX_SESSION_PAYLOAD_SIZE],
+        );
+        assert_eq!(
+            oversized.envelope(),
+            Err(SessionPayloadEnvelopeError::PayloadTooLarge(
+                MAX_SESSION_PAYLOAD_SIZE + SESSION_PAYLOAD_HEADER_LEN
+            ))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod price_tests {
+    use cube::inscriptive::price::market::{
+        next_base_ops_price, MIN_BASE_OPS_PRICE, SESSION_OPS_TARGET,
+    };
+
+    #[test]
+    fn next_base_ops_price_test() -> Result<(), String> {
+        // Hitting the target keeps the price the same.
+        assert_eq!(next_base_ops_price(1000, SESSION_OPS_TARGET), 1000);
+
+        // A full session (twice the target) raises the price by 12.5%.
+        assert_eq!(next_base_ops_price(1000, SESSION_OPS_TARGET * 2), 1125);
+
+        // Overspending beyond twice the target still raises the price by at most 12.5%.
+        assert_eq!(next_base_ops_price(1000, SESSION_OPS_TARGET * 10), 1125);
+
+        // An empty session lowers the price by 12.5%.
+        assert_eq!(next_base_ops_price(1000, 0), 875);
+
+        // Going over the target always raises the price, even when the change rounds to zero.
+        assert_eq!(next_base_ops_price(1, SESSION_OPS_TARGET + 1), 2);
+
+        // The price never goes below the minimum.
+        assert_eq!(
+            next_base_ops_price(MIN_BASE_OPS_PRICE, 0),
+            MIN_BASE_OPS_PRICE
+        );
+
+        // The price never overflows.
+        assert_eq!(next_base_ops_price(u32::MAX, u64::MAX), u32::MAX);
+
+        Ok(())
+    }
+}
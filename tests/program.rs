@@ -1,9 +1,10 @@
 #[cfg(test)]
 mod program_and_method_tests {
     use cube::{
-        constructive::calldata::element_type::CallElementType,
+        constructive::calldata::{element::element::CallElement, element_type::CallElementType},
         executive::{
             opcode::{
+                opcode::Opcode,
                 opcodes::{
                     flow::{op_returnall::OP_RETURNALL, op_returnerr::OP_RETURNERR},
                     push::{
@@ -11,16 +12,16 @@ mod program_and_method_tests {
                     },
                     reserved::op_reserved_1::OP_RESERVED_1,
                 },
-                opcode::Opcode,
             },
             program::{
                 compiler::compiler::ProgramCompiler,
                 method::{
                     compiler::compiler::MethodCompiler, method::ProgramMethod,
-                    method_type::MethodType,
+                    method_error::MethodConstructionError, method_type::MethodType,
                 },
                 program::Program,
             },
+            stack::stack_item::StackItem,
         },
     };
 
@@ -175,6 +176,122 @@ mod program_and_method_tests {
         Ok(())
     }
 
+    #[test]
+    fn composite_arg_types_test() -> Result<(), String> {
+        let arg_types = vec![
+            CallElementType::U128,
+            CallElementType::U256,
+            CallElementType::String,
+            CallElementType::Array(Box::new(CallElementType::Account), 2),
+            CallElementType::Vararray(Box::new(CallElementType::Bytes(3))),
+            CallElementType::Tuple(vec![CallElementType::U8, CallElementType::String]),
+        ];
+
+        let script = vec![
+            Opcode::OP_TRUE(OP_TRUE),
+            Opcode::OP_2(OP_2),
+            Opcode::OP_PUSHDATA(OP_PUSHDATA(vec![0xde, 0xad, 0xbe, 0xef])),
+            Opcode::OP_RETURNALL(OP_RETURNALL),
+        ];
+
+        // Composite arg types round trip through the method bytecode.
+        let method = ProgramMethod::new(
+            "composite".to_string(),
+            MethodType::Callable,
+            arg_types.clone(),
+            script.clone(),
+        )
+        .map_err(|e| format!("{:?}", e))?;
+        let mut bytecode_stream = method.compile().unwrap().into_iter();
+        assert_eq!(
+            ProgramMethod::decompile(&mut bytecode_stream).unwrap(),
+            method
+        );
+        assert_eq!(arg_types[3].to_string(), "Array[Account; 3]");
+        assert_eq!(arg_types[5].to_string(), "Tuple(U8, String)");
+
+        // Composites are not nested, and cannot hold payables.
+        let nested = CallElementType::Vararray(Box::new(CallElementType::Vararray(Box::new(
+            CallElementType::U8,
+        ))));
+        assert!(!nested.is_valid());
+        assert!(CallElementType::from_bytecode(&mut nested.bytecode().into_iter()).is_none());
+        assert!(CallElementType::from_bytecode(&mut vec![0x0e, 0x09].into_iter()).is_none());
+        assert!(CallElementType::from_bytecode(&mut vec![0x0f, 0x01, 0x00].into_iter()).is_none());
+        assert!(matches!(
+            ProgramMethod::new(
+                "nested".to_string(),
+                MethodType::Callable,
+                vec![nested],
+                script.clone(),
+            ),
+            Err(MethodConstructionError::ArgValidationError)
+        ));
+
+        // Composites must fit in a single stack item at their maximum length.
+        let oversized = CallElementType::Array(Box::new(CallElementType::Varbytes), 255);
+        assert_eq!(oversized.max_byte_length(), 256 * (2 + 4096));
+        assert!(!oversized.is_valid());
+        assert!(!CallElementType::Vararray(Box::new(CallElementType::String)).is_valid());
+        assert!(CallElementType::Vararray(Box::new(CallElementType::Bytes(125))).is_valid());
+        assert!(matches!(
+            ProgramMethod::new(
+                "oversized".to_string(),
+                MethodType::Callable,
+                vec![oversized],
+                script,
+            ),
+            Err(MethodConstructionError::ArgValidationError)
+        ));
+
+        // Args match their composite arg types.
+        let account = |byte: u8| CallElement::Bytes(vec![byte; 32]);
+        let args = vec![
+            CallElement::U128(u128::MAX),
+            CallElement::U256([0xff; 32]),
+            CallElement::String("cube".to_string()),
+            CallElement::Array(
+                CallElementType::Bytes(31),
+                vec![account(1), account(2), account(3)],
+            ),
+            CallElement::Vararray(
+                CallElementType::Bytes(3),
+                vec![CallElement::Bytes(vec![0xaa; 4])],
+            ),
+            CallElement::Tuple(vec![
+                CallElement::U8(0),
+                CallElement::String("ok".to_string()),
+            ]),
+        ];
+        let stack_items: Vec<StackItem> = args.iter().map(|arg| arg.into_stack_item()).collect();
+        assert!(method.match_args(&stack_items));
+
+        // Items are prefixed with their 2-byte length.
+        assert_eq!(
+            stack_items[5].bytes(),
+            &[0x00, 0x00, 0x02, 0x00, b'o', b'k']
+        );
+
+        // The array length must match.
+        let mut mismatched = stack_items.clone();
+        mismatched[3] =
+            CallElement::Array(CallElementType::Bytes(31), vec![account(1), account(2)])
+                .into_stack_item();
+        assert!(!method.match_args(&mismatched));
+
+        // Strings must be valid UTF-8.
+        let mut mismatched = stack_items.clone();
+        mismatched[2] = StackItem::new(vec![0xff]);
+        assert!(!method.match_args(&mismatched));
+
+        // Truncated composites do not match.
+        let mut mismatched = stack_items.clone();
+        mismatched[5] = StackItem::new(vec![0x00, 0x00, 0x02, 0x00, b'o']);
+        assert!(!method.match_args(&mismatched));
+
+        Ok(())
+    }
+
     #[test]
     fn program_construction_test() -> Result<(), String> {
         // A program with no methods should fail.
@@ -376,4 +493,51 @@ mod program_and_method_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn factory_contract_id_test() -> Result<(), String> {
+        let method = ProgramMethod::new(
+            "test_method".to_string(),
+            MethodType::Callable,
+            vec![CallElementType::U32],
+            vec![
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_TRUE(OP_TRUE),
+                Opcode::OP_TRUE(OP_TRUE),
+            ],
+        )
+        .unwrap();
+
+        let program = Program::new("test_program".to_string(), [0x00; 32], vec![method]).unwrap();
+
+        let deployer_1 = [0x01; 32];
+        let deployer_2 = [0x02; 32];
+        let salt_1 = [0xaa; 32];
+        let salt_2 = [0xbb; 32];
+
+        // The contract id is deterministic.
+        assert_eq!(
+            program.factory_contract_id(deployer_1, salt_1),
+            program.factory_contract_id(deployer_1, salt_1)
+        );
+
+        // The contract id commits to the deployer and the salt.
+        assert_ne!(
+            program.factory_contract_id(deployer_1, salt_1),
+            program.factory_contract_id(deployer_2, salt_1)
+        );
+        assert_ne!(
+            program.factory_contract_id(deployer_1, salt_1),
+            program.factory_contract_id(deployer_1, salt_2)
+        );
+
+        // And differs from the directly deployed contract id.
+        assert_ne!(
+            program.factory_contract_id(deployer_1, salt_1),
+            program.contract_id()
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod spv_tests {
+    use bitcoin::blockdata::constants::genesis_block;
+    use bitcoin::hashes::{sha256d, Hash};
+    use bitcoin::{consensus::serialize, merkle_tree, Network, TxMerkleNode};
+    use cube::{
+        executive::{
+            exec::caller::Caller,
+            opcode::{
+                opcodes::spv::op_checkbtcinclusion::{
+                    btc_merkle_root, OP_CHECKBTCINCLUSION, SPV_MIN_CONFIRMATIONS,
+                },
+                ops::OpsSchedule,
+            },
+            stack::{
+                stack_error::{SPVError, StackError},
+                stack_holder::StackHolder,
+                stack_item::StackItem,
+                stack_uint::{StackItemUintExt, StackUint},
+            },
+        },
+        inscriptive::rollup::dir::{RollupDirectory, ROLLUP_DIRECTORY},
+    };
+
+    /// Runs `OP_CHECKBTCINCLUSION` with the given items, anchored at the given Bitcoin height.
+    async fn check_inclusion(
+        rollup_dir: &ROLLUP_DIRECTORY,
+        bitcoin_height: u64,
+        tx: Vec<u8>,
+        branch: Vec<u8>,
+        index: u64,
+        height: u64,
+    ) -> Result<StackItem, StackError> {
+        let mut stack_holder = StackHolder::new_with_items(
+            Caller::new_account([0; 32]),
+            [0; 32],
+            0,
+            0,
+            10_000,
+            1,
+            0,
+            0,
+            OpsSchedule::latest(),
+            vec![
+                StackItem::new(tx),
+                StackItem::new(branch),
+                StackItem::from_stack_uint(StackUint::from(index)),
+                StackItem::from_stack_uint(StackUint::from(height)),
+            ],
+        )?;
+
+        OP_CHECKBTCINCLUSION::execute(&mut stack_holder, rollup_dir, bitcoin_height).await?;
+
+        stack_holder.pop()
+    }
+
+    #[test]
+    fn genesis_coinbase_inclusion_test() -> Result<(), String> {
+        let genesis = genesis_block(Network::Bitcoin);
+        let coinbase_txid = genesis.txdata[0].compute_txid().to_byte_array();
+
+        // A single-transaction block has an empty branch.
+        let root = btc_merkle_root(coinbase_txid, &[], 0);
+        assert_eq!(root, genesis.header.merkle_root.to_byte_array());
+
+        Ok(())
+    }
+
+    #[test]
+    fn btc_merkle_branch_test() -> Result<(), String> {
+        // Three leaves, the last one is duplicated at the first level.
+        let leaves: Vec<[u8; 32]> = (0u8..3)
+            .map(|i| sha256d::Hash::hash(&[i]).to_byte_array())
+            .collect();
+
+        let hash_pair = |left: &[u8; 32], right: &[u8; 32]| -> [u8; 32] {
+            let mut preimage = left.to_vec();
+            preimage.extend(right);
+            sha256d::Hash::hash(&preimage).to_byte_array()
+        };
+
+        let node_01 = hash_pair(&leaves[0], &leaves[1]);
+        let node_22 = hash_pair(&leaves[2], &leaves[2]);
+
+        let expected_root: TxMerkleNode = merkle_tree::calculate_root(
+            leaves
+                .iter()
+                .map(|leaf| TxMerkleNode::from_byte_array(*leaf)),
+        )
+        .unwrap();
+
+        // Leaf at index 1.
+        let branch_1 = [leaves[0], node_22].concat();
+        assert_eq!(
+            btc_merkle_root(leaves[1], &branch_1, 1),
+            expected_root.to_byte_array()
+        );
+
+        // Leaf at index 2.
+        let branch_2 = [leaves[2], node_01].concat();
+        assert_eq!(
+            btc_merkle_root(leaves[2], &branch_2, 2),
+            expected_root.to_byte_array()
+        );
+
+        // Wrong index fails.
+        assert_ne!(
+            btc_merkle_root(leaves[2], &branch_2, 0),
+            expected_root.to_byte_array()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn op_checkbtcinclusion_test() -> Result<(), String> {
+        let genesis = genesis_block(Network::Bitcoin);
+        let coinbase = serialize(&genesis.txdata[0]);
+        let coinbase_txid = genesis.txdata[0].compute_txid().to_byte_array().to_vec();
+
+        // The genesis block is indexed at height 100.
+        let rollup_dir = RollupDirectory::new_in_memory().ok_or("Failed to create rollup dir.")?;
+        {
+            let mut _rollup_dir = rollup_dir.lock().await;
+            _rollup_dir.insert_header(100, &genesis.header);
+        }
+
+        // The block is confirmed once buried `SPV_MIN_CONFIRMATIONS` deep.
+        let anchored_height = 100 + SPV_MIN_CONFIRMATIONS - 1;
+
+        // Both the raw transaction and its txid are checked against the header.
+        for tx in [coinbase.clone(), coinbase_txid.clone()] {
+            let result = check_inclusion(&rollup_dir, anchored_height, tx, vec![], 0, 100)
+                .await
+                .map_err(|e| format!("{:?}", e))?;
+            assert!(result.is_true());
+        }
+
+        // A different transaction is not included.
+        let result = check_inclusion(&rollup_dir, anchored_height, vec![0xaa; 32], vec![], 0, 100)
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+        assert!(!result.is_true());
+
+        // A block with one confirmation too few is rejected.
+        assert!(matches!(
+            check_inclusion(
+                &rollup_dir,
+                anchored_height - 1,
+                coinbase_txid.clone(),
+                vec![],
+                0,
+                100
+            )
+            .await,
+            Err(StackError::SPVError(SPVError::SPVHeightNotConfirmed(100)))
+        ));
+
+        // A height that overflows with the confirmations is rejected, rather than wrapping.
+        assert!(matches!(
+            check_inclusion(
+                &rollup_dir,
+                anchored_height,
+                coinbase_txid.clone(),
+                vec![],
+                0,
+                u64::MAX
+            )
+            .await,
+            Err(StackError::SPVError(SPVError::SPVHeightNotConfirmed(
+                u64::MAX
+            )))
+        ));
+
+        // A confirmed height without an indexed header is rejected.
+        assert!(matches!(
+            check_inclusion(
+                &rollup_dir,
+                anchored_height,
+                coinbase_txid.clone(),
+                vec![],
+                0,
+                99
+            )
+            .await,
+            Err(StackError::SPVError(SPVError::SPVHeaderNotFound(99)))
+        ));
+
+        // 64-byte leaves are rejected, as they can be mistaken for inner Merkle nodes.
+        assert!(matches!(
+            check_inclusion(&rollup_dir, anchored_height, vec![0xaa; 64], vec![], 0, 100).await,
+            Err(StackError::SPVError(SPVError::InvalidSPVTxBytes))
+        ));
+
+        Ok(())
+    }
+}
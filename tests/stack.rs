@@ -1,11 +1,15 @@
 #[cfg(test)]
 mod stack_tests {
     use cube::executive::{
-        exec::caller::Caller,
+        exec::{caller::Caller, transient::transient::TransientStorage},
         opcode::opcodes::{
             altstack::{op_fromaltstack::OP_FROMALTSTACK, op_toaltstack::OP_TOALTSTACK},
             arithmetic::op_add::OP_ADD,
             bitwise::op_equalverify::OP_EQUALVERIFY,
+            digest::{
+                op_keccak256::OP_KECCAK256,
+                op_merkleverify::{MerkleHashMode, OP_MERKLEVERIFY},
+            },
             flow::{
                 op_else::OP_ELSE, op_endif::OP_ENDIF, op_if::OP_IF, op_returnerr::OP_RETURNERR,
                 op_verify::OP_VERIFY,
@@ -14,8 +18,11 @@ mod stack_tests {
                 op_2::OP_2, op_3::OP_3, op_4::OP_4, op_5::OP_5, op_6::OP_6, op_7::OP_7, op_8::OP_8,
                 op_false::OP_FALSE, op_true::OP_TRUE,
             },
+            signature::{op_checkecdsasig::OP_CHECKECDSASIG, op_ecdsarecover::OP_ECDSARECOVER},
             splice::op_cat::OP_CAT,
+            transient::{op_tread::OP_TREAD, op_twrite::OP_TWRITE},
         },
+        opcode::ops::{OpsSchedule, OPS_SCHEDULE_V1},
         stack::{
             stack::Stack,
             stack_error::StackError,
@@ -24,6 +31,11 @@ mod stack_tests {
             stack_uint::{StackItemUintExt, StackUint},
         },
     };
+    use cube::transmutative::{
+        hash::{Hash, HashTag},
+        secp::ecdsa,
+    };
+    use secp::{Point, Scalar};
 
     #[test]
     fn stack_test() -> Result<(), StackError> {
@@ -42,6 +54,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
         )?;
 
         // Push 0xdeadbeef
@@ -91,6 +104,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
         )?;
 
         // Test 0 + 1 = 1;
@@ -203,6 +217,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
             vec![StackItem::true_item()],
         )?;
 
@@ -248,6 +263,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
             vec![StackItem::true_item()],
         )?;
 
@@ -315,6 +331,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
             vec![StackItem::true_item()],
         )?;
 
@@ -397,6 +414,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
             vec![StackItem::false_item()],
         )?;
 
@@ -482,6 +500,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
             vec![StackItem::false_item()],
         )?;
 
@@ -530,6 +549,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
             vec![StackItem::false_item()],
         )?;
 
@@ -583,6 +603,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
             vec![StackItem::true_item()],
         )?;
 
@@ -636,6 +657,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
             vec![StackItem::true_item()],
         )?;
 
@@ -694,6 +716,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
             vec![StackItem::true_item()],
         )?;
 
@@ -733,6 +756,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
             vec![StackItem::false_item()],
         )?;
 
@@ -785,6 +809,7 @@ mod stack_tests {
             1,
             internal_ops_counter,
             external_ops_counter,
+            OpsSchedule::latest(),
             vec![StackItem::false_item()],
         )?;
 
@@ -834,4 +859,368 @@ mod stack_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn keccak_ecdsa_test() -> Result<(), StackError> {
+        let internal_ops_counter = 0;
+        let external_ops_counter = 0;
+
+        let timestamp = 1715619200;
+
+        // Sign a message with the well-known Ethereum test key.
+        let message = [0xaa; 32];
+        let signature = ecdsa::sign_recoverable([0x46; 32], message).unwrap();
+
+        // Initialize stack with the signature and the message.
+        let mut stack_holder = StackHolder::new_with_items(
+            Caller::new_account([0; 32]),
+            [0; 32],
+            timestamp,
+            0,
+            1000,
+            1,
+            internal_ops_counter,
+            external_ops_counter,
+            OpsSchedule::latest(),
+            vec![
+                StackItem::new(signature.to_vec()),
+                StackItem::new(message.to_vec()),
+            ],
+        )?;
+
+        // OP_ECDSARECOVER
+        OP_ECDSARECOVER::execute(&mut stack_holder)?;
+
+        // Recovered key is the 65-byte uncompressed key.
+        let public_key = stack_holder.pop()?;
+        assert_eq!(public_key.len(), 65);
+
+        // Push the compact signature, the message and the recovered key.
+        stack_holder.push(StackItem::new(signature[..64].to_vec()))?;
+        stack_holder.push(StackItem::new(message.to_vec()))?;
+        stack_holder.push(public_key.clone())?;
+
+        // OP_CHECKECDSASIG
+        OP_CHECKECDSASIG::execute(&mut stack_holder)?;
+
+        // OP_VERIFY
+        OP_VERIFY::execute(&mut stack_holder)?;
+
+        // Derive the Ethereum address from the recovered key.
+        stack_holder.push(StackItem::new(public_key.bytes()[1..].to_vec()))?;
+
+        // OP_KECCAK256
+        OP_KECCAK256::execute(&mut stack_holder)?;
+
+        let hash = stack_holder.pop()?;
+        assert_eq!(
+            hex::encode(&hash.bytes()[12..]),
+            "9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f"
+        );
+
+        // Ops: 150 (recover) + 100 (check) + 1 (verify) + 42 (keccak).
+        assert_eq!(stack_holder.internal_ops_counter(), 293);
+
+        // Stack must be empty.
+        assert_eq!(stack_holder.stack_items_count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn merkle_verify_test() -> Result<(), StackError> {
+        let timestamp = 1715619200;
+
+        // Mode items: SHA-256, BLAKE2b-256 and tagged hash.
+        let mode_items = vec![
+            StackItem::false_item(),
+            StackItem::new(vec![0x01]),
+            StackItem::new([vec![0x02], b"Airdrop/node".to_vec()].concat()),
+        ];
+
+        for mode_item in mode_items {
+            let mode = MerkleHashMode::from_item(&mode_item).unwrap();
+
+            // Build a 4-leaf tree.
+            let leaves: Vec<[u8; 32]> = (0..4u8).map(|i| [i; 32]).collect();
+            let n01 = mode.hash_nodes(&leaves[0], &leaves[1]);
+            let n23 = mode.hash_nodes(&leaves[2], &leaves[3]);
+            let root = mode.hash_nodes(&n01, &n23);
+
+            for index in 0..4usize {
+                // Sibling at the leaf level and at the next level.
+                let leaf_sibling = leaves[index ^ 1];
+                let node_sibling = if index < 2 { n23 } else { n01 };
+                let path = [leaf_sibling, node_sibling].concat();
+
+                for (root, expected) in [(root, true), ([0xff; 32], false)] {
+                    let mut stack_holder = StackHolder::new_with_items(
+                        Caller::new_account([0; 32]),
+                        [0; 32],
+                        timestamp,
+                        0,
+                        1000,
+                        1,
+                        0,
+                        0,
+                        OpsSchedule::latest(),
+                        vec![
+                            StackItem::new(leaves[index].to_vec()),
+                            StackItem::new(path.clone()),
+                            StackItem::from_stack_uint(StackUint::from(index)),
+                            StackItem::new(root.to_vec()),
+                            mode_item.clone(),
+                        ],
+                    )?;
+
+                    // OP_MERKLEVERIFY
+                    OP_MERKLEVERIFY::execute(&mut stack_holder)?;
+
+                    // Check the result.
+                    assert_eq!(stack_holder.pop()?.is_true(), expected);
+
+                    // Ops: 10 + (42 * 2).
+                    assert_eq!(stack_holder.internal_ops_counter(), 94);
+                }
+            }
+        }
+
+        // Index out of range for a depth-1 proof.
+        let mut stack_holder = StackHolder::new_with_items(
+            Caller::new_account([0; 32]),
+            [0; 32],
+            timestamp,
+            0,
+            1000,
+            1,
+            0,
+            0,
+            OpsSchedule::latest(),
+            vec![
+                StackItem::new(vec![0x00; 32]),
+                StackItem::new(vec![0x01; 32]),
+                StackItem::from_stack_uint(StackUint::from(2)),
+                StackItem::new(vec![0x02; 32]),
+                StackItem::false_item(),
+            ],
+        )?;
+        assert!(OP_MERKLEVERIFY::execute(&mut stack_holder).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn merkle_verify_taproot_vector_test() -> Result<(), StackError> {
+        // A Taproot script path spend from Bitcoin Core's feature_taproot.py: the output key, the
+        // leaf script and the control block (leaf version, internal key, then the merkle path).
+        let output_key = "5dc8e62b15e0ebdf44751676be35ba32eed2e84608b290d4061bbff136cd7ba9";
+        let script = hex::decode("6a").unwrap();
+        let control_block = hex::decode("c1a9d6f66cd4b25004f526bfa873e56942f98e8e492bd79ed6532b966104817c2bda584e7d32612381cf88edc1c02e28a296e807c16ad22f591ee113946e48a71e0641e660d1e5392fb79d64838c2b84faf04b7f5f283c9d8bf83e39e177b64372a0cd22eeab7e093873e851e247714eff762d8a30be699ba4456cfe6491b282e193a071350ae099005a5950d74f73ba13077a57bc478007fb0e4d1099ce9cf3d4").unwrap();
+        let internal_key: [u8; 32] = control_block[1..33].try_into().unwrap();
+        let path = control_block[33..].to_vec();
+
+        // The leaf is the TapLeaf hash of the leaf version and the script.
+        let leaf = [vec![control_block[0] & 0xfe, script.len() as u8], script]
+            .concat()
+            .hash(Some(HashTag::CustomBytes(b"TapLeaf".to_vec())));
+
+        // The merkle root, which tweaks the internal key into the output key.
+        let root = hex::decode("d3f76026af7aba068ec6db7664a2cdd9e746dd2569d72c719faaedec652ac6cc")
+            .unwrap();
+        let tweak = [internal_key.to_vec(), root.clone()]
+            .concat()
+            .hash(Some(HashTag::CustomBytes(b"TapTweak".to_vec())));
+        let tweaked_key = Point::lift_x(&internal_key).unwrap()
+            + Scalar::from_slice(&tweak).unwrap().base_point_mul();
+        assert_eq!(hex::encode(tweaked_key.serialize_xonly()), output_key);
+
+        // TapBranch orders each pair of nodes, which the index bits encode here.
+        for (index, expected) in [(6, true), (7, false)] {
+            let mut stack_holder = StackHolder::new_with_items(
+                Caller::new_account([0; 32]),
+                [0; 32],
+                1715619200,
+                0,
+                1000,
+                1,
+                0,
+                0,
+                OpsSchedule::latest(),
+                vec![
+                    StackItem::new(leaf.to_vec()),
+                    StackItem::new(path.clone()),
+                    StackItem::from_stack_uint(StackUint::from(index)),
+                    StackItem::new(root.clone()),
+                    StackItem::new([vec![0x02], b"TapBranch".to_vec()].concat()),
+                ],
+            )?;
+
+            // OP_MERKLEVERIFY
+            OP_MERKLEVERIFY::execute(&mut stack_holder)?;
+
+            // Check the result.
+            assert_eq!(stack_holder.pop()?.is_true(), expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn transient_storage_test() -> Result<(), StackError> {
+        let timestamp = 1715619200;
+
+        let mut transient_storage = TransientStorage::new();
+
+        // First call frame writes a reentrancy lock.
+        let mut stack_holder = StackHolder::new_with_items(
+            Caller::new_account([0; 32]),
+            [0; 32],
+            timestamp,
+            0,
+            1000,
+            1,
+            0,
+            0,
+            OpsSchedule::latest(),
+            vec![StackItem::true_item(), StackItem::new(b"lock".to_vec())],
+        )?;
+        OP_TWRITE::execute(&mut stack_holder, &mut transient_storage)?;
+
+        // 20 base ops + 1 key + 4 value bytes.
+        assert_eq!(stack_holder.internal_ops_counter(), 25);
+
+        // A separate call frame of the same contract sees the lock.
+        let mut stack_holder = StackHolder::new_with_items(
+            Caller::new_account([0; 32]),
+            [0; 32],
+            timestamp,
+            0,
+            1000,
+            1,
+            0,
+            0,
+            OpsSchedule::latest(),
+            vec![StackItem::new(b"lock".to_vec())],
+        )?;
+        OP_TREAD::execute(&mut stack_holder, &transient_storage)?;
+        assert!(stack_holder.pop()?.is_true());
+
+        // Another contract does not.
+        let mut stack_holder = StackHolder::new_with_items(
+            Caller::new_account([0; 32]),
+            [1; 32],
+            timestamp,
+            0,
+            1000,
+            1,
+            0,
+            0,
+            OpsSchedule::latest(),
+            vec![StackItem::new(b"lock".to_vec())],
+        )?;
+        OP_TREAD::execute(&mut stack_holder, &transient_storage)?;
+        assert!(!stack_holder.pop()?.is_true());
+
+        // Writes within a reverted savepoint are discarded.
+        transient_storage.push_savepoint();
+        transient_storage.insert_value(&[0; 32], &b"lock".to_vec(), &vec![0x02]);
+        transient_storage.revert_to_savepoint();
+        assert_eq!(
+            transient_storage.get_value(&b"lock".to_vec(), &[0; 32]),
+            Some(vec![0x01])
+        );
+
+        // Clearing empties the storage.
+        transient_storage.clear();
+        assert_eq!(
+            transient_storage.get_value(&b"lock".to_vec(), &[0; 32]),
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn ops_schedule_test() -> Result<(), StackError> {
+        // The first schedule is active from the genesis session.
+        assert_eq!(OpsSchedule::at_height(0).version, 1);
+        assert_eq!(OpsSchedule::by_version(1), Some(OpsSchedule::at_height(0)));
+        assert_eq!(OpsSchedule::at_height(u64::MAX), OpsSchedule::latest());
+
+        // Opcodes charge the costs of the schedule the stack holder runs with.
+        let ops_schedule = OpsSchedule::at_height(0);
+
+        let mut stack_holder = StackHolder::new_with_items(
+            Caller::new_account([0; 32]),
+            [0; 32],
+            0,
+            0,
+            1000,
+            1,
+            0,
+            0,
+            ops_schedule,
+            vec![
+                StackItem::from_stack_uint(StackUint::from(2)),
+                StackItem::from_stack_uint(StackUint::from(3)),
+            ],
+        )?;
+
+        OP_ADD::execute(&mut stack_holder)?;
+
+        assert_eq!(stack_holder.internal_ops_counter(), ops_schedule.op_add);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ops_schedule_fork_test() -> Result<(), StackError> {
+        // A re-tuned schedule activating at session height 100.
+        static SCHEDULES: [OpsSchedule; 2] = [
+            OPS_SCHEDULE_V1,
+            OpsSchedule {
+                version: 2,
+                activation_height: 100,
+                op_add: OPS_SCHEDULE_V1.op_add * 2,
+                ..OPS_SCHEDULE_V1
+            },
+        ];
+
+        let schedules = &SCHEDULES;
+
+        // The first schedule stays active right up to the fork boundary.
+        assert_eq!(OpsSchedule::active_in(schedules, 0).version, 1);
+        assert_eq!(OpsSchedule::active_in(schedules, 99).version, 1);
+
+        // The second schedule activates exactly at the fork boundary.
+        assert_eq!(OpsSchedule::active_in(schedules, 100).version, 2);
+        assert_eq!(OpsSchedule::active_in(schedules, u64::MAX).version, 2);
+
+        // Calls on either side of the fork are charged with their own schedule.
+        for (session_height, expected_ops) in
+            [(99, OPS_SCHEDULE_V1.op_add), (100, SCHEDULES[1].op_add)]
+        {
+            let mut stack_holder = StackHolder::new_with_items(
+                Caller::new_account([0; 32]),
+                [0; 32],
+                0,
+                0,
+                1000,
+                1,
+                0,
+                0,
+                OpsSchedule::active_in(schedules, session_height),
+                vec![
+                    StackItem::from_stack_uint(StackUint::from(2)),
+                    StackItem::from_stack_uint(StackUint::from(3)),
+                ],
+            )?;
+
+            OP_ADD::execute(&mut stack_holder)?;
+
+            assert_eq!(stack_holder.internal_ops_counter(), expected_ops);
+        }
+
+        Ok(())
+    }
 }